@@ -0,0 +1,112 @@
+//! Compares the two ways of converting strings between Rust and Java: through Modified UTF-8
+//! (`NewStringUTF` and `GetStringUTFChars`, which duchess uses) and through UTF-16
+//! (`NewString` and `GetStringRegion`, JNI's "wide-char" functions).
+//!
+//! Run with `cargo bench --bench string_conversions`. Without `--bench` (e.g. under
+//! `cargo test --benches`), each case runs once, checking that both paths agree.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use duchess::plumbing::{check_exception, HasEnvPtr, JavaObjectExt, ObjectPtr};
+use duchess::{java, prelude::*, Error, Jvm, Local};
+
+const ITERATIONS: u32 = 10_000;
+
+fn new_string_utf16<'jvm>(
+    jvm: &mut Jvm<'jvm>,
+    text: &str,
+) -> duchess::Result<'jvm, Local<'jvm, java::lang::String>> {
+    let units: Vec<u16> = text.encode_utf16().collect();
+    let env = jvm.env();
+    // SAFETY: `units` points to `units.len()` UTF-16 code units.
+    let string = unsafe {
+        env.invoke(
+            |env| env.NewString,
+            |env, f| f(env, units.as_ptr(), units.len() as i32),
+        )
+    };
+    match ObjectPtr::new(string) {
+        // SAFETY: `string` is a new local reference to a `java.lang.String`.
+        Some(string) => Ok(unsafe { Local::from_raw(env, string) }),
+        None => {
+            check_exception(jvm)?;
+            Err(Error::JvmInternal("JVM failed to create new String".into()))
+        }
+    }
+}
+
+fn into_rust_utf16<'jvm>(
+    jvm: &mut Jvm<'jvm>,
+    string: &java::lang::String,
+) -> duchess::Result<'jvm, String> {
+    let raw = string.as_raw();
+    let env = jvm.env();
+    // SAFETY: `raw` points to a non-null `java.lang.String`.
+    let len = unsafe { env.invoke(|env| env.GetStringLength, |env, f| f(env, raw.as_ptr())) };
+    let mut units = vec![0u16; len as usize];
+    // SAFETY: `units` has room for the `len` code units of the string.
+    unsafe {
+        env.invoke(
+            |env| env.GetStringRegion,
+            |env, f| f(env, raw.as_ptr(), 0, len, units.as_mut_ptr()),
+        )
+    };
+    check_exception(jvm)?;
+    String::from_utf16(&units)
+        .map_err(|e| Error::JvmInternal(format!("Java String contained invalid UTF-16: {e}")))
+}
+
+fn bench<'jvm>(
+    name: &str,
+    iterations: u32,
+    mut f: impl FnMut() -> duchess::Result<'jvm, ()>,
+) -> duchess::Result<'jvm, ()> {
+    f()?; // warm up
+    let start = Instant::now();
+    for _ in 0..iterations {
+        f()?;
+    }
+    let per_iteration = start.elapsed() / iterations;
+    println!("{name:<32} {per_iteration:>10.2?}/iter");
+    Ok(())
+}
+
+fn main() -> duchess::GlobalResult<()> {
+    let iterations = if std::env::args().any(|arg| arg == "--bench") {
+        ITERATIONS
+    } else {
+        1
+    };
+
+    let inputs = [
+        ("ascii", "duchess ".repeat(128)),
+        ("non-ascii", "crab 🦀 ".repeat(128)),
+    ];
+    for (name, text) in &inputs {
+        Jvm::with(|jvm| {
+            let utf8 = text.as_str().execute_with(jvm)?;
+            let utf16 = new_string_utf16(jvm, text)?;
+            assert_eq!(&IntoRust::<String>::into_rust(&*utf16, jvm)?, text);
+            assert_eq!(&into_rust_utf16(jvm, &utf8)?, text);
+
+            bench(&format!("to_java/{name}/utf8"), iterations, || {
+                black_box(text.as_str().execute_with(jvm)?);
+                Ok(())
+            })?;
+            bench(&format!("to_java/{name}/utf16"), iterations, || {
+                black_box(new_string_utf16(jvm, text)?);
+                Ok(())
+            })?;
+            bench(&format!("to_rust/{name}/utf8"), iterations, || {
+                black_box(IntoRust::<String>::into_rust(&*utf8, jvm)?);
+                Ok(())
+            })?;
+            bench(&format!("to_rust/{name}/utf16"), iterations, || {
+                black_box(into_rust_utf16(jvm, &utf8)?);
+                Ok(())
+            })
+        })?;
+    }
+    Ok(())
+}
@@ -0,0 +1,94 @@
+//! Without the `dylibjvm` feature, libjvm is linked when the crate is built, and a JDK
+//! that doesn't match the target (32- vs 64-bit, glibc vs musl) only shows up later as an
+//! opaque loader error. Check the JDK in `JAVA_HOME` up front and explain any mismatch.
+
+use std::path::{Path, PathBuf};
+
+#[allow(dead_code)]
+#[path = "src/libjvm/probe.rs"]
+mod probe;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/libjvm/probe.rs");
+    println!("cargo:rerun-if-env-changed=JAVA_HOME");
+
+    if std::env::var_os("CARGO_FEATURE_DYLIBJVM").is_some() {
+        // libjvm is located and loaded at runtime, where failures are diagnosed instead.
+        return;
+    }
+
+    let Some(java_home) = std::env::var_os("JAVA_HOME") else {
+        println!(
+            "cargo:warning=duchess: `JAVA_HOME` is not set, so the libjvm that will be linked could not be checked against the target"
+        );
+        return;
+    };
+
+    let Some(libjvm) = find_libjvm(Path::new(&java_home)) else {
+        println!(
+            "cargo:warning=duchess: no libjvm found under `JAVA_HOME` ({}); is it a JDK installation?",
+            Path::new(&java_home).display()
+        );
+        return;
+    };
+
+    let Some(target) = probe::Target::from_cargo_env() else {
+        return;
+    };
+
+    let info = match probe::probe_path(&libjvm) {
+        Ok(info) => info,
+        Err(e) => {
+            println!(
+                "cargo:warning=duchess: unable to inspect `{}`: {e}",
+                libjvm.display()
+            );
+            return;
+        }
+    };
+
+    let problems = probe::mismatches(&info, &target);
+    if !problems.is_empty() {
+        let mut message = format!(
+            "`{}` ({info}) cannot be linked into a build for `{target}`:",
+            libjvm.display()
+        );
+        for problem in problems {
+            message.push_str("\n  - ");
+            message.push_str(&problem);
+        }
+        panic!("{message}");
+    }
+}
+
+/// Looks for libjvm in the places JDKs put it, across versions and platforms.
+fn find_libjvm(java_home: &Path) -> Option<PathBuf> {
+    let file_names = ["libjvm.so", "libjvm.dylib", "jvm.dll"];
+    let dirs = [
+        "lib/server",
+        "jre/lib/server",
+        "bin/server",
+        "jre/bin/server",
+        "lib/client",
+        "jre/bin/client",
+    ];
+
+    let mut candidates: Vec<PathBuf> = dirs
+        .iter()
+        .flat_map(|dir| {
+            file_names
+                .iter()
+                .map(move |name| java_home.join(dir).join(name))
+        })
+        .collect();
+
+    // Java 8 puts libjvm under an architecture-specific directory (e.g., `jre/lib/amd64/server`).
+    if let Ok(entries) = std::fs::read_dir(java_home.join("jre/lib")) {
+        for entry in entries.flatten() {
+            candidates.push(entry.path().join("server").join("libjvm.so"));
+        }
+    }
+
+    candidates.into_iter().find(|c| c.is_file())
+}
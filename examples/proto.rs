@@ -0,0 +1,71 @@
+//! Treats the (protobuf-like) classes in the Java `proto` package as value types:
+//! the methods of `service.Greeter` take and return the Rust structs in `messages`,
+//! and the Java `Person`/`Greeting` objects never escape into user code.
+
+use duchess::prelude::*;
+
+duchess::java_package! {
+    convert proto.* => crate::messages::*;
+
+    package proto;
+
+    class Person { * }
+    class Greeting { * }
+
+    package service;
+
+    class Greeter { * }
+}
+
+mod messages {
+    use crate::proto;
+    use duchess::java;
+
+    #[derive(Debug, PartialEq, duchess::ToRust, duchess::ToJava)]
+    #[java(proto.Person)]
+    pub struct Person {
+        pub name: String,
+        pub city: String,
+    }
+
+    #[derive(Debug, PartialEq, duchess::ToRust)]
+    #[java(proto.Greeting)]
+    pub struct Greeting {
+        pub text: String,
+    }
+}
+
+#[test]
+fn round_trip() -> duchess::GlobalResult<()> {
+    let greeter = service::Greeter::new().global().execute()?;
+    let person = messages::Person {
+        name: "Ferris".into(),
+        city: "Paris".into(),
+    };
+
+    let greeting: Option<messages::Greeting> = greeter.greet(&person).execute()?;
+    assert_eq!(
+        greeting,
+        Some(messages::Greeting {
+            text: "Hello, Ferris from Paris!".into()
+        })
+    );
+
+    let moved = greeter.relocate(&person, "Lyon").execute()?;
+    assert_eq!(moved.map(|p| p.city), Some("Lyon".to_string()));
+
+    assert_eq!(service::Greeter::nobody().execute()?, None);
+    Ok(())
+}
+
+fn main() -> duchess::GlobalResult<()> {
+    let greeter = service::Greeter::new().global().execute()?;
+    let person = messages::Person {
+        name: "Ferris".into(),
+        city: "Paris".into(),
+    };
+    if let Some(greeting) = greeter.greet(&person).execute()? {
+        println!("{}", greeting.text);
+    }
+    Ok(())
+}
@@ -0,0 +1,19 @@
+public class DefaultPackageGreeter {
+    private final String name;
+
+    public DefaultPackageGreeter(String name) {
+        this.name = name;
+    }
+
+    public static DefaultPackageGreeter forWorld() {
+        return new DefaultPackageGreeter("World");
+    }
+
+    public DefaultPackageGreeting greeting() {
+        return new DefaultPackageGreeting("Hello, " + name);
+    }
+
+    public DefaultPackageGreeter combine(DefaultPackageGreeter other) {
+        return new DefaultPackageGreeter(name + " and " + other.name);
+    }
+}
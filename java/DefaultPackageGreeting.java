@@ -0,0 +1,11 @@
+public class DefaultPackageGreeting {
+    private final String text;
+
+    public DefaultPackageGreeting(String text) {
+        this.text = text;
+    }
+
+    public String text() {
+        return text;
+    }
+}
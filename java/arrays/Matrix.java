@@ -0,0 +1,33 @@
+package arrays;
+
+public class Matrix {
+    public static int[][] identity(int n) {
+        int[][] result = new int[n][n];
+        for (int i = 0; i < n; i++) {
+            result[i][i] = 1;
+        }
+        return result;
+    }
+
+    public static int sum(int[][] rows) {
+        int total = 0;
+        for (int[] row : rows) {
+            for (int x : row) {
+                total += x;
+            }
+        }
+        return total;
+    }
+
+    public static String[][] words() {
+        return new String[][] { { "a", "b" }, { "c" } };
+    }
+
+    public static String join(String[][] rows) {
+        StringBuilder sb = new StringBuilder();
+        for (String[] row : rows) {
+            sb.append(String.join(",", row)).append(";");
+        }
+        return sb.toString();
+    }
+}
@@ -0,0 +1,42 @@
+package beans;
+
+public class Customer {
+    private String name;
+    private int age;
+    private boolean active;
+
+    public Customer(String name, int age) {
+        this.name = name;
+        this.age = age;
+        this.active = true;
+    }
+
+    public static Customer of(String name, int age) {
+        return new Customer(name, age);
+    }
+
+    public String getName() {
+        return name;
+    }
+
+    public int getAge() {
+        return age;
+    }
+
+    public boolean isActive() {
+        return active;
+    }
+
+    public void setName(String name) {
+        this.name = name;
+    }
+
+    public void deactivate() {
+        this.active = false;
+    }
+
+    @Override
+    public String toString() {
+        return "Customer(" + name + ")";
+    }
+}
@@ -0,0 +1,24 @@
+package builders;
+
+/** A class with a long constructor, compiled with `javac -parameters`. */
+public class Connection {
+    private final String description;
+
+    public Connection(
+            String host,
+            int port,
+            String user,
+            String password,
+            int timeoutMillis,
+            boolean secure,
+            int retries,
+            String database) {
+        this.description = String.format(
+                "%s@%s:%d/%s timeout=%d secure=%b retries=%d password=%b",
+                user, host, port, database, timeoutMillis, secure, retries, !password.isEmpty());
+    }
+
+    public String describe() {
+        return description;
+    }
+}
@@ -0,0 +1,14 @@
+package builders;
+
+/** A class with a long constructor, compiled without `-parameters`. */
+public class Endpoint {
+    private final String url;
+
+    public Endpoint(String scheme, String host, int port, String path, String query, String fragment) {
+        this.url = scheme + "://" + host + ":" + port + path + "?" + query + "#" + fragment;
+    }
+
+    public String url() {
+        return url;
+    }
+}
@@ -0,0 +1,26 @@
+package clients;
+
+/** Has constructors taking either `Client`, whose parameter types have the same simple name. */
+public class Connection {
+    private final String description;
+
+    public Connection() {
+        this.description = "none";
+    }
+
+    public Connection(clients.foo.Client client) {
+        this.description = client.service();
+    }
+
+    public Connection(clients.bar.Client client) {
+        this.description = client.service();
+    }
+
+    public Connection(String host, int port) {
+        this.description = host + ":" + port;
+    }
+
+    public String describe() {
+        return description;
+    }
+}
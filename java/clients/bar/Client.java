@@ -0,0 +1,9 @@
+package clients.bar;
+
+public class Client {
+    public Client() {}
+
+    public String service() {
+        return "bar";
+    }
+}
@@ -0,0 +1,8 @@
+package clients.bar;
+
+/** Refers to `Client` of its own package, whose struct is renamed by the package prefix. */
+public class Registry {
+    public static Client primary() {
+        return new Client();
+    }
+}
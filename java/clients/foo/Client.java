@@ -0,0 +1,9 @@
+package clients.foo;
+
+public class Client {
+    public Client() {}
+
+    public String service() {
+        return "foo";
+    }
+}
@@ -0,0 +1,7 @@
+package colors;
+
+public enum Color {
+    RED,
+    GREEN,
+    BLUE;
+}
@@ -0,0 +1,13 @@
+package colors;
+
+import java.util.EnumSet;
+
+public class Palette {
+    public static <E extends Enum<E>> String none(Class<E> kind) {
+        return EnumSet.noneOf(kind).toString();
+    }
+
+    public static <E extends Enum<E>> String all(Class<E> kind) {
+        return EnumSet.allOf(kind).toString();
+    }
+}
@@ -0,0 +1,17 @@
+package constants;
+
+/** A class with constants, like those of a wire protocol. */
+public class Protocol {
+    public static final int VERSION = 3;
+    public static final long MAX_LENGTH = 9223372036854775807L;
+    public static final short PORT = -1;
+    public static final byte FLAGS = 0x7f;
+    public static final double TIMEOUT_SECONDS = 1.5;
+    public static final float RETRY_FACTOR = Float.POSITIVE_INFINITY;
+    public static final boolean COMPRESSED = true;
+    public static final char SEPARATOR = ';';
+    public static final String GREETING = "hello \"world\"\né";
+
+    /** Computed in the static initializer, so not in the class file. */
+    public static final int STARTED = Integer.parseInt("42");
+}
@@ -0,0 +1,29 @@
+package fields;
+
+/** A class that exposes its state through public fields, like `java.awt.Point`. */
+public class Point {
+    public static final Point ORIGIN = new Point(0, 0, "origin");
+    public static int created;
+
+    public int x;
+    public int y;
+    public String label;
+    public boolean visible = true;
+    private int secret = 42;
+
+    public Point(int x, int y, String label) {
+        this.x = x;
+        this.y = y;
+        this.label = label;
+        created++;
+    }
+
+    public double getX() {
+        return x;
+    }
+
+    public void moveBy(int dx, int dy) {
+        x += dx;
+        y += dy;
+    }
+}
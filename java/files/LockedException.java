@@ -0,0 +1,7 @@
+package files;
+
+public class LockedException extends StoreException {
+    public LockedException(String name) {
+        super(name + " is locked");
+    }
+}
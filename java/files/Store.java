@@ -0,0 +1,19 @@
+package files;
+
+import java.io.FileNotFoundException;
+import java.io.IOException;
+
+public class Store {
+    public static String read(String name) throws LockedException, IOException {
+        switch (name) {
+            case "missing":
+                throw new FileNotFoundException(name);
+            case "locked":
+                throw new LockedException(name);
+            case "":
+                throw new IllegalArgumentException("empty name");
+            default:
+                return "contents of " + name;
+        }
+    }
+}
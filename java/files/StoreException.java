@@ -0,0 +1,9 @@
+package files;
+
+import java.io.IOException;
+
+public class StoreException extends IOException {
+    public StoreException(String message) {
+        super(message);
+    }
+}
@@ -0,0 +1,14 @@
+package generics;
+
+/** A class whose type parameter has a bound, which its erased descriptors use. */
+public class Gauge<T extends Number> {
+    private final T value;
+
+    public Gauge(T value) {
+        this.value = value;
+    }
+
+    public T value() {
+        return value;
+    }
+}
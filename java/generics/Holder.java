@@ -0,0 +1,31 @@
+package generics;
+
+import java.util.List;
+
+/** Methods that declare type parameters of their own, besides the class's `T`. */
+public class Holder<T> {
+    private final T value;
+
+    public Holder(T value) {
+        this.value = value;
+    }
+
+    public T get() {
+        return value;
+    }
+
+    /** This `T` shadows the class's. */
+    public <T> T echo(T value) {
+        return value;
+    }
+
+    /** Named like type parameters that duchess uses in generated code. */
+    public <J, N> J first(List<J> values, N unused) {
+        return values.get(0);
+    }
+
+    /** The descriptor of this method uses `Number`, the erasure of `U`. */
+    public static <U extends Number> U larger(U a, U b) {
+        return a.doubleValue() >= b.doubleValue() ? a : b;
+    }
+}
@@ -0,0 +1,28 @@
+package generics;
+
+/** A generic class with static generic methods. */
+public class Pair<A, B> {
+    private final A first;
+    private final B second;
+
+    private Pair(A first, B second) {
+        this.first = first;
+        this.second = second;
+    }
+
+    public static <A, B> Pair<A, B> of(A first, B second) {
+        return new Pair<>(first, second);
+    }
+
+    public static <A, B> Pair<B, A> swap(Pair<A, B> pair) {
+        return new Pair<>(pair.second, pair.first);
+    }
+
+    public A first() {
+        return first;
+    }
+
+    public B second() {
+        return second;
+    }
+}
@@ -0,0 +1,28 @@
+package generics;
+
+import java.util.ArrayList;
+import java.util.List;
+
+/** Members whose types contain wildcards in output position. */
+public class Shelf {
+    public List<? super Integer> sink = new ArrayList<Number>();
+
+    public Shelf() {
+        sink.add(42);
+    }
+
+    public ArrayList<? extends Number> numbers() {
+        ArrayList<Integer> numbers = new ArrayList<>();
+        numbers.add(1);
+        numbers.add(2);
+        return numbers;
+    }
+
+    public List<?> anything() {
+        return List.of("something");
+    }
+
+    public static Class<? extends Number> numberClass() {
+        return Integer.class;
+    }
+}
@@ -0,0 +1,11 @@
+package hashing;
+
+public class Unhashable {
+    public Unhashable() {
+    }
+
+    @Override
+    public int hashCode() {
+        throw new IllegalStateException("no hash");
+    }
+}
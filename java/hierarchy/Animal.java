@@ -0,0 +1,13 @@
+package hierarchy;
+
+public class Animal implements Named {
+    private final String name;
+
+    public Animal(String name) {
+        this.name = name;
+    }
+
+    public String name() {
+        return name;
+    }
+}
@@ -0,0 +1,11 @@
+package hierarchy;
+
+public class Dog extends Animal {
+    public Dog(String name) {
+        super(name);
+    }
+
+    public String bark() {
+        return name() + " says woof";
+    }
+}
@@ -0,0 +1,14 @@
+package inheritance;
+
+public class Base {
+    public Base() {
+    }
+
+    public String name() {
+        return "base";
+    }
+
+    public Base copy() {
+        return new Base();
+    }
+}
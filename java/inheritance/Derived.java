@@ -0,0 +1,15 @@
+package inheritance;
+
+public class Derived extends Base {
+    public Derived() {
+    }
+
+    // Overrides `Base.copy()` with a covariant return type.
+    public Derived copy() {
+        return new Derived();
+    }
+
+    public int generation() {
+        return 2;
+    }
+}
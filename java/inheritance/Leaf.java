@@ -0,0 +1,6 @@
+package inheritance;
+
+public class Leaf extends Derived {
+    public Leaf() {
+    }
+}
@@ -0,0 +1,29 @@
+package iterables;
+
+import java.util.Iterator;
+import java.util.NoSuchElementException;
+
+public class Countdown implements Iterable<Integer> {
+    private final int from;
+
+    public Countdown(int from) {
+        this.from = from;
+    }
+
+    public Iterator<Integer> iterator() {
+        return new Iterator<Integer>() {
+            private int next = from;
+
+            public boolean hasNext() {
+                return next > 0;
+            }
+
+            public Integer next() {
+                if (next <= 0) {
+                    throw new NoSuchElementException();
+                }
+                return next--;
+            }
+        };
+    }
+}
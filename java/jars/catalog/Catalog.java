@@ -0,0 +1,44 @@
+package catalog;
+
+import java.util.ArrayList;
+import java.util.List;
+
+/**
+ * A list of items, read from `catalog.jar` by the macro.
+ *
+ * <p>The documentation in this package comes from {@code catalog-sources.jar}.
+ *
+ * @param <T> the type of the items
+ */
+public class Catalog<T> {
+    private final List<T> items = new ArrayList<>();
+
+    /**
+     * Adds an item at the end of the catalog.
+     *
+     * @param item the item to add
+     */
+    public void add(T item) {
+        items.add(item);
+    }
+
+    /**
+     * Counts the items.
+     *
+     * @return the number of items, at least {@code 0}
+     */
+    public int size() {
+        return items.size();
+    }
+
+    /**
+     * Returns the item at a position of the catalog.
+     *
+     * @param index the position, starting at {@code 0}
+     * @return the item
+     * @throws IndexOutOfBoundsException if there is no item at {@code index}
+     */
+    public T get(int index) {
+        return items.get(index);
+    }
+}
@@ -0,0 +1,23 @@
+package catalog;
+
+/** An item of a catalog; this package is only compiled into `catalog.jar`. */
+public class Item {
+    /** The maximum length of a {@linkplain #getName() name}. */
+    public static final int MAX_NAME_LENGTH = 64;
+
+    private final String name;
+
+    /**
+     * Creates an item.
+     *
+     * @param name the name, like <code>"lamp"</code>
+     */
+    public Item(String name) {
+        this.name = name;
+    }
+
+    /** Returns the name given to {@link #Item(String)}. */
+    public String getName() {
+        return name;
+    }
+}
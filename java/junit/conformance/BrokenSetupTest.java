@@ -0,0 +1,10 @@
+package conformance;
+
+public class BrokenSetupTest {
+    public static void beforeAll() {
+        throw new IllegalStateException("fixture is missing");
+    }
+
+    public void neverRuns() {
+    }
+}
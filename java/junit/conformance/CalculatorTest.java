@@ -0,0 +1,27 @@
+package conformance;
+
+import org.opentest4j.TestAbortedException;
+
+public class CalculatorTest {
+    public void adds() {
+        check(2 + 2 == 4, "2 + 2 should be 4");
+    }
+
+    public void subtracts() {
+        check(2 - 1 == 2, "expected: <2> but was: <1>");
+    }
+
+    public void needsNetwork() {
+        throw new TestAbortedException("Assumption failed: no network");
+    }
+
+    public void disabledDivides() {
+        throw new AssertionError("disabled tests do not run");
+    }
+
+    private static void check(boolean condition, String message) {
+        if (!condition) {
+            throw new AssertionError(message);
+        }
+    }
+}
@@ -0,0 +1,39 @@
+package org.junit.platform.engine;
+
+import java.util.Optional;
+
+public final class TestExecutionResult {
+    public enum Status {
+        SUCCESSFUL,
+        ABORTED,
+        FAILED
+    }
+
+    private final Status status;
+    private final Throwable throwable;
+
+    private TestExecutionResult(Status status, Throwable throwable) {
+        this.status = status;
+        this.throwable = throwable;
+    }
+
+    public static TestExecutionResult successful() {
+        return new TestExecutionResult(Status.SUCCESSFUL, null);
+    }
+
+    public static TestExecutionResult aborted(Throwable throwable) {
+        return new TestExecutionResult(Status.ABORTED, throwable);
+    }
+
+    public static TestExecutionResult failed(Throwable throwable) {
+        return new TestExecutionResult(Status.FAILED, throwable);
+    }
+
+    public Status getStatus() {
+        return status;
+    }
+
+    public Optional<Throwable> getThrowable() {
+        return Optional.ofNullable(throwable);
+    }
+}
@@ -0,0 +1,33 @@
+package org.junit.platform.engine.discovery;
+
+import org.junit.platform.engine.DiscoverySelector;
+
+/**
+ * A stand-in for the JUnit Platform's selectors, for testing {@code duchess::testing::run_junit}
+ * without JUnit's jars. Like the rest of this directory, it has only the parts of JUnit's API
+ * that duchess uses, and a tiny engine behind them.
+ */
+public final class DiscoverySelectors {
+    private DiscoverySelectors() {
+    }
+
+    public static DiscoverySelector selectClass(String className) {
+        return new Selector(className, null);
+    }
+
+    public static DiscoverySelector selectMethod(String fullyQualifiedMethodName) {
+        String[] parts = fullyQualifiedMethodName.split("#", 2);
+        return new Selector(parts[0], parts[1]);
+    }
+
+    /** A class, and the one method of it to run ({@code null} for all). */
+    public static final class Selector implements DiscoverySelector {
+        public final String className;
+        public final String methodName;
+
+        Selector(String className, String methodName) {
+            this.className = className;
+            this.methodName = methodName;
+        }
+    }
+}
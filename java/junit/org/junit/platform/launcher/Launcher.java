@@ -0,0 +1,5 @@
+package org.junit.platform.launcher;
+
+public interface Launcher {
+    void execute(LauncherDiscoveryRequest launcherDiscoveryRequest, TestExecutionListener... listeners);
+}
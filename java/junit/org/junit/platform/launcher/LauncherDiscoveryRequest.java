@@ -0,0 +1,9 @@
+package org.junit.platform.launcher;
+
+import java.util.List;
+
+import org.junit.platform.engine.DiscoverySelector;
+
+public interface LauncherDiscoveryRequest {
+    List<DiscoverySelector> getSelectors();
+}
@@ -0,0 +1,29 @@
+package org.junit.platform.launcher;
+
+public final class TestIdentifier {
+    private final String uniqueId;
+    private final String displayName;
+    private final boolean test;
+
+    public TestIdentifier(String uniqueId, String displayName, boolean test) {
+        this.uniqueId = uniqueId;
+        this.displayName = displayName;
+        this.test = test;
+    }
+
+    public String getUniqueId() {
+        return uniqueId;
+    }
+
+    public String getDisplayName() {
+        return displayName;
+    }
+
+    public boolean isTest() {
+        return test;
+    }
+
+    public boolean isContainer() {
+        return !test;
+    }
+}
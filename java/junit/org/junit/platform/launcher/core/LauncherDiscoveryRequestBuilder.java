@@ -0,0 +1,28 @@
+package org.junit.platform.launcher.core;
+
+import java.util.ArrayList;
+import java.util.List;
+
+import org.junit.platform.engine.DiscoverySelector;
+import org.junit.platform.launcher.LauncherDiscoveryRequest;
+
+public final class LauncherDiscoveryRequestBuilder {
+    private final List<DiscoverySelector> selectors = new ArrayList<>();
+
+    private LauncherDiscoveryRequestBuilder() {
+    }
+
+    public static LauncherDiscoveryRequestBuilder request() {
+        return new LauncherDiscoveryRequestBuilder();
+    }
+
+    public LauncherDiscoveryRequestBuilder selectors(List<? extends DiscoverySelector> selectors) {
+        this.selectors.addAll(selectors);
+        return this;
+    }
+
+    public LauncherDiscoveryRequest build() {
+        List<DiscoverySelector> selectors = new ArrayList<>(this.selectors);
+        return () -> selectors;
+    }
+}
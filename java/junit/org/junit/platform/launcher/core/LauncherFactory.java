@@ -0,0 +1,112 @@
+package org.junit.platform.launcher.core;
+
+import java.lang.reflect.InvocationTargetException;
+import java.lang.reflect.Method;
+import java.lang.reflect.Modifier;
+import java.util.Arrays;
+import java.util.Comparator;
+
+import org.junit.platform.engine.DiscoverySelector;
+import org.junit.platform.engine.TestExecutionResult;
+import org.junit.platform.engine.discovery.DiscoverySelectors.Selector;
+import org.junit.platform.launcher.Launcher;
+import org.junit.platform.launcher.LauncherDiscoveryRequest;
+import org.junit.platform.launcher.TestExecutionListener;
+import org.junit.platform.launcher.TestIdentifier;
+import org.junit.platform.launcher.TestPlan;
+import org.opentest4j.TestAbortedException;
+
+/**
+ * Creates a launcher with a tiny engine: the tests of a class are its public instance methods
+ * without parameters, run in name order on a new instance each. Those whose name starts with
+ * {@code disabled} are skipped, and a static {@code beforeAll()} method runs first.
+ */
+public final class LauncherFactory {
+    private LauncherFactory() {
+    }
+
+    public static Launcher create() {
+        return LauncherFactory::execute;
+    }
+
+    private static void execute(LauncherDiscoveryRequest request, TestExecutionListener... listeners) {
+        TestPlan plan = new TestPlan();
+        for (TestExecutionListener listener : listeners) {
+            listener.testPlanExecutionStarted(plan);
+        }
+        for (DiscoverySelector selector : request.getSelectors()) {
+            runClass((Selector) selector, listeners);
+        }
+        for (TestExecutionListener listener : listeners) {
+            listener.testPlanExecutionFinished(plan);
+        }
+    }
+
+    private static void runClass(Selector selector, TestExecutionListener[] listeners) {
+        Class<?> testClass;
+        try {
+            testClass = Class.forName(selector.className);
+        } catch (ClassNotFoundException e) {
+            throw new IllegalArgumentException("no test class " + selector.className, e);
+        }
+        String classId = "[engine:fake]/[class:" + testClass.getName() + "]";
+        TestIdentifier container = new TestIdentifier(classId, testClass.getSimpleName(), false);
+        for (TestExecutionListener listener : listeners) {
+            listener.executionStarted(container);
+        }
+
+        TestExecutionResult classResult = run(testClass, "beforeAll", null);
+        if (classResult.getStatus() == TestExecutionResult.Status.SUCCESSFUL) {
+            Method[] methods = testClass.getDeclaredMethods();
+            Arrays.sort(methods, Comparator.comparing(Method::getName));
+            for (Method method : methods) {
+                if (!Modifier.isPublic(method.getModifiers())
+                        || Modifier.isStatic(method.getModifiers())
+                        || method.getParameterCount() != 0
+                        || (selector.methodName != null && !selector.methodName.equals(method.getName()))) {
+                    continue;
+                }
+                String name = method.getName() + "()";
+                TestIdentifier test = new TestIdentifier(classId + "/[method:" + name + "]", name, true);
+                if (method.getName().startsWith("disabled")) {
+                    for (TestExecutionListener listener : listeners) {
+                        listener.executionSkipped(test, name + " is disabled");
+                    }
+                    continue;
+                }
+                for (TestExecutionListener listener : listeners) {
+                    listener.executionStarted(test);
+                }
+                TestExecutionResult result = run(testClass, method.getName(), method);
+                for (TestExecutionListener listener : listeners) {
+                    listener.executionFinished(test, result);
+                }
+            }
+        }
+
+        for (TestExecutionListener listener : listeners) {
+            listener.executionFinished(container, classResult);
+        }
+    }
+
+    /** Runs {@code method} on a new instance, or the static method {@code name} if there is one. */
+    private static TestExecutionResult run(Class<?> testClass, String name, Method method) {
+        try {
+            if (method != null) {
+                method.invoke(testClass.getConstructor().newInstance());
+            } else {
+                testClass.getMethod(name).invoke(null);
+            }
+            return TestExecutionResult.successful();
+        } catch (NoSuchMethodException e) {
+            return TestExecutionResult.successful();
+        } catch (InvocationTargetException e) {
+            Throwable cause = e.getCause();
+            return cause instanceof TestAbortedException
+                    ? TestExecutionResult.aborted(cause)
+                    : TestExecutionResult.failed(cause);
+        } catch (ReflectiveOperationException e) {
+            return TestExecutionResult.failed(e);
+        }
+    }
+}
@@ -0,0 +1,7 @@
+package org.opentest4j;
+
+public class TestAbortedException extends RuntimeException {
+    public TestAbortedException(String message) {
+        super(message);
+    }
+}
@@ -0,0 +1,13 @@
+package keywords.impl.async;
+
+public class Step {
+    private final String name;
+
+    public Step(String name) {
+        this.name = name;
+    }
+
+    public String name() {
+        return name;
+    }
+}
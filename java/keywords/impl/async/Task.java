@@ -0,0 +1,21 @@
+package keywords.impl.async;
+
+public class Task {
+    private final String name;
+
+    public Task(String name) {
+        this.name = name;
+    }
+
+    public static Task named(String name) {
+        return new Task(name);
+    }
+
+    public String name() {
+        return name;
+    }
+
+    public Task then(Step step) {
+        return new Task(name + " then " + step.name());
+    }
+}
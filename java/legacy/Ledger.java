@@ -0,0 +1,40 @@
+package legacy;
+
+import java.util.List;
+
+public class Ledger {
+    public int total;
+
+    /** @deprecated use {@link #total} */
+    @Deprecated
+    public int sum;
+
+    public Ledger() {}
+
+    @Deprecated
+    public Ledger(int total) {
+        this.total = total;
+    }
+
+    public void add(int amount) {
+        total += amount;
+        sum = total;
+    }
+
+    public int getTotal() {
+        return total;
+    }
+
+    @Deprecated
+    public int getSum() {
+        return sum;
+    }
+
+    @Deprecated
+    public <T extends Number> int addAll(List<T> amounts) {
+        for (T amount : amounts) {
+            add(amount.intValue());
+        }
+        return total;
+    }
+}
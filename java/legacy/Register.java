@@ -0,0 +1,14 @@
+package legacy;
+
+/** A counter, replaced by {@link Ledger}. */
+@Deprecated(since = "2.0", forRemoval = true)
+public class Register {
+    public int count;
+
+    public Register() {}
+
+    @Deprecated(since = "1.5")
+    public void increment() {
+        count++;
+    }
+}
@@ -0,0 +1,45 @@
+package lines;
+
+import java.io.BufferedReader;
+import java.io.IOException;
+import java.io.InputStream;
+import java.io.InputStreamReader;
+import java.io.Reader;
+import java.io.StringReader;
+import java.io.UncheckedIOException;
+
+/** Reads lines from one of several kinds of sources, like `java.util.Scanner`. */
+public class LineReader {
+    private final BufferedReader reader;
+    private String next;
+
+    public LineReader(InputStream in) {
+        this(new InputStreamReader(in));
+    }
+
+    public LineReader(String text) {
+        this(new StringReader(text));
+    }
+
+    public LineReader(Reader reader) {
+        this.reader = new BufferedReader(reader);
+    }
+
+    public boolean hasNextLine() {
+        if (next == null) {
+            try {
+                next = reader.readLine();
+            } catch (IOException e) {
+                throw new UncheckedIOException(e);
+            }
+        }
+        return next != null;
+    }
+
+    public String nextLine() {
+        hasNextLine();
+        String line = next;
+        next = null;
+        return line;
+    }
+}
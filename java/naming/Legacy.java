@@ -0,0 +1,12 @@
+package naming;
+
+/** Methods whose names are the same in snake case. */
+public class Legacy {
+    public int getValue() {
+        return 1;
+    }
+
+    public int get_value() {
+        return 2;
+    }
+}
@@ -0,0 +1,46 @@
+package nested;
+
+import java.util.List;
+
+/** A class with a static nested class and a non-static inner class. */
+public class Outer {
+    private final String name;
+
+    public Outer(String name) {
+        this.name = name;
+    }
+
+    public Inner inner(String label) {
+        return new Inner(label);
+    }
+
+    /** Constructed with an enclosing `Outer`, which the descriptor passes first. */
+    public class Inner {
+        private final String label;
+
+        public Inner(String label) {
+            this.label = label;
+        }
+
+        /** Generic, so the `Signature` attribute leaves out the enclosing instance. */
+        public Inner(List<String> parts) {
+            this.label = String.join("-", parts);
+        }
+
+        public String describe() {
+            return name + "/" + label;
+        }
+
+        public Outer outer() {
+            return Outer.this;
+        }
+    }
+
+    public static class Nested {
+        public Nested() {}
+
+        public Outer outer(String name) {
+            return new Outer(name);
+        }
+    }
+}
@@ -0,0 +1,14 @@
+package optionals;
+
+import java.util.Optional;
+
+/** Lookups that may or may not find something. */
+public class Directory {
+    public static Optional<String> greeting(boolean present) {
+        return present ? Optional.of("hello") : Optional.empty();
+    }
+
+    public static Optional<Integer> extension(String name) {
+        return name.equals("ferris") ? Optional.of(42) : Optional.empty();
+    }
+}
@@ -0,0 +1,24 @@
+package ordering;
+
+public class Version implements Comparable<Version> {
+    private final int major;
+    private final int minor;
+
+    public Version(int major, int minor) {
+        this.major = major;
+        this.minor = minor;
+    }
+
+    @Override
+    public int compareTo(Version other) {
+        if (major != other.major) {
+            return Integer.compare(major, other.major);
+        }
+        return Integer.compare(minor, other.minor);
+    }
+
+    @Override
+    public String toString() {
+        return major + "." + minor;
+    }
+}
@@ -0,0 +1,11 @@
+package printing;
+
+public class Broken {
+    public Broken() {
+    }
+
+    @Override
+    public String toString() {
+        throw new IllegalStateException("no text");
+    }
+}
@@ -0,0 +1,14 @@
+package printing;
+
+public class Label {
+    private final String text;
+
+    public Label(String text) {
+        this.text = text;
+    }
+
+    @Override
+    public String toString() {
+        return "Label(" + text + ")";
+    }
+}
@@ -0,0 +1,5 @@
+package proto;
+
+public record Greeting(
+    String text
+) { }
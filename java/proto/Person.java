@@ -0,0 +1,6 @@
+package proto;
+
+public record Person(
+    String name,
+    String city
+) { }
@@ -0,0 +1,13 @@
+package records;
+
+public record Point(int x, int y) {
+    public Point {
+        if (x < 0 || y < 0) {
+            throw new IllegalArgumentException("negative coordinate");
+        }
+    }
+
+    public int sum() {
+        return x + y;
+    }
+}
@@ -0,0 +1,6 @@
+package records;
+
+import java.util.List;
+
+public record Tagged<T>(String label, T value, List<String> tags) {
+}
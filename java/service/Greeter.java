@@ -0,0 +1,20 @@
+package service;
+
+import proto.Greeting;
+import proto.Person;
+
+public class Greeter {
+
+    public Greeting greet(Person person) {
+        return new Greeting("Hello, " + person.name() + " from " + person.city() + "!");
+    }
+
+    public Person relocate(Person person, String city) {
+        return new Person(person.name(), city);
+    }
+
+    public static Person nobody() {
+        return null;
+    }
+
+}
@@ -0,0 +1,17 @@
+package shapes;
+
+public abstract sealed class Shape permits Circle, Square, Triangle {
+    public abstract double area();
+
+    public static Shape circle(double radius) {
+        return new Circle(radius);
+    }
+
+    public static Shape square(double side) {
+        return new Square(side);
+    }
+
+    public static Shape triangle(double base, double height) {
+        return new Triangle(base, height);
+    }
+}
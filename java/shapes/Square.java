@@ -0,0 +1,18 @@
+package shapes;
+
+public final class Square extends Shape {
+    private final double side;
+
+    public Square(double side) {
+        this.side = side;
+    }
+
+    public double side() {
+        return side;
+    }
+
+    @Override
+    public double area() {
+        return side * side;
+    }
+}
@@ -0,0 +1,46 @@
+package signatures;
+
+/** Static methods whose overloads differ only in their (array) parameter types. */
+public class Overloads {
+    public static int count(int[] values) {
+        return values.length;
+    }
+
+    public static int count(long[] values) {
+        return -values.length;
+    }
+
+    public static String join(String[] parts) {
+        return String.join("+", parts);
+    }
+
+    public static String join(String part) {
+        return part;
+    }
+
+    public static int sum(int[][] rows) {
+        int sum = 0;
+        for (int[] row : rows) {
+            for (int value : row) {
+                sum += value;
+            }
+        }
+        return sum;
+    }
+
+    public static int sum(int[] row) {
+        return -1;
+    }
+
+    public static long max(long[] values) {
+        long max = Long.MIN_VALUE;
+        for (long value : values) {
+            max = Math.max(max, value);
+        }
+        return max;
+    }
+
+    public static long max(int[] values) {
+        return -1;
+    }
+}
@@ -0,0 +1,42 @@
+package signatures;
+
+/** A string builder whose instance methods are overloaded. */
+public class Text {
+    private final StringBuilder text = new StringBuilder();
+
+    public Text append(String s) {
+        text.append(s);
+        return this;
+    }
+
+    public Text append(int i) {
+        text.append(i);
+        return this;
+    }
+
+    public Text append(char[] chars) {
+        text.append(chars);
+        return this;
+    }
+
+    public Text append(clients.foo.Client client) {
+        return append("foo");
+    }
+
+    public Text append(clients.bar.Client client) {
+        return append("bar");
+    }
+
+    public int indexOf(String s) {
+        return text.indexOf(s);
+    }
+
+    public int indexOf(String s, int fromIndex) {
+        return text.indexOf(s, fromIndex);
+    }
+
+    @Override
+    public String toString() {
+        return text.toString();
+    }
+}
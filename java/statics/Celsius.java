@@ -0,0 +1,30 @@
+package statics;
+
+/** A class whose static and instance methods share names. */
+public class Celsius {
+    private final int degrees;
+
+    public Celsius(int degrees) {
+        this.degrees = degrees;
+    }
+
+    public static Celsius of(int degrees) {
+        return new Celsius(degrees);
+    }
+
+    public String format() {
+        return format(degrees);
+    }
+
+    public static String format(int degrees) {
+        return degrees + " C";
+    }
+
+    public int fahrenheit() {
+        return fahrenheit(degrees);
+    }
+
+    public static int fahrenheit(int degrees) {
+        return degrees * 9 / 5 + 32;
+    }
+}
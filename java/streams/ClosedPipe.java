@@ -0,0 +1,12 @@
+package streams;
+
+import java.io.IOException;
+import java.io.OutputStream;
+
+/** Fails like a `FileOutputStream` whose reader has gone away. */
+public class ClosedPipe extends OutputStream {
+    @Override
+    public void write(int b) throws IOException {
+        throw new IOException("Broken pipe");
+    }
+}
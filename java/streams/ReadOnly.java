@@ -0,0 +1,18 @@
+package streams;
+
+import java.io.OutputStream;
+import java.nio.file.AccessDeniedException;
+
+/** Fails like a stream over a file that may not be written to. */
+public class ReadOnly extends OutputStream {
+    private final String path;
+
+    public ReadOnly(String path) {
+        this.path = path;
+    }
+
+    @Override
+    public void write(int b) throws AccessDeniedException {
+        throw new AccessDeniedException(path);
+    }
+}
@@ -0,0 +1,22 @@
+package streams;
+
+import java.io.EOFException;
+import java.io.InputStream;
+
+/** Yields `prefix` and then fails as if the data had been cut short. */
+public class Truncated extends InputStream {
+    private final byte[] prefix;
+    private int position;
+
+    public Truncated(byte[] prefix) {
+        this.prefix = prefix;
+    }
+
+    @Override
+    public int read() throws EOFException {
+        if (position < prefix.length) {
+            return prefix[position++] & 0xff;
+        }
+        throw new EOFException("stream was truncated");
+    }
+}
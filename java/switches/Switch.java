@@ -0,0 +1,19 @@
+package switches;
+
+public class Switch {
+    private final boolean on;
+
+    public Switch(boolean on) {
+        this.on = on;
+    }
+
+    public boolean isOn() {
+        return on;
+    }
+
+    public Switch toggled() {
+        return new Switch(flip(on));
+    }
+
+    native boolean flip(boolean on);
+}
@@ -0,0 +1,29 @@
+package varargs;
+
+import java.util.Arrays;
+import java.util.stream.Collectors;
+
+/** A class with varargs methods, next to a method taking a plain array. */
+public class Joiner {
+    private final String separator;
+
+    public Joiner(String separator) {
+        this.separator = separator;
+    }
+
+    public String join(Object... parts) {
+        return Arrays.stream(parts).map(String::valueOf).collect(Collectors.joining(separator));
+    }
+
+    public static String joinWith(String separator, String... parts) {
+        return String.join(separator, parts);
+    }
+
+    public static int sum(int... values) {
+        return Arrays.stream(values).sum();
+    }
+
+    public static int count(String[] parts) {
+        return parts.length;
+    }
+}
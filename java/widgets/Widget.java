@@ -0,0 +1,29 @@
+package widgets;
+
+public class Widget {
+    protected int size;
+
+    public Widget() {
+        this(1);
+    }
+
+    protected Widget(int size) {
+        this.size = size;
+    }
+
+    public int area() {
+        return size * size;
+    }
+
+    protected void grow(int by) {
+        size += by;
+    }
+
+    protected static String describe(int size) {
+        return "widget of size " + size;
+    }
+
+    int packageSize() {
+        return size;
+    }
+}
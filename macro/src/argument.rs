@@ -1,19 +1,126 @@
-use proc_macro2::Span;
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+use proc_macro2::{Delimiter, Span, TokenStream, TokenTree};
+use quote::quote_spanned;
 
 use crate::{
     class_info::{ClassDecl, ClassInfo, DotId, Id},
+    imports::{self, Imports},
     parse::{Parse, Parser},
     span_error::SpanError,
 };
 
 pub struct DuchessDeclaration {
     pub packages: Vec<JavaPackage>,
+    pub conversions: Vec<Conversion>,
+    /// Directories given with `classpath = "...";`, whose class files are reflected on
+    /// without running `javap`.
+    pub classpath: Vec<PathBuf>,
 }
 
 impl Parse for DuchessDeclaration {
     fn parse(p: &mut Parser) -> Result<Option<Self>, SpanError> {
-        let packages = JavaPackage::parse_many(p)?;
-        Ok(Some(DuchessDeclaration { packages }))
+        let mut packages = vec![];
+        let mut conversions = vec![];
+        let mut classpath = vec![];
+        let mut default_classes = vec![];
+        loop {
+            // Errors are reported and skipped over, so that we can report
+            // as many problems as possible in one go.
+            match Self::parse_classpath(p) {
+                Ok(Some(entries)) => {
+                    classpath.extend(entries);
+                    continue;
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    p.report(e);
+                    p.recover();
+                    continue;
+                }
+            }
+
+            match Conversion::parse(p) {
+                Ok(Some(conversion)) => {
+                    conversions.push(conversion);
+                    continue;
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    p.report(e);
+                    p.recover();
+                    continue;
+                }
+            }
+
+            match JavaPackage::parse(p) {
+                Ok(Some(package)) => {
+                    packages.push(package);
+                    continue;
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    p.report(e);
+                    p.recover();
+                    continue;
+                }
+            }
+
+            // Classes outside of any `package` block are in the default (unnamed) package.
+            match ClassDecl::parse(p) {
+                Ok(Some(class)) => {
+                    default_classes.push(class);
+                    continue;
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    p.report(e);
+                    p.recover();
+                    continue;
+                }
+            }
+
+            let Some(t) = p.eat_token() else {
+                break;
+            };
+            p.report(SpanError {
+                span: t.span(),
+                message: format!(
+                    "unexpected `{t}`, expected `package`, `convert`, `classpath`, or a class definition"
+                ),
+            });
+            p.recover();
+        }
+        if !default_classes.is_empty() {
+            packages.push(JavaPackage {
+                package_name: JavaPath::default_package(),
+                options: PackageOptions::default(),
+                classes: default_classes,
+            });
+        }
+
+        // Any remaining bare names that match a class in the default package refer to it.
+        let default_package_classes: Vec<Id> = packages
+            .iter()
+            .filter(|package| package.package_name.ids.is_empty())
+            .flat_map(|package| &package.classes)
+            .map(|class| class.name().class_name().clone())
+            .collect();
+        for package in &mut packages {
+            for class in &mut package.classes {
+                if let ClassDecl::Specified(c) = class {
+                    imports::resolve_default_package_classes(c, &default_package_classes);
+                }
+            }
+        }
+        Ok(Some(DuchessDeclaration {
+            packages,
+            conversions,
+            classpath,
+        }))
     }
 
     fn description() -> String {
@@ -21,6 +128,47 @@ impl Parse for DuchessDeclaration {
     }
 }
 
+impl DuchessDeclaration {
+    /// Parses `classpath = "target/classes:lib/classes";`, whose entries are separated as in
+    /// the `CLASSPATH` environment variable. Relative entries are relative to the crate root.
+    fn parse_classpath(p: &mut Parser) -> Result<Option<Vec<PathBuf>>, SpanError> {
+        let Some(()) = p.eat_map(|t| match t {
+            TokenTree::Ident(i) if i == "classpath" => Some(()),
+            _ => None,
+        }) else {
+            return Ok(None);
+        };
+
+        if p.eat_punct('=').is_none() {
+            return Err(p.error("expected `=` after `classpath`"));
+        }
+        let Some(value) = p.eat_map(|t| match t {
+            TokenTree::Literal(l) => syn::parse2::<syn::LitStr>(TokenTree::from(l.clone()).into())
+                .ok()
+                .map(|l| l.value()),
+            _ => None,
+        }) else {
+            return Err(p.error(
+                "expected a string after `classpath`, like `classpath = \"target/classes\"`",
+            ));
+        };
+        if p.eat_punct(';').is_none() {
+            return Err(p.error("expected `;` after the classpath"));
+        }
+
+        let entries = env::split_paths(&value).map(crate_relative_path).collect();
+        Ok(Some(entries))
+    }
+}
+
+/// Resolves a path written in the macro input relative to the root of the crate being built.
+fn crate_relative_path(path: impl AsRef<Path>) -> PathBuf {
+    let root = env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_default();
+    root.join(path)
+}
+
 /// There are various points where the user must select
 /// a method. In these cases, we permit them to either write
 /// just a class name (in which case we search for (hopefully) at most one
@@ -36,7 +184,7 @@ pub enum MethodSelector {
 
     /// User wrote `class Foo { ... }` with full details.
     /// This class should have at most one member.
-    ClassInfo(ClassInfo),
+    ClassInfo(Box<ClassInfo>),
 }
 
 impl MethodSelector {
@@ -128,28 +276,210 @@ impl Parse for MethodSelector {
 }
 
 pub struct JavaPackage {
+    /// Empty for the default (unnamed) package.
     pub package_name: JavaPath,
+    pub options: PackageOptions,
     pub classes: Vec<ClassDecl>,
 }
 
+/// Options written in parentheses after the package name,
+/// e.g. `package com.legacy (omit_deprecated, prefix = "Legacy");`.
+#[derive(Clone, Debug, Default)]
+pub struct PackageOptions {
+    /// Leave out deprecated members of reflected classes, unless a member filter names them
+    /// (`class Foo { oldMethod; }`).
+    pub omit_deprecated: bool,
+
+    /// Prepended to the struct name of each class that has no alias of its own
+    /// (`class Foo as Bar`), e.g. `LegacyClient` for `com.legacy.Client`.
+    pub prefix: Option<String>,
+
+    /// A JAR, relative to the crate root, that is added to the classpath, so that the classes
+    /// in it are reflected on without running `javap`.
+    pub jar: Option<PathBuf>,
+
+    /// Constructors with at least this many parameters also get a builder,
+    /// e.g. `package com.legacy (builder_arity = 6);`.
+    pub builder_arity: Option<usize>,
+}
+
+impl PackageOptions {
+    /// Parses the contents of the parentheses.
+    fn parse(stream: TokenStream) -> Result<Self, SpanError> {
+        let mut options = PackageOptions::default();
+        let mut tokens = stream.into_iter();
+        while let Some(t) = tokens.next() {
+            match &t {
+                TokenTree::Ident(i) if i == "omit_deprecated" => {
+                    options.omit_deprecated = true;
+                }
+                TokenTree::Ident(i) if i == "prefix" => {
+                    options.prefix = Some(Self::parse_string(i, &mut tokens, "Foo")?);
+                }
+                TokenTree::Ident(i) if i == "jar" => {
+                    let jar = Self::parse_string(i, &mut tokens, "lib/foo.jar")?;
+                    options.jar = Some(crate_relative_path(jar));
+                }
+                TokenTree::Ident(i) if i == "builder_arity" => {
+                    options.builder_arity = Some(Self::parse_int(i, &mut tokens, 6)?);
+                }
+                _ => {
+                    return Err(SpanError {
+                        span: t.span(),
+                        message: format!(
+                            "unexpected `{t}`, expected a package option like `omit_deprecated`"
+                        ),
+                    })
+                }
+            }
+            match tokens.next() {
+                None => break,
+                Some(TokenTree::Punct(p)) if p.as_char() == ',' => {}
+                Some(t) => {
+                    return Err(SpanError {
+                        span: t.span(),
+                        message: format!("unexpected `{t}`, expected `,` between package options"),
+                    })
+                }
+            }
+        }
+        Ok(options)
+    }
+
+    /// Parses the `= "..."` after the option `name`.
+    fn parse_string(
+        name: &proc_macro2::Ident,
+        tokens: &mut impl Iterator<Item = TokenTree>,
+        example: &str,
+    ) -> Result<String, SpanError> {
+        let value = match (tokens.next(), tokens.next()) {
+            (Some(TokenTree::Punct(eq)), Some(TokenTree::Literal(l))) if eq.as_char() == '=' => {
+                syn::parse2::<syn::LitStr>(TokenTree::from(l).into()).ok()
+            }
+            _ => None,
+        };
+        match value {
+            Some(value) => Ok(value.value()),
+            None => Err(SpanError {
+                span: name.span(),
+                message: format!("expected a string after `{name}`, like `{name} = \"{example}\"`"),
+            }),
+        }
+    }
+
+    /// Parses the `= N` after the option `name`.
+    fn parse_int(
+        name: &proc_macro2::Ident,
+        tokens: &mut impl Iterator<Item = TokenTree>,
+        example: usize,
+    ) -> Result<usize, SpanError> {
+        let value = match (tokens.next(), tokens.next()) {
+            (Some(TokenTree::Punct(eq)), Some(TokenTree::Literal(l))) if eq.as_char() == '=' => {
+                syn::parse2::<syn::LitInt>(TokenTree::from(l).into())
+                    .ok()
+                    .and_then(|l| l.base10_parse().ok())
+            }
+            _ => None,
+        };
+        match value {
+            Some(value) => Ok(value),
+            None => Err(SpanError {
+                span: name.span(),
+                message: format!("expected a number after `{name}`, like `{name} = {example}`"),
+            }),
+        }
+    }
+}
+
 impl Parse for JavaPackage {
     fn parse(p: &mut Parser) -> Result<Option<Self>, SpanError> {
         let Some(()) = p.eat_keyword("package") else {
             return Ok(None);
         };
 
-        let Some(package_name) = JavaPath::parse(p)? else {
+        // `package <default>;` declares classes in the default (unnamed) package.
+        let package_name = if p.eat_punct('<').is_some() {
+            let span = p.last_span().unwrap();
+            let default = p.eat_map(|t| match t {
+                TokenTree::Ident(i) if i == "default" => Some(()),
+                _ => None,
+            });
+            if default.is_none() || p.eat_punct('>').is_none() {
+                return Err(p.error("expected `<default>`"));
+            }
+            JavaPath {
+                ids: vec![],
+                span: span.join(p.last_span().unwrap()).unwrap_or(span),
+            }
+        } else if let Some(package_name) = JavaPath::parse(p)? {
+            package_name
+        } else {
             return Err(p.error("expected package name"));
         };
 
-        let Some(_) = p.eat_punct(';') else {
-            return Err(p.error("expected `;` after package name"));
+        let options = match p.eat_map(|t| match t {
+            TokenTree::Group(g) if g.delimiter() == Delimiter::Parenthesis => Some(g.stream()),
+            _ => None,
+        }) {
+            Some(stream) => PackageOptions::parse(stream).unwrap_or_else(|e| {
+                p.report(e);
+                PackageOptions::default()
+            }),
+            None => PackageOptions::default(),
         };
 
-        let classes = ClassDecl::parse_many(p)?;
+        if p.eat_punct(';').is_none() {
+            // Carry on as if the `;` were there.
+            p.report(p.error("expected `;` after package name"));
+        };
+
+        // `use java.lang.String;` lines, which must precede the classes
+        let mut imports = Imports::default();
+        while let Some(()) = p.eat_map(|t| match t {
+            TokenTree::Ident(i) if i == "use" => Some(()),
+            _ => None,
+        }) {
+            if let Err(e) = Self::parse_import(p, &mut imports) {
+                p.report(e);
+                p.recover();
+            }
+        }
+
+        let mut classes = vec![];
+        loop {
+            match ClassDecl::parse(p) {
+                Ok(Some(class)) => classes.push(class),
+                Ok(None) => {
+                    // Anything other than the start of the next package (or the end of input)
+                    // is a stray token; skip it and keep looking for classes.
+                    let Some(t) = p.peek_token() else {
+                        break;
+                    };
+                    if matches!(t, TokenTree::Ident(i) if i == "package" || i == "convert" || i == "classpath")
+                    {
+                        break;
+                    }
+                    let t = p.eat_token().unwrap();
+                    p.report(SpanError {
+                        span: t.span(),
+                        message: format!("unexpected `{t}`, expected a class definition"),
+                    });
+                    p.recover();
+                }
+                Err(e) => {
+                    p.report(e);
+                    p.recover();
+                }
+            }
+        }
+
+        for class in &mut classes {
+            imports.resolve_class_decl(class);
+        }
 
         Ok(Some(JavaPackage {
             package_name,
+            options,
             classes,
         }))
     }
@@ -159,12 +489,178 @@ impl Parse for JavaPackage {
     }
 }
 
+impl JavaPackage {
+    /// Parses the remainder of a `use java.lang.String;` line, after the `use`.
+    fn parse_import(p: &mut Parser, imports: &mut Imports) -> Result<(), SpanError> {
+        let Some(path) = JavaPath::parse(p)? else {
+            return Err(p.error("expected class name after `use`"));
+        };
+        let Some(_) = p.eat_punct(';') else {
+            return Err(p.error("expected `;` after imported class name"));
+        };
+        imports.insert(path.to_dot_id(), path.span)
+    }
+}
+
+/// User wrote `convert com.foo.* => crate::foo::*;` or `convert com.foo.Bar => crate::foo::Bar;`.
+///
+/// Java objects of the matching classes are never exposed to the user: methods returning them
+/// convert the result to the Rust type (via `ToRust`) and methods taking them as arguments
+/// accept a reference to the Rust type instead (converted via `ToJava`).
+#[derive(Clone, Debug)]
+pub struct Conversion {
+    /// The class (or, if `glob` is true, the package) being converted.
+    pub java: DotId,
+
+    /// If true, the conversion applies to every class in the package `java`.
+    pub glob: bool,
+
+    /// The Rust type (or, if `glob` is true, the Rust module containing the types).
+    pub rust: TokenStream,
+
+    pub span: Span,
+}
+
+impl Conversion {
+    /// If `class` is covered by this conversion, returns the Rust type it converts to.
+    pub fn rust_ty(&self, class: &DotId) -> Option<TokenStream> {
+        if self.glob {
+            let (package, class_name) = class.split();
+            if package != &self.java[..] {
+                return None;
+            }
+            let rust = &self.rust;
+            let ident = class_name.to_ident(self.span);
+            Some(quote_spanned!(self.span => #rust :: #ident))
+        } else if *class == self.java {
+            Some(self.rust.clone())
+        } else {
+            None
+        }
+    }
+}
+
+impl Parse for Conversion {
+    fn parse(p: &mut Parser) -> Result<Option<Self>, SpanError> {
+        let Some(()) = p.eat_map(|t| match t {
+            TokenTree::Ident(i) if i == "convert" => Some(()),
+            _ => None,
+        }) else {
+            return Ok(None);
+        };
+        let mut span = p.last_span().unwrap();
+
+        // Java side: `foo.bar.Baz` or `foo.bar.*`
+        let mut ids = vec![];
+        let mut java_glob = false;
+        loop {
+            let Some(id) = Ident::parse(p)? else {
+                return Err(p.error("expected Java class or package name after `convert`"));
+            };
+            ids.push(id.to_id());
+            if p.eat_punct('.').is_none() {
+                break;
+            }
+            if p.eat_punct('*').is_some() {
+                java_glob = true;
+                break;
+            }
+        }
+
+        if p.eat_punct('=').is_none() || p.eat_punct('>').is_none() {
+            return Err(p.error("expected `=>` after Java name in `convert`"));
+        }
+
+        // Rust side: any path, up to the `;`
+        let mut rust: Vec<TokenTree> = vec![];
+        loop {
+            match p.eat_token() {
+                Some(TokenTree::Punct(punct)) if punct.as_char() == ';' => break,
+                Some(t) => rust.push(t),
+                None => return Err(p.error("expected `;` after `convert`")),
+            }
+        }
+        span = span.join(p.last_span().unwrap()).unwrap_or(span);
+
+        let rust_glob = match &rust[..] {
+            [.., TokenTree::Punct(c1), TokenTree::Punct(c2), TokenTree::Punct(star)]
+                if c1.as_char() == ':' && c2.as_char() == ':' && star.as_char() == '*' =>
+            {
+                rust.truncate(rust.len() - 3);
+                true
+            }
+            _ => false,
+        };
+
+        if rust.is_empty() {
+            return Err(SpanError {
+                span,
+                message: "expected Rust type after `=>` in `convert`".to_string(),
+            });
+        }
+
+        if java_glob != rust_glob {
+            return Err(SpanError {
+                span,
+                message: "either both sides of a `convert` must end in `*` (e.g., `convert foo.* => crate::foo::*`), or neither".to_string(),
+            });
+        }
+
+        Ok(Some(Conversion {
+            java: ids.into_iter().collect(),
+            glob: java_glob,
+            rust: rust.into_iter().collect(),
+            span,
+        }))
+    }
+
+    fn description() -> String {
+        "conversion (e.g., `convert com.foo.* => crate::foo::*;`)".to_string()
+    }
+}
+
 pub struct JavaPath {
     pub ids: Vec<Ident>,
     pub span: Span,
 }
 
 impl JavaPath {
+    /// The name of the default (unnamed) package.
+    pub fn default_package() -> Self {
+        JavaPath {
+            ids: vec![],
+            span: Span::call_site(),
+        }
+    }
+
+    /// Parses a path written as a string literal, e.g. `"com.example.impl"`, which allows
+    /// segments that are not valid in a Rust token stream or that are Rust keywords.
+    fn parse_string(text: &str, span: Span) -> Result<Self, SpanError> {
+        let ids = text
+            .split('.')
+            .map(|segment| {
+                let mut chars = segment.chars();
+                let valid = chars
+                    .next()
+                    .is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+                    && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$');
+                if !valid {
+                    return Err(SpanError {
+                        span,
+                        message: format!(
+                            "expected a dotted Java name like `com.example`, found `{text}`"
+                        ),
+                    });
+                }
+                Ok(Ident {
+                    text: segment.to_string(),
+                    span,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(JavaPath { ids, span })
+    }
+
     pub fn to_dot_id(&self) -> DotId {
         self.ids.iter().map(|ident| ident.to_id()).collect()
     }
@@ -172,6 +668,13 @@ impl JavaPath {
 
 impl Parse for JavaPath {
     fn parse(p: &mut Parser) -> Result<Option<Self>, SpanError> {
+        if let Some(literal) = p.eat_map(|t| match t {
+            TokenTree::Literal(l) => litrs::StringLit::try_from(l).ok(),
+            _ => None,
+        }) {
+            return Self::parse_string(literal.value(), p.last_span().unwrap()).map(Some);
+        }
+
         let Some(text) = Ident::parse(p)? else {
             return Ok(None);
         };
@@ -181,7 +684,10 @@ impl Parse for JavaPath {
 
         while let Some(_) = p.eat_punct('.') {
             let Some(next) = Ident::parse(p)? else {
-                return Err(SpanError { span: p.last_span().unwrap(), message: format!("expected identifier after `.`") });
+                return Err(SpanError {
+                    span: p.last_span().unwrap(),
+                    message: "expected identifier after `.`".to_string(),
+                });
             };
             span = span.join(next.span).unwrap_or(span);
             ids.push(next);
@@ -204,7 +710,7 @@ impl std::fmt::Display for JavaPath {
             }
             Ok(())
         } else {
-            Ok(())
+            write!(f, "<default>")
         }
     }
 }
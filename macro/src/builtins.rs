@@ -0,0 +1,36 @@
+use std::str::FromStr;
+
+use proc_macro2::TokenStream;
+
+use crate::{
+    argument::DuchessDeclaration,
+    class_info::{ClassDecl, ClassInfo},
+    parse::Parser,
+    span_error::SpanErrors,
+};
+
+/// The classes that duchess itself mirrors in `duchess::java`, in the syntax of `java_package!`.
+const BUILTINS: &str = include_str!("builtins.duchess");
+
+/// Parses the declaration of the classes in `duchess::java`, which `java_builtins!` expands.
+pub fn declaration() -> Result<DuchessDeclaration, SpanErrors> {
+    let tokens = TokenStream::from_str(BUILTINS).expect("`builtins.duchess` is not valid tokens");
+    Parser::from(tokens).parse::<DuchessDeclaration>()
+}
+
+/// The classes in `duchess::java`, see [`declaration`].
+pub fn classes() -> Vec<ClassInfo> {
+    let Ok(declaration) = declaration() else {
+        // Reported when `java_builtins!` is expanded for `duchess::java` itself.
+        return vec![];
+    };
+    declaration
+        .packages
+        .into_iter()
+        .flat_map(|package| package.classes)
+        .filter_map(|class| match class {
+            ClassDecl::Specified(c) => Some(*c),
+            ClassDecl::Reflected(_) => None,
+        })
+        .collect()
+}
@@ -1,9 +1,14 @@
 use std::collections::HashSet;
 
+use proc_macro2::Span;
+
 use crate::{
-    class_info::{ClassInfo, ClassRef, Constructor, Flags, Method, RefType, RootMap, Type},
+    class_info::{
+        ClassInfo, ClassRef, Constructor, DotId, Flags, Id, Method, RefType, RootMap, Type,
+    },
     reflect::Reflector,
     span_error::SpanError,
+    substitution::{Substitute, Substitution},
 };
 
 impl RootMap {
@@ -33,25 +38,46 @@ impl ClassInfo {
     ) -> Result<(), SpanError> {
         let info = reflector.reflect(&self.name, self.span)?;
 
-        let mut push_error_message = |m: String| {
-            push_error(SpanError {
-                span: self.span,
-                message: format!("error in class `{}`: {m}", self.name),
-            });
+        let class_error = |span: Span, m: String| SpanError {
+            span,
+            message: format!("error in class `{}`: {m}", self.name),
         };
+        let mut push_error_message = |m: String| push_error(class_error(self.span, m));
 
         // We always allow people to elide generics, in which case
         // they are mirroring the "erased" version of the class.
         //
         // We need this (at minimum) to deal with `java.lang.Class`, since we
         // don't want to mirror its parameter.
+        //
+        // Generic parameters that were specialized to a type argument are
+        // likewise substituted away from the reflected class before comparing.
+        let subst: Substitution<'_> = self
+            .type_arguments
+            .iter()
+            .map(|(id, ty)| (id, ty))
+            .collect();
+        let info_generics: Vec<_> = info
+            .generics
+            .iter()
+            .filter(|g| !self.type_arguments.iter().any(|(id, _)| *id == g.id))
+            .map(|g| g.substitute(&subst))
+            .collect();
+        let info_extends = info.extends.substitute(&subst);
+        let info_implements = info.implements.substitute(&subst);
+        let info_supertypes: Vec<ClassRef> = info_extends
+            .iter()
+            .chain(&info_implements)
+            .cloned()
+            .collect();
+
         if !self.generics.is_empty() {
             // But if there *are* generics, they must match exactly.
-            if self.generics != info.generics {
+            if self.generics != info_generics {
                 push_error_message(format!(
                     "class `{}` should have generic parameters `<{}>`",
                     self.name,
-                    info.generics
+                    info_generics
                         .iter()
                         .map(|g| g.to_string())
                         .collect::<Vec<_>>()
@@ -61,9 +87,8 @@ impl ClassInfo {
         }
 
         for cref in &self.extends {
-            if !info.extends.iter().any(|c| c == cref) {
-                let extends_list: String = info
-                    .extends
+            if !is_reflected_supertype(cref, &info_extends, &info_supertypes, reflector) {
+                let extends_list: String = info_extends
                     .iter()
                     .map(|c| format!("`{}`", c))
                     .collect::<Vec<String>>()
@@ -83,9 +108,8 @@ impl ClassInfo {
         error_on_duplicates(self.extends.as_slice(), "extends", &mut push_error_message);
 
         for cref in &self.implements {
-            if !info.implements.iter().any(|c| c == cref) {
-                let implements_list: String = info
-                    .implements
+            if !is_reflected_supertype(cref, &info_implements, &info_supertypes, reflector) {
+                let implements_list: String = info_implements
                     .iter()
                     .map(|c| format!("`{}`", c))
                     .collect::<Vec<String>>()
@@ -108,8 +132,11 @@ impl ClassInfo {
             &mut push_error_message,
         );
 
+        // Errors about a particular member are reported where the user wrote it.
         for c in &self.constructors {
             let c_method_sig = c.to_method_sig(self);
+            let span = self.constructor_span(c);
+            let mut push_error_message = |msg: String| push_error(class_error(span, msg));
 
             c.check(root_map, &mut |m| {
                 push_error_message(format!(
@@ -118,20 +145,42 @@ impl ClassInfo {
                 ));
             });
 
-            if !info
+            match info
                 .constructors
                 .iter()
-                .any(|info_c| info_c.to_method_sig(&info) == c_method_sig)
+                .find(|info_c| info_c.to_method_sig(&info) == c_method_sig)
             {
-                push_error_message(format!(
+                Some(info_c) => check_descriptor(
+                    &format!("constructor `{c_method_sig}`"),
+                    &c.descriptor(),
+                    info_c.jvm_descriptor.as_deref(),
+                    &mut push_error_message,
+                ),
+                None => push_error_message(format!(
                     "constructor {} does not match any constructors in the reflected class",
                     c_method_sig,
-                ));
+                )),
+            }
+        }
+
+        for f in &self.fields {
+            let span = self.field_span(f);
+            let mut push_error_message = |msg: String| push_error(class_error(span, msg));
+
+            if let Some(info_f) = info.fields.iter().find(|info_f| info_f.name == f.name) {
+                check_descriptor(
+                    &format!("field `{}`", f.name),
+                    &f.descriptor(),
+                    info_f.jvm_descriptor.as_deref(),
+                    &mut push_error_message,
+                );
             }
         }
 
         for m in &self.methods {
             let m_method_sig = m.to_method_sig();
+            let span = self.method_span(m);
+            let mut push_error_message = |msg: String| push_error(class_error(span, msg));
 
             let mut push_method_error_message = |msg: String| {
                 push_error_message(format!(
@@ -148,6 +197,12 @@ impl ClassInfo {
                 .find(|info_c| info_c.to_method_sig() == m_method_sig)
             {
                 self.compare_flags(m.flags, reflected_m.flags, &mut push_method_error_message);
+                check_descriptor(
+                    &format!("method `{m_method_sig}`"),
+                    &m.descriptor(),
+                    reflected_m.jvm_descriptor.as_deref(),
+                    &mut push_error_message,
+                );
             } else {
                 let same_names: Vec<_> = info
                     .methods
@@ -171,9 +226,52 @@ impl ClassInfo {
             }
         }
 
+        self.check_snake_case_names(&mut |span, m| push_error(class_error(span, m)));
+
         Ok(())
     }
 
+    /// Reports members with different Java names that would get the same name in Rust, since
+    /// names are converted to snake case, e.g. `getValue()` and `get_value()`. (Overloads are
+    /// named apart from each other, see [`ClassInfo::rust_method_name`], as are field accessors
+    /// from methods.)
+    fn check_snake_case_names(&self, push_error: &mut dyn FnMut(Span, String)) {
+        let mut rust_names: Vec<(Id, &Id, Span)> = vec![];
+        for m in &self.methods {
+            if self.should_mirror_in_rust(m.flags.privacy) {
+                rust_names.push((self.rust_method_name(m), &m.name, self.method_span(m)));
+            }
+        }
+        for f in &self.fields {
+            // Constants are named in screaming snake case instead.
+            let is_constant = f.flags.is_static && f.flags.is_final && f.value.is_some();
+            if !self.should_mirror_in_rust(f.flags.privacy) || is_constant {
+                continue;
+            }
+            let span = self.field_span(f);
+            rust_names.push((self.rust_field_getter_name(&f.name), &f.name, span));
+            if !f.flags.is_final {
+                rust_names.push((self.rust_field_setter_name(&f.name), &f.name, span));
+            }
+        }
+
+        for (i, (rust_name, java_name, span)) in rust_names.iter().enumerate() {
+            let clash = rust_names[..i].iter().find(|(other_rust_name, other, _)| {
+                other_rust_name == rust_name && other != java_name
+            });
+            if let Some((_, other, _)) = clash {
+                push_error(
+                    *span,
+                    format!(
+                        "`{other}` and `{java_name}` would both be named `{rust_name}` in Rust; \
+                        list the members to mirror and rename one of them, \
+                        e.g. `{java_name} as {rust_name}_2;`"
+                    ),
+                );
+            }
+        }
+    }
+
     fn compare_flags(
         &self,
         flags: Flags,
@@ -206,7 +304,16 @@ impl ClassInfo {
 impl ClassRef {
     fn check(&self, root_map: &RootMap, push_error: &mut dyn FnMut(String)) {
         let (package_name, class_id) = self.name.split();
-        if let Some(package) = root_map.find_package(package_name) {
+        if package_name.is_empty() {
+            if !root_map.default_package.is_empty()
+                && !root_map.default_package.contains(&self.name)
+            {
+                push_error(format!(
+                    "class `{}` not in list of classes to be translated",
+                    self.name,
+                ))
+            }
+        } else if let Some(package) = root_map.find_package(package_name) {
             if let None = package.find_class(&class_id) {
                 push_error(format!(
                     "class `{}` not in list of classes to be translated",
@@ -256,6 +363,67 @@ impl RefType {
     }
 }
 
+/// Whether the declared supertype `cref` agrees with reflection. `direct` are the reflected
+/// supertypes of the same kind (superclasses or interfaces) and `all_direct` all of them.
+/// Besides the direct supertypes themselves, users may declare `java.lang.Object` or any
+/// (transitive) supertype of them, which lets them skip intermediate classes they do not
+/// want to mirror.
+/// Checks that the JNI `descriptor` computed for `member` from its types is the descriptor
+/// `jvm_descriptor` in the class file (if it was read from one), since the generated code would
+/// otherwise fail to find the member at runtime (with a `NoSuchMethodError` or similar).
+fn check_descriptor(
+    member: &str,
+    descriptor: &str,
+    jvm_descriptor: Option<&str>,
+    push_error: &mut dyn FnMut(String),
+) {
+    match jvm_descriptor {
+        Some(jvm_descriptor) if jvm_descriptor != descriptor => push_error(format!(
+            "{member} has the JVM descriptor `{jvm_descriptor}` in the class file, \
+            but duchess computed `{descriptor}` from its types"
+        )),
+        _ => {}
+    }
+}
+
+fn is_reflected_supertype(
+    cref: &ClassRef,
+    direct: &[ClassRef],
+    all_direct: &[ClassRef],
+    reflector: &Reflector,
+) -> bool {
+    if cref.name == DotId::object() || direct.contains(cref) {
+        return true;
+    }
+
+    // A direct supertype with different generic arguments is a contradiction.
+    if direct.iter().any(|c| c.name == cref.name) {
+        return false;
+    }
+
+    let mut seen = HashSet::new();
+    let mut queue: Vec<DotId> = all_direct.iter().map(|c| c.name.clone()).collect();
+    while let Some(name) = queue.pop() {
+        if name == cref.name {
+            return true;
+        }
+        if !seen.insert(name.clone()) {
+            continue;
+        }
+        // Supertypes that cannot be reflected are simply not followed.
+        let Ok(info) = reflector.reflect(&name, Span::call_site()) else {
+            continue;
+        };
+        queue.extend(
+            info.extends
+                .iter()
+                .chain(&info.implements)
+                .map(|c| c.name.clone()),
+        );
+    }
+    false
+}
+
 fn error_on_duplicates(
     references: &[ClassRef],
     ref_type: &str,
@@ -1,12 +1,14 @@
-use std::{collections::BTreeMap, sync::Arc};
+use std::{collections::BTreeMap, rc::Rc, sync::Arc};
 
 use inflector::Inflector;
-use proc_macro2::{Delimiter, Ident, Span, TokenStream, TokenTree};
+use proc_macro2::{Delimiter, Group, Ident, Span, TokenStream, TokenTree};
 use quote::quote_spanned;
 
 use crate::{
+    argument::Conversion,
     parse::{Parse, TextAccum},
     span_error::SpanError,
+    substitution::{Substitute, Substitution},
     upcasts::Upcasts,
 };
 
@@ -15,13 +17,16 @@ use crate::{
 #[derive(Debug)]
 pub struct RootMap {
     pub subpackages: BTreeMap<Id, SpannedPackageInfo>,
-    pub classes: BTreeMap<DotId, Arc<ClassInfo>>,
+    /// Classes in the default (unnamed) package, generated in the macro's enclosing module.
+    pub default_package: Vec<DotId>,
+    pub classes: BTreeMap<DotId, Rc<ClassInfo>>,
     pub upcasts: Upcasts,
+    pub conversions: Vec<Conversion>,
 }
 
 impl RootMap {
     /// Finds the class with the given name (if present).
-    pub fn find_class(&self, cn: &DotId) -> Option<&Arc<ClassInfo>> {
+    pub fn find_class(&self, cn: &DotId) -> Option<&Rc<ClassInfo>> {
         self.classes.get(cn)
     }
 
@@ -71,14 +76,24 @@ pub enum ClassDecl {
     Reflected(ReflectedClassInfo),
 
     /// User wrote `class Foo { ... }` with full details.
-    Specified(ClassInfo),
+    Specified(Box<ClassInfo>),
 }
 
 impl Parse for ClassDecl {
     fn parse(p: &mut crate::parse::Parser) -> Result<Option<Self>, SpanError> {
+        // Attributes and doc comments are passed through to the generated struct.
+        let attributes = p.eat_attributes()?;
+        let no_class = |p: &mut crate::parse::Parser| {
+            if attributes.is_empty() {
+                Ok(None)
+            } else {
+                Err(p.error("expected a class definition after attributes"))
+            }
+        };
+
         // Look for a keyword that could start a class definition.
         let Some(t0) = p.peek_token() else {
-            return Ok(None);
+            return no_class(p);
         };
         match t0 {
             TokenTree::Ident(i) => {
@@ -93,27 +108,65 @@ impl Parse for ClassDecl {
                 ];
                 let s = i.to_string();
                 if !START_KEYWORDS.contains(&s.as_str()) {
-                    return Ok(None);
+                    return no_class(p);
                 }
             }
-            _ => return Ok(None),
+            _ => return no_class(p),
         }
 
         // Accumulate tokens until we see a braced block `{}` that is the class body.
         let t0 = p.eat_token().unwrap();
         let mut accum = TextAccum::new(p, t0);
-        while let Some(t1) = accum.accum() {
-            match t1 {
-                TokenTree::Group(d) if d.delimiter() == Delimiter::Brace => {
-                    break;
-                }
-                _ => {}
-            }
-        }
+        let is_body =
+            |t: &TokenTree| matches!(t, TokenTree::Group(d) if d.delimiter() == Delimiter::Brace);
+        while accum.accum_if(|t| !is_body(t)).is_some() {}
+        let header = accum.text().to_string();
+        let body = accum.accum();
+
+        // A body listing member names (`{ get*; isEmpty; }`) selects members of the reflected
+        // class, so parse it as if it were `{ * }`.
+        let members = match &body {
+            Some(TokenTree::Group(body)) => Self::member_filters(body.stream())?,
+            _ => None,
+        };
 
         // Parse the text with LALRPOP.
         let (text, span) = accum.into_accumulated_result();
-        let r = javap::parse_class_decl(span, &text)?;
+        let text = match members {
+            Some(_) => format!("{header}{{ * }}"),
+            None => text,
+        };
+        let mut r = match javap::parse_class_decl(span, &text) {
+            Ok(r) => r,
+            Err(e) => {
+                // Try to pin down each member with a problem, so they can all be reported at once.
+                let mut errors = match body {
+                    Some(TokenTree::Group(body)) => {
+                        Self::member_errors(&header, span, body.stream())
+                    }
+                    _ => vec![],
+                };
+                let Some(last) = errors.pop() else {
+                    return Err(e);
+                };
+                for error in errors {
+                    p.report(error);
+                }
+                return Err(last);
+            }
+        };
+        match &mut r {
+            ClassDecl::Reflected(c) => {
+                c.attributes = attributes;
+                c.members = members;
+            }
+            ClassDecl::Specified(c) => {
+                c.attributes = attributes;
+                if let Some(TokenTree::Group(body)) = body {
+                    c.member_spans = Self::member_spans(&header, span, body.stream());
+                }
+            }
+        }
         Ok(Some(r))
     }
 
@@ -122,26 +175,400 @@ impl Parse for ClassDecl {
     }
 }
 
+impl ClassDecl {
+    /// The class name, as the user wrote it.
+    pub fn name(&self) -> &DotId {
+        match self {
+            ClassDecl::Reflected(c) => &c.name,
+            ClassDecl::Specified(c) => &c.name,
+        }
+    }
+
+    /// Parses each member of a class body on its own (splitting at `;`), returning the errors.
+    /// If the class header itself is malformed, returns just that error.
+    fn member_errors(header: &str, span: Span, body: TokenStream) -> Vec<SpanError> {
+        if let Err(e) = javap::parse_class_decl(span, &format!("{header}{{ }}")) {
+            return vec![e];
+        }
+
+        let (members, trailing) = Self::split_members(body);
+        let mut errors = vec![];
+        for member in members {
+            let member_span = member[0].span();
+            let member_text = TokenStream::from_iter(member).to_string();
+            if let Err(e) =
+                javap::parse_class_decl(member_span, &format!("{header}{{ {member_text} }}"))
+            {
+                errors.push(e);
+            }
+        }
+
+        if let Some(t) = trailing.first() {
+            errors.push(SpanError {
+                span: t.span(),
+                message: "expected `;` at the end of class member".to_string(),
+            });
+        }
+
+        errors
+    }
+
+    /// Finds the span of each member's name in a class body that is known to parse.
+    /// Each member is parsed on its own to learn what kind of member it is.
+    fn member_spans(header: &str, span: Span, body: TokenStream) -> MemberSpans {
+        let mut spans = MemberSpans::default();
+        for member in Self::split_members(body).0 {
+            let member_text = TokenStream::from_iter(member.iter().cloned()).to_string();
+            let Ok(ClassDecl::Specified(c)) =
+                javap::parse_class_decl(span, &format!("{header}{{ {member_text} }}"))
+            else {
+                continue;
+            };
+
+            // The name is just before the argument list, or, for fields, just before the `;`.
+            let name_index = match member.iter().position(
+                |t| matches!(t, TokenTree::Group(g) if g.delimiter() == Delimiter::Parenthesis),
+            ) {
+                Some(i) => i.saturating_sub(1),
+                None => member.len().saturating_sub(2),
+            };
+            let name_span = member[name_index].span();
+
+            if !c.constructors.is_empty() {
+                spans.constructors.push(name_span);
+            } else if !c.fields.is_empty() {
+                spans.fields.push(name_span);
+            } else if !c.methods.is_empty() {
+                spans.methods.push(name_span);
+            }
+        }
+        spans
+    }
+
+    /// Recognizes a class body made up only of member names and globs, e.g. `get*; isEmpty;`,
+    /// where names may be given a Rust name, e.g. `getValue as value;`, and constructors may be
+    /// selected with `new;`. Methods and constructors can also be selected by their parameter
+    /// types, e.g. `valueOf(char[]);` or `new(java.io.InputStream);`.
+    fn member_filters(body: TokenStream) -> Result<Option<Vec<MemberFilter>>, SpanError> {
+        let (members, trailing) = Self::split_members(body);
+        if members.is_empty() || !trailing.is_empty() {
+            return Ok(None);
+        }
+
+        let filters: Option<Vec<_>> = members
+            .iter()
+            .map(|member| match &member[..] {
+                [TokenTree::Ident(name), _semi] => Some(Ok(MemberFilter {
+                    span: name.span(),
+                    name: name.to_string(),
+                    glob: false,
+                    rust_name: None,
+                    arguments: None,
+                })),
+                [TokenTree::Ident(name), TokenTree::Group(arguments), _semi]
+                    if arguments.delimiter() == Delimiter::Parenthesis =>
+                {
+                    let span = name.span().join(arguments.span()).unwrap_or(name.span());
+                    Some(
+                        Self::filter_argument_types(arguments).map(|arguments| MemberFilter {
+                            span,
+                            name: name.to_string(),
+                            glob: false,
+                            rust_name: None,
+                            arguments: Some(arguments),
+                        }),
+                    )
+                }
+                [TokenTree::Ident(name), TokenTree::Ident(as_kw), TokenTree::Ident(rust_name), _semi]
+                    if *as_kw == "as" =>
+                {
+                    Some(Ok(MemberFilter {
+                        span: name.span(),
+                        name: name.to_string(),
+                        glob: false,
+                        rust_name: Some((
+                            Id::from(rust_name.to_string().trim_start_matches("r#")),
+                            rust_name.span(),
+                        )),
+                        arguments: None,
+                    }))
+                }
+                [TokenTree::Ident(name), TokenTree::Punct(star), _semi]
+                    if star.as_char() == '*' =>
+                {
+                    Some(Ok(MemberFilter {
+                        span: name.span().join(star.span()).unwrap_or(name.span()),
+                        name: name.to_string(),
+                        glob: true,
+                        rust_name: None,
+                        arguments: None,
+                    }))
+                }
+                _ => None,
+            })
+            .collect();
+
+        match filters {
+            Some(filters) => filters.into_iter().collect::<Result<_, _>>().map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Parses the parameter types of a member filter: either Java types (`int[], java.lang.String`)
+    /// or a string with their JVM descriptors (`"[ILjava/lang/String;"`), e.g. pasted from `javap -s`.
+    fn filter_argument_types(arguments: &Group) -> Result<Vec<Type>, SpanError> {
+        match syn::parse2::<syn::LitStr>(arguments.stream()) {
+            Ok(descriptor) => parse_descriptor_types(descriptor.span(), &descriptor.value()),
+            Err(_) => {
+                javap::parse_argument_types(arguments.span(), &arguments.stream().to_string())
+            }
+        }
+    }
+
+    /// Splits a class body into its `;`-terminated members, plus any trailing tokens without a `;`.
+    fn split_members(body: TokenStream) -> (Vec<Vec<TokenTree>>, Vec<TokenTree>) {
+        let mut members = vec![];
+        let mut member: Vec<TokenTree> = vec![];
+        for t in body {
+            let is_semi = matches!(&t, TokenTree::Punct(p) if p.as_char() == ';');
+            member.push(t);
+            if is_semi {
+                members.push(std::mem::take(&mut member));
+            }
+        }
+        (members, member)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct ReflectedClassInfo {
     pub span: Span,
+    /// Rust attributes (including doc comments) written before the class.
+    pub attributes: Vec<TokenStream>,
     pub flags: Flags,
     pub name: DotId,
+    /// The name for the generated struct given with `as` (`class com.foo.Client as FooClient`).
+    pub alias: Option<Id>,
     pub kind: ClassKind,
+    /// Generics the user wrote after the class name, if any.
+    pub generics: Vec<GenericDecl>,
+    /// Superclasses the user declared (`class Foo extends Bar { * }`), if any. These replace
+    /// the reflected ones, so that only upcasts to the declared classes are generated.
+    pub extends: Option<Vec<ClassRef>>,
+    /// Interfaces the user declared, if any; like `extends`.
+    pub implements: Option<Vec<ClassRef>>,
+    /// The fields and methods to include (`class Foo { get*; isEmpty; }`),
+    /// or `None` to include all members (`class Foo { * }`).
+    pub members: Option<Vec<MemberFilter>>,
+    /// Whether the user wrote `(protected)` before the class body, to also generate
+    /// the `protected` members; see [`ClassInfo::mirror_protected`].
+    pub mirror_protected: bool,
+}
+
+/// A member name in the body of a reflected class: either an exact name (`isEmpty;`),
+/// possibly with a Rust name (`getValue as value;`) or parameter types (`valueOf(char[]);`),
+/// or a prefix followed by `*` (`get*;`).
+#[derive(Clone, Debug)]
+pub struct MemberFilter {
+    pub span: Span,
+    /// The name, or, for a glob, the part before the `*`.
+    pub name: String,
+    pub glob: bool,
+    /// The Rust name given with `as`, and where it was written.
+    pub rust_name: Option<(Id, Span)>,
+    /// The parameter types given in parentheses, e.g. for `new(java.io.InputStream);`;
+    /// only the overloads with exactly these parameters are selected.
+    pub arguments: Option<Vec<Type>>,
+}
+
+impl MemberFilter {
+    /// Whether this selects constructors (`new;` or `new(...);`) rather than fields and methods.
+    pub fn is_constructor(&self) -> bool {
+        !self.glob && self.name == "new"
+    }
+
+    fn matches_name(&self, name: &Id) -> bool {
+        if self.is_constructor() {
+            false
+        } else if self.glob {
+            name.starts_with(&self.name)
+        } else {
+            name[..] == self.name
+        }
+    }
+
+    /// Whether the parameters given in parentheses (if any) are those in `descriptor`.
+    fn matches_arguments(&self, descriptor: &str) -> bool {
+        match &self.arguments {
+            Some(arguments) => {
+                let arguments: String = arguments.iter().map(|a| a.descriptor()).collect();
+                descriptor.starts_with(&format!("({arguments})"))
+            }
+            None => true,
+        }
+    }
+
+    /// Whether this selects `field`; filters with parameter types only select methods.
+    pub fn matches_field(&self, field: &Field) -> bool {
+        self.arguments.is_none() && self.matches_name(&field.name)
+    }
+
+    pub fn matches_method(&self, method: &Method) -> bool {
+        self.matches_name(&method.name) && self.matches_arguments(&method.descriptor())
+    }
+
+    /// Whether this is a constructor filter that selects `constructor`.
+    pub fn matches_constructor(&self, constructor: &Constructor) -> bool {
+        self.is_constructor() && self.matches_arguments(&constructor.descriptor())
+    }
+}
+
+/// Parses JVM parameter descriptors like `[ILjava/lang/String;`, optionally in parentheses.
+fn parse_descriptor_types(span: Span, descriptor: &str) -> Result<Vec<Type>, SpanError> {
+    let error = || {
+        SpanError {
+        span,
+        message: format!(
+            "`{descriptor}` is not a valid list of JVM parameter descriptors, like `[ILjava/lang/String;`"
+        ),
+    }
+    };
+
+    let mut rest = descriptor
+        .strip_prefix('(')
+        .and_then(|d| d.strip_suffix(')'))
+        .unwrap_or(descriptor);
+    let mut types = vec![];
+    while !rest.is_empty() {
+        types.push(parse_descriptor_type(&mut rest).ok_or_else(error)?);
+    }
+    Ok(types)
+}
+
+/// Parses one type from the start of `rest`, and advances `rest` past it.
+fn parse_descriptor_type(rest: &mut &str) -> Option<Type> {
+    let mut chars = rest.chars();
+    let ty = match chars.next()? {
+        'B' => Type::Scalar(ScalarType::Byte),
+        'C' => Type::Scalar(ScalarType::Char),
+        'D' => Type::Scalar(ScalarType::F64),
+        'F' => Type::Scalar(ScalarType::F32),
+        'I' => Type::Scalar(ScalarType::Int),
+        'J' => Type::Scalar(ScalarType::Long),
+        'S' => Type::Scalar(ScalarType::Short),
+        'Z' => Type::Scalar(ScalarType::Boolean),
+        '[' => {
+            *rest = chars.as_str();
+            let element = parse_descriptor_type(rest)?;
+            return Some(Type::Ref(RefType::Array(Arc::new(element))));
+        }
+        'L' => {
+            let (name, after) = chars.as_str().split_once(';')?;
+            if name.split('/').any(|id| id.is_empty()) {
+                return None;
+            }
+            *rest = after;
+            let name: DotId = name.split('/').map(Id::from).collect();
+            return Some(Type::Ref(RefType::Class(ClassRef {
+                name,
+                generics: vec![],
+            })));
+        }
+        _ => return None,
+    };
+    *rest = chars.as_str();
+    Some(ty)
+}
+
+impl std::fmt::Display for MemberFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+        if self.glob {
+            write!(f, "*")?;
+        }
+        if let Some(arguments) = &self.arguments {
+            write!(f, "(")?;
+            for (i, argument) in arguments.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{argument}")?;
+            }
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+/// A generic written in the header of a reflected class, e.g. `class ArrayList<...> { * }`.
+#[derive(Clone, Debug)]
+pub enum GenericDecl {
+    /// User wrote `E` or `E extends Bound`; must match the reflected generic parameter.
+    Parameter(Generic),
+
+    /// User wrote a type like `java.lang.String`; the reflected generic parameter
+    /// is replaced by this type throughout the class.
+    Argument(RefType),
 }
 
 #[derive(Clone, Debug)]
 pub struct ClassInfo {
     pub span: Span,
+    /// Rust attributes (including doc comments) written before the class;
+    /// these are applied to the generated struct.
+    pub attributes: Vec<TokenStream>,
+    /// The Javadoc of the class as Markdown, if its source was found (see [`ClassInfo::from_jar`]).
+    pub docs: Option<String>,
+    /// Set if the class is deprecated. `javap` only shows this in its verbose output, so for
+    /// classes without a class file it is only set by `Reflector::reflect_deprecations`.
+    pub deprecated: Option<Deprecation>,
     pub flags: Flags,
     pub name: DotId,
+    /// The name of the generated struct, if it is not the class name: given with `as`
+    /// (`class com.foo.Client as FooClient`), or made with the package's `prefix` option.
+    /// The class name still refers to the struct within the crate, see [`Self::struct_name`].
+    pub alias: Option<Id>,
     pub kind: ClassKind,
+    /// Whether this is a record class (`record Point(int x, int y)`), which extends
+    /// `java.lang.Record`.
+    pub is_record: bool,
+    /// The components of a record class, in declaration order; empty for other classes.
+    pub record_components: Vec<RecordComponent>,
+    /// The subclasses a sealed class permits (`sealed class Shape permits Circle, Square`),
+    /// in declaration order; empty for other classes.
+    pub permitted_subclasses: Vec<ClassRef>,
     pub generics: Vec<Generic>,
     pub extends: Vec<ClassRef>,
     pub implements: Vec<ClassRef>,
+    /// Generic parameters of the Java class that the user fixed to a specific type
+    /// (e.g., `class ArrayList<java.lang.String> { * }`). These are not among `generics`,
+    /// and `extends`/`implements` have already been substituted, but member types still
+    /// refer to the parameters, so that they continue to match the reflected descriptors.
+    pub type_arguments: Vec<(Id, RefType)>,
+    /// Rust names the user chose for fields and methods (`getValue as value;`), keyed by
+    /// their Java name. They apply to all overloads of a method.
+    pub rust_names: Vec<(Id, Id)>,
     pub constructors: Vec<Constructor>,
     pub fields: Vec<Field>,
     pub methods: Vec<Method>,
+    /// Where the user wrote each member, if they did.
+    pub member_spans: MemberSpans,
+    /// Whether `protected` members are generated as well as public ones; set for reflected
+    /// classes declared with `(protected)`, e.g. `class com.foo.Widget (protected) { * }`.
+    pub mirror_protected: bool,
+    /// Constructors with at least this many parameters also get a builder; set from the
+    /// package's `builder_arity` option.
+    pub builder_arity: Option<usize>,
+}
+
+/// The spans of the names of the members of a class as the user wrote them, in the same order as
+/// [`ClassInfo::constructors`], [`ClassInfo::fields`], and [`ClassInfo::methods`].
+/// Empty for classes whose members come from reflection.
+#[derive(Clone, Debug, Default)]
+pub struct MemberSpans {
+    pub constructors: Vec<Span>,
+    pub fields: Vec<Span>,
+    pub methods: Vec<Span>,
 }
 
 impl ClassInfo {
@@ -149,6 +576,216 @@ impl ClassInfo {
         javap::parse_class_info(span, &text)
     }
 
+    /// Whether `method` is the accessor of one of the components of this record class,
+    /// e.g. `public int x()` for `record Point(int x, int y)`.
+    pub fn is_record_accessor(&self, method: &Method) -> bool {
+        !method.flags.is_static
+            && method.argument_tys.is_empty()
+            && self
+                .record_components
+                .iter()
+                .any(|c| c.name == method.name && Some(&c.ty) == method.return_ty.as_ref())
+    }
+
+    /// Whether `method` is a bridge method standing in for another method of the same name and
+    /// number of arguments, which is the one to call. (Bridges that make a method of a
+    /// non-public superclass accessible, like `StringBuilder.length()`, have no such other
+    /// method.)
+    pub fn is_redundant_bridge(&self, method: &Method) -> bool {
+        method.flags.is_bridge
+            && self.methods.iter().any(|m| {
+                !m.flags.is_bridge
+                    && m.name == method.name
+                    && m.argument_tys.len() == method.argument_tys.len()
+            })
+    }
+
+    /// The span to use for errors about `constructor`, which must be one of `self.constructors`.
+    pub fn constructor_span(&self, constructor: &Constructor) -> Span {
+        Self::member_span(
+            &self.constructors,
+            &self.member_spans.constructors,
+            constructor,
+        )
+        .unwrap_or(self.span)
+    }
+
+    /// The span to use for errors about `field`, which must be one of `self.fields`.
+    pub fn field_span(&self, field: &Field) -> Span {
+        Self::member_span(&self.fields, &self.member_spans.fields, field).unwrap_or(self.span)
+    }
+
+    /// The span to use for errors about `method`, which must be one of `self.methods`.
+    pub fn method_span(&self, method: &Method) -> Span {
+        Self::member_span(&self.methods, &self.member_spans.methods, method).unwrap_or(self.span)
+    }
+
+    fn member_span<M>(members: &[M], spans: &[Span], member: &M) -> Option<Span> {
+        let index = members.iter().position(|m| std::ptr::eq(m, member))?;
+        spans.get(index).copied()
+    }
+
+    /// The name of the Rust function generated for the field or method `java_name`: the name
+    /// the user chose for it, if any, and otherwise `java_name` in snake case.
+    pub fn rust_member_name(&self, java_name: &Id) -> Id {
+        match self.rust_names.iter().find(|(id, _)| id == java_name) {
+            Some((_, rust_name)) => rust_name.clone(),
+            None => java_name.to_snake_case(),
+        }
+    }
+
+    /// The name of the Rust function generated for `constructor`, which must be one of
+    /// `self.constructors`. The only constructor of a class, and the one without parameters,
+    /// are called `new`; the others are named after their (erased) parameter types, e.g.
+    /// `new_from_string_i32`. Constructors that would still get the same name (e.g. for
+    /// classes of the same name in different packages) are numbered in the order of their
+    /// descriptors, so that the names do not depend on the order of the constructors in
+    /// the class file.
+    pub fn rust_constructor_name(&self, constructor: &Constructor) -> Id {
+        if self.constructors.len() == 1 || constructor.argument_tys.is_empty() {
+            return Id::from("new");
+        }
+
+        let name_of = |c: &Constructor| {
+            let types: Vec<String> = c.argument_tys.iter().map(Type::erased_name).collect();
+            format!("new_from_{}", types.join("_"))
+        };
+        let name = name_of(constructor);
+        let mut same_name: Vec<String> = self
+            .constructors
+            .iter()
+            .filter(|c| !c.argument_tys.is_empty() && name_of(c) == name)
+            .map(|c| c.descriptor())
+            .collect();
+        if same_name.len() == 1 {
+            return Id::from(name);
+        }
+
+        same_name.sort();
+        let descriptor = constructor.descriptor();
+        let index = same_name.iter().position(|d| *d == descriptor).unwrap();
+        Id::from(format!("{name}_{}", index + 1))
+    }
+
+    /// The name of the Rust function generated for `method`, which must be one of `self.methods`.
+    ///
+    /// A method that is not overloaded (among the methods mirrored in Rust that are static, or
+    /// not static, like `method`) gets its Rust member name (see `rust_member_name`). Of a set of
+    /// overloads, the one with the fewest parameters whose number of parameters is unique keeps
+    /// that name too, e.g. `indexOf(String)` is `index_of` next to `indexOf(String, int)`.
+    /// The others are named after their (erased) parameter types, like constructors, e.g.
+    /// `index_of_string_i32` or `append_char_array`, and numbered in the order of their
+    /// descriptors if that is not enough. None of this depends on the order of the methods in
+    /// the class file.
+    ///
+    /// Static methods get a `_static` suffix if their name is also used by an instance method,
+    /// see `rust_static_method_name`.
+    pub fn rust_method_name(&self, method: &Method) -> Id {
+        if method.flags.is_static {
+            self.rust_static_method_name(method)
+        } else {
+            self.rust_overload_name(method)
+        }
+    }
+
+    fn rust_overload_name(&self, method: &Method) -> Id {
+        let name = self.rust_member_name(&method.name);
+        let overloads: Vec<&Method> = self
+            .methods
+            .iter()
+            .filter(|m| {
+                m.name == method.name
+                    && m.flags.is_static == method.flags.is_static
+                    && self.should_mirror_in_rust(m.flags.privacy)
+            })
+            .collect();
+        let arity_is_unique = |m: &Method| {
+            overloads
+                .iter()
+                .filter(|o| o.argument_tys.len() == m.argument_tys.len())
+                .count()
+                == 1
+        };
+        let keeps_name = overloads
+            .iter()
+            .filter(|m| arity_is_unique(m))
+            .min_by_key(|m| m.argument_tys.len());
+        let descriptor = method.descriptor();
+        if overloads.len() <= 1 || keeps_name.is_some_and(|m| m.descriptor() == descriptor) {
+            return name;
+        }
+
+        let name_of = |m: &Method| {
+            let types: Vec<String> = m.argument_tys.iter().map(Type::erased_name).collect();
+            format!("{name}_{}", types.join("_"))
+        };
+        let mangled = name_of(method);
+        let mut same_name: Vec<String> = overloads
+            .iter()
+            .filter(|m| keeps_name.is_none_or(|k| k.descriptor() != m.descriptor()))
+            .filter(|m| name_of(m) == mangled)
+            .map(|m| m.descriptor())
+            .collect();
+        if same_name.len() == 1 {
+            return Id::from(mangled);
+        }
+
+        same_name.sort();
+        let index = same_name.iter().position(|d| *d == descriptor).unwrap();
+        Id::from(format!("{mangled}_{}", index + 1))
+    }
+
+    /// The name of the Rust function generated for the static method `method`.
+    /// Instance methods also get an associated function on the class (e.g. `Foo::to_string(foo)`),
+    /// so a static method whose name is also used by an instance method gets a `_static` suffix.
+    pub fn rust_static_method_name(&self, method: &Method) -> Id {
+        let name = self.rust_overload_name(method);
+        let shared_with_instance_method = self.methods.iter().any(|m| {
+            !m.flags.is_static
+                && self.should_mirror_in_rust(m.flags.privacy)
+                && self.rust_overload_name(m) == name
+        });
+        if shared_with_instance_method {
+            Id::from(format!("{name}_static"))
+        } else {
+            name
+        }
+    }
+
+    /// The name of the Rust constant generated for the field `java_name`: the name the user
+    /// chose for it, if any, and otherwise `java_name` in screaming snake case (e.g. `MAX_VALUE`).
+    pub fn rust_constant_name(&self, java_name: &Id) -> Id {
+        match self.rust_names.iter().find(|(id, _)| id == java_name) {
+            Some((_, rust_name)) => rust_name.clone(),
+            None => Id::from(java_name.to_screaming_snake_case()),
+        }
+    }
+
+    /// The name of the Rust function that gets the field `java_name`, e.g. `get_value`.
+    /// If a method is also named `get_value` in Rust (e.g. `getValue()`), the getter
+    /// gets a `_field` suffix.
+    pub fn rust_field_getter_name(&self, java_name: &Id) -> Id {
+        self.rust_field_accessor_name("get", java_name)
+    }
+
+    /// The name of the Rust function that sets the (non-final) field `java_name`, e.g. `set_value`,
+    /// with a `_field` suffix if a method has the same name (see `rust_field_getter_name`).
+    pub fn rust_field_setter_name(&self, java_name: &Id) -> Id {
+        self.rust_field_accessor_name("set", java_name)
+    }
+
+    fn rust_field_accessor_name(&self, prefix: &str, java_name: &Id) -> Id {
+        let name = Id::from(format!("{prefix}_{}", self.rust_member_name(java_name)));
+        let shared_with_method = self.methods.iter().any(|m| {
+            self.should_mirror_in_rust(m.flags.privacy) && self.rust_method_name(m) == name
+        });
+        if shared_with_method {
+            Id::from(format!("{name}_field"))
+        } else {
+            name
+        }
+    }
+
     pub fn this_ref(&self) -> ClassRef {
         ClassRef {
             name: self.name.clone(),
@@ -164,13 +801,14 @@ impl ClassInfo {
     /// We always mirror things declared as public.
     /// In classes, the default privacy indicates "package level" visibility and we do not mirror.
     /// In interfaces, the default privacy indicates "public" visibility and we DO mirror.
+    /// Protected members are only mirrored if the user asked for them, see [`Self::mirror_protected`].
     pub fn should_mirror_in_rust(&self, privacy: Privacy) -> bool {
         match (privacy, self.kind) {
             (Privacy::Public, _) | (Privacy::Default, ClassKind::Interface) => true,
 
-            (Privacy::Protected, _)
-            | (Privacy::Private, _)
-            | (Privacy::Default, ClassKind::Class) => false,
+            (Privacy::Protected, _) => self.mirror_protected,
+
+            (Privacy::Private, _) | (Privacy::Default, ClassKind::Class) => false,
         }
     }
 }
@@ -217,6 +855,9 @@ pub struct Flags {
     pub is_default: bool,
     pub is_transient: bool,
     pub is_volatile: bool,
+    /// Whether the method is a bridge method generated by the compiler, e.g. `compareTo(Object)`
+    /// next to `compareTo(Version)` in a class implementing `Comparable<Version>`.
+    pub is_bridge: bool,
 }
 
 impl Flags {
@@ -231,10 +872,20 @@ impl Flags {
             is_default: false,
             is_transient: false,
             is_volatile: false,
+            is_bridge: false,
         }
     }
 }
 
+/// What `@Deprecated` says about a deprecated class or member.
+#[derive(Eq, Ord, PartialEq, PartialOrd, Clone, Debug, Default)]
+pub struct Deprecation {
+    /// The version in which it was deprecated (`@Deprecated(since = "9")`), if given.
+    pub since: Option<String>,
+    /// Whether it is going to be removed (`@Deprecated(forRemoval = true)`).
+    pub for_removal: bool,
+}
+
 #[derive(Eq, Ord, PartialEq, PartialOrd, Copy, Clone, Debug)]
 pub enum Privacy {
     Public,
@@ -269,10 +920,28 @@ pub struct Constructor {
     pub flags: Flags,
     pub generics: Vec<Generic>,
     pub argument_tys: Vec<Type>,
+    /// The names of the parameters, from the `MethodParameters` attribute of a class
+    /// compiled with `javac -parameters`; empty if they are not known.
+    pub parameter_names: Vec<Id>,
     pub throws: Vec<ClassRef>,
+    /// The Javadoc as Markdown, like [`ClassInfo::docs`].
+    pub docs: Option<String>,
+    /// Set if the member is deprecated, like [`ClassInfo::deprecated`].
+    pub deprecated: Option<Deprecation>,
+    /// The JVM descriptor in the class file, if the member was read from one. It must agree
+    /// with [`Self::descriptor`], see [`ClassInfo::check`].
+    pub jvm_descriptor: Option<String>,
 }
 
 impl Constructor {
+    /// The name of the parameter at `index`, or `arg0`, `arg1`, ... if the names are not known.
+    pub fn parameter_name(&self, index: usize) -> Id {
+        match self.parameter_names.get(index) {
+            Some(name) if self.parameter_names.len() == self.argument_tys.len() => name.clone(),
+            _ => Id::from(format!("arg{index}")),
+        }
+    }
+
     pub fn to_method_sig(&self, class: &ClassInfo) -> MethodSig {
         MethodSig {
             name: class.name.class_name().clone(),
@@ -286,17 +955,134 @@ impl Constructor {
             "({})V",
             self.argument_tys
                 .iter()
-                .map(|a| a.descriptor())
+                .map(|a| descriptor_with_generics(a, &self.generics))
                 .collect::<String>()
         )
     }
 }
 
+/// A component of a record class. For each component, the class has a private final field
+/// and a public accessor method of the same name.
+#[derive(Eq, Ord, PartialEq, PartialOrd, Clone, Debug)]
+pub struct RecordComponent {
+    pub name: Id,
+    pub ty: Type,
+}
+
+impl RecordComponent {
+    /// The components of a record class, from its (private) fields: records cannot declare
+    /// instance fields other than those of their components.
+    pub fn from_fields(fields: &[Field]) -> Vec<RecordComponent> {
+        fields
+            .iter()
+            .filter(|f| !f.flags.is_static)
+            .map(|f| RecordComponent {
+                name: f.name.clone(),
+                ty: f.ty.clone(),
+            })
+            .collect()
+    }
+}
+
 #[derive(Eq, Ord, PartialEq, PartialOrd, Clone, Debug)]
 pub struct Field {
     pub flags: Flags,
     pub name: Id,
     pub ty: Type,
+    /// The value of a constant (`static final`) field, if it is in the class file.
+    /// Constants computed in a static initializer have none.
+    pub value: Option<ConstantValue>,
+    /// The Javadoc as Markdown, like [`ClassInfo::docs`].
+    pub docs: Option<String>,
+    /// Set if the member is deprecated, like [`ClassInfo::deprecated`].
+    pub deprecated: Option<Deprecation>,
+    /// The JVM descriptor in the class file, like [`Constructor::jvm_descriptor`].
+    pub jvm_descriptor: Option<String>,
+}
+
+impl Field {
+    /// The JVM descriptor of the field's type, e.g. `Ljava/lang/String;`.
+    pub fn descriptor(&self) -> String {
+        self.ty.descriptor()
+    }
+}
+
+/// The value of a constant field, as shown by `javap -constants`
+/// (e.g. `public static final int MAX_VALUE = 2147483647;`).
+#[derive(Eq, Ord, PartialEq, PartialOrd, Clone, Debug)]
+pub enum ConstantValue {
+    Boolean(bool),
+    /// An integer or floating-point number without its type suffix, e.g. `-2147483648` or `1.5E300`.
+    Number(String),
+    NotANumber,
+    Infinity {
+        negative: bool,
+    },
+    Char(u16),
+    /// The UTF-16 code units of a string, which may include unpaired surrogates.
+    String(Vec<u16>),
+}
+
+impl ConstantValue {
+    pub(crate) fn number(negative: bool, text: &str) -> Self {
+        let digits = text.trim_end_matches(['l', 'L', 'f', 'F', 'd', 'D']);
+        let sign = if negative { "-" } else { "" };
+        ConstantValue::Number(format!("{sign}{digits}"))
+    }
+
+    /// Values that `javap` shows as identifiers, e.g. `true` or `-Infinityf`.
+    pub(crate) fn named(negative: bool, text: &str) -> Option<Self> {
+        match (negative, text) {
+            (false, "true") => Some(ConstantValue::Boolean(true)),
+            (false, "false") => Some(ConstantValue::Boolean(false)),
+            (false, "NaNf" | "NaNd") => Some(ConstantValue::NotANumber),
+            (_, "Infinityf" | "Infinityd") => Some(ConstantValue::Infinity { negative }),
+            _ => None,
+        }
+    }
+
+    /// Parses a quoted character literal like `'a'` or `'\u0000'`.
+    pub(crate) fn char(text: &str) -> Option<Self> {
+        match Self::unescape(&text[1..text.len() - 1])?[..] {
+            [c] => Some(ConstantValue::Char(c)),
+            _ => None,
+        }
+    }
+
+    /// Parses a quoted string literal like `"a\tb"`.
+    pub(crate) fn string(text: &str) -> Option<Self> {
+        Self::unescape(&text[1..text.len() - 1]).map(ConstantValue::String)
+    }
+
+    /// Decodes the escapes used by `javap`, which are those of Java except for octal escapes.
+    fn unescape(text: &str) -> Option<Vec<u16>> {
+        let mut units = vec![];
+        let mut chars = text.chars();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                units.extend(c.encode_utf16(&mut [0; 2]).iter());
+                continue;
+            }
+            let unit = match chars.next()? {
+                'b' => 0x08,
+                't' => 0x09,
+                'n' => 0x0a,
+                'f' => 0x0c,
+                'r' => 0x0d,
+                c @ ('"' | '\'' | '\\') => c as u16,
+                'u' => {
+                    let hex: String = chars.by_ref().take(4).collect();
+                    if hex.len() != 4 {
+                        return None;
+                    }
+                    u16::from_str_radix(&hex, 16).ok()?
+                }
+                _ => return None,
+            };
+            units.push(unit);
+        }
+        Some(units)
+    }
 }
 
 #[derive(Eq, Ord, PartialEq, PartialOrd, Clone, Debug)]
@@ -307,6 +1093,12 @@ pub struct Method {
     pub argument_tys: Vec<Type>,
     pub return_ty: Option<Type>,
     pub throws: Vec<ClassRef>,
+    /// The Javadoc as Markdown, like [`ClassInfo::docs`].
+    pub docs: Option<String>,
+    /// Set if the member is deprecated, like [`ClassInfo::deprecated`].
+    pub deprecated: Option<Deprecation>,
+    /// The JVM descriptor in the class file, like [`Constructor::jvm_descriptor`].
+    pub jvm_descriptor: Option<String>,
 }
 
 impl Method {
@@ -323,16 +1115,29 @@ impl Method {
             "({}){}",
             self.argument_tys
                 .iter()
-                .map(|a| a.descriptor())
+                .map(|a| descriptor_with_generics(a, &self.generics))
                 .collect::<String>(),
             self.return_ty
                 .as_ref()
-                .map(|r| r.descriptor())
+                .map(|r| descriptor_with_generics(r, &self.generics))
                 .unwrap_or_else(|| format!("V")),
         )
     }
 }
 
+/// The descriptor of `ty` in a method or constructor that declares the type parameters
+/// `generics`. These erase to their leftmost bound, e.g. `T` in `<T extends Number> void f(T)`
+/// is a `java.lang.Number`. (Type parameters of the class still erase to `Object`, see
+/// `NonRepeatingType::descriptor`.)
+fn descriptor_with_generics(ty: &Type, generics: &[Generic]) -> String {
+    let erasures: Vec<(&Id, RefType)> = generics
+        .iter()
+        .filter_map(|g| Some((&g.id, RefType::Class(g.extends.first()?.clone()))))
+        .collect();
+    let subst: Substitution<'_> = erasures.iter().map(|(id, ty)| (*id, ty)).collect();
+    ty.substitute(&subst).descriptor()
+}
+
 /// Signature of a single method in a class;
 /// identifies the method precisely enough
 /// to select from one of many overloaded methods.
@@ -428,6 +1233,25 @@ impl Type {
     pub fn descriptor(&self) -> String {
         self.to_non_repeating().descriptor()
     }
+
+    /// A snake case name for the erasure of this type, used to tell overloads apart in the
+    /// names of Rust functions, e.g. `i32`, `string`, or `i8_array`.
+    pub fn erased_name(&self) -> String {
+        match self.to_non_repeating() {
+            NonRepeatingType::Scalar(s) => s.to_tokens(Span::call_site()).to_string(),
+            NonRepeatingType::Ref(RefType::Class(c)) => {
+                c.name.class_name().replace('$', "_").to_snake_case()
+            }
+            NonRepeatingType::Ref(RefType::Array(e)) => format!("{}_array", e.erased_name()),
+            // FIXME(#42): like the descriptor, this depends on how the type parameter was declared.
+            NonRepeatingType::Ref(
+                RefType::TypeParameter(_)
+                | RefType::Extends(_)
+                | RefType::Super(_)
+                | RefType::Wildcard,
+            ) => "object".to_string(),
+        }
+    }
 }
 
 /// A variant of type
@@ -563,9 +1387,14 @@ impl Id {
         DotId::from(self).dot(s)
     }
 
+    /// Converts to a Rust identifier, using a raw identifier (`r#impl`) for Rust keywords.
     pub fn to_ident(&self, span: Span) -> Ident {
         let data = self.data.replace("$", "__");
-        Ident::new(&data, span)
+        if RUST_KEYWORDS.contains(&&data[..]) {
+            Ident::new_raw(&data, span)
+        } else {
+            Ident::new(&data, span)
+        }
     }
 
     pub fn to_snake_case(&self) -> Self {
@@ -575,6 +1404,16 @@ impl Id {
     }
 }
 
+/// Rust keywords that may appear as Java identifiers and can be written as raw identifiers.
+/// (`self`, `Self`, `super`, and `crate` cannot be.)
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "dyn", "else", "enum", "extern", "false",
+    "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+    "return", "static", "struct", "trait", "true", "type", "unsafe", "use", "where", "while",
+    "abstract", "become", "box", "do", "final", "gen", "macro", "override", "priv", "try",
+    "typeof", "unsized", "virtual", "yield",
+];
+
 impl std::fmt::Display for Id {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.data)
@@ -625,18 +1464,6 @@ impl DotId {
         Self::parse("java.lang.Object")
     }
 
-    pub fn exception() -> Self {
-        Self::parse("java.lang.Exception")
-    }
-
-    pub fn runtime_exception() -> Self {
-        Self::parse("java.lang.RuntimeException")
-    }
-
-    pub fn throwable() -> Self {
-        Self::parse("java.lang.Throwable")
-    }
-
     pub fn parse(s: impl AsRef<str>) -> DotId {
         let s: &str = s.as_ref();
         let ids: Vec<Id> = s.split(".").map(Id::from).collect();
@@ -700,4 +1527,7 @@ impl std::fmt::Display for DotId {
     }
 }
 
+mod class_file;
+mod javadoc;
+pub(crate) use class_file::{jar_entries, jar_entry_name};
 mod javap;
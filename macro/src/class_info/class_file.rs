@@ -0,0 +1,935 @@
+//! Reads class metadata straight from `.class` files (see chapter 4 of the JVM specification),
+//! either on their own or inside a JAR archive, so that classes on the `classpath` given to the
+//! macro can be reflected without `javap`.
+//! The result is the same as parsing the output of `javap -p -constants`, except that
+//! deprecated classes and members are marked as well.
+
+use std::{collections::BTreeSet, fs::File, io::Read, path::Path, sync::Arc};
+
+use proc_macro2::Span;
+
+use crate::span_error::SpanError;
+
+use super::{
+    ClassInfo, ClassKind, ClassRef, ConstantValue, Constructor, Deprecation, DotId, Field, Flags,
+    Generic, Id, MemberSpans, Method, Privacy, RecordComponent, RefType, ScalarType, Type,
+};
+
+const ACC_PUBLIC: u16 = 0x0001;
+const ACC_PRIVATE: u16 = 0x0002;
+const ACC_PROTECTED: u16 = 0x0004;
+const ACC_STATIC: u16 = 0x0008;
+const ACC_FINAL: u16 = 0x0010;
+const ACC_SYNCHRONIZED: u16 = 0x0020;
+const ACC_VOLATILE: u16 = 0x0040;
+const ACC_BRIDGE: u16 = 0x0040;
+const ACC_TRANSIENT: u16 = 0x0080;
+const ACC_VARARGS: u16 = 0x0080;
+const ACC_NATIVE: u16 = 0x0100;
+const ACC_INTERFACE: u16 = 0x0200;
+const ACC_ABSTRACT: u16 = 0x0400;
+
+impl ClassInfo {
+    /// Reads the class file at `path`.
+    pub fn from_class_file(path: &Path, span: Span) -> Result<ClassInfo, SpanError> {
+        let bytes = std::fs::read(path).map_err(|e| SpanError {
+            span,
+            message: format!("failed to read `{}`: {e}", path.display()),
+        })?;
+        Self::from_class_bytes(&bytes, span).map_err(|message| SpanError {
+            span,
+            message: format!("`{}` is not a valid class file: {message}", path.display()),
+        })
+    }
+
+    /// Reads the class file of `class_name` from the JAR (or ZIP) archive at `jar_path`.
+    ///
+    /// If there is a sources JAR next to it (`lib-sources.jar` for `lib.jar`), the Javadoc
+    /// of the class and its members is taken from there.
+    pub fn from_jar(
+        jar_path: &Path,
+        class_name: &DotId,
+        span: Span,
+    ) -> Result<ClassInfo, SpanError> {
+        let entry = jar_entry_name(class_name);
+        let error = |message: String| SpanError {
+            span,
+            message: format!(
+                "failed to read `{entry}` from `{}`: {message}",
+                jar_path.display()
+            ),
+        };
+        let file = File::open(jar_path).map_err(|e| error(e.to_string()))?;
+        let mut archive = zip::ZipArchive::new(file).map_err(|e| error(e.to_string()))?;
+        let mut bytes = vec![];
+        archive
+            .by_name(&entry)
+            .map_err(|e| error(e.to_string()))?
+            .read_to_end(&mut bytes)
+            .map_err(|e| error(e.to_string()))?;
+        let mut info = Self::from_class_bytes(&bytes, span)
+            .map_err(|message| error(format!("not a valid class file: {message}")))?;
+        if let Some(source) = read_source(jar_path, class_name) {
+            info.attach_javadoc(&source);
+        }
+        Ok(info)
+    }
+
+    /// Parses the contents of a class file.
+    pub(crate) fn from_class_bytes(bytes: &[u8], span: Span) -> Result<ClassInfo, String> {
+        let mut r = Reader { bytes };
+        if r.u4()? != 0xCAFEBABE {
+            return Err("missing magic number".to_string());
+        }
+        let _minor_version = r.u2()?;
+        let _major_version = r.u2()?;
+        let pool = ConstantPool::read(&mut r)?;
+
+        let access = r.u2()?;
+        let name = pool.class_name(r.u2()?)?;
+        let super_index = r.u2()?;
+        let superclass = match super_index {
+            0 => None,
+            i => Some(pool.class_name(i)?),
+        };
+        let interfaces = (0..r.u2()?)
+            .map(|_| pool.class_name(r.u2()?))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let kind = if access & ACC_INTERFACE != 0 {
+            ClassKind::Interface
+        } else {
+            ClassKind::Class
+        };
+
+        let mut fields = vec![];
+        for _ in 0..r.u2()? {
+            fields.push(Member::read(&mut r, &pool)?);
+        }
+        let mut methods = vec![];
+        for _ in 0..r.u2()? {
+            methods.push(Member::read(&mut r, &pool)?);
+        }
+        let attributes = Attributes::read(&mut r, &pool)?;
+
+        // Like `javap`, leave out `extends java.lang.Object`, and list the superinterfaces
+        // of an interface as what it extends.
+        let (generics, superclass, interfaces) = match attributes.signature(&pool)? {
+            Some(signature) => {
+                let mut p = SignatureParser::new(&signature);
+                let generics = p.type_parameters()?;
+                let superclass = p.class_type()?;
+                let mut interfaces = vec![];
+                while !p.is_empty() {
+                    interfaces.push(p.class_type()?);
+                }
+                (generics, Some(superclass), interfaces)
+            }
+            None => (
+                vec![],
+                superclass.map(|name| ClassRef {
+                    name,
+                    generics: vec![],
+                }),
+                interfaces
+                    .into_iter()
+                    .map(|name| ClassRef {
+                        name,
+                        generics: vec![],
+                    })
+                    .collect(),
+            ),
+        };
+        let superclass = superclass.filter(|c| c.name != DotId::object());
+        let (extends, implements) = match kind {
+            ClassKind::Class => (superclass.into_iter().collect(), interfaces),
+            ClassKind::Interface => (interfaces, vec![]),
+        };
+
+        let mut flags = Flags::new(privacy(access));
+        flags.is_final = access & ACC_FINAL != 0;
+        flags.is_abstract = kind == ClassKind::Class && access & ACC_ABSTRACT != 0;
+
+        let fields = fields
+            .into_iter()
+            .map(|f| f.into_field(&pool))
+            .collect::<Result<_, _>>()?;
+
+        let record_components = match attributes.get("Record") {
+            Some(data) => read_record_components(Reader { bytes: data }, &pool)?,
+            None => vec![],
+        };
+        let permitted_subclasses = match attributes.get("PermittedSubclasses") {
+            Some(data) => {
+                let mut r = Reader { bytes: data };
+                (0..r.u2()?)
+                    .map(|_| {
+                        Ok(ClassRef {
+                            name: pool.class_name(r.u2()?)?,
+                            generics: vec![],
+                        })
+                    })
+                    .collect::<Result<_, String>>()?
+            }
+            None => vec![],
+        };
+
+        let mut constructors = vec![];
+        let mut class_methods = vec![];
+        for m in methods {
+            match &m.name[..] {
+                "<clinit>" => {}
+                "<init>" => constructors.push(m.into_constructor(&pool)?),
+                _ => class_methods.push(m.into_method(&pool, kind)?),
+            }
+        }
+
+        Ok(ClassInfo {
+            span,
+            attributes: vec![],
+            docs: None,
+            deprecated: attributes.deprecation(&pool)?,
+            flags,
+            name,
+            alias: None,
+            kind,
+            is_record: attributes.get("Record").is_some(),
+            record_components,
+            permitted_subclasses,
+            generics,
+            extends,
+            implements,
+            type_arguments: vec![],
+            rust_names: vec![],
+            constructors,
+            fields,
+            methods: class_methods,
+            member_spans: MemberSpans::default(),
+            mirror_protected: false,
+            builder_arity: None,
+        })
+    }
+}
+
+fn privacy(access: u16) -> Privacy {
+    if access & ACC_PUBLIC != 0 {
+        Privacy::Public
+    } else if access & ACC_PROTECTED != 0 {
+        Privacy::Protected
+    } else if access & ACC_PRIVATE != 0 {
+        Privacy::Private
+    } else {
+        Privacy::Default
+    }
+}
+
+/// Reads the components listed in the `Record` attribute of a record class.
+fn read_record_components(
+    mut r: Reader<'_>,
+    pool: &ConstantPool,
+) -> Result<Vec<RecordComponent>, String> {
+    let mut components = vec![];
+    for _ in 0..r.u2()? {
+        let name = pool.utf8(r.u2()?)?;
+        let descriptor = pool.utf8(r.u2()?)?;
+        let signature = Attributes::read(&mut r, pool)?.signature(pool)?;
+        let ty = SignatureParser::new(signature.as_deref().unwrap_or(&descriptor)).field_type()?;
+        components.push(RecordComponent {
+            name: Id::from(name),
+            ty,
+        });
+    }
+    Ok(components)
+}
+
+/// A field or method, as it appears in the class file.
+struct Member<'a> {
+    access: u16,
+    name: String,
+    descriptor: String,
+    attributes: Attributes<'a>,
+}
+
+impl<'a> Member<'a> {
+    fn read(r: &mut Reader<'a>, pool: &ConstantPool) -> Result<Self, String> {
+        Ok(Member {
+            access: r.u2()?,
+            name: pool.utf8(r.u2()?)?,
+            descriptor: pool.utf8(r.u2()?)?,
+            attributes: Attributes::read(r, pool)?,
+        })
+    }
+
+    fn flags(&self) -> Flags {
+        let mut flags = Flags::new(privacy(self.access));
+        flags.is_static = self.access & ACC_STATIC != 0;
+        flags.is_final = self.access & ACC_FINAL != 0;
+        flags
+    }
+
+    fn into_field(self, pool: &ConstantPool) -> Result<Field, String> {
+        let mut flags = self.flags();
+        flags.is_volatile = self.access & ACC_VOLATILE != 0;
+        flags.is_transient = self.access & ACC_TRANSIENT != 0;
+
+        let signature = self.attributes.signature(pool)?;
+        let ty =
+            SignatureParser::new(signature.as_deref().unwrap_or(&self.descriptor)).field_type()?;
+
+        let value = match self.attributes.get("ConstantValue") {
+            Some(data) => Some(pool.constant_value(Reader { bytes: data }.u2()?, &ty)?),
+            None => None,
+        };
+
+        Ok(Field {
+            flags,
+            name: Id::from(self.name),
+            ty,
+            value,
+            docs: None,
+            deprecated: self.attributes.deprecation(pool)?,
+            jvm_descriptor: Some(self.descriptor),
+        })
+    }
+
+    fn method_signature(&self, pool: &ConstantPool) -> Result<MethodSignature, String> {
+        let signature = self.attributes.signature(pool)?;
+        let MethodSignature {
+            generics,
+            mut argument_tys,
+            return_ty,
+            mut throws,
+        } = SignatureParser::new(signature.as_deref().unwrap_or(&self.descriptor)).method()?;
+
+        // The `Exceptions` attribute lists what is thrown unless the signature does.
+        if throws.is_empty() {
+            if let Some(data) = self.attributes.get("Exceptions") {
+                let mut r = Reader { bytes: data };
+                for _ in 0..r.u2()? {
+                    throws.push(ClassRef {
+                        name: pool.class_name(r.u2()?)?,
+                        generics: vec![],
+                    });
+                }
+            }
+        }
+
+        if self.access & ACC_VARARGS != 0 {
+            if let Some(Type::Ref(RefType::Array(element))) = argument_tys.last() {
+                let element = element.clone();
+                *argument_tys.last_mut().unwrap() = Type::Repeat(element);
+            }
+        }
+
+        Ok(MethodSignature {
+            generics,
+            argument_tys,
+            return_ty,
+            throws,
+        })
+    }
+
+    fn into_constructor(self, pool: &ConstantPool) -> Result<Constructor, String> {
+        let MethodSignature {
+            generics,
+            mut argument_tys,
+            return_ty: _,
+            throws,
+        } = self.method_signature(pool)?;
+
+        // The constructor of an inner class takes its enclosing instance as an implicit
+        // first argument, which its descriptor includes but its `Signature` leaves out
+        // (`Lfoo/Outer;Ljava/util/List;` vs `Ljava/util/List<Ljava/lang/String;>;`).
+        let descriptor_tys = SignatureParser::new(&self.descriptor)
+            .method()?
+            .argument_tys;
+        if descriptor_tys.len() > argument_tys.len() {
+            let implicit = descriptor_tys.len() - argument_tys.len();
+            argument_tys.splice(0..0, descriptor_tys.into_iter().take(implicit));
+        }
+
+        // Classes compiled with `javac -parameters` record the names of the parameters.
+        let parameter_names = match self.attributes.get("MethodParameters") {
+            Some(data) => {
+                let mut r = Reader { bytes: data };
+                (0..r.u1()?)
+                    .map(|i| {
+                        let name = match r.u2()? {
+                            0 => format!("arg{i}"),
+                            index => pool.utf8(index)?,
+                        };
+                        let _access = r.u2()?;
+                        Ok(Id::from(name))
+                    })
+                    .collect::<Result<_, String>>()?
+            }
+            None => vec![],
+        };
+
+        Ok(Constructor {
+            flags: self.flags(),
+            generics,
+            argument_tys,
+            parameter_names,
+            throws,
+            docs: None,
+            deprecated: self.attributes.deprecation(pool)?,
+            jvm_descriptor: Some(self.descriptor),
+        })
+    }
+
+    fn into_method(self, pool: &ConstantPool, kind: ClassKind) -> Result<Method, String> {
+        let MethodSignature {
+            generics,
+            argument_tys,
+            return_ty,
+            throws,
+        } = self.method_signature(pool)?;
+        let mut flags = self.flags();
+        flags.is_synchronized = self.access & ACC_SYNCHRONIZED != 0;
+        flags.is_native = self.access & ACC_NATIVE != 0;
+        flags.is_abstract = self.access & ACC_ABSTRACT != 0;
+        flags.is_bridge = self.access & ACC_BRIDGE != 0;
+        // `javap` shows the public instance methods of an interface that have a body as `default`.
+        flags.is_default = kind == ClassKind::Interface
+            && flags.privacy == Privacy::Public
+            && !flags.is_static
+            && !flags.is_abstract;
+        Ok(Method {
+            flags,
+            name: Id::from(self.name),
+            generics,
+            argument_tys,
+            return_ty,
+            throws,
+            docs: None,
+            deprecated: self.attributes.deprecation(pool)?,
+            jvm_descriptor: Some(self.descriptor),
+        })
+    }
+}
+
+/// The generics, argument types, return type, and thrown exceptions of a method.
+struct MethodSignature {
+    generics: Vec<Generic>,
+    argument_tys: Vec<Type>,
+    return_ty: Option<Type>,
+    throws: Vec<ClassRef>,
+}
+
+/// The attributes of a class or member, by name.
+struct Attributes<'a> {
+    attributes: Vec<(String, &'a [u8])>,
+}
+
+impl<'a> Attributes<'a> {
+    fn read(r: &mut Reader<'a>, pool: &ConstantPool) -> Result<Self, String> {
+        let mut attributes = vec![];
+        for _ in 0..r.u2()? {
+            let name = pool.utf8(r.u2()?)?;
+            let len = r.u4()? as usize;
+            attributes.push((name, r.take(len)?));
+        }
+        Ok(Attributes { attributes })
+    }
+
+    fn get(&self, name: &str) -> Option<&'a [u8]> {
+        self.attributes
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, data)| *data)
+    }
+
+    /// Whether the class or member is deprecated, along with what its `@Deprecated` annotation
+    /// says, if it has one.
+    fn deprecation(&self, pool: &ConstantPool) -> Result<Option<Deprecation>, String> {
+        let mut deprecation = self.get("Deprecated").map(|_| Deprecation::default());
+        let Some(data) = self.get("RuntimeVisibleAnnotations") else {
+            return Ok(deprecation);
+        };
+        let mut r = Reader { bytes: data };
+        for _ in 0..r.u2()? {
+            if pool.utf8(r.u2()?)? != "Ljava/lang/Deprecated;" {
+                r.skip_annotation_elements()?;
+                continue;
+            }
+            let deprecation = deprecation.get_or_insert_with(Deprecation::default);
+            for _ in 0..r.u2()? {
+                let name = pool.utf8(r.u2()?)?;
+                match (&name[..], r.u1()?) {
+                    ("since", b's') => deprecation.since = Some(pool.utf8(r.u2()?)?),
+                    ("forRemoval", b'Z') => {
+                        deprecation.for_removal =
+                            matches!(pool.get(r.u2()?)?, Constant::Integer(v) if *v != 0)
+                    }
+                    (_, tag) => r.skip_element_value(tag)?,
+                }
+            }
+        }
+        Ok(deprecation)
+    }
+
+    /// The generic signature, if the class or member has one.
+    fn signature(&self, pool: &ConstantPool) -> Result<Option<String>, String> {
+        match self.get("Signature") {
+            Some(data) => Ok(Some(pool.utf8(Reader { bytes: data }.u2()?)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+        if self.bytes.len() < len {
+            return Err("unexpected end of file".to_string());
+        }
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn u1(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u2(&mut self) -> Result<u16, String> {
+        let b = self.take(2)?;
+        Ok(u16::from_be_bytes([b[0], b[1]]))
+    }
+
+    fn u4(&mut self) -> Result<u32, String> {
+        let b = self.take(4)?;
+        Ok(u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    fn u8(&mut self) -> Result<u64, String> {
+        Ok((u64::from(self.u4()?) << 32) | u64::from(self.u4()?))
+    }
+
+    /// Skips the element-value pairs of an annotation, after its type.
+    fn skip_annotation_elements(&mut self) -> Result<(), String> {
+        for _ in 0..self.u2()? {
+            let _name = self.u2()?;
+            let tag = self.u1()?;
+            self.skip_element_value(tag)?;
+        }
+        Ok(())
+    }
+
+    /// Skips an annotation element value, after its `tag`.
+    fn skip_element_value(&mut self, tag: u8) -> Result<(), String> {
+        match tag {
+            b'B' | b'C' | b'D' | b'F' | b'I' | b'J' | b'S' | b'Z' | b's' | b'c' => {
+                self.u2()?;
+            }
+            b'e' => {
+                self.u2()?;
+                self.u2()?;
+            }
+            b'@' => {
+                self.u2()?;
+                self.skip_annotation_elements()?;
+            }
+            b'[' => {
+                for _ in 0..self.u2()? {
+                    let tag = self.u1()?;
+                    self.skip_element_value(tag)?;
+                }
+            }
+            _ => return Err(format!("unknown annotation element tag `{}`", tag as char)),
+        }
+        Ok(())
+    }
+}
+
+enum Constant {
+    /// Text, as UTF-16 code units.
+    Utf8(Vec<u16>),
+    Integer(i32),
+    Float(f32),
+    Long(i64),
+    Double(f64),
+    /// The index of the class name.
+    Class(u16),
+    /// The index of the text.
+    String(u16),
+    /// Entries that reflection does not need, and the unusable entry after a `Long` or `Double`.
+    Other,
+}
+
+struct ConstantPool {
+    /// Entry `i` is at index `i - 1`.
+    constants: Vec<Constant>,
+}
+
+impl ConstantPool {
+    fn read(r: &mut Reader<'_>) -> Result<Self, String> {
+        let count = r.u2()?;
+        let mut constants = vec![];
+        while constants.len() + 1 < usize::from(count) {
+            let tag = r.u1()?;
+            let constant = match tag {
+                1 => {
+                    let len = r.u2()?;
+                    Constant::Utf8(decode_modified_utf8(r.take(usize::from(len))?)?)
+                }
+                3 => Constant::Integer(r.u4()? as i32),
+                4 => Constant::Float(f32::from_bits(r.u4()?)),
+                5 => Constant::Long(r.u8()? as i64),
+                6 => Constant::Double(f64::from_bits(r.u8()?)),
+                7 => Constant::Class(r.u2()?),
+                8 => Constant::String(r.u2()?),
+                16 | 19 | 20 => {
+                    r.take(2)?;
+                    Constant::Other
+                }
+                15 => {
+                    r.take(3)?;
+                    Constant::Other
+                }
+                9 | 10 | 11 | 12 | 17 | 18 => {
+                    r.take(4)?;
+                    Constant::Other
+                }
+                _ => return Err(format!("unknown constant pool tag {tag}")),
+            };
+            let wide = matches!(constant, Constant::Long(_) | Constant::Double(_));
+            constants.push(constant);
+            if wide {
+                constants.push(Constant::Other);
+            }
+        }
+        Ok(ConstantPool { constants })
+    }
+
+    fn get(&self, index: u16) -> Result<&Constant, String> {
+        usize::from(index)
+            .checked_sub(1)
+            .and_then(|i| self.constants.get(i))
+            .ok_or_else(|| format!("invalid constant pool index {index}"))
+    }
+
+    fn utf16(&self, index: u16) -> Result<&[u16], String> {
+        match self.get(index)? {
+            Constant::Utf8(units) => Ok(units),
+            _ => Err(format!("constant pool entry {index} is not text")),
+        }
+    }
+
+    fn utf8(&self, index: u16) -> Result<String, String> {
+        Ok(String::from_utf16_lossy(self.utf16(index)?))
+    }
+
+    /// The name of the class at `index`, e.g. `java.lang.Object`.
+    fn class_name(&self, index: u16) -> Result<DotId, String> {
+        match self.get(index)? {
+            Constant::Class(name) => Ok(jvm_name_to_dot_id(&self.utf8(*name)?)),
+            _ => Err(format!("constant pool entry {index} is not a class")),
+        }
+    }
+
+    /// The value of a constant field of type `ty`, shown by `javap` in the same way.
+    fn constant_value(&self, index: u16, ty: &Type) -> Result<ConstantValue, String> {
+        let float = |value: f64, text: String| {
+            if value.is_nan() {
+                ConstantValue::NotANumber
+            } else if value.is_infinite() {
+                ConstantValue::Infinity {
+                    negative: value < 0.0,
+                }
+            } else {
+                ConstantValue::Number(text)
+            }
+        };
+        Ok(match (self.get(index)?, ty) {
+            (Constant::Integer(i), Type::Scalar(ScalarType::Boolean)) => {
+                ConstantValue::Boolean(*i != 0)
+            }
+            (Constant::Integer(i), Type::Scalar(ScalarType::Char)) => {
+                ConstantValue::Char(*i as u16)
+            }
+            (Constant::Integer(i), _) => ConstantValue::Number(i.to_string()),
+            (Constant::Long(l), _) => ConstantValue::Number(l.to_string()),
+            (Constant::Float(f), _) => float(f64::from(*f), format!("{f:?}")),
+            (Constant::Double(d), _) => float(*d, format!("{d:?}")),
+            (Constant::String(s), _) => ConstantValue::String(self.utf16(*s)?.to_vec()),
+            _ => {
+                return Err(format!(
+                    "constant pool entry {index} is not a constant value"
+                ))
+            }
+        })
+    }
+}
+
+/// Converts a name like `java/lang/Object` to `java.lang.Object`.
+fn jvm_name_to_dot_id(name: &str) -> DotId {
+    name.split('/').map(Id::from).collect()
+}
+
+/// The names of the entries in the JAR (or ZIP) archive at `jar_path`.
+pub(crate) fn jar_entries(jar_path: &Path) -> Result<BTreeSet<String>, String> {
+    let file = File::open(jar_path).map_err(|e| e.to_string())?;
+    let archive = zip::ZipArchive::new(file).map_err(|e| e.to_string())?;
+    let entries = archive.file_names().map(String::from).collect();
+    Ok(entries)
+}
+
+/// Reads the `.java` file that declares `class_name` from the sources JAR next to `jar_path`,
+/// if there is one. Documentation is optional, so any failure just gives `None`.
+fn read_source(jar_path: &Path, class_name: &DotId) -> Option<String> {
+    let stem = jar_path.file_stem()?.to_str()?;
+    let sources_path = jar_path.with_file_name(format!("{stem}-sources.jar"));
+    let mut archive = zip::ZipArchive::new(File::open(sources_path).ok()?).ok()?;
+    let (package, class) = class_name.split();
+    let outermost = class.split('$').next()?;
+    let entry: String = package
+        .iter()
+        .map(|id| format!("{id}/"))
+        .chain([format!("{outermost}.java")])
+        .collect();
+    let mut source = String::new();
+    archive
+        .by_name(&entry)
+        .ok()?
+        .read_to_string(&mut source)
+        .ok()?;
+    Some(source)
+}
+
+/// The name of the class file of `class_name` in a JAR, e.g. `java/lang/Object.class`.
+pub(crate) fn jar_entry_name(class_name: &DotId) -> String {
+    let ids: Vec<&str> = class_name.iter().map(|id| &id[..]).collect();
+    format!("{}.class", ids.join("/"))
+}
+
+/// Decodes the "modified UTF-8" of class files, which encodes `\0` with two bytes
+/// and characters outside the BMP as surrogate pairs, into UTF-16 code units.
+fn decode_modified_utf8(bytes: &[u8]) -> Result<Vec<u16>, String> {
+    let error = || "malformed modified UTF-8 text".to_string();
+    let mut units = vec![];
+    let mut bytes = bytes.iter().map(|&b| u16::from(b));
+    while let Some(b0) = bytes.next() {
+        let mut continuation = || match bytes.next() {
+            Some(b) if b & 0xC0 == 0x80 => Ok(b & 0x3F),
+            _ => Err(error()),
+        };
+        let unit = if b0 & 0x80 == 0 {
+            b0
+        } else if b0 & 0xE0 == 0xC0 {
+            ((b0 & 0x1F) << 6) | continuation()?
+        } else if b0 & 0xF0 == 0xE0 {
+            ((b0 & 0x0F) << 12) | (continuation()? << 6) | continuation()?
+        } else {
+            return Err(error());
+        };
+        units.push(unit);
+    }
+    Ok(units)
+}
+
+/// Parses generic signatures (JVM specification, section 4.7.9.1), as well as plain descriptors.
+struct SignatureParser<'s> {
+    signature: &'s str,
+    rest: &'s str,
+}
+
+impl<'s> SignatureParser<'s> {
+    fn new(signature: &'s str) -> Self {
+        SignatureParser {
+            signature,
+            rest: signature,
+        }
+    }
+
+    fn error(&self) -> String {
+        format!("malformed signature `{}`", self.signature)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest.chars().next()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        match self.rest.strip_prefix(c) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), String> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn identifier(&mut self) -> Result<&'s str, String> {
+        let end = self
+            .rest
+            .find([';', '<', '>', ':', '.', '/'])
+            .unwrap_or(self.rest.len());
+        if end == 0 {
+            return Err(self.error());
+        }
+        let (id, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        Ok(id)
+    }
+
+    /// `<T:Ljava/lang/Object;U::Ljava/lang/Comparable<TU;>;>`, if present.
+    fn type_parameters(&mut self) -> Result<Vec<Generic>, String> {
+        let mut generics = vec![];
+        if !self.eat('<') {
+            return Ok(generics);
+        }
+        while !self.eat('>') {
+            let id = Id::from(self.identifier()?);
+            let mut extends = vec![];
+            // The class bound may be empty (`T::Ljava/lang/Runnable;`); interface bounds follow.
+            self.expect(':')?;
+            loop {
+                if !matches!(self.peek(), Some(':' | '>') | None) {
+                    // Bounds that are type variables (`U:TT;`) cannot be represented, so
+                    // they are left out; as in `javap`, so is `java.lang.Object`.
+                    if let RefType::Class(c) = self.reference_type()? {
+                        if c.name != DotId::object() {
+                            extends.push(c);
+                        }
+                    }
+                }
+                if !self.eat(':') {
+                    break;
+                }
+            }
+            generics.push(Generic { id, extends });
+        }
+        Ok(generics)
+    }
+
+    /// `Ljava/util/List<TE;>;`, or a nested class like `Lfoo/Outer<TT;>.Inner;`,
+    /// which becomes `foo.Outer$Inner`.
+    fn class_type(&mut self) -> Result<ClassRef, String> {
+        self.expect('L')?;
+        let mut name = self.identifier()?.to_string();
+        while self.eat('/') {
+            name.push('/');
+            name.push_str(self.identifier()?);
+        }
+        let mut generics = self.type_arguments()?;
+        while self.eat('.') {
+            name.push('$');
+            name.push_str(self.identifier()?);
+            generics = self.type_arguments()?;
+        }
+        self.expect(';')?;
+        Ok(ClassRef {
+            name: jvm_name_to_dot_id(&name),
+            generics,
+        })
+    }
+
+    fn type_arguments(&mut self) -> Result<Vec<RefType>, String> {
+        let mut arguments = vec![];
+        if !self.eat('<') {
+            return Ok(arguments);
+        }
+        while !self.eat('>') {
+            let argument = if self.eat('*') {
+                RefType::Wildcard
+            } else if self.eat('+') {
+                RefType::Extends(Arc::new(self.reference_type()?))
+            } else if self.eat('-') {
+                RefType::Super(Arc::new(self.reference_type()?))
+            } else {
+                self.reference_type()?
+            };
+            arguments.push(argument);
+        }
+        Ok(arguments)
+    }
+
+    fn reference_type(&mut self) -> Result<RefType, String> {
+        match self.peek() {
+            Some('L') => Ok(RefType::Class(self.class_type()?)),
+            Some('T') => {
+                self.expect('T')?;
+                let id = Id::from(self.identifier()?);
+                self.expect(';')?;
+                Ok(RefType::TypeParameter(id))
+            }
+            Some('[') => {
+                self.expect('[')?;
+                Ok(RefType::Array(Arc::new(self.java_type()?)))
+            }
+            _ => Err(self.error()),
+        }
+    }
+
+    fn java_type(&mut self) -> Result<Type, String> {
+        let scalar = match self.peek() {
+            Some('B') => ScalarType::Byte,
+            Some('C') => ScalarType::Char,
+            Some('D') => ScalarType::F64,
+            Some('F') => ScalarType::F32,
+            Some('I') => ScalarType::Int,
+            Some('J') => ScalarType::Long,
+            Some('S') => ScalarType::Short,
+            Some('Z') => ScalarType::Boolean,
+            _ => return Ok(Type::Ref(self.reference_type()?)),
+        };
+        self.rest = &self.rest[1..];
+        Ok(Type::Scalar(scalar))
+    }
+
+    fn field_type(&mut self) -> Result<Type, String> {
+        let ty = self.java_type()?;
+        if !self.is_empty() {
+            return Err(self.error());
+        }
+        Ok(ty)
+    }
+
+    /// `<T:Ljava/lang/Object;>(TT;I)Ljava/util/List<TT;>;^Ljava/io/IOException;`
+    fn method(&mut self) -> Result<MethodSignature, String> {
+        let generics = self.type_parameters()?;
+        self.expect('(')?;
+        let mut argument_tys = vec![];
+        while !self.eat(')') {
+            argument_tys.push(self.java_type()?);
+        }
+        let return_ty = if self.eat('V') {
+            None
+        } else {
+            Some(self.java_type()?)
+        };
+        let mut throws = vec![];
+        while self.eat('^') {
+            // A thrown type variable (`^TE;`) is left out, like a bound that is a type variable.
+            if let RefType::Class(c) = self.reference_type()? {
+                throws.push(c);
+            }
+        }
+        if !self.is_empty() {
+            return Err(self.error());
+        }
+        Ok(MethodSignature {
+            generics,
+            argument_tys,
+            return_ty,
+            throws,
+        })
+    }
+}
@@ -0,0 +1,654 @@
+//! Finds the Javadoc comments in a Java source file, such as one from the `-sources.jar` next to
+//! a JAR, and turns them into Markdown for the doc comments of the generated code.
+//!
+//! This is not a Java parser: it only tracks enough of the structure (comments, literals, braces,
+//! and the text of declarations) to tell which class or member each comment belongs to.
+
+use super::{ClassInfo, RefType, ScalarType, Type};
+
+impl ClassInfo {
+    /// Sets the `docs` of this class and its members from `source`, the contents of the
+    /// `.java` file that declares the class (or its outermost class, for nested classes).
+    pub(crate) fn attach_javadoc(&mut self, source: &str) {
+        let class_name = self.name.class_name().to_string();
+        let path: Vec<&str> = class_name.split('$').collect();
+        let (outer, simple_name) = path.split_at(path.len() - 1);
+        let simple_name = simple_name[0];
+
+        for item in scan(source) {
+            let docs = || Some(to_markdown(&item.comment));
+            match &item.declaration {
+                Declaration::Class { name, .. } => {
+                    if item.classes == outer && name == simple_name {
+                        self.docs = docs();
+                    }
+                }
+                _ if item.classes != path => {}
+                Declaration::Method { name, parameters } if name == simple_name => {
+                    for constructor in &mut self.constructors {
+                        let arguments = simple_type_names(&constructor.argument_tys);
+                        // Constructors of inner classes take the outer instance first.
+                        let inner = !outer.is_empty()
+                            && arguments.len() == parameters.len() + 1
+                            && arguments[1..] == parameters[..];
+                        if arguments == *parameters || inner {
+                            constructor.docs = docs();
+                        }
+                    }
+                }
+                Declaration::Method { name, parameters } => {
+                    for method in &mut self.methods {
+                        if &method.name[..] == name
+                            && simple_type_names(&method.argument_tys) == *parameters
+                        {
+                            method.docs = docs();
+                        }
+                    }
+                }
+                Declaration::Field(name) => {
+                    for field in &mut self.fields {
+                        if &field.name[..] == name {
+                            field.docs = docs();
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The names of the types as they are usually written in Java source, e.g. `int`, `String[]`,
+/// `Entry` (for `java.util.Map$Entry`), or `T`.
+fn simple_type_names(tys: &[Type]) -> Vec<String> {
+    fn simple_ref_type_name(ty: &RefType) -> String {
+        match ty {
+            RefType::Class(c) => {
+                let name = c.name.class_name();
+                name.rsplit('$').next().unwrap_or(name).to_string()
+            }
+            RefType::Array(element) => format!("{}[]", simple_type_name(element)),
+            RefType::TypeParameter(id) => id.to_string(),
+            RefType::Extends(_) | RefType::Super(_) | RefType::Wildcard => "Object".to_string(),
+        }
+    }
+
+    fn simple_type_name(ty: &Type) -> String {
+        match ty {
+            Type::Ref(r) => simple_ref_type_name(r),
+            Type::Scalar(s) => match s {
+                ScalarType::Int => "int",
+                ScalarType::Long => "long",
+                ScalarType::Short => "short",
+                ScalarType::Byte => "byte",
+                ScalarType::F64 => "double",
+                ScalarType::F32 => "float",
+                ScalarType::Boolean => "boolean",
+                ScalarType::Char => "char",
+            }
+            .to_string(),
+            Type::Repeat(element) => format!("{}[]", simple_type_name(element)),
+        }
+    }
+
+    tys.iter().map(simple_type_name).collect()
+}
+
+/// A declaration with a doc comment before it.
+struct Documented {
+    /// The names of the classes whose bodies the declaration is in, outermost first.
+    classes: Vec<String>,
+    declaration: Declaration,
+    /// The text between `/**` and `*/`.
+    comment: String,
+}
+
+enum Declaration {
+    Class {
+        name: String,
+        is_enum: bool,
+    },
+    /// A method or constructor, with the simple names of its parameter types
+    /// (see [`simple_type_names`]).
+    Method {
+        name: String,
+        parameters: Vec<String>,
+    },
+    Field(String),
+}
+
+/// The body of a class.
+struct Body {
+    name: String,
+    /// Whether this is the body of an enum, before the `;` that ends its constants.
+    constants: bool,
+}
+
+/// Finds the doc comments in `source` and the declarations they belong to.
+fn scan(source: &str) -> Vec<Documented> {
+    let chars: Vec<char> = source.chars().collect();
+    let starts_with = |i: usize, s: &str| {
+        s.chars()
+            .enumerate()
+            .all(|(j, c)| chars.get(i + j) == Some(&c))
+    };
+    let find = |i: usize, s: &str| (i..chars.len()).find(|&j| starts_with(j, s));
+
+    let mut items = vec![];
+    // For each open `{`, the class body it starts, or `None` for other blocks (like method
+    // bodies), whose contents are skipped.
+    let mut blocks: Vec<Option<Body>> = vec![];
+    let mut comment: Option<String> = None;
+    let mut declaration = String::new();
+    let mut parens = 0;
+
+    let mut i = 0;
+    while i < chars.len() {
+        let skipping = blocks.iter().any(Option::is_none);
+        let c = chars[i];
+        if starts_with(i, "/*") {
+            let end = find(i + 2, "*/").unwrap_or(chars.len());
+            if starts_with(i, "/**") && end > i + 2 && !skipping {
+                comment = Some(chars[i + 3..end].iter().collect());
+            }
+            i = end + 2;
+            continue;
+        } else if starts_with(i, "//") {
+            i = find(i, "\n").unwrap_or(chars.len());
+            continue;
+        } else if starts_with(i, "\"\"\"") {
+            i = find(i + 3, "\"\"\"").map_or(chars.len(), |end| end + 3);
+            declaration.push_str("\"\"");
+            continue;
+        } else if c == '"' || c == '\'' {
+            i += 1;
+            while i < chars.len() && chars[i] != c {
+                i += if chars[i] == '\\' { 2 } else { 1 };
+            }
+            i += 1;
+            declaration.push_str("\"\"");
+            continue;
+        }
+        i += 1;
+
+        if skipping {
+            match c {
+                '{' => blocks.push(None),
+                '}' => {
+                    blocks.pop();
+                }
+                _ => {}
+            }
+            continue;
+        }
+
+        let classes = || blocks.iter().flatten().map(|b| b.name.clone()).collect();
+        let constants = matches!(blocks.last(), Some(Some(body)) if body.constants);
+        match c {
+            '(' => {
+                parens += 1;
+                declaration.push(c);
+            }
+            ')' => {
+                parens -= 1;
+                declaration.push(c);
+            }
+            // An enum constant, like `RED`, `RED(255, 0, 0)`, or `RED { ... }`
+            ',' | ';' | '{' | '}' if parens == 0 && constants => {
+                let name = tokenize(&strip_annotations(&declaration))
+                    .into_iter()
+                    .next();
+                if let (Some(comment), Some(name)) = (comment.take(), name) {
+                    items.push(Documented {
+                        classes: classes(),
+                        declaration: Declaration::Field(name),
+                        comment,
+                    });
+                }
+                declaration.clear();
+                match c {
+                    ';' => {
+                        if let Some(Some(body)) = blocks.last_mut() {
+                            body.constants = false;
+                        }
+                    }
+                    '{' => blocks.push(None),
+                    '}' => {
+                        blocks.pop();
+                    }
+                    _ => {}
+                }
+            }
+            '{' | ';' if parens == 0 => {
+                let parsed = parse_declaration(&declaration);
+                let classes = classes();
+                if c == '{' {
+                    match &parsed {
+                        Some(Declaration::Class { name, is_enum }) => blocks.push(Some(Body {
+                            name: name.clone(),
+                            constants: *is_enum,
+                        })),
+                        _ => blocks.push(None),
+                    }
+                }
+                if let (Some(comment), Some(declaration)) = (comment.take(), parsed) {
+                    items.push(Documented {
+                        classes,
+                        declaration,
+                        comment,
+                    });
+                }
+                declaration.clear();
+            }
+            '}' if parens == 0 => {
+                blocks.pop();
+                comment = None;
+                declaration.clear();
+            }
+            _ => declaration.push(c),
+        }
+    }
+    items
+}
+
+/// Tells what `declaration`, the text before a `{` or `;` in a class body, declares.
+fn parse_declaration(declaration: &str) -> Option<Declaration> {
+    let tokens = tokenize(&strip_annotations(declaration));
+    let is_identifier =
+        |t: &str| t.starts_with(|c: char| c.is_alphanumeric() || c == '_' || c == '$');
+
+    // `class Foo`, `interface Foo`, `enum Foo`, or `record Foo(...)`
+    for (i, window) in tokens.windows(2).enumerate() {
+        let keyword = matches!(&window[0][..], "class" | "interface" | "enum")
+            || (window[0] == "record"
+                && matches!(tokens.get(i + 2).map(|t| &t[..]), Some("(" | "<")));
+        if keyword && is_identifier(&window[1]) {
+            return Some(Declaration::Class {
+                name: window[1].clone(),
+                is_enum: window[0] == "enum",
+            });
+        }
+    }
+
+    // A field, possibly with an initializer (which may contain parentheses)
+    let end = top_level(&tokens, "=").unwrap_or(tokens.len());
+    let paren = tokens.iter().position(|t| t == "(");
+    if paren.is_none_or(|paren| paren >= end) {
+        let first = &tokens[..top_level(&tokens[..end], ",").unwrap_or(end)];
+        let name = first.iter().rev().find(|t| is_identifier(t))?;
+        return Some(Declaration::Field(name.clone()));
+    }
+
+    // A method or constructor
+    let paren = paren?;
+    let name = tokens[..paren].last().filter(|t| is_identifier(t))?.clone();
+    let close = paren + top_level(&tokens[paren + 1..], ")")? + 1;
+    let mut parameters = vec![];
+    let mut rest = &tokens[paren + 1..close];
+    while !rest.is_empty() {
+        let end = top_level(rest, ",").unwrap_or(rest.len());
+        parameters.push(parameter_type(&rest[..end])?);
+        rest = rest.get(end + 1..).unwrap_or_default();
+    }
+    Some(Declaration::Method { name, parameters })
+}
+
+/// The simple name of the type of a parameter like `final java.util.List<String> items`,
+/// i.e. `List`.
+fn parameter_type(tokens: &[String]) -> Option<String> {
+    let mut depth = 0;
+    let mut name = None;
+    let mut dimensions = 0;
+    // Everything but the parameter name itself
+    let ty = &tokens[..tokens.len().checked_sub(1)?];
+    let mut dots = 0;
+    for token in ty {
+        match &token[..] {
+            "<" => depth += 1,
+            ">" => depth -= 1,
+            "[" if depth == 0 => dimensions += 1,
+            "." if depth == 0 => {
+                dots += 1;
+                // `...` makes the parameter an array
+                if dots == 3 {
+                    dimensions += 1;
+                }
+                continue;
+            }
+            "final" => {}
+            t if depth == 0 && t.starts_with(|c: char| c.is_alphanumeric() || c == '_') => {
+                name = Some(t)
+            }
+            _ => {}
+        }
+        dots = 0;
+    }
+    Some(format!("{}{}", name?, "[]".repeat(dimensions)))
+}
+
+/// The position of the first `token` in `tokens` that is not nested in `<>`, `()`, or `{}`.
+fn top_level(tokens: &[String], token: &str) -> Option<usize> {
+    let mut depth = 0_i32;
+    for (i, t) in tokens.iter().enumerate() {
+        if depth == 0 && t == token {
+            return Some(i);
+        }
+        match &t[..] {
+            "<" | "(" | "{" => depth += 1,
+            ">" | ")" | "}" => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Splits Java source into identifiers (and keywords and numbers) and single characters.
+fn tokenize(text: &str) -> Vec<String> {
+    let mut tokens: Vec<String> = vec![];
+    let mut in_word = false;
+    for c in text.chars() {
+        if c.is_alphanumeric() || c == '_' || c == '$' {
+            if !in_word {
+                tokens.push(String::new());
+            }
+            tokens.last_mut().unwrap().push(c);
+            in_word = true;
+        } else {
+            if !c.is_whitespace() {
+                tokens.push(c.to_string());
+            }
+            in_word = false;
+        }
+    }
+    tokens
+}
+
+/// Removes annotations like `@Override` or `@SuppressWarnings("unchecked")` from a declaration,
+/// keeping the `interface` of `@interface`.
+fn strip_annotations(declaration: &str) -> String {
+    let chars: Vec<char> = declaration.chars().collect();
+    let mut out = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i] != '@' {
+            out.push(chars[i]);
+            i += 1;
+            continue;
+        }
+        i += 1;
+        let start = i;
+        while i < chars.len() && (chars[i].is_alphanumeric() || matches!(chars[i], '_' | '$' | '.'))
+        {
+            i += 1;
+        }
+        if chars[start..i].iter().collect::<String>() == "interface" {
+            out.push_str(" interface ");
+            continue;
+        }
+        let mut j = i;
+        while j < chars.len() && chars[j].is_whitespace() {
+            j += 1;
+        }
+        if chars.get(j) == Some(&'(') {
+            let mut depth = 0;
+            for (k, &c) in chars.iter().enumerate().skip(j) {
+                match c {
+                    '(' => depth += 1,
+                    ')' => depth -= 1,
+                    _ => {}
+                }
+                if depth == 0 {
+                    i = k + 1;
+                    break;
+                }
+            }
+        }
+        out.push(' ');
+    }
+    out
+}
+
+/// Converts the text of a doc comment into Markdown. Block tags become sections:
+/// `@param` and `@throws` are listed under "Parameters" and "Throws", `@return` becomes
+/// "Returns", and `@deprecated` and `@see` become "Deprecated" and "See also".
+/// Other block tags, like `@since` or `@author`, are left out.
+pub(crate) fn to_markdown(comment: &str) -> String {
+    // Strip the leading `*` (and one space) of each line, and split off the block tags,
+    // which start a line with `@`, unless they are in a `<pre>` block.
+    let mut description = String::new();
+    let mut tags: Vec<(String, String)> = vec![];
+    let mut pre = false;
+    for line in comment.lines() {
+        let line = line.trim_start();
+        let line = line.strip_prefix('*').unwrap_or(line);
+        let line = line.strip_prefix(' ').unwrap_or(line);
+        let trimmed = line.trim_start();
+        if !pre && trimmed.starts_with('@') && trimmed[1..].starts_with(char::is_alphabetic) {
+            let (tag, text) = trimmed[1..]
+                .split_once(char::is_whitespace)
+                .unwrap_or((&trimmed[1..], ""));
+            tags.push((tag.to_string(), text.to_string()));
+        } else {
+            let text = match tags.last_mut() {
+                Some((_, text)) => text,
+                None => &mut description,
+            };
+            text.push('\n');
+            text.push_str(line);
+        }
+        pre = (pre || line.contains("<pre")) && !line.contains("</pre>");
+    }
+
+    let mut sections = vec![html_to_markdown(&description)];
+    let mut parameters = vec![];
+    let mut returns = None;
+    let mut throws = vec![];
+    let mut deprecated = None;
+    let mut see = vec![];
+    for (tag, text) in &tags {
+        let text = text.trim();
+        let (first, rest) = text.split_once(char::is_whitespace).unwrap_or((text, ""));
+        match &tag[..] {
+            "param" => parameters.push(list_item(first, rest)),
+            "throws" | "exception" => throws.push(list_item(first, rest)),
+            "return" => returns = Some(html_to_markdown(text)),
+            "deprecated" => deprecated = Some(html_to_markdown(text)),
+            "see" if text.starts_with(['"', '<']) => {
+                see.push(format!("* {}", html_to_markdown(text)))
+            }
+            "see" => see.push(format!("* {}", link(text))),
+            _ => {}
+        }
+    }
+    if !parameters.is_empty() {
+        sections.push(format!("# Parameters\n\n{}", parameters.join("\n")));
+    }
+    if let Some(returns) = returns {
+        sections.push(format!("# Returns\n\n{returns}"));
+    }
+    if !throws.is_empty() {
+        sections.push(format!("# Throws\n\n{}", throws.join("\n")));
+    }
+    if let Some(deprecated) = deprecated {
+        sections.push(format!("# Deprecated\n\n{deprecated}"));
+    }
+    if !see.is_empty() {
+        sections.push(format!("# See also\n\n{}", see.join("\n")));
+    }
+    sections.retain(|s| !s.is_empty());
+    sections.join("\n\n")
+}
+
+/// A list item like ``* `name`: description``, indenting the continuation lines.
+fn list_item(name: &str, description: &str) -> String {
+    let description = html_to_markdown(description).replace('\n', "\n  ");
+    if description.is_empty() {
+        format!("* `{name}`")
+    } else {
+        format!("* `{name}`: {description}")
+    }
+}
+
+/// A `{@link}` or `@see` reference like `Foo#bar(int)` as inline code, or its label if it has one.
+fn link(reference: &str) -> String {
+    let mut depth = 0;
+    let split = reference.find(|c: char| {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ => {}
+        }
+        depth == 0 && c.is_whitespace()
+    });
+    match split {
+        Some(i) if !reference[i..].trim().is_empty() => html_to_markdown(reference[i..].trim()),
+        _ => {
+            let reference = reference.trim();
+            let reference = reference.strip_prefix('#').unwrap_or(reference);
+            code(&reference.replace('#', "."))
+        }
+    }
+}
+
+/// `text` as inline code.
+fn code(text: &str) -> String {
+    if text.contains('`') {
+        format!("`` {text} ``")
+    } else {
+        format!("`{text}`")
+    }
+}
+
+/// Converts Javadoc text, with inline tags like `{@code ...}` and HTML, to Markdown.
+fn html_to_markdown(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::new();
+    // Inside `<pre>`, text is copied as is into a code block.
+    let mut pre = false;
+    // Inside `<code>`, text is copied into inline code.
+    let mut code_span = false;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '{' && chars.get(i + 1) == Some(&'@') {
+            // An inline tag, whose content may contain balanced braces.
+            let mut depth = 0;
+            let end = (i..chars.len())
+                .find(|&j| {
+                    match chars[j] {
+                        '{' => depth += 1,
+                        '}' => depth -= 1,
+                        _ => {}
+                    }
+                    depth == 0
+                })
+                .unwrap_or(chars.len() - 1);
+            let inner: String = chars[i + 2..end].iter().collect();
+            let (tag, content) = inner
+                .split_once(char::is_whitespace)
+                .unwrap_or((&inner, ""));
+            let content = content.trim();
+            out.push_str(&match tag {
+                _ if pre || code_span => decode_entities(content),
+                "code" => code(content),
+                "literal" => escape(content),
+                "link" | "linkplain" => link(content),
+                "value" => code(content),
+                "inheritDoc" | "docRoot" => String::new(),
+                _ => html_to_markdown(content),
+            });
+            i = end + 1;
+            continue;
+        }
+
+        if c == '<' {
+            let rest: String = chars[i + 1..].iter().take_while(|&&c| c != '>').collect();
+            let name: String = rest
+                .trim_start_matches('/')
+                .chars()
+                .take_while(|c| c.is_ascii_alphanumeric())
+                .collect::<String>()
+                .to_ascii_lowercase();
+            if !name.is_empty() && i + 1 + rest.len() < chars.len() {
+                let closing = rest.starts_with('/');
+                i += rest.chars().count() + 2;
+                out.push_str(match (&name[..], closing) {
+                    ("pre", false) => {
+                        pre = true;
+                        "\n\n```text\n"
+                    }
+                    ("pre", true) => {
+                        pre = false;
+                        "\n```\n\n"
+                    }
+                    _ if pre => "",
+                    ("code" | "tt", _) => {
+                        code_span = !closing;
+                        "`"
+                    }
+                    ("p", _) | ("ul" | "ol" | "br", _) => "\n\n",
+                    ("li", false) => "\n* ",
+                    ("em" | "i", _) => "*",
+                    ("strong" | "b", _) => "**",
+                    ("h1" | "h2" | "h3" | "h4" | "h5" | "h6", false) => "\n\n**",
+                    ("h1" | "h2" | "h3" | "h4" | "h5" | "h6", true) => "**\n\n",
+                    _ => "",
+                });
+                continue;
+            }
+        }
+
+        if pre || code_span {
+            let end = (i + 1..chars.len())
+                .find(|&j| matches!(chars[j], '<' | '{'))
+                .unwrap_or(chars.len());
+            out.push_str(&decode_entities(&chars[i..end].iter().collect::<String>()));
+            i = end;
+        } else {
+            out.push_str(&escape(&c.to_string()));
+            i += 1;
+        }
+    }
+
+    // Outside of code blocks, leading whitespace would make lines into code blocks
+    // (or continuations of list items), so remove it.
+    let mut lines = vec![];
+    let mut fenced = false;
+    for line in out.lines() {
+        if line.starts_with("```") {
+            fenced = !fenced;
+        }
+        lines.push(if fenced { line.trim_end() } else { line.trim() });
+    }
+    let mut markdown = lines.join("\n");
+    while markdown.contains("\n\n\n") {
+        markdown = markdown.replace("\n\n\n", "\n\n");
+    }
+    markdown.trim().to_string()
+}
+
+/// Escapes the characters of plain text that Markdown (or rustdoc) would treat specially.
+/// Entities like `&lt;` are kept, as Markdown renders them.
+fn escape(text: &str) -> String {
+    let mut out = String::new();
+    for c in text.chars() {
+        if matches!(c, '[' | ']' | '*' | '`' | '\\') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Decodes the HTML entities that commonly appear in Javadoc, for use in code.
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&#64;", "@")
+        .replace("&#123;", "{")
+        .replace("&#125;", "}")
+        .replace("&amp;", "&")
+}
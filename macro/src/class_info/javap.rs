@@ -5,7 +5,7 @@ use proc_macro2::Span;
 
 use crate::span_error::SpanError;
 
-use super::{ClassDecl, ClassInfo};
+use super::{ClassDecl, ClassInfo, Type};
 
 lalrpop_mod!(pub javap_parser, "/class_info/javap_parser.rs"); // synthesized by LALRPOP
 
@@ -29,6 +29,16 @@ pub(super) fn parse_class_info(span: Span, input: &str) -> Result<ClassInfo, Spa
     }
 }
 
+pub(super) fn parse_argument_types(span: Span, input: &str) -> Result<Vec<Type>, SpanError> {
+    match javap_parser::ArgumentTypesParser::new().parse(span, input) {
+        Ok(v) => Ok(v),
+        Err(error) => Err(SpanError {
+            span,
+            message: format_lalrpop_error(input, error),
+        }),
+    }
+}
+
 fn format_lalrpop_error(
     input: &str,
     error: lalrpop_util::ParseError<usize, Token<'_>, impl Display>,
@@ -1,21 +1,30 @@
 use crate::{
-    argument::DuchessDeclaration,
+    argument::{Conversion, DuchessDeclaration},
     class_info::{
-        ClassInfo, Constructor, DotId, Field, Id, Method, NonRepeatingType, RootMap,
+        ClassInfo, ClassKind, ClassRef, ConstantValue, Constructor, Deprecation, DotId, Field,
+        Generic, Id, Method, NonRepeatingType, Privacy, RefType, RootMap, ScalarType,
         SpannedPackageInfo, Type,
     },
     reflect::Reflector,
     signature::Signature,
     span_error::SpanError,
+    substitution::{Substitute, Substitution},
     upcasts::Upcasts,
 };
 use inflector::Inflector;
 use proc_macro2::{Ident, Literal, Span, TokenStream};
-use quote::quote_spanned;
+use quote::{quote_spanned, ToTokens};
 
 impl DuchessDeclaration {
     pub fn to_tokens(&self) -> Result<TokenStream, SpanError> {
-        let reflector = &mut Reflector::default();
+        // The JARs of `package foo (jar = "...");` are searched after the `classpath`.
+        let classpath = self
+            .classpath
+            .iter()
+            .chain(self.packages.iter().filter_map(|p| p.options.jar.as_ref()))
+            .cloned()
+            .collect();
+        let reflector = &mut Reflector::with_classpath(classpath);
         let root_map = self.to_root_map(reflector)?;
         let () = root_map.check(reflector)?;
         root_map.to_tokens(reflector)
@@ -24,9 +33,41 @@ impl DuchessDeclaration {
 
 impl RootMap {
     fn to_tokens(self, reflector: &mut Reflector) -> Result<TokenStream, SpanError> {
-        self.to_packages()
+        let package_tokens: TokenStream = self
+            .to_packages()
             .map(|p| p.to_tokens(&[], &self, reflector))
-            .collect()
+            .collect::<Result<_, _>>()?;
+        let default_package_tokens = self.default_package_tokens()?;
+        Ok(quote_spanned!(Span::call_site() =>
+            #package_tokens
+            #default_package_tokens
+        ))
+    }
+
+    /// Classes in the default package are generated in the module where the macro is invoked.
+    /// They live in a private module of their own, so that `java` can be imported for them
+    /// without clashing with anything at the call site, and are re-exported from there.
+    fn default_package_tokens(&self) -> Result<TokenStream, SpanError> {
+        if self.default_package.is_empty() {
+            return Ok(TokenStream::new());
+        }
+
+        let class_tokens: TokenStream = self
+            .default_package
+            .iter()
+            .map(|class_id| self.classes[class_id].to_tokens(&self.upcasts, &self.conversions))
+            .collect::<Result<_, _>>()?;
+
+        Ok(quote_spanned!(Span::call_site() =>
+            #[allow(unused_imports, deprecated)]
+            mod __duchess_default_package {
+                use super::*;
+                use duchess::java;
+
+                #class_tokens
+            }
+            pub use __duchess_default_package::*;
+        ))
     }
 }
 
@@ -49,7 +90,9 @@ impl SpannedPackageInfo {
         let class_tokens: TokenStream = self
             .classes
             .iter()
-            .map(|class_id| root_map.classes[class_id].to_tokens(&root_map.upcasts))
+            .map(|class_id| {
+                root_map.classes[class_id].to_tokens(&root_map.upcasts, &root_map.conversions)
+            })
             .collect::<Result<_, _>>()?;
 
         let supers: Vec<TokenStream> = package_id
@@ -58,7 +101,7 @@ impl SpannedPackageInfo {
             .collect();
 
         Ok(quote_spanned!(self.span =>
-            #[allow(unused_imports)]
+            #[allow(unused_imports, deprecated)]
             pub mod #name {
                 // Import the contents of the parent module that we are created inside
                 use #(#supers ::)* *;
@@ -74,7 +117,11 @@ impl SpannedPackageInfo {
 }
 
 impl ClassInfo {
-    pub fn to_tokens(&self, upcasts: &Upcasts) -> Result<TokenStream, SpanError> {
+    pub fn to_tokens(
+        &self,
+        upcasts: &Upcasts,
+        conversions: &[Conversion],
+    ) -> Result<TokenStream, SpanError> {
         let struct_name = self.struct_name();
         let cached_class = self.cached_class();
         let this_ty = self.this_type();
@@ -85,36 +132,62 @@ impl ClassInfo {
         let constructors: Vec<_> = self
             .constructors
             .iter()
-            .map(|c| self.constructor(c))
+            .map(|c| self.constructor(c, conversions))
             .collect::<Result<_, _>>()?;
 
+        // Builders for long constructors
+        let (builder_structs, builder_impls): (Vec<_>, Vec<_>) = self
+            .constructors
+            .iter()
+            .filter(|c| matches!(self.builder_arity, Some(arity) if c.argument_tys.len() >= arity))
+            .map(|c| self.constructor_builder(c, conversions))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
+
         // Convert static methods (not instance methods, those are different)
         let static_methods: Vec<_> = self
             .methods
             .iter()
             .filter(|m| self.should_mirror_in_rust(m.flags.privacy))
             .filter(|m| m.flags.is_static)
-            .map(|m| self.static_method(m))
+            .map(|m| self.static_method(m, conversions))
             .collect::<Result<_, _>>()?;
 
         // Convert instance methods (not static methods, those are different)
-        let op_methods: Vec<_> = self
+        let mut op_methods: Vec<_> = self
             .methods
             .iter()
             .filter(|m| self.should_mirror_in_rust(m.flags.privacy))
             .filter(|m| !m.flags.is_static)
-            .map(|m| self.op_struct_method(m))
+            .map(|m| self.op_struct_method(m, conversions))
             .collect::<Result<_, _>>()?;
 
         // Convert instance methods (not static methods, those are different)
-        let obj_methods: Vec<_> = self
+        let mut obj_methods: Vec<_> = self
             .methods
             .iter()
             .filter(|m| self.should_mirror_in_rust(m.flags.privacy))
             .filter(|m| !m.flags.is_static)
-            .map(|m| self.obj_struct_method(m))
+            .map(|m| self.obj_struct_method(m, conversions))
             .collect::<Result<_, _>>()?;
 
+        // Instance field getters go on the op and object structs too
+        let instance_fields: Vec<&Field> = self
+            .fields
+            .iter()
+            .filter(|f| self.should_mirror_in_rust(f.flags.privacy))
+            .filter(|f| !f.flags.is_static)
+            .collect();
+        for field in &instance_fields {
+            op_methods.push(self.op_struct_field_getter(field, conversions)?);
+            obj_methods.push(self.obj_struct_field_getter(field, conversions)?);
+            if !field.flags.is_final {
+                op_methods.push(self.op_struct_field_setter(field, conversions)?);
+                obj_methods.push(self.obj_struct_field_setter(field, conversions)?);
+            }
+        }
+
         let assoc_struct_declarations = self.assoc_structs(upcasts, op_methods, obj_methods)?;
 
         // Convert instance methods of the form `Foo::method`
@@ -123,30 +196,86 @@ impl ClassInfo {
             .iter()
             .filter(|m| self.should_mirror_in_rust(m.flags.privacy))
             .filter(|m| !m.flags.is_static)
-            .map(|m| self.inherent_object_method(m))
+            .map(|m| self.inherent_object_method(m, conversions))
             .collect::<Result<_, _>>()?;
 
-        // Generate static field getters
-        let static_field_getters: Vec<_> = self
+        // Generate constants for static final fields with a value in the class file,
+        // and static field getters for the others
+        let mut constants = vec![];
+        let mut static_field_getters = vec![];
+        for field in &self.fields {
+            if !self.should_mirror_in_rust(field.flags.privacy) || !field.flags.is_static {
+                continue;
+            }
+            match self.constant(field) {
+                Some(constant) => constants.push(constant),
+                None => static_field_getters.push(self.static_field_getter(field, conversions)?),
+            }
+        }
+
+        // Generate instance field getters of the form `Foo::get_field`
+        let inherent_field_getters: Vec<_> = instance_fields
+            .iter()
+            .map(|f| self.inherent_field_getter(f, conversions))
+            .collect::<Result<_, _>>()?;
+
+        // Generate setters for non-final fields, static or not
+        let field_setters: Vec<_> = self
             .fields
             .iter()
             .filter(|f: &&Field| self.should_mirror_in_rust(f.flags.privacy))
-            .filter(|f| f.flags.is_static)
-            .map(|f| self.static_field_getter(f))
+            .filter(|f| !f.flags.is_final)
+            .map(|f| self.field_setter(f, conversions))
             .collect::<Result<_, _>>()?;
 
         let upcast_impls = self.upcast_impls(upcasts)?;
 
+        let record_impls = self.record_impls();
+
+        let object_method_markers = self.object_method_markers();
+
+        let (sealed_enum, sealed_impl) = self.sealed_enum(upcasts);
+
+        let (interface_trait, interface_trait_impl) = self.interface_trait(conversions)?;
+
+        let attributes = &self.attributes;
+        let cfg_attributes = self.cfg_attributes();
+        let class_name_alias = self.class_name_alias();
+        let java_class_doc = format!(
+            "Java class `{}` (descriptor `L{};`).",
+            self.name,
+            self.name.to_jni_name()
+        );
+        let javadoc = javadoc(self.docs.as_deref(), self.span);
+        let deprecated = deprecated_attribute(self.deprecated.as_ref(), self.span);
+
+        let class_items = self.class_items();
+        let erased_this_ty = self.erased_this_type();
+
         let output = quote_spanned! {
             self.span =>
 
+            #(#attributes)*
+            #[doc = #java_class_doc]
+            #javadoc
+            #deprecated
             #[allow(non_camel_case_types)]
             pub struct #struct_name<#(#java_class_generics_with_defaults,)*> {
                 _dummy: std::marker::PhantomData<(#(#java_class_generics,)*)>
             }
 
+            #(#cfg_attributes)*
+            #class_name_alias
+
+            #interface_trait
+
+            #sealed_enum
+
+            #(#builder_structs)*
+
             // Hide other generated items
-            #[allow(unused_imports)]
+            #(#cfg_attributes)*
+            #[allow(unused_imports, deprecated)]
             #[allow(nonstandard_style)]
             const _: () = {
                 use duchess::{
@@ -205,6 +334,21 @@ impl ClassInfo {
                     }
                 }
 
+                impl<#(#java_class_generics,)*> std::fmt::Debug for #struct_name<#(#java_class_generics,)*>
+                where
+                    #(#java_class_generics: duchess::JavaObject,)*
+                {
+                    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        plumbing::debug_java_class(self, <#erased_this_ty>::DOTTED_NAME, f)
+                    }
+                }
+
+                #record_impls
+
+                #object_method_markers
+
+                #sealed_impl
+
                 // Reflexive upcast impl
                 unsafe impl<#(#java_class_generics,)*> plumbing::Upcast<#struct_name<#(#java_class_generics,)*>> for #struct_name<#(#java_class_generics,)*>
                 where
@@ -214,17 +358,32 @@ impl ClassInfo {
                 // Other upcast impls
                 #upcast_impls
 
+                #interface_trait_impl
+
+                #(#builder_impls)*
+
                 impl< #(#java_class_generics,)* > #this_ty
                 where
                     #(#java_class_generics: duchess::JavaObject,)*
                 {
                     #(#constructors)*
 
+                    #(#inherent_object_methods)*
+
+                    #(#inherent_field_getters)*
+
+                    #(#field_setters)*
+                }
+
+                // Static members, see `erased_this_type`
+                impl #erased_this_ty {
+                    #class_items
+
+                    #(#constants)*
+
                     #(#static_methods)*
 
                     #(#static_field_getters)*
-
-                    #(#inherent_object_methods)*
                 }
             };
         };
@@ -234,6 +393,179 @@ impl ClassInfo {
         Ok(output)
     }
 
+    /// For interfaces, generates a trait `FooTrait` with one method per instance method of
+    /// the interface, along with a blanket impl for every type that upcasts to the interface.
+    /// This lets Rust code be generic over the classes implementing an interface.
+    /// Returns the trait declaration and the impl (which must go in the hidden `const` block).
+    fn interface_trait(
+        &self,
+        conversions: &[Conversion],
+    ) -> Result<(TokenStream, TokenStream), SpanError> {
+        if self.kind != ClassKind::Interface {
+            return Ok((TokenStream::new(), TokenStream::new()));
+        }
+
+        let trait_name = Id::from(format!("{}Trait", self.struct_name())).to_ident(self.span);
+        let op_trait_name = Id::from(format!("{}OpTrait", self.struct_name())).to_ident(self.span);
+        let java_class_generics = self.class_generic_names();
+        let java_class_generics_with_defaults = self.class_generic_names_with_defaults();
+        let this_ty = self.this_type();
+        let this = Ident::new("This", self.span);
+
+        let mut trait_methods = vec![];
+        let mut impl_methods = vec![];
+        let mut op_impl_methods = vec![];
+        for method in &self.methods {
+            if !self.should_mirror_in_rust(method.flags.privacy) || method.flags.is_static {
+                continue;
+            }
+
+            let span = self.method_span(method);
+            let method = &self.with_unshadowed_generics(method);
+
+            let mut sig = Signature::new(&method.name, span, &self.generics)
+                .with_type_arguments(&self.type_arguments)
+                .with_conversions(conversions)
+                .with_internal_generics(&method.generics)?;
+
+            let input_traits: Vec<_> = method
+                .argument_tys
+                .iter()
+                .map(|ty| sig.input_trait(ty))
+                .collect::<Result<_, _>>()?;
+
+            let input_names: Vec<_> = (0..input_traits.len())
+                .map(|i| Ident::new(&format!("a{i}"), self.span))
+                .collect();
+
+            let output_trait = sig.method_trait(&method.return_ty)?;
+            let rust_method_name = self.rust_method_name(method).to_ident(self.span);
+            let rust_method_generics = &sig.rust_generics;
+            let sig_where_clauses = &sig.where_clauses;
+
+            let method_sig = quote_spanned!(self.span =>
+                fn #rust_method_name<'a, #(#rust_method_generics),*>(
+                    &'a self,
+                    #(#input_names: impl #input_traits + 'a),*
+                ) -> impl #output_trait + 'a
+                where
+                    #(#sig_where_clauses,)*
+            );
+
+            let default_doc = if method.flags.is_default {
+                quote_spanned!(self.span =>
+                    #[doc = "default method"]
+                    #[doc = ""]
+                    #[doc = "The interface provides a default implementation, which implementing classes may override."]
+                )
+            } else {
+                TokenStream::new()
+            };
+
+            let method_doc = self.method_doc(method);
+
+            let javadoc = javadoc(method.docs.as_deref(), self.span);
+
+            let deprecated = deprecated_attribute(method.deprecated.as_ref(), self.span);
+            let protected_doc = protected_doc(method.flags.privacy, self.span);
+            let throws_doc = throws_doc(&method.throws, self.span);
+
+            trait_methods.push(quote_spanned!(self.span =>
+                #[doc = #method_doc]
+                #javadoc
+                #protected_doc
+                #throws_doc
+                #deprecated
+                #default_doc
+                #method_sig;
+            ));
+
+            impl_methods.push(quote_spanned!(self.span =>
+                #method_sig
+                {
+                    <#this_ty>::#rust_method_name(self, #(#input_names,)*)
+                }
+            ));
+
+            op_impl_methods.push(quote_spanned!(self.span =>
+                #method_sig
+                {
+                    <#this_ty>::#rust_method_name(*self, #(#input_names,)*)
+                }
+            ));
+        }
+
+        let cfg_attributes = self.cfg_attributes();
+        let trait_doc = format!(
+            "Methods of the Java interface `{}`, implemented for every type that upcasts to it.",
+            self.name,
+        );
+        let op_trait_doc = format!(
+            "Methods of the Java interface `{}`, implemented for every operation whose output \
+            upcasts to it (e.g., a reference to, or a method call returning, an implementing class).",
+            self.name,
+        );
+
+        let declaration = quote_spanned!(self.span =>
+            #(#cfg_attributes)*
+            #[doc = #trait_doc]
+            #[allow(non_camel_case_types)]
+            pub trait #trait_name<#(#java_class_generics_with_defaults,)*>
+            where
+                #(#java_class_generics: duchess::JavaObject,)*
+            {
+                #(#trait_methods)*
+            }
+
+            #(#cfg_attributes)*
+            #[doc = #op_trait_doc]
+            #[allow(non_camel_case_types)]
+            pub trait #op_trait_name<#(#java_class_generics_with_defaults,)*>
+            where
+                #(#java_class_generics: duchess::JavaObject,)*
+            {
+                #(#trait_methods)*
+            }
+        );
+
+        let implementation = quote_spanned!(self.span =>
+            impl<#this, #(#java_class_generics,)*> #trait_name<#(#java_class_generics,)*> for #this
+            where
+                #this: plumbing::Upcast<#this_ty> + TryJDeref<Java = #this>,
+                #(#java_class_generics: duchess::JavaObject,)*
+            {
+                #(#impl_methods)*
+            }
+
+            impl<#this, #(#java_class_generics,)*> #op_trait_name<#(#java_class_generics,)*> for #this
+            where
+                #this: IntoJava<#this_ty>,
+                #(#java_class_generics: duchess::JavaObject,)*
+            {
+                #(#op_impl_methods)*
+            }
+        );
+
+        Ok((declaration, implementation))
+    }
+
+    /// The `#[cfg(...)]` attributes the user placed on the class; these must also be
+    /// applied to the impls we generate so that they are compiled out with the struct.
+    fn cfg_attributes(&self) -> Vec<&TokenStream> {
+        self.attributes
+            .iter()
+            .filter(|attribute| {
+                (*attribute).clone().into_iter().any(|t| match t {
+                    proc_macro2::TokenTree::Group(g) => match g.stream().into_iter().next() {
+                        Some(proc_macro2::TokenTree::Ident(i)) => i == "cfg",
+                        _ => false,
+                    },
+                    _ => false,
+                })
+            })
+            .collect()
+    }
+
     /// Construct the various declarations related to the op struct,
     /// with the exception of any methods that must be invoked.
     fn assoc_structs(
@@ -375,17 +707,13 @@ impl ClassInfo {
 
     /// Returns the ["method resolution order"][mro] for self. This is a series of
     /// supertypes (classes or interfaces) ordered such that the more specific types
-    /// appear first. The returned list only includes "proper" supertypes, it does not
-    /// include the current class.
-    ///
-    /// FIXME: The returned list contains the right items, but is in an arbitary order,
-    /// and is not following the documented order. The result is that calls may wind up
-    /// calling methods from supertypes instead of subtypes. This only matters if subtypes
-    /// refine the return type.
+    /// appear first, so that inherited methods resolve to the most derived declaration
+    /// (see `Upcasts::mro_for_generated_class`). The returned list only includes "proper"
+    /// supertypes, it does not include the current class.
     ///
     /// [mro]: https://duchess-rs.github.io/duchess/methods.html#method-resolution-order
     fn mro(&self, upcasts: &Upcasts) -> Result<Vec<TokenStream>, SpanError> {
-        let class_refs = upcasts.upcasts_for_generated_class(&self.name);
+        let class_refs = upcasts.mro_for_generated_class(&self.name);
         class_refs
             .iter()
             .map(|r| {
@@ -396,6 +724,120 @@ impl ClassInfo {
             .collect()
     }
 
+    /// For a record class, implements `JavaRecord`, and `PartialEq` with the record's `equals()`.
+    fn record_impls(&self) -> TokenStream {
+        if !self.is_record {
+            return TokenStream::new();
+        }
+
+        let struct_name = self.struct_name();
+        let java_class_generics = self.class_generic_names();
+        quote_spanned!(self.span =>
+            impl<#(#java_class_generics,)*> PartialEq for #struct_name<#(#java_class_generics,)*>
+            where
+                #(#java_class_generics: duchess::JavaObject,)*
+            {
+                fn eq(&self, other: &Self) -> bool {
+                    plumbing::java_equals(self, other)
+                }
+            }
+
+            impl<#(#java_class_generics,)*> duchess::JavaRecord for #struct_name<#(#java_class_generics,)*>
+            where
+                #(#java_class_generics: duchess::JavaObject,)*
+            {}
+        )
+    }
+
+    /// Implements `ImplementsEquals` for a class that overrides `equals(Object)`, and
+    /// `ImplementsHashCode` for one that overrides `hashCode()`, so that `Local` references to it
+    /// compare and hash with them.
+    fn object_method_markers(&self) -> TokenStream {
+        let overrides = |name: &str, descriptor: &str| {
+            self.methods
+                .iter()
+                .any(|m| &m.name[..] == name && !m.flags.is_static && m.descriptor() == descriptor)
+        };
+        let mut marker_traits = vec![];
+        if overrides("equals", "(Ljava/lang/Object;)Z") {
+            marker_traits.push(quote_spanned!(self.span => ImplementsEquals));
+        }
+        if overrides("hashCode", "()I") {
+            marker_traits.push(quote_spanned!(self.span => ImplementsHashCode));
+        }
+
+        let struct_name = self.struct_name();
+        let java_class_generics = self.class_generic_names();
+        marker_traits
+            .into_iter()
+            .map(|marker_trait| {
+                quote_spanned!(self.span =>
+                    impl<#(#java_class_generics,)*> duchess::#marker_trait for #struct_name<#(#java_class_generics,)*>
+                    where
+                        #(#java_class_generics: duchess::JavaObject,)*
+                    {}
+                )
+            })
+            .collect()
+    }
+
+    /// For a sealed class, the enum of its permitted subclasses (`ShapeSealed<'jvm>` for `Shape`),
+    /// and the impl of `JavaSealed` that picks the variant with `instanceof` checks. Permitted
+    /// subclasses that are not declared here (or are generic) fall into the `Unknown` variant,
+    /// as do those added to the Java code later. Generic sealed classes are not supported.
+    fn sealed_enum(&self, upcasts: &Upcasts) -> (TokenStream, TokenStream) {
+        if self.permitted_subclasses.is_empty() || !self.generics.is_empty() {
+            return (TokenStream::new(), TokenStream::new());
+        }
+
+        let struct_name = self.struct_name();
+        let enum_name = Id::from(format!("{struct_name}Sealed")).to_ident(self.span);
+        let (variants, tys): (Vec<Ident>, Vec<TokenStream>) = self
+            .permitted_subclasses
+            .iter()
+            .filter(|c| upcasts.is_non_generic_subclass(&c.name, &self.name))
+            .map(|c| {
+                (
+                    c.name.class_name().to_ident(self.span),
+                    c.name.to_module_name(self.span),
+                )
+            })
+            .unzip();
+
+        let cfg_attributes = self.cfg_attributes();
+        let enum_doc = format!(
+            "The permitted subclasses of the sealed class `{}`, see [`duchess::JvmOp::seal`].",
+            self.name
+        );
+        let sealed_enum = quote_spanned!(self.span =>
+            #(#cfg_attributes)*
+            #[doc = #enum_doc]
+            #[derive(Debug)]
+            #[allow(non_camel_case_types)]
+            pub enum #enum_name<'jvm> {
+                #(#variants(duchess::Local<'jvm, #tys>),)*
+                /// An instance of a subclass that is not declared in this `java_package!`.
+                Unknown(duchess::Local<'jvm, #struct_name>),
+            }
+        );
+        let sealed_impl = quote_spanned!(self.span =>
+            impl duchess::JavaSealed for #struct_name {
+                type Sealed<'jvm> = #enum_name<'jvm>;
+
+                fn seal<'jvm>(obj: Local<'jvm, Self>) -> duchess::Result<'jvm, #enum_name<'jvm>> {
+                    #(
+                        let obj = match obj.try_downcast::<#tys>()? {
+                            Ok(obj) => return Ok(#enum_name::#variants(obj)),
+                            Err(obj) => obj,
+                        };
+                    )*
+                    Ok(#enum_name::Unknown(obj))
+                }
+            }
+        );
+        (sealed_enum, sealed_impl)
+    }
+
     fn upcast_impls(&self, upcasts: &Upcasts) -> Result<TokenStream, SpanError> {
         let struct_name = self.struct_name();
         let java_class_generics = self.class_generic_names();
@@ -413,23 +855,63 @@ impl ClassInfo {
     }
 
     fn cached_class(&self) -> TokenStream {
+        let erased_this_ty = self.erased_this_type();
+        quote_spanned! {
+            self.span =>
+            fn class<'jvm>(jvm: &mut Jvm<'jvm>) -> duchess::Result<'jvm, Local<'jvm, java::lang::Class<java::lang::Object>>> {
+                let global = <#erased_this_ty>::cached_class(jvm)?;
+                Ok(jvm.local(global))
+            }
+        }
+    }
+
+    /// Generates the `CLASS_NAME` and `DOTTED_NAME` constants and the `cached_class` accessor
+    /// that should be part of the inherent impl for the struct. `JavaObject::class` hands out
+    /// local references to the class cached here.
+    fn class_items(&self) -> TokenStream {
         let jni_class_name = self.jni_class_name();
+        let class_name = self.name.to_jni_name();
+        let dotted_name = self.name.to_string();
+        let class_name_doc = format!("The name of the Java class in JNI form, `{class_name}`.");
+        let dotted_name_doc = format!("The name of the Java class, `{dotted_name}`.");
 
         quote_spanned! {
             self.span =>
-            fn class<'jvm>(jvm: &mut Jvm<'jvm>) -> duchess::Result<'jvm, Local<'jvm, java::lang::Class>> {
-                static CLASS: OnceCell<Global<java::lang::Class>> = OnceCell::new();
-                let global = CLASS.get_or_try_init::<_, duchess::Error<Local<java::lang::Throwable>>>(|| {
+            // Unlike the others, this is not used by the generated code itself, which would
+            // warn for classes mirrored in a private module.
+            #[doc = #class_name_doc]
+            #[allow(dead_code)]
+            pub const CLASS_NAME: &'static str = #class_name;
+
+            #[doc = #dotted_name_doc]
+            pub const DOTTED_NAME: &'static str = #dotted_name;
+
+            /// The Java class object for this class, which is looked up on first use and
+            /// kept for the rest of the program, e.g. for making JNI calls against it directly.
+            pub fn cached_class<'jvm>(
+                jvm: &mut Jvm<'jvm>,
+            ) -> duchess::Result<'jvm, &'static Global<java::lang::Class<java::lang::Object>>> {
+                static CLASS: OnceCell<Global<java::lang::Class<java::lang::Object>>> = OnceCell::new();
+                CLASS.get_or_try_init(|| {
                     let class = find_class(jvm, #jni_class_name)?;
                     Ok(jvm.global(&class))
-                })?;
-                Ok(jvm.local(global))
+                })
             }
         }
     }
 
-    fn constructor(&self, constructor: &Constructor) -> Result<TokenStream, SpanError> {
-        let mut sig = Signature::new(self.name.class_name(), self.span, &self.generics);
+    fn constructor(
+        &self,
+        constructor: &Constructor,
+        conversions: &[Conversion],
+    ) -> Result<TokenStream, SpanError> {
+        let mut sig = Signature::new(
+            self.name.class_name(),
+            self.constructor_span(constructor),
+            &self.generics,
+        )
+        .with_type_arguments(&self.type_arguments)
+        .with_conversions(conversions);
 
         let input_traits: Vec<_> = constructor
             .argument_tys
@@ -449,16 +931,51 @@ impl ClassInfo {
         let jni_descriptor = jni_c_str(constructor.descriptor(), self.span);
 
         // Code to convert each input appropriately
-        let prepare_inputs = self.prepare_inputs(&input_names, &constructor.argument_tys);
+        let prepare_inputs =
+            self.prepare_inputs(&mut sig, &input_names, &constructor.argument_tys)?;
+
+        let conversion_checks = self.conversion_checks(
+            &mut sig,
+            self.name.class_name(),
+            &format!("the constructor `{}`", self.name),
+            &constructor.argument_tys,
+            None,
+        )?;
 
         // for debugging JVM invocation failures
         let name = Literal::string(&self.name.to_string());
         let descriptor = Literal::string(&constructor.descriptor());
 
+        let rust_constructor_name = self.rust_constructor_name(constructor).to_ident(self.span);
+        let argument_tys: Vec<String> = constructor
+            .argument_tys
+            .iter()
+            .map(|ty| ty.to_string())
+            .collect();
+        let constructor_doc = format!(
+            "Java constructor `{}({})` (descriptor `{}`).",
+            self.name.class_name(),
+            argument_tys.join(", "),
+            constructor.descriptor(),
+        );
+
+        let javadoc = javadoc(constructor.docs.as_deref(), self.span);
+
+        let deprecated = deprecated_attribute(constructor.deprecated.as_ref(), self.span);
+        let protected_doc = protected_doc(constructor.flags.privacy, self.span);
+        let throws_doc = throws_doc(&constructor.throws, self.span);
+
         let output = quote_spanned!(self.span =>
-            pub fn new(
+            #[doc = #constructor_doc]
+            #javadoc
+            #protected_doc
+            #throws_doc
+            #deprecated
+            pub fn #rust_constructor_name(
                 #(#input_names : impl #input_traits,)*
             ) -> impl #output_trait {
+                #conversion_checks
+
                 struct Impl<
                     #(#java_class_generics,)*
                     #(#input_names),*
@@ -580,16 +1097,26 @@ impl ClassInfo {
         Ok(output)
     }
 
-    /// Generates code for the methods that goes on the `ops` object.
-    ///
-    ///
-    /// NB. This function (particularly the JvmOp impl) has significant overlap with `static_method`
-    /// and `static_field_getter`, so if you make changes here, you may well need changes there.
-    fn op_struct_method(&self, method: &Method) -> Result<TokenStream, SpanError> {
-        let mut sig = Signature::new(&method.name, self.span, &self.generics)
-            .with_internal_generics(&method.generics)?;
+    /// For a constructor with at least `builder_arity` parameters, generates a builder with a
+    /// setter per parameter, named after it, e.g. `Foo::builder().timeout(30).name("x").build()`.
+    /// The builder struct has a type parameter per constructor parameter, which is the type of the
+    /// value that was set, or `Unset`; `build` requires all of them to be set, so leaving one out
+    /// is a compilation error. Returns the struct declaration and the impls (which must go in the
+    /// hidden `const` block).
+    fn constructor_builder(
+        &self,
+        constructor: &Constructor,
+        conversions: &[Conversion],
+    ) -> Result<(TokenStream, TokenStream), SpanError> {
+        let mut sig = Signature::new(
+            self.name.class_name(),
+            self.constructor_span(constructor),
+            &self.generics,
+        )
+        .with_type_arguments(&self.type_arguments)
+        .with_conversions(conversions);
 
-        let input_traits: Vec<_> = method
+        let input_traits: Vec<_> = constructor
             .argument_tys
             .iter()
             .map(|ty| sig.input_trait(ty))
@@ -598,34 +1125,238 @@ impl ClassInfo {
         let input_names: Vec<_> = (0..input_traits.len())
             .map(|i| Ident::new(&format!("a{i}"), self.span))
             .collect();
+        let input_tys: Vec<_> = (0..input_traits.len())
+            .map(|i| Ident::new(&format!("A{i}"), self.span))
+            .collect();
 
-        // The "output trait" is the trait bounds we declare for the user,
-        // e.g., for a method like `Foo method()`, we will declare a
-        // Rust method `-> impl JavaMethod<Foo>`, and this variable
-        // would be `JavaMethod<Foo>`.
-        let output_trait = sig.method_trait(&method.return_ty)?;
+        let ty = self.this_type();
+        let java_class_generics = self.class_generic_names();
 
-        let rust_method_name = Id::from(method.name.to_snake_case()).to_ident(self.span);
+        // `new_from_string_i32` is built by `builder_from_string_i32`, of `FooBuilderFromStringI32`
+        let rust_constructor_name = self.rust_constructor_name(constructor);
+        let rust_builder_name = format!("builder{}", &rust_constructor_name["new".len()..]);
+        let builder_struct = Ident::new(
+            &format!(
+                "{}{}",
+                self.struct_name(),
+                rust_builder_name.to_pascal_case()
+            ),
+            self.span,
+        );
+        let rust_builder_name = Ident::new(&rust_builder_name, self.span);
+        let rust_constructor_name = rust_constructor_name.to_ident(self.span);
 
-        // The generic parameters we need on the Rust method, these include:
-        //
-        // * a type parameter for each java generic
-        // * any fresh generics we created to capture wildcards
-        let rust_method_generics = &sig.rust_generics;
+        let setters: Vec<_> = constructor
+            .argument_tys
+            .iter()
+            .enumerate()
+            .map(|(i, argument_ty)| {
+                let input_trait = &input_traits[i];
+                let input_name = &input_names[i];
+                let setter_name = self.builder_setter_name(constructor, i);
+                let setter_doc = format!(
+                    "Sets the parameter `{}` (`{argument_ty}`).",
+                    constructor.parameter_name(i),
+                );
+                let output_tys = input_tys.iter().enumerate().map(|(j, t)| {
+                    if i == j {
+                        quote_spanned!(self.span => V)
+                    } else {
+                        quote_spanned!(self.span => #t)
+                    }
+                });
+                let other_names: Vec<_> = input_names.iter().filter(|n| *n != input_name).collect();
+                quote_spanned!(self.span =>
+                    #[doc = #setter_doc]
+                    pub fn #setter_name<V: #input_trait>(
+                        self,
+                        value: V,
+                    ) -> #builder_struct<#(#java_class_generics,)* #(#output_tys,)*> {
+                        #builder_struct {
+                            #input_name: value,
+                            #(#other_names: self.#other_names,)*
+                            phantom: self.phantom,
+                        }
+                    }
+                )
+            })
+            .collect();
 
-        // The final where clauses we need on the method.
-        // This includes the bounds declared in Java but also
-        // other bounds we added as we converted input types
-        // to account for captures.
-        let sig_where_clauses = &sig.where_clauses;
+        let argument_tys: Vec<String> = constructor
+            .argument_tys
+            .iter()
+            .map(|ty| ty.to_string())
+            .collect();
+        let struct_doc = format!(
+            "Builder for the Java constructor `{}({})`, created by `{}::{rust_builder_name}`.",
+            self.name.class_name(),
+            argument_tys.join(", "),
+            self.struct_name(),
+        );
+        let builder_doc = format!(
+            "Starts building a call to the Java constructor `{}({})` one parameter at a time, \
+            see [`{rust_constructor_name}`](Self::{rust_constructor_name}).",
+            self.name.class_name(),
+            argument_tys.join(", "),
+        );
+        let unset = input_names
+            .iter()
+            .map(|_| quote_spanned!(self.span => duchess::plumbing::Unset));
 
-        let this_ty = self.this_type();
+        let cfg_attributes = self.cfg_attributes();
+        let declaration = quote_spanned!(self.span =>
+            #(#cfg_attributes)*
+            #[doc = #struct_doc]
+            #[allow(nonstandard_style)]
+            pub struct #builder_struct<#(#java_class_generics,)* #(#input_tys,)*> {
+                #(#input_names: #input_tys,)*
+                phantom: std::marker::PhantomData<(#(#java_class_generics,)*)>,
+            }
+        );
 
-        let inherent_method = quote_spanned!(self.span =>
-            pub fn #rust_method_name<#(#rust_method_generics),*>(
-                &self,
-                #(#input_names: impl #input_traits),*
-            ) -> impl #output_trait
+        let impls = quote_spanned!(self.span =>
+            impl<#(#java_class_generics,)*> #ty
+            where
+                #(#java_class_generics: duchess::JavaObject,)*
+            {
+                #[doc = #builder_doc]
+                pub fn #rust_builder_name() -> #builder_struct<#(#java_class_generics,)* #(#unset,)*> {
+                    #builder_struct {
+                        #(#input_names: duchess::plumbing::Unset,)*
+                        phantom: std::marker::PhantomData,
+                    }
+                }
+            }
+
+            impl<#(#java_class_generics,)* #(#input_tys,)*> #builder_struct<#(#java_class_generics,)* #(#input_tys,)*>
+            where
+                #(#java_class_generics: duchess::JavaObject,)*
+            {
+                #(#setters)*
+            }
+
+            impl<#(#java_class_generics,)* #(#input_tys,)*> #builder_struct<#(#java_class_generics,)* #(#input_tys,)*>
+            where
+                #(#java_class_generics: duchess::JavaObject,)*
+                #(#input_tys: #input_traits,)*
+            {
+                /// The constructor call, once every parameter is set.
+                pub fn build(self) -> impl JavaConstructor<#ty> {
+                    <#ty>::#rust_constructor_name(#(self.#input_names,)*)
+                }
+            }
+        );
+
+        Ok((declaration, impls))
+    }
+
+    /// The name of the builder setter for the parameter at `index` of `constructor`, which is
+    /// the parameter's name in snake case (with a `_` appended if that is taken).
+    fn builder_setter_name(&self, constructor: &Constructor, index: usize) -> Ident {
+        // Trailing digits are kept as they are, as in `arg0`, rather than becoming `arg_0`.
+        let name = constructor.parameter_name(index);
+        let stem = name.trim_end_matches(|c: char| c.is_ascii_digit());
+        let name = Id::from(format!("{}{}", stem.to_snake_case(), &name[stem.len()..]));
+        match &name[..] {
+            "build" | "self" | "crate" | "super" => Ident::new(&format!("{name}_"), self.span),
+            _ => name.to_ident(self.span),
+        }
+    }
+
+    /// The first line of the doc comment of the Rust functions generated for `method`, with its
+    /// Java signature and descriptor, which tell overloads apart.
+    fn method_doc(&self, method: &Method) -> String {
+        let argument_tys: Vec<String> = method
+            .argument_tys
+            .iter()
+            .map(|ty| ty.to_string())
+            .collect();
+        let record_component = if self.is_record_accessor(method) {
+            format!(", the accessor of the record component `{}`", method.name)
+        } else {
+            String::new()
+        };
+        format!(
+            "Java {}method `{}({})` (descriptor `{}`){record_component}.",
+            if method.flags.is_static {
+                "static "
+            } else {
+                ""
+            },
+            method.name,
+            argument_tys.join(", "),
+            method.descriptor(),
+        )
+    }
+
+    /// Generates code for the methods that goes on the `ops` object.
+    ///
+    ///
+    /// NB. This function (particularly the JvmOp impl) has significant overlap with `static_method`
+    /// and `static_field_getter`, so if you make changes here, you may well need changes there.
+    fn op_struct_method(
+        &self,
+        method: &Method,
+        conversions: &[Conversion],
+    ) -> Result<TokenStream, SpanError> {
+        let span = self.method_span(method);
+        let method = &self.with_unshadowed_generics(method);
+
+        let mut sig = Signature::new(&method.name, span, &self.generics)
+            .with_type_arguments(&self.type_arguments)
+            .with_conversions(conversions)
+            .with_internal_generics(&method.generics)?;
+
+        let input_traits: Vec<_> = method
+            .argument_tys
+            .iter()
+            .map(|ty| sig.input_trait(ty))
+            .collect::<Result<_, _>>()?;
+
+        let input_names: Vec<_> = (0..input_traits.len())
+            .map(|i| Ident::new(&format!("a{i}"), self.span))
+            .collect();
+
+        // The "output trait" is the trait bounds we declare for the user,
+        // e.g., for a method like `Foo method()`, we will declare a
+        // Rust method `-> impl JavaMethod<Foo>`, and this variable
+        // would be `JavaMethod<Foo>`.
+        let output_trait = sig.method_trait(&method.return_ty)?;
+
+        let rust_method_name = self.rust_method_name(method).to_ident(self.span);
+
+        // The generic parameters we need on the Rust method, these include:
+        //
+        // * a type parameter for each java generic
+        // * any fresh generics we created to capture wildcards
+        let rust_method_generics = &sig.rust_generics;
+
+        // The final where clauses we need on the method.
+        // This includes the bounds declared in Java but also
+        // other bounds we added as we converted input types
+        // to account for captures.
+        let sig_where_clauses = &sig.where_clauses;
+
+        let this_ty = self.this_type();
+
+        let method_doc = self.method_doc(method);
+
+        let javadoc = javadoc(method.docs.as_deref(), self.span);
+
+        let deprecated = deprecated_attribute(method.deprecated.as_ref(), self.span);
+        let protected_doc = protected_doc(method.flags.privacy, self.span);
+        let throws_doc = throws_doc(&method.throws, self.span);
+
+        let inherent_method = quote_spanned!(self.span =>
+            #[doc = #method_doc]
+            #javadoc
+            #protected_doc
+            #throws_doc
+            #deprecated
+            pub fn #rust_method_name<#(#rust_method_generics),*>(
+                &self,
+                #(#input_names: impl #input_traits),*
+            ) -> impl #output_trait
             where
                 #(#sig_where_clauses,)*
             {
@@ -639,8 +1370,17 @@ impl ClassInfo {
         Ok(inherent_method)
     }
 
-    fn obj_struct_method(&self, method: &Method) -> Result<TokenStream, SpanError> {
-        let mut sig = Signature::new(&method.name, self.span, &self.generics)
+    fn obj_struct_method(
+        &self,
+        method: &Method,
+        conversions: &[Conversion],
+    ) -> Result<TokenStream, SpanError> {
+        let span = self.method_span(method);
+        let method = &self.with_unshadowed_generics(method);
+
+        let mut sig = Signature::new(&method.name, span, &self.generics)
+            .with_type_arguments(&self.type_arguments)
+            .with_conversions(conversions)
             .with_internal_generics(&method.generics)?;
 
         let input_traits: Vec<_> = method
@@ -659,7 +1399,7 @@ impl ClassInfo {
         // would be `JavaMethod<Foo>`.
         let output_trait = sig.method_trait(&method.return_ty)?;
 
-        let rust_method_name = Id::from(method.name.to_snake_case()).to_ident(self.span);
+        let rust_method_name = self.rust_method_name(method).to_ident(self.span);
 
         // The generic parameters we need on the Rust method, these include:
         //
@@ -675,7 +1415,20 @@ impl ClassInfo {
 
         let this_ty = self.this_type();
 
+        let method_doc = self.method_doc(method);
+
+        let javadoc = javadoc(method.docs.as_deref(), self.span);
+
+        let deprecated = deprecated_attribute(method.deprecated.as_ref(), self.span);
+        let protected_doc = protected_doc(method.flags.privacy, self.span);
+        let throws_doc = throws_doc(&method.throws, self.span);
+
         let inherent_method = quote_spanned!(self.span =>
+            #[doc = #method_doc]
+            #javadoc
+            #protected_doc
+            #throws_doc
+            #deprecated
             pub fn #rust_method_name<'a, #(#rust_method_generics),*>(
                 &'a self,
                 #(#input_names: impl #input_traits + 'a),*
@@ -693,8 +1446,17 @@ impl ClassInfo {
         Ok(inherent_method)
     }
 
-    fn inherent_object_method(&self, method: &Method) -> Result<TokenStream, SpanError> {
-        let mut sig = Signature::new(&method.name, self.span, &self.generics)
+    fn inherent_object_method(
+        &self,
+        method: &Method,
+        conversions: &[Conversion],
+    ) -> Result<TokenStream, SpanError> {
+        let span = self.method_span(method);
+        let method = &self.with_unshadowed_generics(method);
+
+        let mut sig = Signature::new(&method.name, span, &self.generics)
+            .with_type_arguments(&self.type_arguments)
+            .with_conversions(conversions)
             .with_internal_generics(&method.generics)?;
 
         let input_traits: Vec<_> = method
@@ -724,21 +1486,34 @@ impl ClassInfo {
         // Rust type representing the java class/interface that is returned
         // (e.g., `Some(java::lang::Object)`).
         let java_ref_output_ty = match &method.return_ty {
-            Some(java_return_type) => {
-                sig.forbid_capture(|sig| sig.java_ty_if_ref(java_return_type))?
+            Some(java_return_type) if sig.converted_ty(java_return_type).is_none() => {
+                sig.erase_wildcards(|sig| sig.java_ty_if_ref(java_return_type))?
             }
-            None => None,
+            _ => None,
         };
 
+        // Code to convert the JNI output into `output_ty`
+        let prepare_output =
+            self.prepare_output(&mut sig, method.return_ty.as_ref(), &output_ty)?;
+
+        let conversion_checks = self.conversion_checks(
+            &mut sig,
+            &method.name,
+            &format!("the method `{}.{}`", self.name, method.name),
+            &method.argument_tys,
+            method.return_ty.as_ref(),
+        )?;
+
         let jni_descriptor = jni_c_str(&method.descriptor(), self.span);
 
         // Code to convert each input appropriately
-        let prepare_inputs = self.prepare_inputs(&input_names, &method.argument_tys);
+        let prepare_inputs = self.prepare_inputs(&mut sig, &input_names, &method.argument_tys)?;
 
         let jni_method = jni_c_str(&*method.name, self.span);
 
-        let rust_method_name = Id::from(method.name.to_snake_case()).to_ident(self.span);
-        let rust_method_type_name = Id::from(method.name.to_camel_case()).to_ident(self.span);
+        let rust_method_name = self.rust_method_name(method).to_ident(self.span);
+        let rust_method_type_name =
+            Id::from(self.rust_method_name(method).to_camel_case()).to_ident(self.span);
 
         // The generic parameters declared on the Java method.
         let java_class_generics: Vec<_> = self.class_generic_names();
@@ -849,7 +1624,7 @@ impl ClassInfo {
                     };
                     check_exception(jvm)?;
 
-                    let output: #output_ty = unsafe { FromJniValue::from_jni_value(jvm, output) };
+                    #prepare_output
                     Ok(output)
                 }
             }
@@ -875,7 +1650,20 @@ impl ClassInfo {
             )
         });
 
+        let method_doc = self.method_doc(method);
+
+        let javadoc = javadoc(method.docs.as_deref(), self.span);
+
+        let deprecated = deprecated_attribute(method.deprecated.as_ref(), self.span);
+        let protected_doc = protected_doc(method.flags.privacy, self.span);
+        let throws_doc = throws_doc(&method.throws, self.span);
+
         let inherent_method = quote_spanned!(self.span =>
+            #[doc = #method_doc]
+            #javadoc
+            #protected_doc
+            #throws_doc
+            #deprecated
             pub fn #rust_method_name<#(#rust_method_generics),*>(
                 #this: impl IntoJava<#this_ty>,
                 #(#input_names: impl #input_traits),*
@@ -883,6 +1671,8 @@ impl ClassInfo {
             where
                 #(#sig_where_clauses,)*
             {
+                #conversion_checks
+
                 #method_struct
 
                 #jvmop_impl
@@ -905,10 +1695,19 @@ impl ClassInfo {
     ///
     /// NB. This function (particularly the JvmOp impl) has significant overlap with `object_method`
     /// and `static_field_getter`, so if you make changes here, you may well need changes there.
-    fn static_method(&self, method: &Method) -> Result<TokenStream, SpanError> {
+    fn static_method(
+        &self,
+        method: &Method,
+        conversions: &[Conversion],
+    ) -> Result<TokenStream, SpanError> {
         assert!(method.flags.is_static);
 
-        let mut sig = Signature::new(&method.name, self.span, &self.generics)
+        let span = self.method_span(method);
+        let method = &self.with_unshadowed_generics(method);
+
+        let mut sig = Signature::new(&method.name, span, &self.generics)
+            .with_type_arguments(&self.type_arguments)
+            .with_conversions(conversions)
             .with_internal_generics(&method.generics)?;
 
         let input_traits: Vec<_> = method
@@ -921,32 +1720,63 @@ impl ClassInfo {
             .map(|i| Ident::new(&format!("a{i}"), self.span))
             .collect();
 
-        let output_ty = sig.output_type(&method.return_ty)?;
-        let output_trait = sig.method_trait(&method.return_ty)?;
         let jni_call_fn = sig.jni_static_call_fn(&method.return_ty)?;
 
         // If this method returns a java object, then this is the
         // Rust type representing the java class/interface that is returned
         // (e.g., `Some(java::lang::Object)`).
         let java_ref_output_ty = match &method.return_ty {
-            Some(java_return_type) => {
-                sig.forbid_capture(|sig| sig.java_ty_if_ref(java_return_type))?
+            Some(java_return_type) if sig.converted_ty(java_return_type).is_none() => {
+                sig.erase_wildcards(|sig| sig.java_ty_if_ref(java_return_type))?
+            }
+            _ => None,
+        };
+
+        // A factory method, which returns an instance of this very class (`Pattern.compile`),
+        // produces a non-null `Local` like a constructor does, so that its result can be used
+        // without `assert_not_null`; `null` is an `Error::NullDeref`.
+        let (output_ty, output_trait, prepare_output) = match &java_ref_output_ty {
+            Some(java_ref_output_ty) if self.is_factory_method(method) => (
+                quote_spanned!(self.span => Local<'jvm, #java_ref_output_ty>),
+                quote_spanned!(self.span => duchess::JavaConstructor<#java_ref_output_ty>),
+                quote_spanned!(self.span =>
+                    let output: Option<Local<'jvm, #java_ref_output_ty>> =
+                        unsafe { FromJniValue::from_jni_value(jvm, output) };
+                    let output = output.ok_or(duchess::Error::NullDeref)?;
+                ),
+            ),
+            _ => {
+                let output_ty = sig.output_type(&method.return_ty)?;
+                let output_trait = sig.method_trait(&method.return_ty)?;
+                // Code to convert the JNI output into `output_ty`
+                let prepare_output =
+                    self.prepare_output(&mut sig, method.return_ty.as_ref(), &output_ty)?;
+                (output_ty, output_trait, prepare_output)
             }
-            None => None,
         };
 
+        let conversion_checks = self.conversion_checks(
+            &mut sig,
+            &method.name,
+            &format!("the method `{}.{}`", self.name, method.name),
+            &method.argument_tys,
+            method.return_ty.as_ref(),
+        )?;
+
         let jni_descriptor = jni_c_str(&method.descriptor(), self.span);
 
         // Code to convert each input appropriately
-        let prepare_inputs = self.prepare_inputs(&input_names, &method.argument_tys);
+        let prepare_inputs = self.prepare_inputs(&mut sig, &input_names, &method.argument_tys)?;
 
         let jni_method = jni_c_str(&*method.name, self.span);
 
-        let rust_method_name = Id::from(method.name.to_snake_case()).to_ident(self.span);
-        let rust_method_type_name = Id::from(method.name.to_camel_case()).to_ident(self.span);
+        let rust_name = self.rust_method_name(method);
+        let rust_method_name = rust_name.to_ident(self.span);
+        let rust_method_type_name = Id::from(rust_name.to_camel_case()).to_ident(self.span);
 
-        // The generic parameters declared on the Java method.
-        let java_class_generics: Vec<_> = self.class_generic_names();
+        // Static methods do not depend on the class generics: they are generated in an impl
+        // for the erased class (see `erased_this_type`), so that their generics can be inferred.
+        let java_class_generics: Vec<Ident> = vec![];
 
         // The generic parameters we need on the Rust method, these include:
         //
@@ -980,7 +1810,7 @@ impl ClassInfo {
 
         // Implementation of `JvmOp` for `m` -- when executed, call the method
         // via JNI, after converting its arguments appropriately.
-        let this_ty = self.this_type();
+        let this_ty = self.erased_this_type();
         let jvmop_impl = quote_spanned!(self.span =>
             impl<#(#method_struct_generics),*> Copy
             for #rust_method_type_name<#(#method_struct_generics),*>
@@ -1040,7 +1870,7 @@ impl ClassInfo {
                     };
                     check_exception(jvm)?;
 
-                    let output: #output_ty = unsafe { FromJniValue::from_jni_value(jvm, output) };
+                    #prepare_output
                     Ok(output)
                 }
             }
@@ -1066,13 +1896,28 @@ impl ClassInfo {
             )
         });
 
+        let method_doc = self.method_doc(method);
+
+        let javadoc = javadoc(method.docs.as_deref(), self.span);
+
+        let deprecated = deprecated_attribute(method.deprecated.as_ref(), self.span);
+        let protected_doc = protected_doc(method.flags.privacy, self.span);
+        let throws_doc = throws_doc(&method.throws, self.span);
+
         let inherent_method = quote_spanned!(self.span =>
+            #[doc = #method_doc]
+            #javadoc
+            #protected_doc
+            #throws_doc
+            #deprecated
             pub fn #rust_method_name<#(#rust_method_generics),*>(
                 #(#input_names: impl #input_traits),*
             ) -> impl #output_trait
             where
                 #(#sig_where_clauses,)*
             {
+                #conversion_checks
+
                 #method_struct
 
                 #jvmop_impl
@@ -1089,30 +1934,197 @@ impl ClassInfo {
         Ok(inherent_method)
     }
 
+    /// Whether the static `method` returns an instance of this class, with type parameters (of the
+    /// method) as its type arguments, if any: `static Pattern compile(String)` or
+    /// `static <T> Optional<T> of(T)`, but not `static Number parse(String)` on `Integer`, or
+    /// `static Optional<String> named(String)`.
+    fn is_factory_method(&self, method: &Method) -> bool {
+        match &method.return_ty {
+            Some(Type::Ref(RefType::Class(class_ref))) => {
+                class_ref.name == self.name
+                    && class_ref
+                        .generics
+                        .iter()
+                        .all(|g| matches!(g, RefType::TypeParameter(_)))
+            }
+            _ => false,
+        }
+    }
+
+    /// The generic parameters of a method become generic parameters of the Rust function, which
+    /// is generated in an `impl` that already has the class generics in scope, as well as the
+    /// generics of the view structs (`J` and `N`) or of the interface trait impls (`This`).
+    /// A method generic with one of these names would clash with it, like `T` in
+    /// `static <T> Comparator<T> nullsFirst(Comparator<? super T>)` on `Comparator<T>`.
+    /// Returns `method` with such generics renamed (e.g. to `T1`).
+    fn with_unshadowed_generics(&self, method: &Method) -> Method {
+        const RESERVED: &[&str] = &["J", "N", "This"];
+        let clashes =
+            |id: &Id| self.generics.iter().any(|c| c.id == *id) || RESERVED.contains(&&id[..]);
+
+        let mut taken: Vec<Id> = self
+            .generics
+            .iter()
+            .chain(&method.generics)
+            .map(|g| g.id.clone())
+            .chain(RESERVED.iter().map(|&r| Id::from(r)))
+            .collect();
+        let mut renames: Vec<(Id, RefType)> = vec![];
+        for g in &method.generics {
+            if !clashes(&g.id) {
+                continue;
+            }
+            let fresh = (1..)
+                .map(|i| Id::from(format!("{}{i}", g.id)))
+                .find(|id| !taken.contains(id))
+                .unwrap();
+            taken.push(fresh.clone());
+            renames.push((g.id.clone(), RefType::TypeParameter(fresh)));
+        }
+        if renames.is_empty() {
+            return method.clone();
+        }
+
+        let subst: Substitution<'_> = renames.iter().map(|(id, ty)| (id, ty)).collect();
+        let generics = method
+            .generics
+            .iter()
+            .map(|g| Generic {
+                id: match renames.iter().find(|(id, _)| *id == g.id) {
+                    Some((_, RefType::TypeParameter(fresh))) => fresh.clone(),
+                    _ => g.id.clone(),
+                },
+                extends: g.extends.substitute(&subst),
+            })
+            .collect();
+        Method {
+            generics,
+            argument_tys: method.argument_tys.substitute(&subst),
+            return_ty: method.return_ty.as_ref().map(|ty| ty.substitute(&subst)),
+            ..method.clone()
+        }
+    }
+
+    /// For a `static final` field whose value is in the class file, generates a constant
+    /// that should be part of the inherent impl for the struct, e.g.
+    /// `pub const MAX_VALUE: i32 = 2147483647;`. Returns `None` for other fields, and for
+    /// values that Rust cannot represent (like strings with unpaired surrogates); these get
+    /// a getter instead.
+    fn constant(&self, field: &Field) -> Option<TokenStream> {
+        if !field.flags.is_static || !field.flags.is_final {
+            return None;
+        }
+
+        let span = self.field_span(field);
+        let (ty, value) = match (&field.ty, field.value.as_ref()?) {
+            (Type::Scalar(ScalarType::Boolean), ConstantValue::Boolean(b)) => {
+                (quote_spanned!(span => bool), quote_spanned!(span => #b))
+            }
+            (Type::Scalar(ScalarType::Char), ConstantValue::Char(c)) => (
+                quote_spanned!(span => u16),
+                Literal::u16_unsuffixed(*c).into_token_stream(),
+            ),
+            (Type::Scalar(ScalarType::F32), ConstantValue::NotANumber) => (
+                quote_spanned!(span => f32),
+                quote_spanned!(span => f32::NAN),
+            ),
+            (Type::Scalar(ScalarType::F64), ConstantValue::NotANumber) => (
+                quote_spanned!(span => f64),
+                quote_spanned!(span => f64::NAN),
+            ),
+            (Type::Scalar(ScalarType::F32), ConstantValue::Infinity { negative }) => {
+                let value = if *negative {
+                    quote_spanned!(span => f32::NEG_INFINITY)
+                } else {
+                    quote_spanned!(span => f32::INFINITY)
+                };
+                (quote_spanned!(span => f32), value)
+            }
+            (Type::Scalar(ScalarType::F64), ConstantValue::Infinity { negative }) => {
+                let value = if *negative {
+                    quote_spanned!(span => f64::NEG_INFINITY)
+                } else {
+                    quote_spanned!(span => f64::INFINITY)
+                };
+                (quote_spanned!(span => f64), value)
+            }
+            (Type::Scalar(s), ConstantValue::Number(n)) => {
+                let literal = match s {
+                    ScalarType::Int => Literal::i32_unsuffixed(n.parse().ok()?),
+                    ScalarType::Long => Literal::i64_unsuffixed(n.parse().ok()?),
+                    ScalarType::Short => Literal::i16_unsuffixed(n.parse().ok()?),
+                    ScalarType::Byte => Literal::i8_unsuffixed(n.parse().ok()?),
+                    ScalarType::F64 => Literal::f64_unsuffixed(n.parse().ok()?),
+                    ScalarType::F32 => Literal::f32_unsuffixed(n.parse().ok()?),
+                    ScalarType::Boolean | ScalarType::Char => return None,
+                };
+                (s.to_tokens(span), literal.into_token_stream())
+            }
+            (Type::Ref(RefType::Class(c)), ConstantValue::String(units))
+                if c.name == DotId::parse("java.lang.String") =>
+            {
+                let s = String::from_utf16(units).ok()?;
+                (
+                    quote_spanned!(span => &'static str),
+                    quote_spanned!(span => #s),
+                )
+            }
+            _ => return None,
+        };
+
+        let rust_constant_name = self.rust_constant_name(&field.name).to_ident(span);
+        let javadoc = javadoc(field.docs.as_deref(), span);
+        let deprecated = deprecated_attribute(field.deprecated.as_ref(), span);
+        Some(quote_spanned!(span =>
+            #javadoc
+            #deprecated
+            pub const #rust_constant_name: #ty = #value;
+        ))
+    }
+
     /// Generates a static field getter that should be part of the inherent methods
     /// for the struct.
     ///
     /// NB. This function (particularly the JvmOp impl) has significant overlap with `object_method`
     /// and `static_method`, so if you make changes here, you may well need changes there.
-    fn static_field_getter(&self, field: &Field) -> Result<TokenStream, SpanError> {
+    fn static_field_getter(
+        &self,
+        field: &Field,
+        conversions: &[Conversion],
+    ) -> Result<TokenStream, SpanError> {
         assert!(field.flags.is_static);
 
-        let mut sig = Signature::new(&field.name, self.span, &self.generics);
+        let mut sig = Signature::new(&field.name, self.field_span(field), &self.generics)
+            .with_type_arguments(&self.type_arguments)
+            .with_conversions(conversions);
 
         let output_ty = sig.non_void_output_type(&field.ty)?;
         let output_trait = sig.field_trait(&field.ty)?;
         let jni_field_fn = sig.jni_static_field_get_fn(&field.ty)?;
 
+        // Code to convert the JNI output into `output_ty`
+        let prepare_output = self.prepare_output(&mut sig, Some(&field.ty), &output_ty)?;
+
+        let conversion_checks = self.conversion_checks(
+            &mut sig,
+            &field.name,
+            &format!("the field `{}.{}`", self.name, field.name),
+            [],
+            Some(&field.ty),
+        )?;
+
         let jni_field = jni_c_str(&*field.name, self.span);
-        let jni_descriptor = jni_c_str(&field.ty.descriptor(), self.span);
+        let jni_descriptor = jni_c_str(field.descriptor(), self.span);
 
-        let rust_field_name =
-            Id::from(format!("get_{}", field.name.to_snake_case())).to_ident(self.span);
-        let rust_field_type_name =
-            Id::from(format!("{}Getter", field.name.to_camel_case())).to_ident(self.span);
+        let rust_field_name = self.rust_field_getter_name(&field.name).to_ident(self.span);
+        let rust_field_type_name = Id::from(format!(
+            "{}Getter",
+            self.rust_member_name(&field.name).to_camel_case()
+        ))
+        .to_ident(self.span);
 
-        // The generic parameters declared on the Java method.
-        let java_class_generics: Vec<_> = self.class_generic_names();
+        // Like static methods, static fields do not depend on the class generics.
+        let java_class_generics: Vec<Ident> = vec![];
 
         // The generic parameters we need on the *method struct* (which will implement the `JvmOp`).
         // These include the class generics plus all the generics from the method.
@@ -1134,7 +2146,7 @@ impl ClassInfo {
 
         // Implementation of `JvmOp` for `f` -- when executed, call the method
         // via JNI, after converting its arguments appropriately.
-        let this_ty = self.this_type();
+        let this_ty = self.erased_this_type();
         let jvmop_impl = quote_spanned!(self.span =>
             impl<#(#field_struct_generics),*> JvmOp
             for #rust_field_type_name<#(#field_struct_generics),*>
@@ -1168,7 +2180,7 @@ impl ClassInfo {
                     };
                     check_exception(jvm)?;
 
-                    let output: #output_ty = unsafe { FromJniValue::from_jni_value(jvm, output) };
+                    #prepare_output
                     Ok(output)
                 }
             }
@@ -1191,11 +2203,21 @@ impl ClassInfo {
             }
         );
 
+        let javadoc = javadoc(field.docs.as_deref(), self.span);
+
+        let deprecated = deprecated_attribute(field.deprecated.as_ref(), self.span);
+        let protected_doc = protected_doc(field.flags.privacy, self.span);
+
         let inherent_method = quote_spanned!(self.span =>
+            #javadoc
+            #protected_doc
+            #deprecated
             pub fn #rust_field_name() -> impl #output_trait
             where
                 #(#sig_where_clauses,)*
             {
+                #conversion_checks
+
                 #field_struct
 
                 #jvmop_impl
@@ -1212,8 +2234,489 @@ impl ClassInfo {
         Ok(inherent_method)
     }
 
+    /// Generates the getter for an instance field that goes on the `ops` object.
+    fn op_struct_field_getter(
+        &self,
+        field: &Field,
+        conversions: &[Conversion],
+    ) -> Result<TokenStream, SpanError> {
+        let mut sig = Signature::new(&field.name, self.field_span(field), &self.generics)
+            .with_type_arguments(&self.type_arguments)
+            .with_conversions(conversions);
+
+        let output_trait = sig.field_trait(&field.ty)?;
+        let rust_field_name = self.rust_field_getter_name(&field.name).to_ident(self.span);
+        let sig_where_clauses = &sig.where_clauses;
+        let this_ty = self.this_type();
+        let javadoc = javadoc(field.docs.as_deref(), self.span);
+        let deprecated = deprecated_attribute(field.deprecated.as_ref(), self.span);
+        let protected_doc = protected_doc(field.flags.privacy, self.span);
+
+        Ok(quote_spanned!(self.span =>
+            #javadoc
+            #protected_doc
+            #deprecated
+            pub fn #rust_field_name(&self) -> impl #output_trait
+            where
+                #(#sig_where_clauses,)*
+            {
+                <#this_ty>::#rust_field_name(self.this)
+            }
+        ))
+    }
+
+    /// Generates the getter for an instance field that goes on the object struct.
+    fn obj_struct_field_getter(
+        &self,
+        field: &Field,
+        conversions: &[Conversion],
+    ) -> Result<TokenStream, SpanError> {
+        let mut sig = Signature::new(&field.name, self.field_span(field), &self.generics)
+            .with_type_arguments(&self.type_arguments)
+            .with_conversions(conversions);
+
+        let output_trait = sig.field_trait(&field.ty)?;
+        let rust_field_name = self.rust_field_getter_name(&field.name).to_ident(self.span);
+        let sig_where_clauses = &sig.where_clauses;
+        let this_ty = self.this_type();
+        let javadoc = javadoc(field.docs.as_deref(), self.span);
+        let deprecated = deprecated_attribute(field.deprecated.as_ref(), self.span);
+        let protected_doc = protected_doc(field.flags.privacy, self.span);
+
+        Ok(quote_spanned!(self.span =>
+            #javadoc
+            #protected_doc
+            #deprecated
+            pub fn #rust_field_name<'a>(&'a self) -> impl #output_trait + 'a
+            where
+                #(#sig_where_clauses,)*
+            {
+                <#this_ty>::#rust_field_name(&self.this)
+            }
+        ))
+    }
+
+    /// Generates the setter for a non-final instance field that goes on the `ops` object.
+    fn op_struct_field_setter(
+        &self,
+        field: &Field,
+        conversions: &[Conversion],
+    ) -> Result<TokenStream, SpanError> {
+        let mut sig = Signature::new(&field.name, self.field_span(field), &self.generics)
+            .with_type_arguments(&self.type_arguments)
+            .with_conversions(conversions);
+
+        let input_trait = sig.input_trait(&field.ty)?;
+        let rust_field_name = self.rust_field_setter_name(&field.name).to_ident(self.span);
+        let rust_setter_generics = &sig.rust_generics;
+        let sig_where_clauses = &sig.where_clauses;
+        let this_ty = self.this_type();
+        let deprecated = deprecated_attribute(field.deprecated.as_ref(), self.span);
+        let protected_doc = protected_doc(field.flags.privacy, self.span);
+
+        Ok(quote_spanned!(self.span =>
+            #protected_doc
+            #deprecated
+            pub fn #rust_field_name<#(#rust_setter_generics),*>(
+                &self,
+                value: impl #input_trait,
+            ) -> impl duchess::VoidMethod
+            where
+                #(#sig_where_clauses,)*
+            {
+                <#this_ty>::#rust_field_name(self.this, value)
+            }
+        ))
+    }
+
+    /// Generates the setter for a non-final instance field that goes on the object struct.
+    fn obj_struct_field_setter(
+        &self,
+        field: &Field,
+        conversions: &[Conversion],
+    ) -> Result<TokenStream, SpanError> {
+        let mut sig = Signature::new(&field.name, self.field_span(field), &self.generics)
+            .with_type_arguments(&self.type_arguments)
+            .with_conversions(conversions);
+
+        let input_trait = sig.input_trait(&field.ty)?;
+        let rust_field_name = self.rust_field_setter_name(&field.name).to_ident(self.span);
+        let rust_setter_generics = &sig.rust_generics;
+        let sig_where_clauses = &sig.where_clauses;
+        let this_ty = self.this_type();
+        let deprecated = deprecated_attribute(field.deprecated.as_ref(), self.span);
+        let protected_doc = protected_doc(field.flags.privacy, self.span);
+
+        Ok(quote_spanned!(self.span =>
+            #protected_doc
+            #deprecated
+            pub fn #rust_field_name<'a, #(#rust_setter_generics),*>(
+                &'a self,
+                value: impl #input_trait + 'a,
+            ) -> impl duchess::VoidMethod + 'a
+            where
+                #(#sig_where_clauses,)*
+            {
+                <#this_ty>::#rust_field_name(&self.this, value)
+            }
+        ))
+    }
+
+    /// Generates a setter for a non-final field that should be part of the inherent methods
+    /// for the struct: `Foo::set_field(this, value)` for an instance field, or
+    /// `Foo::set_field(value)` for a static one.
+    fn field_setter(
+        &self,
+        field: &Field,
+        conversions: &[Conversion],
+    ) -> Result<TokenStream, SpanError> {
+        assert!(!field.flags.is_final);
+        let is_static = field.flags.is_static;
+
+        let mut sig = Signature::new(&field.name, self.field_span(field), &self.generics)
+            .with_type_arguments(&self.type_arguments)
+            .with_conversions(conversions);
+
+        let input_trait = sig.input_trait(&field.ty)?;
+        let jni_field_fn = sig.jni_field_set_fn(&field.ty, is_static)?;
+        let jni_value_member = sig.jni_value_member(&field.ty);
+
+        let conversion_checks = self.conversion_checks(
+            &mut sig,
+            &field.name,
+            &format!("the field `{}.{}`", self.name, field.name),
+            [&field.ty],
+            None,
+        )?;
+
+        let value = Ident::new("value", self.span);
+        let prepare_value = self.prepare_inputs(
+            &mut sig,
+            std::slice::from_ref(&value),
+            std::slice::from_ref(&field.ty),
+        )?;
+
+        let jni_field = jni_c_str(&*field.name, self.span);
+        let jni_descriptor = jni_c_str(field.descriptor(), self.span);
+
+        let rust_field_name = self.rust_field_setter_name(&field.name).to_ident(self.span);
+        let rust_field_type_name = Id::from(format!(
+            "{}Setter",
+            self.rust_member_name(&field.name).to_camel_case()
+        ))
+        .to_ident(self.span);
+
+        let java_class_generics: Vec<_> = self.class_generic_names();
+        let rust_setter_generics = &sig.rust_generics;
+
+        // The generic parameters we need on the *field struct* (which will implement the `JvmOp`):
+        // the class generics, any captures, the type of `this` (if not static), and the type of
+        // the value.
+        let this = Ident::new("this", self.span);
+        let field_struct_generics: Vec<_> = java_class_generics
+            .iter()
+            .chain(rust_setter_generics)
+            .chain(if is_static { None } else { Some(&this) })
+            .chain(Some(&value))
+            .collect();
+
+        let this_ty = self.this_type();
+        let this_field = (!is_static).then(|| quote_spanned!(self.span => #this: #this,));
+        let this_bound =
+            (!is_static).then(|| quote_spanned!(self.span => #this: IntoJava<#this_ty>,));
+        let this_param =
+            (!is_static).then(|| quote_spanned!(self.span => #this: impl IntoJava<#this_ty>,));
+        let this_init = (!is_static).then(|| quote_spanned!(self.span => #this: #this,));
+
+        // The object (or, for a static field, the class) whose field we set.
+        let prepare_target = if is_static {
+            quote_spanned!(self.span =>
+                let target = <#this_ty>::class(jvm)?;
+                let target = target.as_raw();
+            )
+        } else {
+            quote_spanned!(self.span =>
+                let target = self.#this.into_java(jvm)?;
+                let target: & #this_ty = target.as_jref()?;
+                let target = target.as_raw();
+            )
+        };
+
+        let field_struct = quote_spanned!(self.span =>
+            pub struct #rust_field_type_name<
+                #(#field_struct_generics,)*
+            > {
+                #this_field
+                #value: #value,
+                phantom: std::marker::PhantomData<(
+                    #(#field_struct_generics,)*
+                )>,
+            }
+        );
+
+        let sig_where_clauses = &sig.where_clauses;
+
+        let jvmop_impl = quote_spanned!(self.span =>
+            impl<#(#field_struct_generics),*> JvmOp
+            for #rust_field_type_name<#(#field_struct_generics),*>
+            where
+                #this_bound
+                #value: #input_trait,
+                #(#java_class_generics: duchess::JavaObject,)*
+                #(#sig_where_clauses,)*
+            {
+                type Output<'jvm> = ();
+
+                fn execute_with<'jvm>(
+                    self,
+                    jvm: &mut Jvm<'jvm>,
+                ) -> duchess::Result<'jvm, Self::Output<'jvm>> {
+                    #prepare_target
+
+                    #(#prepare_value)*
+
+                    // Cache the field id for this field, shared by all monomorphizations.
+                    static FIELD: OnceCell<FieldPtr> = OnceCell::new();
+                    let field = FIELD.get_or_try_init(|| {
+                        let class = <#this_ty>::class(jvm)?;
+                        find_field(jvm, &class, #jni_field, #jni_descriptor, #is_static)
+                    })?;
+
+                    unsafe {
+                        jvm.env().invoke(|env| env.#jni_field_fn, |env, f| f(
+                            env,
+                            target.as_ptr(),
+                            field.as_ptr(),
+                            #value.into_jni_value().#jni_value_member,
+                        ))
+                    };
+                    check_exception(jvm)?;
+
+                    Ok(())
+                }
+            }
+
+            impl<#(#field_struct_generics),*> Copy for #rust_field_type_name<#(#field_struct_generics),*>
+            where
+                #this_bound
+                #value: #input_trait,
+                #(#java_class_generics: duchess::JavaObject,)*
+                #(#sig_where_clauses,)*
+            {
+            }
+
+            impl<#(#field_struct_generics),*> Clone for #rust_field_type_name<#(#field_struct_generics),*>
+            where
+                #this_bound
+                #value: #input_trait,
+                #(#java_class_generics: duchess::JavaObject,)*
+                #(#sig_where_clauses,)*
+            {
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+        );
+
+        let deprecated = deprecated_attribute(field.deprecated.as_ref(), self.span);
+        let protected_doc = protected_doc(field.flags.privacy, self.span);
+
+        Ok(quote_spanned!(self.span =>
+            #protected_doc
+            #deprecated
+            pub fn #rust_field_name<#(#rust_setter_generics),*>(
+                #this_param
+                #value: impl #input_trait,
+            ) -> impl duchess::VoidMethod
+            where
+                #(#sig_where_clauses,)*
+            {
+                #conversion_checks
+
+                #field_struct
+
+                #jvmop_impl
+
+                #rust_field_type_name {
+                    #this_init
+                    #value: #value,
+                    phantom: Default::default(),
+                }
+            }
+        ))
+    }
+
+    /// Generates an instance field getter of the form `Foo::get_field(this)` that should be
+    /// part of the inherent methods for the struct.
+    ///
+    /// NB. This function (particularly the JvmOp impl) has significant overlap with
+    /// `static_field_getter` and `inherent_object_method`, so if you make changes here,
+    /// you may well need changes there.
+    fn inherent_field_getter(
+        &self,
+        field: &Field,
+        conversions: &[Conversion],
+    ) -> Result<TokenStream, SpanError> {
+        assert!(!field.flags.is_static);
+
+        let mut sig = Signature::new(&field.name, self.field_span(field), &self.generics)
+            .with_type_arguments(&self.type_arguments)
+            .with_conversions(conversions);
+
+        let output_ty = sig.non_void_output_type(&field.ty)?;
+        let output_trait = sig.field_trait(&field.ty)?;
+        let jni_field_fn = sig.jni_field_get_fn(&field.ty)?;
+
+        // Code to convert the JNI output into `output_ty`
+        let prepare_output = self.prepare_output(&mut sig, Some(&field.ty), &output_ty)?;
+
+        let conversion_checks = self.conversion_checks(
+            &mut sig,
+            &field.name,
+            &format!("the field `{}.{}`", self.name, field.name),
+            [],
+            Some(&field.ty),
+        )?;
+
+        let jni_field = jni_c_str(&*field.name, self.span);
+        let jni_descriptor = jni_c_str(field.descriptor(), self.span);
+
+        let rust_field_name = self.rust_field_getter_name(&field.name).to_ident(self.span);
+        let rust_field_type_name = Id::from(format!(
+            "{}Getter",
+            self.rust_member_name(&field.name).to_camel_case()
+        ))
+        .to_ident(self.span);
+
+        let java_class_generics: Vec<_> = self.class_generic_names();
+
+        // The generic parameters we need on the *field struct* (which will implement the `JvmOp`):
+        // the class generics plus the type of `this`.
+        let this = Ident::new("this", self.span);
+        let field_struct_generics: Vec<_> = java_class_generics.iter().chain(Some(&this)).collect();
+
+        let field_struct = quote_spanned!(self.span =>
+            pub struct #rust_field_type_name<
+                #(#field_struct_generics,)*
+            > {
+                #this: #this,
+                phantom: std::marker::PhantomData<(
+                    #(#field_struct_generics,)*
+                )>,
+            }
+        );
+
+        let sig_where_clauses = &sig.where_clauses;
+
+        let this_ty = self.this_type();
+        let jvmop_impl = quote_spanned!(self.span =>
+            impl<#(#field_struct_generics),*> JvmOp
+            for #rust_field_type_name<#(#field_struct_generics),*>
+            where
+                #this: IntoJava<#this_ty>,
+                #(#java_class_generics: duchess::JavaObject,)*
+                #(#sig_where_clauses,)*
+            {
+                type Output<'jvm> = #output_ty;
+
+                fn execute_with<'jvm>(
+                    self,
+                    jvm: &mut Jvm<'jvm>,
+                ) -> duchess::Result<'jvm, Self::Output<'jvm>> {
+                    let this = self.#this.into_java(jvm)?;
+                    let this: & #this_ty = this.as_jref()?;
+                    let this = this.as_raw();
+
+                    // Cache the field id for this field, shared by all monomorphizations.
+                    static FIELD: OnceCell<FieldPtr> = OnceCell::new();
+                    let field = FIELD.get_or_try_init(|| {
+                        let class = <#this_ty>::class(jvm)?;
+                        find_field(jvm, &class, #jni_field, #jni_descriptor, false)
+                    })?;
+
+                    let output = unsafe {
+                        jvm.env().invoke(|env| env.#jni_field_fn, |env, f| f(
+                            env,
+                            this.as_ptr(),
+                            field.as_ptr(),
+                        ))
+                    };
+                    check_exception(jvm)?;
+
+                    #prepare_output
+                    Ok(output)
+                }
+            }
+
+            impl<#(#field_struct_generics),*> Copy for #rust_field_type_name<#(#field_struct_generics),*>
+            where
+                #this: IntoJava<#this_ty>,
+                #(#java_class_generics: duchess::JavaObject,)*
+                #(#sig_where_clauses,)*
+            {
+            }
+
+            impl<#(#field_struct_generics),*> Clone for #rust_field_type_name<#(#field_struct_generics),*>
+            where
+                #this: IntoJava<#this_ty>,
+                #(#java_class_generics: duchess::JavaObject,)*
+                #(#sig_where_clauses,)*
+            {
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+        );
+
+        let javadoc = javadoc(field.docs.as_deref(), self.span);
+
+        let deprecated = deprecated_attribute(field.deprecated.as_ref(), self.span);
+        let protected_doc = protected_doc(field.flags.privacy, self.span);
+
+        Ok(quote_spanned!(self.span =>
+            #javadoc
+            #protected_doc
+            #deprecated
+            pub fn #rust_field_name(
+                #this: impl IntoJava<#this_ty>,
+            ) -> impl #output_trait
+            where
+                #(#sig_where_clauses,)*
+            {
+                #conversion_checks
+
+                #field_struct
+
+                #jvmop_impl
+
+                #rust_field_type_name {
+                    #this: #this,
+                    phantom: Default::default(),
+                }
+            }
+        ))
+    }
+
+    /// The name of the generated struct: the class name, unless the class has an alias.
     fn struct_name(&self) -> Ident {
-        self.name.class_name().to_ident(self.span)
+        self.alias
+            .as_ref()
+            .unwrap_or(self.name.class_name())
+            .to_ident(self.span)
+    }
+
+    /// For a class with an alias, lets the class name refer to the struct within the crate,
+    /// which is how other classes refer to it (see `DotId::to_module_name`). This is not `pub`,
+    /// so that modules with classes of the same name can still be glob-imported together.
+    fn class_name_alias(&self) -> TokenStream {
+        if self.alias.is_none() {
+            return TokenStream::new();
+        }
+        let struct_name = self.struct_name();
+        let class_name = self.name.class_name().to_ident(self.span);
+        quote_spanned!(self.span =>
+            pub(crate) use self::#struct_name as #class_name;
+        )
     }
 
     fn class_generic_names(&self) -> Vec<Ident> {
@@ -1240,32 +2743,235 @@ impl ClassInfo {
         }
     }
 
+    /// The type of the struct with `java::lang::Object` for each class generic, like
+    /// `Comparator<java::lang::Object>`. Static members are generated in an impl for this type,
+    /// which rustc selects when the class generics are left to inference, as in
+    /// `Comparator::nulls_first(...)`; in an impl generic over them, they could not be inferred.
+    fn erased_this_type(&self) -> TokenStream {
+        let s = self.struct_name();
+        if self.generics.is_empty() {
+            quote_spanned!(self.span => #s)
+        } else {
+            let objects = self
+                .generics
+                .iter()
+                .map(|_| quote_spanned!(self.span => java::lang::Object));
+            quote_spanned!(self.span => #s < #(#objects),* >)
+        }
+    }
+
     /// Returns a class name with `/`, like `java/lang/Object` as a &CStr
     fn jni_class_name(&self) -> TokenStream {
         jni_c_str(self.name.to_jni_name(), self.span)
     }
 
-    fn prepare_inputs(&self, input_names: &[Ident], input_types: &[Type]) -> Vec<TokenStream> {
+    /// Code to convert the value returned from JNI (stored in the variable `output`)
+    /// into `output_ty`. Java objects of classes that are converted to Rust (see `Conversion`)
+    /// are converted here, via `IntoRust`; a `const` item checks that the conversion exists,
+    /// so that a missing `ToRust` impl is reported against the member that needs it.
+    fn prepare_output(
+        &self,
+        sig: &mut Signature,
+        ty: Option<&Type>,
+        output_ty: &TokenStream,
+    ) -> Result<TokenStream, SpanError> {
+        let Some(ty) = ty.filter(|ty| sig.converted_ty(ty).is_some()) else {
+            return Ok(quote_spanned!(self.span =>
+                let output: #output_ty = unsafe { FromJniValue::from_jni_value(jvm, output) };
+            ));
+        };
+
+        let java_ty = sig.erase_wildcards(|sig| sig.java_ty(ty))?;
+        if !is_concrete_class(ty) {
+            return Ok(quote_spanned!(self.span =>
+                let output: Option<Local<'jvm, #java_ty>> = unsafe { FromJniValue::from_jni_value(jvm, output) };
+                let output: #output_ty = output.into_rust(jvm)?;
+            ));
+        }
+
+        // `conversion_checks` asserts that the conversion exists, so this does not fail to
+        // compile (again) if it does not.
+        let rust_ty = sig.converted_ty(ty).unwrap();
+        Ok(quote_spanned!(self.span =>
+            let output: Option<Local<'jvm, #java_ty>> = unsafe { FromJniValue::from_jni_value(jvm, output) };
+            let output: #output_ty = {
+                use duchess::plumbing::MissingConversion as _;
+                duchess::plumbing::Conversion::<#java_ty, #rust_ty>::into_rust(output, jvm)?
+            };
+        ))
+    }
+
+    /// Code that checks (at compilation time) that the conversions used by the member `name`
+    /// (`member` in error messages) exist, i.e., that its converted argument types implement
+    /// `ToJava` and its converted return type implements `ToRust`. For classes without type
+    /// arguments, the check is a `const` assertion whose message names the member and the
+    /// types; otherwise, it is a function named after the Java member, so that the compiler's
+    /// error at least points the user at the member needing the conversion.
+    fn conversion_checks<'a>(
+        &self,
+        sig: &mut Signature,
+        name: &Id,
+        member: &str,
+        input_types: impl IntoIterator<Item = &'a Type>,
+        output_ty: Option<&Type>,
+    ) -> Result<TokenStream, SpanError> {
+        let check_fn = name.to_ident(self.span);
+        let mut checks = vec![];
+
+        for ty in input_types {
+            if let Some(rust_ty) = sig.converted_ty(ty) {
+                let java_ty = sig.forbid_capture(|sig| sig.java_ty(ty))?;
+                if is_concrete_class(ty) {
+                    let message = format!(
+                        "{member} takes a `{ty}`, which is converted from `{rust}`, \
+                        but `{rust}` does not implement `ToJava` for it",
+                        rust = type_name(&rust_ty),
+                    );
+                    checks.push(quote_spanned!(sig.span() => const _: () = {
+                        use duchess::plumbing::MissingConversion as _;
+                        assert!(duchess::plumbing::Conversion::<#java_ty, #rust_ty>::TO_JAVA, #message);
+                    };));
+                } else {
+                    checks.push(quote_spanned!(self.span => {
+                        fn #check_fn<J, R>()
+                        where
+                            J: duchess::plumbing::Upcast<duchess::java::lang::Object>,
+                            R: duchess::plumbing::ToJavaImpl<J>,
+                        {}
+                        let _ = #check_fn::<#java_ty, #rust_ty>;
+                    }));
+                }
+            }
+        }
+
+        if let Some(ty) = output_ty {
+            if let Some(rust_ty) = sig.converted_ty(ty) {
+                let java_ty = sig.erase_wildcards(|sig| sig.java_ty(ty))?;
+                if is_concrete_class(ty) {
+                    let message = format!(
+                        "{member} returns a `{ty}`, which is converted to `{rust}`, \
+                        but `{rust}` does not implement `ToRust` for it",
+                        rust = type_name(&rust_ty),
+                    );
+                    checks.push(quote_spanned!(sig.span() => const _: () = {
+                        use duchess::plumbing::MissingConversion as _;
+                        assert!(duchess::plumbing::Conversion::<#java_ty, #rust_ty>::TO_RUST, #message);
+                    };));
+                } else {
+                    checks.push(quote_spanned!(self.span => {
+                        fn #check_fn<J: duchess::JavaObject, R>() where for<'a> &'a J: duchess::IntoRust<R> {}
+                        let _ = #check_fn::<#java_ty, #rust_ty>;
+                    }));
+                }
+            }
+        }
+
+        Ok(quote_spanned!(self.span => #(#checks)*))
+    }
+
+    fn prepare_inputs(
+        &self,
+        sig: &mut Signature,
+        input_names: &[Ident],
+        input_types: &[Type],
+    ) -> Result<Vec<TokenStream>, SpanError> {
         input_names
             .iter()
             .zip(input_types)
             .map(|(input_name, input_ty)| match input_ty.to_non_repeating() {
-                NonRepeatingType::Scalar(_) => quote_spanned!(self.span =>
-                    let #input_name = self.#input_name.execute_with(jvm)?;
-                ),
-                NonRepeatingType::Ref(_) => quote_spanned!(self.span =>
+                _ if sig.converted_ty(input_ty).is_some() => {
+                    let java_ty = sig.forbid_capture(|sig| sig.java_ty(input_ty))?;
+                    Ok(quote_spanned!(self.span =>
+                        let #input_name = self.#input_name.convert_to_java(jvm)?;
+                        let #input_name: &#java_ty = #input_name.as_jref()?;
+                    ))
+                }
+                _ if matches!(input_ty, Type::Repeat(e) if !e.is_scalar()) => {
+                    Ok(quote_spanned!(self.span =>
+                        let #input_name = self.#input_name.into_varargs(jvm)?;
+                        let #input_name = #input_name.as_jref()?;
+                    ))
+                }
+                NonRepeatingType::Scalar(_) => Ok(quote_spanned!(self.span =>
+                    let #input_name = self.#input_name.into_scalar(jvm)?;
+                )),
+                NonRepeatingType::Ref(_) => Ok(quote_spanned!(self.span =>
                     let #input_name = self.#input_name.into_java(jvm)?;
                     let #input_name = #input_name.as_jref()?;
-                ),
+                )),
             })
             .collect()
     }
 }
 
+/// Whether `ty` is a class without type arguments, see `ClassInfo::conversion_checks`.
+fn is_concrete_class(ty: &Type) -> bool {
+    matches!(ty, Type::Ref(RefType::Class(c)) if c.generics.is_empty())
+}
+
+/// The Rust type `ty` as written, e.g. `crate::Text` rather than `crate :: Text`.
+fn type_name(ty: &TokenStream) -> String {
+    ty.to_string().replace(" :: ", "::").replace(":: ", "::")
+}
+
 trait GenericExt {
     fn to_where_clause(&self, span: Span) -> TokenStream;
 }
 
+/// A note for the rustdoc of a `protected` member, which is only generated for classes declared
+/// with `(protected)`.
+fn protected_doc(privacy: Privacy, span: Span) -> TokenStream {
+    if privacy != Privacy::Protected {
+        return TokenStream::new();
+    }
+    let note = "This member is `protected` in Java. JNI skips most access checks, so calling it \
+        from Rust usually works, but depending on the context the JVM may still throw an \
+        `IllegalAccessError`.";
+    quote_spanned!(span => #[doc = ""] #[doc = #note])
+}
+
+/// An "Errors" section for the doc comment of a constructor or method that declares `throws`,
+/// which lists the exceptions and how to tell them apart.
+fn throws_doc(throws: &[ClassRef], span: Span) -> TokenStream {
+    let Some(first) = throws.first() else {
+        return TokenStream::new();
+    };
+    let names: Vec<String> = throws.iter().map(|c| format!("`{}`", c.name)).collect();
+    let note = format!(
+        "Declared to throw {}. A thrown exception is an `Error::Thrown`, whose class can be \
+        checked with `Error::is`, e.g. `err.is::<{}>()`.",
+        names.join(", "),
+        first.name.to_module_name(span),
+    );
+    quote_spanned!(span => #[doc = ""] #[doc = "# Errors"] #[doc = ""] #[doc = #note])
+}
+
+/// A `#[deprecated]` attribute for a deprecated class or member, with what its `@Deprecated`
+/// annotation says.
+fn deprecated_attribute(deprecation: Option<&Deprecation>, span: Span) -> TokenStream {
+    let Some(deprecation) = deprecation else {
+        return TokenStream::new();
+    };
+    let note = if deprecation.for_removal {
+        "deprecated in Java, and marked for removal"
+    } else {
+        "deprecated in Java"
+    };
+    match &deprecation.since {
+        Some(since) => quote_spanned!(span => #[deprecated(since = #since, note = #note)]),
+        None => quote_spanned!(span => #[deprecated(note = #note)]),
+    }
+}
+
+/// The Javadoc of a class or member as doc attributes, after an empty line that separates it
+/// from any generated documentation.
+fn javadoc(docs: Option<&str>, span: Span) -> TokenStream {
+    match docs {
+        Some(docs) => quote_spanned!(span => #[doc = ""] #[doc = #docs]),
+        None => TokenStream::new(),
+    }
+}
+
 fn jni_c_str(contents: impl Into<String>, span: Span) -> TokenStream {
     let mut contents = contents.into().into_bytes();
     // \0 isn't valid UTF-8, so don't need to check that contents doesn't contain interior nul bytes.
@@ -1,6 +1,6 @@
 use std::{
     collections::{BTreeMap, BTreeSet, VecDeque},
-    sync::Arc,
+    rc::Rc,
 };
 
 use proc_macro2::{Span, TokenStream};
@@ -535,5 +535,5 @@ fn unique_variant_classes<'a, 'i>(
 struct ToRustVariant<'i> {
     variant: &'i VariantInfo<'i>,
     selector: MethodSelector,
-    class: Arc<ClassInfo>,
+    class: Rc<ClassInfo>,
 }
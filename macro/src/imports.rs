@@ -0,0 +1,218 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use proc_macro2::Span;
+
+use crate::{
+    class_info::{ClassDecl, ClassInfo, ClassRef, DotId, Generic, GenericDecl, Id, RefType, Type},
+    span_error::SpanError,
+};
+
+/// Aliases introduced by `use java.lang.String;` lines at the top of a `package` block.
+/// After an import, the class can be referred to by its simple name (`String`)
+/// anywhere within the classes declared in that package.
+#[derive(Default)]
+pub struct Imports {
+    aliases: BTreeMap<Id, DotId>,
+}
+
+impl Imports {
+    /// Records an import of `name`, reporting an error at `span` if it is a duplicate
+    /// or if another class with the same simple name was already imported.
+    pub fn insert(&mut self, name: DotId, span: Span) -> Result<(), SpanError> {
+        if name.split().0.is_empty() {
+            return Err(SpanError {
+                span,
+                message: "expected a fully qualified class name after `use`".to_string(),
+            });
+        }
+
+        let alias = name.class_name().clone();
+        match self.aliases.get(&alias) {
+            Some(previous) if *previous == name => Err(SpanError {
+                span,
+                message: format!("`{name}` is imported more than once"),
+            }),
+            Some(previous) => Err(SpanError {
+                span,
+                message: format!(
+                    "ambiguous import: `{alias}` already refers to `{previous}`, cannot also import `{name}`"
+                ),
+            }),
+            None => {
+                self.aliases.insert(alias, name);
+                Ok(())
+            }
+        }
+    }
+
+    /// Replaces references to imported names within `decl` with the fully qualified class.
+    /// Generic parameters in scope shadow imports, as they would in Java.
+    pub fn resolve_class_decl(&self, decl: &mut ClassDecl) {
+        if self.aliases.is_empty() {
+            return;
+        }
+
+        let lookup = |id: &Id| self.aliases.get(id).cloned();
+        match decl {
+            ClassDecl::Reflected(r) => {
+                for g in &mut r.generics {
+                    match g {
+                        GenericDecl::Parameter(p) if p.extends.is_empty() => {
+                            if let Some(name) = lookup(&p.id) {
+                                *g = GenericDecl::Argument(RefType::Class(ClassRef {
+                                    name,
+                                    generics: vec![],
+                                }));
+                            }
+                        }
+                        GenericDecl::Parameter(p) => p.resolve(&lookup, &[]),
+                        GenericDecl::Argument(a) => a.resolve(&lookup, &[]),
+                    }
+                }
+                let scope: Vec<Id> = r
+                    .generics
+                    .iter()
+                    .filter_map(|g| match g {
+                        GenericDecl::Parameter(p) => Some(p.id.clone()),
+                        GenericDecl::Argument(_) => None,
+                    })
+                    .collect();
+                for supertypes in [&mut r.extends, &mut r.implements].into_iter().flatten() {
+                    supertypes.resolve(&lookup, &scope);
+                }
+                for filter in r.members.iter_mut().flatten() {
+                    for argument in filter.arguments.iter_mut().flatten() {
+                        argument.resolve(&lookup, &scope);
+                    }
+                }
+            }
+            ClassDecl::Specified(c) => resolve_class_info(c, &lookup),
+        }
+    }
+}
+
+/// Treats any name that is not a generic parameter in scope as a class in the default
+/// (unnamed) package. That is how `javap` prints references to such classes.
+pub fn resolve_default_package(c: &mut ClassInfo) {
+    resolve_class_info(c, &|id: &Id| Some(DotId::from(id)))
+}
+
+/// Like [`resolve_default_package`], but only for the names in `classes`. Used for classes
+/// the user wrote out, where a stray name is more likely a mistake than a class.
+pub fn resolve_default_package_classes(c: &mut ClassInfo, classes: &[Id]) {
+    if classes.is_empty() {
+        return;
+    }
+    resolve_class_info(c, &|id: &Id| classes.contains(id).then(|| DotId::from(id)))
+}
+
+fn resolve_class_info(c: &mut ClassInfo, lookup: Lookup<'_>) {
+    let class_scope: Vec<Id> = c.generics.iter().map(|g| g.id.clone()).collect();
+    c.generics.resolve(lookup, &class_scope);
+    c.extends.resolve(lookup, &class_scope);
+    c.implements.resolve(lookup, &class_scope);
+    for (_, ty) in &mut c.type_arguments {
+        ty.resolve(lookup, &class_scope);
+    }
+
+    let member_scope = |generics: &[Generic]| -> Vec<Id> {
+        class_scope
+            .iter()
+            .cloned()
+            .chain(generics.iter().map(|g| g.id.clone()))
+            .collect()
+    };
+
+    for ctor in &mut c.constructors {
+        let scope = member_scope(&ctor.generics);
+        ctor.generics.resolve(lookup, &scope);
+        ctor.argument_tys.resolve(lookup, &scope);
+        ctor.throws.resolve(lookup, &scope);
+    }
+
+    for field in &mut c.fields {
+        field.ty.resolve(lookup, &class_scope);
+    }
+
+    for method in &mut c.methods {
+        let scope = member_scope(&method.generics);
+        method.generics.resolve(lookup, &scope);
+        method.argument_tys.resolve(lookup, &scope);
+        if let Some(ty) = &mut method.return_ty {
+            ty.resolve(lookup, &scope);
+        }
+        method.throws.resolve(lookup, &scope);
+    }
+}
+
+/// Maps a bare name to the class it refers to, if any.
+type Lookup<'a> = &'a dyn Fn(&Id) -> Option<DotId>;
+
+trait Resolve {
+    /// Rewrites bare names that `lookup` knows about in place; `scope` lists the generic parameters in scope.
+    fn resolve(&mut self, lookup: Lookup<'_>, scope: &[Id]);
+}
+
+impl Resolve for RefType {
+    fn resolve(&mut self, lookup: Lookup<'_>, scope: &[Id]) {
+        match self {
+            RefType::Class(c) => c.resolve(lookup, scope),
+            RefType::Array(a) => a.resolve(lookup, scope),
+            RefType::TypeParameter(id) => {
+                if scope.contains(id) {
+                    return;
+                }
+                if let Some(name) = lookup(id) {
+                    *self = RefType::Class(ClassRef {
+                        name,
+                        generics: vec![],
+                    });
+                }
+            }
+            RefType::Extends(e) | RefType::Super(e) => e.resolve(lookup, scope),
+            RefType::Wildcard => {}
+        }
+    }
+}
+
+impl Resolve for Type {
+    fn resolve(&mut self, lookup: Lookup<'_>, scope: &[Id]) {
+        match self {
+            Type::Ref(r) => r.resolve(lookup, scope),
+            Type::Scalar(_) => {}
+            Type::Repeat(r) => r.resolve(lookup, scope),
+        }
+    }
+}
+
+impl Resolve for ClassRef {
+    fn resolve(&mut self, lookup: Lookup<'_>, scope: &[Id]) {
+        self.generics.resolve(lookup, scope);
+    }
+}
+
+impl Resolve for Generic {
+    fn resolve(&mut self, lookup: Lookup<'_>, scope: &[Id]) {
+        self.extends.resolve(lookup, scope);
+    }
+}
+
+impl<F> Resolve for Vec<F>
+where
+    F: Resolve,
+{
+    fn resolve(&mut self, lookup: Lookup<'_>, scope: &[Id]) {
+        for e in self {
+            e.resolve(lookup, scope);
+        }
+    }
+}
+
+impl<F> Resolve for Arc<F>
+where
+    F: Resolve + Clone,
+{
+    fn resolve(&mut self, lookup: Lookup<'_>, scope: &[Id]) {
+        Arc::make_mut(self).resolve(lookup, scope);
+    }
+}
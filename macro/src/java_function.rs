@@ -1,4 +1,4 @@
-use std::{iter::once, sync::Arc};
+use std::{iter::once, rc::Rc};
 
 use proc_macro2::{Ident, Literal, TokenStream};
 use quote::quote_spanned;
@@ -146,7 +146,7 @@ pub fn java_function(selector: MethodSelector, input: syn::ItemFn) -> syn::Resul
 fn reflected_method(
     selector: &MethodSelector,
     reflector: &mut Reflector,
-) -> syn::Result<(Arc<ClassInfo>, MethodIndex)> {
+) -> syn::Result<(Rc<ClassInfo>, MethodIndex)> {
     let reflected_method = reflector.reflect_method(selector)?;
 
     match reflected_method {
@@ -238,6 +238,11 @@ impl Driver<'_> {
                     quote_spanned!(span => &#java_ty)
                 }
 
+                // A `jboolean` may be any non-zero value, which is not a valid Rust `bool`.
+                class_info::Type::Scalar(class_info::ScalarType::Boolean) => {
+                    quote_spanned!(span => duchess::plumbing::jni_sys::jboolean)
+                }
+
                 class_info::Type::Scalar(_) => java_ty,
             };
 
@@ -319,16 +324,13 @@ impl Driver<'_> {
 
         // Push the `this` argument onto `output`
         if !self.method_info.flags.is_static {
-            output.push(self.rust_argument(this_name, false, inputs.next().unwrap())?);
+            let this_ty: Type = self.class_info.this_ref().into();
+            output.push(self.rust_argument(this_name, &this_ty, inputs.next().unwrap())?);
         }
 
         // Push each subsequent argument
         for (user_name, argument_ty) in user_names.iter().zip(&self.method_info.argument_tys) {
-            output.push(self.rust_argument(
-                user_name,
-                argument_ty.is_scalar(),
-                inputs.next().unwrap(),
-            )?);
+            output.push(self.rust_argument(user_name, argument_ty, inputs.next().unwrap())?);
         }
 
         Ok(output)
@@ -337,7 +339,7 @@ impl Driver<'_> {
     fn rust_argument(
         &self,
         arg_name: &Ident,
-        java_ty_is_scalar: bool,
+        java_ty: &Type,
         rust_ty: &syn::PatType,
     ) -> syn::Result<TokenStream> {
         // Case 1. Decorated Rust function has a `&J` type for this argument.
@@ -345,7 +347,7 @@ impl Driver<'_> {
         if let syn::Type::Reference(_) = &*rust_ty.ty {
             // If the decorated Rust function argument type is a Rust reference
             // (`&J`), then just pass the Java type directly.
-            if java_ty_is_scalar {
+            if java_ty.is_scalar() {
                 return Err(syn::Error::new(
                         rust_ty.ty.span(),
                         &format!("unexpected Rust reference; Java function declares a scalar type for this argument"),
@@ -355,8 +357,13 @@ impl Driver<'_> {
             return Ok(quote_spanned!(rust_ty.span() => #arg_name));
         }
 
-        // Case 2. Java type is scalar. Then just pass it.
-        if java_ty_is_scalar {
+        // Case 2. Java type is scalar. Then just pass it, converting a `jboolean` to `bool`.
+        if let Type::Scalar(class_info::ScalarType::Boolean) = java_ty {
+            return Ok(
+                quote_spanned!(rust_ty.span() => #arg_name != duchess::plumbing::jni_sys::JNI_FALSE),
+            );
+        }
+        if java_ty.is_scalar() {
             return Ok(quote_spanned!(rust_ty.span() => #arg_name));
         }
 
@@ -4,16 +4,19 @@ use proc_macro::TokenStream;
 use rust_format::Formatter;
 
 mod argument;
+mod builtins;
 mod check;
 mod class_info;
 mod codegen;
 mod derive;
+mod imports;
 mod java_function;
 mod parse;
 mod reflect;
 mod signature;
 mod span_error;
 mod substitution;
+mod test_fn;
 mod upcasts;
 
 /// The main duchess macro, used like so
@@ -42,6 +45,21 @@ pub fn java_package(input: TokenStream) -> TokenStream {
     }
 }
 
+/// Mirrors the classes that duchess declares in `duchess::java` (see `builtins.duchess`).
+#[doc(hidden)]
+#[proc_macro]
+pub fn java_builtins(_input: TokenStream) -> TokenStream {
+    let decl = match builtins::declaration() {
+        Ok(decl) => decl,
+        Err(err) => return err.into_tokens().into(),
+    };
+
+    match decl.to_tokens() {
+        Ok(t) => t.into(),
+        Err(e) => e.into_tokens().into(),
+    }
+}
+
 #[proc_macro_attribute]
 pub fn java_function(args: TokenStream, input: TokenStream) -> TokenStream {
     let args: proc_macro2::TokenStream = args.into();
@@ -61,6 +79,35 @@ pub fn java_function(args: TokenStream, input: TokenStream) -> TokenStream {
     }
 }
 
+/// Marks a test function that uses the JVM, like so
+///
+/// ```rust,ignore
+/// #[duchess::test(classpath = "target/java")]
+/// fn greets() -> duchess::GlobalResult<()> {
+///     // ...
+/// }
+/// ```
+///
+/// The JVM is started (with the given classpath, if any) before the function runs. A Java
+/// exception returned from the function fails the test, printing the Java stack trace.
+#[proc_macro_attribute]
+pub fn test(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = match syn::parse::<test_fn::TestArgs>(args) {
+        Ok(args) => args,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    let item_fn = match syn::parse::<syn::ItemFn>(input) {
+        Ok(item_fn) => item_fn,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    match test_fn::test_fn(args, item_fn) {
+        Ok(t) => t.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
 synstructure::decl_derive!([ToRust, attributes(java)] => derive::derive_to_rust);
 
 synstructure::decl_derive!([ToJava, attributes(java)] => derive::derive_to_java);
@@ -1,12 +1,19 @@
 use std::iter::Peekable;
 
-use proc_macro2::{Spacing, Span, TokenStream, TokenTree};
+use proc_macro2::{Delimiter, Spacing, Span, TokenStream, TokenTree};
 
-use crate::span_error::SpanError;
+use crate::span_error::{SpanError, SpanErrors};
 
 pub struct Parser {
     tokens: Peekable<Box<dyn Iterator<Item = TokenTree>>>,
     last_span: Option<Span>,
+
+    /// True if the most recently consumed token ends an item (a `;` or a `{...}` block),
+    /// which is where we resume after an error; see [`Parser::recover`].
+    at_item_boundary: bool,
+
+    /// Errors reported so far, see [`Parser::report`].
+    errors: Vec<SpanError>,
 }
 
 impl From<TokenStream> for Parser {
@@ -15,32 +22,68 @@ impl From<TokenStream> for Parser {
         Parser {
             tokens: tokens.peekable(),
             last_span: None,
+            at_item_boundary: true,
+            errors: vec![],
         }
     }
 }
 
 impl Parser {
     /// Top-level parse function that parses the input to the proc macro.
-    pub fn parse<T: Parse>(mut self) -> Result<T, SpanError> {
-        match T::parse(&mut self) {
+    /// Fails if any errors were encountered, including those that the parser
+    /// recovered from (see [`Parser::report`]).
+    pub fn parse<T: Parse>(mut self) -> Result<T, SpanErrors> {
+        let result = match T::parse(&mut self) {
             Ok(Some(t)) => {
                 if let Some(s) = self.peek_span() {
-                    return Err(SpanError {
+                    Err(SpanError {
                         span: s,
                         message: format!("extra input after the end of what was expected"),
-                    });
+                    })
+                } else {
+                    Ok(t)
                 }
-                Ok(t)
             }
 
             Err(e) => Err(e),
 
             Ok(None) => {
                 let span = Span::call_site();
-                return Err(SpanError {
+                Err(SpanError {
                     span,
                     message: format!("expected a {}", T::description()),
-                });
+                })
+            }
+        };
+
+        match result {
+            Ok(t) if self.errors.is_empty() => Ok(t),
+            Ok(_) => Err(SpanErrors {
+                errors: self.errors,
+            }),
+            Err(e) => {
+                self.errors.push(e);
+                Err(SpanErrors {
+                    errors: self.errors,
+                })
+            }
+        }
+    }
+
+    /// Records an error without aborting the parse, so that later errors can be
+    /// reported in the same expansion. The overall parse will still fail.
+    /// Usually followed by [`Parser::recover`].
+    pub fn report(&mut self, error: SpanError) {
+        self.errors.push(error);
+    }
+
+    /// Skips ahead to the end of the current item (just past the next `;` or `{...}` block),
+    /// so that parsing can resume after an error. Does nothing if the last consumed token
+    /// already ended an item.
+    pub fn recover(&mut self) {
+        while !self.at_item_boundary {
+            if self.eat_token().is_none() {
+                break;
             }
         }
     }
@@ -72,6 +115,11 @@ impl Parser {
     pub fn eat_token(&mut self) -> Option<TokenTree> {
         let t = self.tokens.next()?;
         self.last_span = Some(t.span());
+        self.at_item_boundary = match &t {
+            TokenTree::Punct(p) => p.as_char() == ';',
+            TokenTree::Group(g) => g.delimiter() == Delimiter::Brace,
+            _ => false,
+        };
         Some(t)
     }
 
@@ -121,6 +169,29 @@ impl Parser {
         })
     }
 
+    /// Consumes any number of outer Rust attributes (`#[...]`), including doc comments
+    /// (which the compiler desugars to `#[doc = "..."]`). The attributes are returned
+    /// verbatim; their contents are not validated.
+    pub fn eat_attributes(&mut self) -> Result<Vec<TokenStream>, SpanError> {
+        let mut attributes = vec![];
+        while self.eat_punct('#').is_some() {
+            let pound = self.last_span().unwrap();
+            let Some(group) = self.eat_map(|t| match t {
+                TokenTree::Group(g) if g.delimiter() == Delimiter::Bracket => Some(g.clone()),
+                _ => None,
+            }) else {
+                return Err(self.error("expected `[...]` after `#`"));
+            };
+            let mut punct = proc_macro2::Punct::new('#', Spacing::Alone);
+            punct.set_span(pound);
+            attributes.push(TokenStream::from_iter([
+                TokenTree::Punct(punct),
+                TokenTree::Group(group),
+            ]));
+        }
+        Ok(attributes)
+    }
+
     pub fn eat_punct(&mut self, ch: char) -> Option<Span> {
         self.eat_map(|t| match t {
             TokenTree::Punct(punct) if punct.as_char() == ch => Some(punct.span()),
@@ -164,6 +235,19 @@ impl<'p> TextAccum<'p> {
     }
 
     fn accum_token(&mut self, token: &TokenTree) {
+        // `$` is part of Java identifiers (e.g., `java.util.Map$Entry`) but is its own token in Rust,
+        // so glue it to the surrounding identifiers.
+        if let TokenTree::Punct(p) = token {
+            if p.as_char() == '$' {
+                if self.text.ends_with(' ') {
+                    self.text.pop();
+                }
+                self.text.push('$');
+                self.span = self.span.join(token.span()).unwrap_or(self.span);
+                return;
+            }
+        }
+
         self.text.push_str(&token.to_string());
 
         // insert whitespace if this is a token that needs to be separated from following tokens
@@ -180,6 +264,11 @@ impl<'p> TextAccum<'p> {
         self.span = self.span.join(token.span()).unwrap_or(self.span);
     }
 
+    /// The string accumulated so far.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
     /// Return the string we accumulated.
     pub fn into_accumulated_result(self) -> (String, Span) {
         (self.text, self.span)
@@ -197,17 +286,6 @@ pub trait Parse: Sized {
     /// Ok(Some(e)) -- successful parse of `Self`
     fn parse(p: &mut Parser) -> Result<Option<Self>, SpanError>;
 
-    /// parse any number of instances of self.
-    fn parse_many(p: &mut Parser) -> Result<Vec<Self>, SpanError> {
-        let mut result = vec![];
-
-        while let Some(e) = Self::parse(p)? {
-            result.push(e);
-        }
-
-        Ok(result)
-    }
-
     /// Describes the thing we are parsing, for use in error messages.
     /// e.g. "java path".
     fn description() -> String;
@@ -1,13 +1,25 @@
-use std::{cell::RefCell, collections::BTreeMap, env, path::PathBuf, process::Command, sync::Arc};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+    env,
+    path::PathBuf,
+    process::Command,
+    rc::Rc,
+    sync::Arc,
+};
 
 use proc_macro2::Span;
 
 use crate::{
-    argument::{DuchessDeclaration, Ident, JavaPackage, MethodSelector},
+    argument::{DuchessDeclaration, Ident, JavaPackage, MethodSelector, PackageOptions},
     class_info::{
-        ClassDecl, ClassInfo, DotId, Generic, Id, Method, RootMap, SpannedPackageInfo, Type,
+        jar_entries, jar_entry_name, ClassDecl, ClassInfo, ClassRef, Constructor, Deprecation,
+        DotId, Generic, GenericDecl, Id, MemberFilter, Method, RefType, ReflectedClassInfo,
+        RootMap, SpannedPackageInfo, Type,
     },
+    imports,
     span_error::SpanError,
+    substitution::{Substitute, Substitution},
     upcasts::Upcasts,
 };
 
@@ -15,7 +27,16 @@ impl DuchessDeclaration {
     pub fn to_root_map(&self, reflector: &mut Reflector) -> Result<RootMap, SpanError> {
         let mut subpackages = BTreeMap::new();
         let mut classes = BTreeMap::new();
+        let mut default_package = vec![];
         for package in &self.packages {
+            if package.package_name.ids.is_empty() {
+                package.insert_classes_into_root_map(
+                    reflector,
+                    &mut default_package,
+                    &mut classes,
+                )?;
+                continue;
+            }
             package.to_spanned_packages(
                 &package.package_name.ids,
                 reflector,
@@ -24,23 +45,111 @@ impl DuchessDeclaration {
             )?;
         }
 
+        insert_thrown_classes(
+            reflector,
+            &mut subpackages,
+            &mut default_package,
+            &mut classes,
+        )?;
+
+        // `javap` only lists the permitted subclasses of a sealed class in its verbose output,
+        // so they are only looked up for the classes that other classes here extend.
+        let supertypes: BTreeSet<DotId> = classes
+            .values()
+            .flat_map(|c| c.extends.iter().chain(&c.implements))
+            .map(|c| c.name.clone())
+            .collect();
+        for (name, info) in classes.iter_mut() {
+            if !supertypes.contains(name)
+                || info.flags.is_final
+                || !info.permitted_subclasses.is_empty()
+            {
+                continue;
+            }
+            let permitted_subclasses = reflector.reflect_permitted_subclasses(name, info.span)?;
+            if !permitted_subclasses.is_empty() {
+                Rc::make_mut(info).permitted_subclasses = permitted_subclasses;
+            }
+        }
+
         let upcasts: Upcasts = Upcasts::from_iter(classes.values().map(|v| &**v));
 
         Ok(RootMap {
             subpackages,
+            default_package,
             classes,
             upcasts,
+            conversions: self.conversions.clone(),
         })
     }
 }
 
+/// Exceptions in the `throws` clauses of the members here get a struct of their own, if their
+/// package is declared here but they are not, so that errors can be told apart with
+/// `Error::is`. These classes are opaque: they mirror no members, and only their superclass (which
+/// is added in turn if needed, for the upcasts to `Throwable`).
+fn insert_thrown_classes(
+    reflector: &mut Reflector,
+    subpackages: &mut BTreeMap<Id, SpannedPackageInfo>,
+    default_package: &mut Vec<DotId>,
+    classes: &mut BTreeMap<DotId, Rc<ClassInfo>>,
+) -> Result<(), SpanError> {
+    let mut thrown: Vec<(DotId, Span)> = classes
+        .values()
+        .flat_map(|c| {
+            let constructor_throws = c.constructors.iter().flat_map(|k| &k.throws);
+            let method_throws = c.methods.iter().flat_map(|m| &m.throws);
+            constructor_throws
+                .chain(method_throws)
+                .map(|t| (t.name.clone(), c.span))
+        })
+        .collect();
+
+    while let Some((name, span)) = thrown.pop() {
+        if classes.contains_key(&name) {
+            continue;
+        }
+        let (package, _) = name.split();
+        let package_classes = match package.split_first() {
+            None if !default_package.is_empty() => &mut *default_package,
+            None => continue,
+            Some((p0, ps)) => {
+                let mut subpackage = subpackages.get_mut(p0);
+                for id in ps {
+                    subpackage = subpackage.and_then(|p| p.subpackages.get_mut(id));
+                }
+                match subpackage {
+                    Some(subpackage) => &mut subpackage.classes,
+                    None => continue,
+                }
+            }
+        };
+
+        let info = reflector.reflect(&name, span)?;
+        thrown.extend(info.extends.iter().map(|c| (c.name.clone(), span)));
+        package_classes.push(name.clone());
+        classes.insert(
+            name,
+            Rc::new(ClassInfo {
+                span,
+                implements: vec![],
+                constructors: vec![],
+                fields: vec![],
+                methods: vec![],
+                ..(*info).clone()
+            }),
+        );
+    }
+    Ok(())
+}
+
 impl JavaPackage {
     fn to_spanned_packages(
         &self,
         name: &[Ident],
         reflector: &mut Reflector,
         map: &mut BTreeMap<Id, SpannedPackageInfo>,
-        classes: &mut BTreeMap<DotId, Arc<ClassInfo>>,
+        classes: &mut BTreeMap<DotId, Rc<ClassInfo>>,
     ) -> Result<(), SpanError> {
         let (first, rest) = name.split_first().unwrap();
 
@@ -58,7 +167,7 @@ impl JavaPackage {
         let parent = map.entry(first_id).or_insert_with(package_info);
 
         if rest.is_empty() {
-            self.insert_classes_into_root_map(reflector, parent, classes)
+            self.insert_classes_into_root_map(reflector, &mut parent.classes, classes)
         } else {
             self.to_spanned_packages(rest, reflector, &mut parent.subpackages, classes)
         }
@@ -67,29 +176,46 @@ impl JavaPackage {
     fn insert_classes_into_root_map(
         &self,
         reflector: &mut Reflector,
-        package: &mut SpannedPackageInfo,
-        classes: &mut BTreeMap<DotId, Arc<ClassInfo>>,
+        package_classes: &mut Vec<DotId>,
+        classes: &mut BTreeMap<DotId, Rc<ClassInfo>>,
     ) -> Result<(), SpanError> {
         for c in &self.classes {
             let (dot_id, info) = match c {
                 ClassDecl::Reflected(c) => {
                     let dot_id = self.make_absolute_dot_id(c.span, &c.name)?;
-                    let info = reflector.reflect(&dot_id, c.span)?;
-                    (dot_id, info.clone())
+                    // Deprecated members are marked `#[deprecated]`, or omitted.
+                    let info = reflector.reflect_deprecations(&dot_id, c.span)?;
+                    (dot_id, c.apply_to(&info, &self.options)?)
                 }
                 ClassDecl::Specified(c) => {
                     let dot_id = self.make_absolute_dot_id(c.span, &c.name)?;
                     (
                         dot_id.clone(),
-                        Arc::new(ClassInfo {
+                        Rc::new(ClassInfo {
                             name: dot_id,
-                            ..c.clone()
+                            ..(**c).clone()
                         }),
                     )
                 }
             };
 
-            package.classes.push(dot_id.clone());
+            // An alias written on the class takes precedence over the package's prefix.
+            let info = match &self.options.prefix {
+                Some(prefix) if info.alias.is_none() => Rc::new(ClassInfo {
+                    alias: Some(Id::from(format!("{prefix}{}", dot_id.class_name()))),
+                    ..(*info).clone()
+                }),
+                _ => info,
+            };
+            let info = match self.options.builder_arity {
+                Some(arity) => Rc::new(ClassInfo {
+                    builder_arity: Some(arity),
+                    ..(*info).clone()
+                }),
+                None => info,
+            };
+
+            package_classes.push(dot_id.clone());
             classes.insert(dot_id, info);
         }
         Ok(())
@@ -121,28 +247,738 @@ impl JavaPackage {
     }
 }
 
+impl ReflectedClassInfo {
+    /// Applies what the user wrote in the class header to the reflected `info`.
+    /// Declared `extends`/`implements` lists replace the reflected ones (they are
+    /// checked against reflection later, see [`ClassInfo::check`]).
+    fn apply_to(
+        &self,
+        info: &Rc<ClassInfo>,
+        options: &PackageOptions,
+    ) -> Result<Rc<ClassInfo>, SpanError> {
+        let info = self.apply_generics_to(info)?;
+        let info = if self.mirror_protected {
+            Rc::new(ClassInfo {
+                mirror_protected: true,
+                ..(*info).clone()
+            })
+        } else {
+            info
+        };
+        let info = Self::omit_redundant_bridges_from(&info);
+        let info = self.apply_member_filters_to(&info)?;
+        let info = if options.omit_deprecated {
+            self.omit_deprecated_from(&info)
+        } else {
+            info
+        };
+        let info = match &self.alias {
+            Some(alias) => Rc::new(ClassInfo {
+                alias: Some(alias.clone()),
+                ..(*info).clone()
+            }),
+            None => info,
+        };
+        if self.extends.is_none() && self.implements.is_none() {
+            return Ok(info);
+        }
+
+        // Errors about the declared supertypes are reported at this declaration.
+        Ok(Rc::new(ClassInfo {
+            span: self.span,
+            extends: self.extends.clone().unwrap_or_else(|| info.extends.clone()),
+            implements: self
+                .implements
+                .clone()
+                .unwrap_or_else(|| info.implements.clone()),
+            ..(*info).clone()
+        }))
+    }
+
+    /// Drops the bridge methods that stand in for other methods, see
+    /// [`ClassInfo::is_redundant_bridge`]; they would share their Rust name.
+    fn omit_redundant_bridges_from(info: &Rc<ClassInfo>) -> Rc<ClassInfo> {
+        if !info.methods.iter().any(|m| info.is_redundant_bridge(m)) {
+            return info.clone();
+        }
+        Rc::new(ClassInfo {
+            methods: info
+                .methods
+                .iter()
+                .filter(|m| !info.is_redundant_bridge(m))
+                .cloned()
+                .collect(),
+            ..(**info).clone()
+        })
+    }
+
+    /// If the user listed member names (`class Foo { get*; }`), keeps only the fields and
+    /// methods that match one of them, and the constructors selected with `new` (if any).
+    fn apply_member_filters_to(&self, info: &Rc<ClassInfo>) -> Result<Rc<ClassInfo>, SpanError> {
+        let Some(filters) = &self.members else {
+            return Ok(info.clone());
+        };
+
+        // A filter that matches nothing is most likely stale (or a typo).
+        let constructors: Vec<&Constructor> = info
+            .constructors
+            .iter()
+            .filter(|c| info.should_mirror_in_rust(c.flags.privacy))
+            .collect();
+        let matches_any = |filter: &MemberFilter| {
+            info.fields.iter().any(|f| filter.matches_field(f))
+                || info.methods.iter().any(|m| filter.matches_method(m))
+                || constructors.iter().any(|c| filter.matches_constructor(c))
+        };
+        if let Some(filter) = filters.iter().find(|f| !matches_any(f)) {
+            let overloads: Vec<String> = info
+                .methods
+                .iter()
+                .filter(|m| filter.arguments.is_some() && m.name[..] == filter.name)
+                .map(|m| format!("`{}`", m.descriptor()))
+                .collect();
+            let message = if !overloads.is_empty() {
+                format!(
+                    "no method of `{}` matches `{filter}`; the `{}` methods are {}",
+                    info.name,
+                    filter.name,
+                    overloads.join(", ")
+                )
+            } else if !filter.is_constructor() {
+                format!("no field or method of `{}` matches `{filter}`", info.name)
+            } else if constructors.is_empty() {
+                format!("`{}` has no public constructors", info.name)
+            } else {
+                let descriptors: Vec<String> = constructors
+                    .iter()
+                    .map(|c| format!("`{}`", c.descriptor()))
+                    .collect();
+                format!(
+                    "no constructor of `{}` matches `{filter}`; the constructors are {}",
+                    info.name,
+                    descriptors.join(", ")
+                )
+            };
+            return Err(SpanError {
+                span: filter.span,
+                message,
+            });
+        }
+
+        let mut rust_names: Vec<(Id, Id)> = vec![];
+        for filter in filters {
+            let Some((rust_name, span)) = &filter.rust_name else {
+                continue;
+            };
+            let java_name = Id::from(&filter.name[..]);
+            if let Some((_, previous)) = rust_names.iter().find(|(id, _)| *id == java_name) {
+                if previous != rust_name {
+                    return Err(SpanError {
+                        span: *span,
+                        message: format!("`{java_name}` is already renamed to `{previous}`"),
+                    });
+                }
+                continue;
+            }
+            rust_names.push((java_name, rust_name.clone()));
+        }
+
+        let info = ClassInfo {
+            constructors: constructors
+                .into_iter()
+                .filter(|c| filters.iter().any(|f| f.matches_constructor(c)))
+                .cloned()
+                .collect(),
+            fields: info
+                .fields
+                .iter()
+                .filter(|f| filters.iter().any(|filter| filter.matches_field(f)))
+                .cloned()
+                .collect(),
+            methods: info
+                .methods
+                .iter()
+                .filter(|m| filters.iter().any(|filter| filter.matches_method(m)))
+                .cloned()
+                .collect(),
+            rust_names,
+            ..(**info).clone()
+        };
+        self.check_rust_names(&info, filters)?;
+        Ok(Rc::new(info))
+    }
+
+    /// Drops deprecated members, except for those the user named exactly (`class Foo { oldMethod; }`,
+    /// or `new(int);` for a constructor).
+    fn omit_deprecated_from(&self, info: &Rc<ClassInfo>) -> Rc<ClassInfo> {
+        let exact_filters = || self.members.iter().flatten().filter(|f| !f.glob);
+        Rc::new(ClassInfo {
+            constructors: info
+                .constructors
+                .iter()
+                .filter(|c| {
+                    c.deprecated.is_none()
+                        || self
+                            .members
+                            .iter()
+                            .flatten()
+                            .any(|f| f.arguments.is_some() && f.matches_constructor(c))
+                })
+                .cloned()
+                .collect(),
+            fields: info
+                .fields
+                .iter()
+                .filter(|f| f.deprecated.is_none() || exact_filters().any(|e| e.matches_field(f)))
+                .cloned()
+                .collect(),
+            methods: info
+                .methods
+                .iter()
+                .filter(|m| m.deprecated.is_none() || exact_filters().any(|e| e.matches_method(m)))
+                .cloned()
+                .collect(),
+            ..(**info).clone()
+        })
+    }
+
+    /// Reports renamed members (`getValue as value;`) whose Rust name is also that of another
+    /// member. (Overloads are named apart from each other, see [`ClassInfo::rust_method_name`].)
+    fn check_rust_names(
+        &self,
+        info: &ClassInfo,
+        filters: &[MemberFilter],
+    ) -> Result<(), SpanError> {
+        let rust_names: Vec<(Id, &Id)> = info
+            .fields
+            .iter()
+            .map(|f| (info.rust_field_getter_name(&f.name), &f.name))
+            .chain(
+                info.fields
+                    .iter()
+                    .filter(|f| !f.flags.is_final)
+                    .map(|f| (info.rust_field_setter_name(&f.name), &f.name)),
+            )
+            .chain(
+                info.methods
+                    .iter()
+                    .map(|m| (info.rust_method_name(m), &m.name)),
+            )
+            .collect();
+
+        for filter in filters {
+            let Some((rust_name, span)) = &filter.rust_name else {
+                continue;
+            };
+            let java_name = Id::from(&filter.name[..]);
+            let renamed: Vec<&Id> = rust_names
+                .iter()
+                .filter(|(_, id)| **id == java_name)
+                .map(|(name, _)| name)
+                .collect();
+            let clash = rust_names
+                .iter()
+                .find(|(name, id)| **id != java_name && renamed.contains(&name));
+            if let Some((_, other)) = clash {
+                return Err(SpanError {
+                    span: *span,
+                    message: format!(
+                        "`{java_name}` and `{other}` would both be named `{rust_name}` in Rust"
+                    ),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Generic parameters (`E`, `E extends Bound`) are checked against the reflected ones;
+    /// generic arguments (`java.lang.String`) specialize the class, see
+    /// [`ClassInfo::type_arguments`].
+    fn apply_generics_to(&self, info: &Rc<ClassInfo>) -> Result<Rc<ClassInfo>, SpanError> {
+        if self.generics.is_empty() && self.attributes.is_empty() {
+            return Ok(info.clone());
+        }
+
+        if self.generics.is_empty() {
+            return Ok(Rc::new(ClassInfo {
+                attributes: self.attributes.clone(),
+                ..(**info).clone()
+            }));
+        }
+
+        if self.generics.len() != info.generics.len() {
+            return Err(SpanError {
+                span: self.span,
+                message: format!(
+                    "class `{}` has {} generic parameter(s), but {} were declared",
+                    info.name,
+                    info.generics.len(),
+                    self.generics.len(),
+                ),
+            });
+        }
+
+        let mut generics = vec![];
+        let mut arguments = vec![];
+        for (decl, reflected) in self.generics.iter().zip(&info.generics) {
+            match decl {
+                GenericDecl::Parameter(g) => {
+                    if g.id != reflected.id || !same_bounds(&g.extends, &reflected.extends) {
+                        return Err(SpanError {
+                            span: self.span,
+                            message: format!(
+                                "generic parameter `{}` does not match `{}` declared by class `{}`",
+                                g, reflected, info.name,
+                            ),
+                        });
+                    }
+                    generics.push(reflected.clone());
+                }
+                GenericDecl::Argument(ty) => arguments.push((&reflected.id, ty)),
+            }
+        }
+
+        // Members keep their declared types (so that their JNI descriptors are unchanged);
+        // the type arguments are substituted when generating their Rust signatures.
+        let type_arguments: Vec<(Id, RefType)> = arguments
+            .into_iter()
+            .map(|(id, ty)| (id.clone(), ty.clone()))
+            .collect();
+        let subst: Substitution<'_> = type_arguments.iter().map(|(id, ty)| (id, ty)).collect();
+        Ok(Rc::new(ClassInfo {
+            attributes: self.attributes.clone(),
+            generics: generics.substitute(&subst),
+            extends: info.extends.substitute(&subst),
+            implements: info.implements.substitute(&subst),
+            type_arguments: type_arguments.clone(),
+            ..(**info).clone()
+        }))
+    }
+}
+
+/// Compares generic bounds, treating an explicit `extends java.lang.Object` as no bound
+/// (`javap` omits it).
+fn same_bounds(a: &[ClassRef], b: &[ClassRef]) -> bool {
+    let object = DotId::object();
+    let normalize = |bounds: &[ClassRef]| {
+        let mut bounds: Vec<ClassRef> = bounds
+            .iter()
+            .filter(|b| b.name != object)
+            .cloned()
+            .collect();
+        bounds.sort();
+        bounds
+    };
+    normalize(a) == normalize(b)
+}
+
+/// What the verbose `javap` output says about a member beyond what `reflect` sees.
+struct MemberDetails {
+    deprecated: Option<Deprecation>,
+    is_bridge: bool,
+    /// See [`Constructor::parameter_names`].
+    parameter_names: Vec<Id>,
+    /// The JVM descriptor, e.g. `(Lnested/Outer;Ljava/util/List;)V`.
+    descriptor: Option<String>,
+}
+
+/// The details of a class and its members in the verbose (`-p -v`) `javap` output.
+struct VerboseClass {
+    deprecated: Option<Deprecation>,
+    fields: Vec<MemberDetails>,
+    constructors: Vec<MemberDetails>,
+    methods: Vec<MemberDetails>,
+}
+
+/// Reads the details of the class and its members in the verbose (`-p -v`) `javap` output,
+/// sorting the members into fields, constructors, and methods the way `ClassInfo` does. The
+/// members are listed in the same order as `reflect` sees them, each followed by indented lines
+/// with details, such as `Deprecated: true`; those of the class come after its body.
+fn verbose_class(s: &str, class_name: &DotId) -> VerboseClass {
+    let mut members: Vec<(&str, Vec<&str>)> = vec![];
+    let mut class_lines = vec![];
+    let mut in_body = false;
+    for line in s.lines() {
+        if line == "{" {
+            in_body = true;
+        } else if line == "}" {
+            in_body = false;
+        } else if !in_body {
+            class_lines.push(line);
+        } else if line.starts_with("  ") && !line.starts_with("   ") {
+            members.push((line.trim(), vec![]));
+        } else if let Some((_, lines)) = members.last_mut() {
+            lines.push(line);
+        }
+    }
+
+    // Static initializers (`static {};`) are not recorded in `ClassInfo`.
+    let constructor_prefix = format!("{class_name}(");
+    let mut class = VerboseClass {
+        deprecated: verbose_deprecation(&class_lines),
+        fields: vec![],
+        constructors: vec![],
+        methods: vec![],
+    };
+    for (member, lines) in members {
+        if member.ends_with("{};") {
+            continue;
+        }
+        let details = MemberDetails {
+            deprecated: verbose_deprecation(&lines),
+            is_bridge: lines.iter().any(|line| {
+                let line = line.trim();
+                line.starts_with("flags:") && line.contains("ACC_BRIDGE")
+            }),
+            parameter_names: verbose_parameter_names(&lines),
+            descriptor: lines
+                .iter()
+                .find_map(|line| line.trim().strip_prefix("descriptor: "))
+                .map(String::from),
+        };
+        let Some((head, _)) = member.split_once('(') else {
+            class.fields.push(details);
+            continue;
+        };
+        let name = head.rsplit(' ').next().unwrap_or(head);
+        if format!("{name}(") == constructor_prefix {
+            class.constructors.push(details);
+        } else {
+            class.methods.push(details);
+        }
+    }
+    class
+}
+
+/// Reads the names of the parameters of a method that was compiled with `javac -parameters`,
+/// which are listed after `MethodParameters:` and a `Name Flags` header, one per line, with
+/// `<no name>` for those without a name.
+fn verbose_parameter_names(lines: &[&str]) -> Vec<Id> {
+    let indent = |line: &str| line.len() - line.trim_start().len();
+    let Some(start) = lines.iter().position(|l| l.trim() == "MethodParameters:") else {
+        return vec![];
+    };
+    lines[start + 1..]
+        .iter()
+        .take_while(|line| indent(line) > indent(lines[start]))
+        .skip(1)
+        .enumerate()
+        .map(|(i, line)| match line.split_whitespace().next() {
+            Some(name) if !name.starts_with('<') => Id::from(name),
+            _ => Id::from(format!("arg{i}")),
+        })
+        .collect()
+}
+
+/// The number of arguments in the JVM method `descriptor`, e.g. 2 for `(I[Ljava/lang/String;)V`.
+fn descriptor_argument_count(descriptor: &str) -> usize {
+    let arguments = descriptor
+        .strip_prefix('(')
+        .and_then(|d| d.split_once(')'))
+        .map_or("", |(arguments, _)| arguments);
+    let mut count = 0;
+    let mut chars = arguments.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '[' => continue,
+            'L' => {
+                chars.by_ref().find(|&c| c == ';');
+            }
+            _ => {}
+        }
+        count += 1;
+    }
+    count
+}
+
+/// Reads whether the verbose `javap` output marks a class or member as deprecated
+/// (`Deprecated: true`), and what its annotation says, which is shown as
+/// `java.lang.Deprecated(`, lines like `since="1.2"` and `forRemoval=true`, and `)`.
+fn verbose_deprecation(lines: &[&str]) -> Option<Deprecation> {
+    let mut deprecation = None;
+    let mut in_annotation = false;
+    for line in lines.iter().map(|line| line.trim()) {
+        if line == "Deprecated: true" {
+            deprecation.get_or_insert_with(Deprecation::default);
+        } else if line == "java.lang.Deprecated(" {
+            deprecation.get_or_insert_with(Deprecation::default);
+            in_annotation = true;
+        } else if in_annotation && line == ")" {
+            in_annotation = false;
+        } else if let Some(deprecation) = deprecation.as_mut().filter(|_| in_annotation) {
+            if let Some(since) = line.strip_prefix("since=\"") {
+                deprecation.since = Some(since.trim_end_matches('"').to_string());
+            } else if line == "forRemoval=true" {
+                deprecation.for_removal = true;
+            }
+        }
+    }
+    deprecation
+}
+
 /// Reflection cache. Given fully qualified java class names,
 /// look up info about their interfaces.
 #[derive(Default)]
 pub struct Reflector {
-    classes: RefCell<BTreeMap<DotId, Arc<ClassInfo>>>,
+    classes: RefCell<BTreeMap<DotId, Rc<ClassInfo>>>,
+    /// Classes with their deprecated members marked, see [`Reflector::reflect_deprecations`].
+    deprecations: RefCell<BTreeMap<DotId, Rc<ClassInfo>>>,
+    /// Directories and JARs whose class files are read directly, see [`Reflector::with_classpath`].
+    classpath: Vec<PathBuf>,
+    /// The entries of each JAR on the `classpath`, listed when a class is first looked up in it.
+    jar_entries: RefCell<BTreeMap<PathBuf, Arc<BTreeSet<String>>>>,
+}
+
+/// Where [`Reflector::find_class_file`] found the class file of a class.
+enum ClassFile {
+    /// A `.class` file in one of the `classpath` directories.
+    File(PathBuf),
+    /// An entry of one of the JARs on the `classpath`.
+    Jar(PathBuf),
 }
 
 impl Reflector {
+    /// A reflector that reads the class files it finds in the `classpath` directories and JARs
+    /// (entries ending in `.jar`) itself, only running `javap` for other classes (such as those
+    /// of the JDK).
+    pub fn with_classpath(classpath: Vec<PathBuf>) -> Self {
+        Reflector {
+            classpath,
+            ..Default::default()
+        }
+    }
+
     /// Returns the (potentially cached) info about `class_name`;
-    pub fn reflect(&self, class_name: &DotId, span: Span) -> Result<Arc<ClassInfo>, SpanError> {
+    pub fn reflect(&self, class_name: &DotId, span: Span) -> Result<Rc<ClassInfo>, SpanError> {
         // yields an error if we cannot reflect on that class.
-        if let Some(class) = self.classes.borrow().get(class_name).map(Arc::clone) {
+        if let Some(class) = self.classes.borrow().get(class_name).map(Rc::clone) {
+            return Ok(class);
+        }
+
+        let mut ci = match self.find_class_file(class_name, span)? {
+            Some(ClassFile::File(path)) => ClassInfo::from_class_file(&path, span)?,
+            Some(ClassFile::Jar(jar)) => ClassInfo::from_jar(&jar, class_name, span)?,
+            None => {
+                // `-constants` shows the values of constant fields, see `Field::value`.
+                let s = Self::javap(&["-p", "-constants"], class_name, span)?;
+                let mut ci = ClassInfo::parse(&s, span)?;
+                imports::resolve_default_package(&mut ci);
+                Self::mark_bridge_methods(&mut ci, class_name, span)?;
+                Self::add_enclosing_instances(&mut ci, class_name, span)?;
+                ci
+            }
+        };
+
+        // reset the span for the cached data to the call site so that when others look it up,
+        // they get the same span.
+        ci.span = Span::call_site();
+        Ok(self
+            .classes
+            .borrow_mut()
+            .entry(class_name.clone())
+            .or_insert(Rc::new(ci))
+            .clone())
+    }
+
+    /// Like [`Reflector::reflect`], but also sets `deprecated` on `class_name` and its members
+    /// if they are deprecated. This takes another, verbose, run of `javap`.
+    pub fn reflect_deprecations(
+        &self,
+        class_name: &DotId,
+        span: Span,
+    ) -> Result<Rc<ClassInfo>, SpanError> {
+        if let Some(class) = self.deprecations.borrow().get(class_name).map(Rc::clone) {
             return Ok(class);
         }
 
+        // Class files say what is deprecated, so `reflect` has already marked it.
+        if self.find_class_file(class_name, span)?.is_some() {
+            return self.reflect(class_name, span);
+        }
+
+        let s = Self::javap(&["-p", "-v"], class_name, span)?;
+        let verbose = verbose_class(&s, class_name);
+        let mut info = (*self.reflect(class_name, span)?).clone();
+        if info.fields.len() != verbose.fields.len()
+            || info.constructors.len() != verbose.constructors.len()
+            || info.methods.len() != verbose.methods.len()
+        {
+            return Err(SpanError {
+                span,
+                message: format!(
+                    "failed to match up the verbose `javap` output for `{class_name}`"
+                ),
+            });
+        }
+        info.deprecated = verbose.deprecated;
+        for (field, details) in info.fields.iter_mut().zip(verbose.fields) {
+            field.deprecated = details.deprecated;
+        }
+        for (constructor, details) in info.constructors.iter_mut().zip(verbose.constructors) {
+            constructor.deprecated = details.deprecated;
+            constructor.parameter_names = details.parameter_names;
+        }
+        for (method, details) in info.methods.iter_mut().zip(verbose.methods) {
+            method.deprecated = details.deprecated;
+        }
+
+        Ok(self
+            .deprecations
+            .borrow_mut()
+            .entry(class_name.clone())
+            .or_insert(Rc::new(info))
+            .clone())
+    }
+
+    /// Returns the permitted subclasses of `class_name` if it is a sealed class that `reflect`
+    /// ran `javap` on, which only lists them in its verbose output. (Class files have them
+    /// already, and classes the user specified list them with `permits`.)
+    pub fn reflect_permitted_subclasses(
+        &self,
+        class_name: &DotId,
+        span: Span,
+    ) -> Result<Vec<ClassRef>, SpanError> {
+        if !self.classes.borrow().contains_key(class_name)
+            || self.find_class_file(class_name, span)?.is_some()
+        {
+            return Ok(vec![]);
+        }
+
+        // The verbose output ends with the class attributes, e.g.
+        // `PermittedSubclasses:` followed by indented lines like `  shapes/Circle`.
+        let s = Self::javap(&["-v"], class_name, span)?;
+        Ok(s.lines()
+            .skip_while(|line| *line != "PermittedSubclasses:")
+            .skip(1)
+            .take_while(|line| line.starts_with("  "))
+            .map(|line| ClassRef {
+                name: line.trim().split('/').map(Id::from).collect(),
+                generics: vec![],
+            })
+            .collect())
+    }
+
+    /// Sets `flags.is_bridge` on the bridge methods of `info`, see
+    /// [`ClassInfo::is_redundant_bridge`]. `javap` only shows this in its verbose output, so that
+    /// is only run if there are methods of the same name and number of arguments.
+    fn mark_bridge_methods(
+        info: &mut ClassInfo,
+        class_name: &DotId,
+        span: Span,
+    ) -> Result<(), SpanError> {
+        let may_have_bridges = info.methods.iter().enumerate().any(|(i, m)| {
+            info.methods[..i]
+                .iter()
+                .any(|n| n.name == m.name && n.argument_tys.len() == m.argument_tys.len())
+        });
+        if !may_have_bridges {
+            return Ok(());
+        }
+
+        let s = Self::javap(&["-p", "-v"], class_name, span)?;
+        let methods = verbose_class(&s, class_name).methods;
+        if info.methods.len() != methods.len() {
+            return Err(SpanError {
+                span,
+                message: format!(
+                    "failed to match up the verbose `javap` output for `{class_name}`"
+                ),
+            });
+        }
+        for (method, details) in info.methods.iter_mut().zip(methods) {
+            method.flags.is_bridge = details.is_bridge;
+        }
+        Ok(())
+    }
+
+    /// Adds the enclosing instance as the first argument of the constructors of `info`, if it is
+    /// an inner class, where `javap` leaves it out. The constructor of an inner class takes its
+    /// enclosing instance as an implicit first argument, which `javap` only shows if the
+    /// constructor has no generic signature (compare `ClassInfo::from_class_file`). Inner
+    /// classes are recognized by the synthetic `this$0` field holding the enclosing instance.
+    fn add_enclosing_instances(
+        info: &mut ClassInfo,
+        class_name: &DotId,
+        span: Span,
+    ) -> Result<(), SpanError> {
+        let Some(enclosing) = info.fields.iter().find(|f| &f.name[..] == "this$0") else {
+            return Ok(());
+        };
+        let enclosing = enclosing.ty.clone();
+
+        let s = Self::javap(&["-p", "-v"], class_name, span)?;
+        let constructors = verbose_class(&s, class_name).constructors;
+        if info.constructors.len() != constructors.len() {
+            return Err(SpanError {
+                span,
+                message: format!(
+                    "failed to match up the verbose `javap` output for `{class_name}`"
+                ),
+            });
+        }
+        for (constructor, details) in info.constructors.iter_mut().zip(constructors) {
+            let Some(descriptor) = details.descriptor else {
+                continue;
+            };
+            if descriptor_argument_count(&descriptor) > constructor.argument_tys.len() {
+                constructor.argument_tys.insert(0, enclosing.clone());
+            }
+        }
+        Ok(())
+    }
+
+    /// Finds the class file for `class_name` in the `classpath` directories and JARs, if any.
+    /// Unlike missing directories, which are skipped, JARs that cannot be read are an error.
+    fn find_class_file(
+        &self,
+        class_name: &DotId,
+        span: Span,
+    ) -> Result<Option<ClassFile>, SpanError> {
+        for entry in &self.classpath {
+            if entry.extension().is_some_and(|e| e == "jar") {
+                let entries = self.jar_entries(entry, span)?;
+                if entries.contains(&jar_entry_name(class_name)) {
+                    return Ok(Some(ClassFile::Jar(entry.clone())));
+                }
+            } else {
+                let mut path = entry.clone();
+                path.extend(class_name.iter().map(|id| &id[..]));
+                path.set_extension("class");
+                if path.is_file() {
+                    return Ok(Some(ClassFile::File(path)));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns the (cached) names of the entries in `jar`.
+    fn jar_entries(&self, jar: &PathBuf, span: Span) -> Result<Arc<BTreeSet<String>>, SpanError> {
+        if let Some(entries) = self.jar_entries.borrow().get(jar) {
+            return Ok(entries.clone());
+        }
+        let entries = jar_entries(jar).map_err(|message| SpanError {
+            span,
+            message: format!("failed to read `{}`: {message}", jar.display()),
+        })?;
+        Ok(self
+            .jar_entries
+            .borrow_mut()
+            .entry(jar.clone())
+            .or_insert(Arc::new(entries))
+            .clone())
+    }
+
+    /// Runs `javap` with `args` on `class_name`, returning its output.
+    fn javap(args: &[&str], class_name: &DotId, span: Span) -> Result<String, SpanError> {
         let mut javap_path = PathBuf::new();
         if let Ok(java_home) = env::var("JAVA_HOME") {
             javap_path.extend([java_home.as_str(), "bin"]);
         }
         javap_path.push("javap");
 
-        let classpath = match env::var("CLASSPATH") {
+        // `DUCHESS_CLASSPATH` is set by `duchess::build::add_classpath_entry`.
+        let classpath = match env::var("DUCHESS_CLASSPATH").or_else(|_| env::var("CLASSPATH")) {
             Ok(val) => val,
             Err(e) => panic!("duchess cannot read the CLASSPATH environment variable: {e}"),
         };
@@ -151,7 +987,7 @@ impl Reflector {
         command
             .arg("-cp")
             .arg(classpath)
-            .arg("-p")
+            .args(args)
             .arg(format!("{}", class_name));
 
         let output_or_err = command.output();
@@ -176,27 +1012,13 @@ impl Reflector {
             });
         }
 
-        let s = match String::from_utf8(output.stdout) {
-            Ok(o) => o,
-            Err(err) => {
-                return Err(SpanError {
-                    span,
-                    message: format!("failed to parse output of `{command:?}` as utf-8: {err}"),
-                });
-            }
-        };
-
-        let mut ci = ClassInfo::parse(&s, span)?;
-
-        // reset the span for the cached data to the call site so that when others look it up,
-        // they get the same span.
-        ci.span = Span::call_site();
-        Ok(self
-            .classes
-            .borrow_mut()
-            .entry(class_name.clone())
-            .or_insert(Arc::new(ci))
-            .clone())
+        match String::from_utf8(output.stdout) {
+            Ok(o) => Ok(o),
+            Err(err) => Err(SpanError {
+                span,
+                message: format!("failed to parse output of `{command:?}` as utf-8: {err}"),
+            }),
+        }
     }
 
     ///
@@ -243,8 +1065,8 @@ pub type MethodIndex = usize;
 /// Reflection on something callable.
 #[derive(Clone, Debug)]
 pub enum ReflectedMethod {
-    Constructor(Arc<ClassInfo>, ConstructorIndex),
-    Method(Arc<ClassInfo>, MethodIndex),
+    Constructor(Rc<ClassInfo>, ConstructorIndex),
+    Method(Rc<ClassInfo>, MethodIndex),
 }
 
 impl ReflectedMethod {
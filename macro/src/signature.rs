@@ -1,4 +1,5 @@
 use crate::{
+    argument::Conversion,
     class_info::{ClassRef, Generic, Id, NonRepeatingType, RefType, ScalarType, Type},
     span_error::SpanError,
 };
@@ -19,6 +20,14 @@ pub struct Signature {
     /// Used to check for validity and to avoid generating conflicting names.
     in_scope_generics: Vec<Id>,
 
+    /// Generic parameters of the class that are fixed to a specific type;
+    /// these are replaced by that type wherever they appear.
+    type_arguments: Vec<(Id, RefType)>,
+
+    /// Classes that are converted to/from Rust types at the boundary
+    /// (see `Conversion`).
+    conversions: Vec<Conversion>,
+
     /// Generics to include on the generated Rust method.
     /// Includes the Java generics but also includes fresh generic
     /// parameters generated from Java wildcards.
@@ -39,6 +48,10 @@ pub struct Signature {
     /// If false, report an error if `?` appears, because it is a context where
     /// we don't support capture.
     capture_generics: bool,
+
+    /// If true, translate `?` to `java::Wild` rather than capturing it, as we do
+    /// for types in output position (see `Self::erase_wildcards`).
+    erase_wildcards: bool,
 }
 
 impl Signature {
@@ -56,12 +69,49 @@ impl Signature {
             item_name: method_name.clone(),
             span,
             in_scope_generics: external_generics.iter().map(|g| g.id.clone()).collect(),
+            type_arguments: vec![],
+            conversions: vec![],
             rust_generics: vec![],
             where_clauses: vec![],
             capture_generics: true,
+            erase_wildcards: false,
+        }
+    }
+
+    /// Declares class generic parameters that have been specialized to a given type
+    /// (see `ClassInfo::type_arguments`).
+    pub fn with_type_arguments(self, type_arguments: &[(Id, RefType)]) -> Self {
+        Signature {
+            type_arguments: type_arguments.to_vec(),
+            ..self
         }
     }
 
+    /// The span of the member being translated, for error reporting.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Declares the classes that are converted to/from Rust types when they appear
+    /// as an argument or return type.
+    pub fn with_conversions(self, conversions: &[Conversion]) -> Self {
+        Signature {
+            conversions: conversions.to_vec(),
+            ..self
+        }
+    }
+
+    /// If `ty` is a class that is converted to a Rust type (see `Conversion`),
+    /// returns that Rust type.
+    pub fn converted_ty(&self, ty: &Type) -> Option<TokenStream> {
+        let Type::Ref(RefType::Class(class_ref)) = ty else {
+            return None;
+        };
+        self.conversions
+            .iter()
+            .find_map(|c| c.rust_ty(&class_ref.name))
+    }
+
     /// Declares the generic parameters on the method/constructor being translated.
     /// "Internal" generics are distinct from "external" generics because they are
     /// added to `self.rust_generics` and `self.where_clauses`, so that in the end
@@ -73,6 +123,10 @@ impl Signature {
         s.in_scope_generics
             .extend(internal_generics.iter().map(|g| g.id.clone()));
 
+        // Method generics shadow class generics of the same name.
+        s.type_arguments
+            .retain(|(id, _)| !internal_generics.iter().any(|g| g.id == *id));
+
         // Forbid capture we don't have to worry about things like `X extends ArrayList<?>`.
         // Actually, we could probably support capture here, but I don't know want to right now.
         s.forbid_capture(|s| {
@@ -102,6 +156,18 @@ impl Signature {
         r
     }
 
+    /// Translate wildcards to `java::Wild` while `op` executes, then restore the previous mode.
+    ///
+    /// Used for types in output position, where the caller can't choose a type to
+    /// capture the wildcard: a method returning `ArrayList<? extends Foo>` returns an
+    /// `ArrayList<java::Wild<Foo>>`, whose elements can only be upcast to `Foo`.
+    pub fn erase_wildcards<R>(&mut self, op: impl FnOnce(&mut Self) -> R) -> R {
+        let v = std::mem::replace(&mut self.erase_wildcards, true);
+        let r = op(self);
+        self.erase_wildcards = v;
+        r
+    }
+
     /// Generates a fresh generic type and adds it to `self.generics`.
     ///
     /// Used to manage Java wildcards. A type like `ArrayList<?>` gets
@@ -142,6 +208,19 @@ impl Signature {
     /// Returns an appropriate `impl type` for a funtion that
     /// takes `ty` as input. Assumes objects are nullable.
     pub fn input_trait(&mut self, ty: &Type) -> Result<TokenStream, SpanError> {
+        if let Some(r) = self.converted_ty(ty) {
+            let t = self.java_ty(ty)?;
+            return Ok(quote_spanned!(self.span => duchess::plumbing::ConvertToJava<#t, #r>));
+        }
+
+        // Varargs of objects take a tuple or array of arguments, see `duchess::IntoVarargs`.
+        if let Type::Repeat(element) = ty {
+            if let Type::Ref(element) = &**element {
+                let t = self.java_ref_ty(element)?;
+                return Ok(quote_spanned!(self.span => duchess::IntoVarargs<#t>));
+            }
+        }
+
         match ty.to_non_repeating() {
             NonRepeatingType::Ref(ty) => {
                 let t = self.java_ref_ty(&ty)?;
@@ -166,8 +245,12 @@ impl Signature {
     /// Returns an appropriate `impl type` for a function that
     /// returns `ty`. Assumes objects are nullable.
     pub fn non_void_output_type(&mut self, ty: &Type) -> Result<TokenStream, SpanError> {
+        if let Some(r) = self.converted_ty(ty) {
+            return Ok(quote_spanned!(self.span => Option<#r>));
+        }
+
         // XX: do we need the non_repeating transform here? Shouldn't be allowed in return position
-        self.forbid_capture(|this| match ty.to_non_repeating() {
+        self.erase_wildcards(|this| match ty.to_non_repeating() {
             NonRepeatingType::Ref(ty) => {
                 let t = this.java_ref_ty(&ty)?;
                 Ok(quote_spanned!(this.span => Option<Local<'jvm, #t>>))
@@ -233,6 +316,82 @@ impl Signature {
         Ok(Ident::new(f, self.span))
     }
 
+    pub fn jni_field_get_fn(&mut self, ty: &Type) -> Result<Ident, SpanError> {
+        let f = match ty {
+            Type::Ref(_) => "GetObjectField",
+            Type::Repeat(_) => {
+                return Err(SpanError {
+                    span: self.span,
+                    message: format!(
+                        "unsupported repeating type in getter of field `{}`",
+                        self.item_name
+                    ),
+                })
+            }
+            Type::Scalar(scalar) => match scalar {
+                ScalarType::Int => "GetIntField",
+                ScalarType::Long => "GetLongField",
+                ScalarType::Short => "GetShortField",
+                ScalarType::Byte => "GetByteField",
+                ScalarType::F64 => "GetDoubleField",
+                ScalarType::F32 => "GetFloatField",
+                ScalarType::Boolean => "GetBooleanField",
+                ScalarType::Char => "GetCharField",
+            },
+        };
+        Ok(Ident::new(f, self.span))
+    }
+
+    /// The JNI function that sets a field of type `ty`, e.g. `SetIntField` or `SetStaticIntField`.
+    pub fn jni_field_set_fn(&mut self, ty: &Type, is_static: bool) -> Result<Ident, SpanError> {
+        let kind = match ty {
+            Type::Ref(_) => "Object",
+            Type::Repeat(_) => {
+                return Err(SpanError {
+                    span: self.span,
+                    message: format!(
+                        "unsupported repeating type in setter of field `{}`",
+                        self.item_name
+                    ),
+                })
+            }
+            Type::Scalar(scalar) => match scalar {
+                ScalarType::Int => "Int",
+                ScalarType::Long => "Long",
+                ScalarType::Short => "Short",
+                ScalarType::Byte => "Byte",
+                ScalarType::F64 => "Double",
+                ScalarType::F32 => "Float",
+                ScalarType::Boolean => "Boolean",
+                ScalarType::Char => "Char",
+            },
+        };
+        let f = if is_static {
+            format!("SetStatic{kind}Field")
+        } else {
+            format!("Set{kind}Field")
+        };
+        Ok(Ident::new(&f, self.span))
+    }
+
+    /// The member of the JNI `jvalue` union that holds a value of type `ty`.
+    pub fn jni_value_member(&mut self, ty: &Type) -> Ident {
+        let f = match ty.to_non_repeating() {
+            NonRepeatingType::Ref(_) => "l",
+            NonRepeatingType::Scalar(scalar) => match scalar {
+                ScalarType::Int => "i",
+                ScalarType::Long => "j",
+                ScalarType::Short => "s",
+                ScalarType::Byte => "b",
+                ScalarType::F64 => "d",
+                ScalarType::F32 => "f",
+                ScalarType::Boolean => "z",
+                ScalarType::Char => "c",
+            },
+        };
+        Ident::new(f, self.span)
+    }
+
     pub fn jni_static_field_get_fn(&mut self, ty: &Type) -> Result<Ident, SpanError> {
         let f = match ty {
             Type::Ref(_) => "GetStaticObjectField",
@@ -262,7 +421,11 @@ impl Signature {
     /// Returns an appropriate trait for a method that
     /// returns `ty`. Assumes objects are nullable.
     pub fn method_trait(&mut self, ty: &Option<Type>) -> Result<TokenStream, SpanError> {
-        self.forbid_capture(|this| match ty.as_ref().map(|ty| ty.to_non_repeating()) {
+        if let Some(r) = ty.as_ref().and_then(|ty| self.converted_ty(ty)) {
+            return Ok(self.converted_output_trait(r));
+        }
+
+        self.erase_wildcards(|this| match ty.as_ref().map(|ty| ty.to_non_repeating()) {
             Some(NonRepeatingType::Ref(ty)) => {
                 let t = this.java_ref_ty(&ty)?;
                 Ok(quote_spanned!(this.span => duchess::JavaMethod<#t>))
@@ -278,7 +441,11 @@ impl Signature {
     /// Returns an appropriate trait for a field that
     /// returns `ty`. Assumes objects are nullable.
     pub fn field_trait(&mut self, ty: &Type) -> Result<TokenStream, SpanError> {
-        self.forbid_capture(|this| match ty.to_non_repeating() {
+        if let Some(r) = self.converted_ty(ty) {
+            return Ok(self.converted_output_trait(r));
+        }
+
+        self.erase_wildcards(|this| match ty.to_non_repeating() {
             NonRepeatingType::Ref(ty) => {
                 let t = this.java_ref_ty(&ty)?;
                 Ok(quote_spanned!(this.span => duchess::JavaField<#t>))
@@ -290,6 +457,12 @@ impl Signature {
         })
     }
 
+    /// Trait for a method or field that returns a Java object which is converted
+    /// to the Rust type `r`.
+    fn converted_output_trait(&self, r: TokenStream) -> TokenStream {
+        quote_spanned!(self.span => for<'jvm> duchess::JvmOp<Output<'jvm> = Option<#r>>)
+    }
+
     /// Returns the Rust type that represents this Java type -- e.g., for `Object`,
     /// returns `java::lang::Object`. Note that this is not the type of a *reference* to this
     /// java type (which would be e.g. `Global<java::lang::Object>`).
@@ -311,12 +484,18 @@ impl Signature {
     fn java_ref_ty(&mut self, ty: &RefType) -> Result<TokenStream, SpanError> {
         match ty {
             RefType::Class(ty) => Ok(self.class_ref_ty(ty)?),
+            RefType::Array(e) if **e == Type::Scalar(ScalarType::Byte) => {
+                Ok(quote_spanned!(self.span => java::ByteArray))
+            }
             RefType::Array(e) => {
                 let e = self.java_ty(e)?;
                 Ok(quote_spanned!(self.span => java::Array<#e>))
             }
             RefType::TypeParameter(t) => {
-                if self.in_scope_generics.contains(t) {
+                if let Some((_, ty)) = self.type_arguments.iter().find(|(id, _)| id == t) {
+                    let ty = ty.clone();
+                    self.java_ref_ty(&ty)
+                } else if self.in_scope_generics.contains(t) {
                     let t = t.to_ident(self.span);
                     Ok(quote_spanned!(self.span => #t))
                 } else {
@@ -329,6 +508,14 @@ impl Signature {
                     })
                 }
             }
+            RefType::Extends(ty) if self.erase_wildcards => {
+                let e = self.java_ref_ty(ty)?;
+                Ok(quote_spanned!(self.span => java::Wild<#e>))
+            }
+            RefType::Super(_) | RefType::Wildcard if self.erase_wildcards => {
+                // All we know of an unknown supertype is that it is an object.
+                Ok(quote_spanned!(self.span => java::Wild<java::lang::Object>))
+            }
             RefType::Extends(ty) => {
                 let g = self.fresh_generic()?;
                 let e = self.java_ref_ty(ty)?;
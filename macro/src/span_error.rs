@@ -20,3 +20,42 @@ impl From<SpanError> for syn::Error {
         syn::Error::new(value.span, value.message)
     }
 }
+
+/// A non-empty list of errors, for when we can keep going after an error
+/// (e.g., when parsing) and report all the problems at once.
+#[derive(Debug)]
+pub struct SpanErrors {
+    pub errors: Vec<SpanError>,
+}
+
+impl SpanErrors {
+    /// Emits one `compile_error!` per error.
+    pub fn into_tokens(self) -> TokenStream {
+        self.errors
+            .into_iter()
+            .map(SpanError::into_tokens)
+            .collect()
+    }
+}
+
+impl From<SpanError> for SpanErrors {
+    fn from(value: SpanError) -> Self {
+        SpanErrors {
+            errors: vec![value],
+        }
+    }
+}
+
+impl From<SpanErrors> for syn::Error {
+    fn from(value: SpanErrors) -> Self {
+        value
+            .errors
+            .into_iter()
+            .map(syn::Error::from)
+            .reduce(|mut e1, e2| {
+                e1.combine(e2);
+                e1
+            })
+            .expect("`SpanErrors` is never empty")
+    }
+}
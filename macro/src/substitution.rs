@@ -1,7 +1,7 @@
 use std::iter::FromIterator;
 use std::{collections::BTreeMap, sync::Arc};
 
-use crate::class_info::{ClassRef, Id, RefType, Type};
+use crate::class_info::{ClassRef, Generic, Id, RefType, Type};
 
 pub struct Substitution<'s> {
     map: BTreeMap<&'s Id, &'s RefType>,
@@ -55,6 +55,15 @@ impl Substitute for ClassRef {
     }
 }
 
+impl Substitute for Generic {
+    fn substitute(&self, subst: &Substitution<'_>) -> Self {
+        Generic {
+            id: self.id.clone(),
+            extends: self.extends.substitute(subst),
+        }
+    }
+}
+
 impl<F> Substitute for Vec<F>
 where
     F: Substitute,
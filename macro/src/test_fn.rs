@@ -0,0 +1,70 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote_spanned;
+use syn::spanned::Spanned;
+
+/// Arguments to `#[duchess::test]`, e.g. `#[duchess::test(classpath = "target/java")]`.
+#[derive(Default)]
+pub struct TestArgs {
+    classpath: Option<syn::LitStr>,
+}
+
+impl syn::parse::Parse for TestArgs {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut args = TestArgs::default();
+        let parser = syn::meta::parser(|meta| {
+            if meta.path.is_ident("classpath") {
+                args.classpath = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `classpath = \"...\"`"))
+            }
+        });
+        syn::parse::Parser::parse2(parser, input.parse()?)?;
+        Ok(args)
+    }
+}
+
+/// Wraps a test function so that it runs with the JVM started, see
+/// `duchess::testing::run_test_with_jvm`.
+///
+/// The function is kept as is (as an item nested in the generated `#[test]` function),
+/// so it may return `()` or a `duchess::GlobalResult<()>`.
+pub fn test_fn(args: TestArgs, input: syn::ItemFn) -> syn::Result<TokenStream> {
+    let span = input.sig.span();
+
+    if let Some(asyncness) = &input.sig.asyncness {
+        return Err(syn::Error::new(
+            asyncness.span(),
+            "`#[duchess::test]` functions cannot be async",
+        ));
+    }
+    if !input.sig.inputs.is_empty() {
+        return Err(syn::Error::new(
+            input.sig.inputs.span(),
+            "`#[duchess::test]` functions cannot take arguments",
+        ));
+    }
+
+    let syn::ItemFn {
+        attrs,
+        vis,
+        sig,
+        block,
+    } = input;
+    let name = &sig.ident;
+
+    let classpath = match &args.classpath {
+        Some(classpath) => quote_spanned!(classpath.span() => Some(#classpath)),
+        None => quote_spanned!(Span::call_site() => None),
+    };
+
+    Ok(quote_spanned!(span =>
+        #[test]
+        #(#attrs)*
+        #vis fn #name() {
+            #sig #block
+
+            duchess::testing::run_test_with_jvm(#classpath, #name)
+        }
+    ))
+}
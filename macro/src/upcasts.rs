@@ -1,6 +1,7 @@
 use std::collections::{BTreeMap, BTreeSet};
 
 use crate::{
+    builtins,
     class_info::{ClassInfo, ClassRef, DotId, Id},
     substitution::{Substitute, Substitution},
 };
@@ -31,7 +32,7 @@ impl<'a> FromIterator<&'a ClassInfo> for Upcasts {
             upcasts.insert_direct_upcasts(class_info);
         }
 
-        upcasts.insert_hardcoded_upcasts();
+        upcasts.insert_builtin_upcasts();
 
         upcasts.compute_transitive_upcasts();
 
@@ -47,6 +48,33 @@ impl Upcasts {
         &self.map[name].extends
     }
 
+    /// Returns the transitive superclasses / interfaces of `name` (see `upcasts_for_generated_class`)
+    /// in method resolution order: a type always comes before its own supertypes, so that the
+    /// most derived declaration of a method is found first (e.g. an override with a covariant
+    /// return type). `java.lang.Object` comes last.
+    pub fn mro_for_generated_class(&self, name: &DotId) -> Vec<&ClassRef> {
+        // A type has strictly more supertypes than each of its supertypes.
+        // The sort is stable, so unrelated types keep their order.
+        let mut mro: Vec<&ClassRef> = self.map[name].extends.iter().collect();
+        mro.sort_by_key(|c| {
+            let supertypes = self.map.get(&c.name).map_or(0, |u| u.extends.len());
+            (c.name == DotId::object(), std::cmp::Reverse(supertypes))
+        });
+        mro
+    }
+
+    /// Whether `name` is a class without generic parameters that can be upcast to `to`,
+    /// which must not have generic parameters either. False for classes that are unknown here.
+    pub fn is_non_generic_subclass(&self, name: &DotId, to: &DotId) -> bool {
+        self.map.get(name).is_some_and(|upcasts| {
+            upcasts.generics.is_empty()
+                && upcasts
+                    .extends
+                    .iter()
+                    .any(|c| c.name == *to && c.generics.is_empty())
+        })
+    }
+
     /// Insert the direct (declared by user) superclasses of `class` into the map.
     fn insert_direct_upcasts(&mut self, class: &ClassInfo) {
         let mut upcasts = ClassUpcasts {
@@ -72,24 +100,17 @@ impl Upcasts {
         assert!(old_value.is_none());
     }
 
-    fn insert_hardcoded_upcasts(&mut self) {
-        let mut insert = |c: DotId, d: DotId| {
-            self.map
-                .entry(c)
-                .or_insert_with(|| ClassUpcasts {
-                    generics: vec![],
-                    extends: BTreeSet::default(),
-                })
-                .extends
-                .insert(ClassRef {
-                    name: d,
-                    generics: vec![],
-                });
-        };
-
-        insert(DotId::runtime_exception(), DotId::exception());
-        insert(DotId::exception(), DotId::throwable());
-        insert(DotId::throwable(), DotId::object());
+    /// Insert the upcasts between the classes that duchess itself declares in `duchess::java`
+    /// (see `builtins.duchess`). These are always in scope for generated code, so unlike other
+    /// classes outside of our package, we can follow the superclass chain through them (e.g., a
+    /// class that extends `java.util.HashMap<K, V>` can also be upcast to `java.util.Map<K, V>`).
+    /// Classes that are also declared by the user keep the supertypes the user declared.
+    fn insert_builtin_upcasts(&mut self) {
+        for class in builtins::classes() {
+            if !self.map.contains_key(&class.name) {
+                self.insert_direct_upcasts(&class);
+            }
+        }
     }
 
     /// Extend the map with transitive upcasts for each of its entries. i.e., if class `A` extends `B`,
@@ -132,7 +153,12 @@ impl Upcasts {
             return vec![];
         };
 
-        assert_eq!(class_ref.generics.len(), c_u.generics.len());
+        if class_ref.generics.len() != c_u.generics.len() {
+            // A class declared in `duchess::java` refers to a class that the user declared
+            // with fewer generics (e.g. `java.lang.Comparable<java.lang.String>`, see
+            // `ClassInfo::type_arguments`), so it means the class in `duchess::java`.
+            return vec![];
+        }
 
         let subst: Substitution<'_> = c_u.generics.iter().zip(&class_ref.generics).collect();
 
@@ -0,0 +1,37 @@
+use std::marker::PhantomData;
+
+use crate::{Jvm, JvmOp};
+
+#[derive_where::derive_where(Copy, Clone; J, F)]
+pub struct AndThen<J, F, O> {
+    j: J,
+    f: F,
+    phantom: PhantomData<fn() -> O>,
+}
+
+impl<J, F, O> AndThen<J, F, O>
+where
+    J: JvmOp,
+    F: for<'jvm> FnOnce(J::Output<'jvm>, &mut Jvm<'jvm>) -> crate::Result<'jvm, O> + Copy,
+{
+    pub(crate) fn new(j: J, f: F) -> Self {
+        AndThen {
+            j,
+            f,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<J, F, O> JvmOp for AndThen<J, F, O>
+where
+    J: JvmOp,
+    F: for<'jvm> FnOnce(J::Output<'jvm>, &mut Jvm<'jvm>) -> crate::Result<'jvm, O> + Copy,
+{
+    type Output<'jvm> = O;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        let output = self.j.execute_with(jvm)?;
+        (self.f)(output, jvm)
+    }
+}
@@ -6,7 +6,7 @@ use crate::{
     java::{self, lang::Class},
     jvm::JavaView,
     plumbing::{FromRef, JavaObjectExt},
-    raw::{HasEnvPtr, ObjectPtr},
+    raw::{FromJniValue, HasEnvPtr, ObjectPtr},
     to_java::ToJavaImpl,
     AsJRef, Error, IntoRust, JDeref, JavaObject, JavaType, Jvm, JvmOp, Local, Nullable,
     ScalarMethod, TryJDeref,
@@ -43,7 +43,9 @@ impl<T, J, N> FromRef<J> for JavaArrayObj<T, J, N> {
 }
 
 unsafe impl<T: JavaType> JavaObject for JavaArray<T> {
-    fn class<'jvm>(jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Local<'jvm, Class>> {
+    fn class<'jvm>(
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Local<'jvm, Class<java::lang::Object>>> {
         T::array_class(jvm)
     }
 }
@@ -165,6 +167,22 @@ macro_rules! primivite_array {
                 }
             }
 
+            impl JvmOp for &Vec<$rust> {
+                type Output<'jvm> = Local<'jvm, JavaArray<$rust>>;
+
+                fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+                    <&[$rust] as JvmOp>::execute_with(self, jvm)
+                }
+            }
+
+            impl<const N: usize> JvmOp for &[$rust; N] {
+                type Output<'jvm> = Local<'jvm, JavaArray<$rust>>;
+
+                fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+                    <&[$rust] as JvmOp>::execute_with(self, jvm)
+                }
+            }
+
             impl ToJavaImpl<java::Array<$rust>> for [$rust] {
                 fn to_java_impl<'jvm>(
                     rust: &Self,
@@ -186,7 +204,7 @@ macro_rules! primivite_array {
             impl IntoRust<Vec<$rust>> for &JavaArray<$rust> {
                 fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> $crate::Result<'jvm, Vec<$rust>> {
                     let len = self.length().execute_with(jvm)?;
-                    let mut vec = Vec::<$rust>::with_capacity(len as usize);
+                    let mut vec = Vec::<jni_sys::$java_ty>::with_capacity(len as usize);
 
                     unsafe {
                         jvm.env().invoke(|env| env.$get_fn, |env, f| f(
@@ -194,13 +212,17 @@ macro_rules! primivite_array {
                             self.as_raw().as_ptr(),
                             0,
                             len,
-                            vec.as_mut_ptr().cast::<jni_sys::$java_ty>(),
+                            vec.as_mut_ptr(),
                         ));
                         vec.set_len(len as usize);
                     }
                     check_exception(jvm)?;
 
-                    Ok(vec)
+                    // Elements are read as their JNI types, as a `jboolean` may be any non-zero value.
+                    Ok(vec
+                        .into_iter()
+                        .map(|value| unsafe { <$rust as FromJniValue>::from_jni_value(jvm, value) })
+                        .collect())
                 }
             }
         )*
@@ -218,3 +240,243 @@ primivite_array! {
     [f32]: "float" jfloat NewFloatArray GetFloatArrayRegion SetFloatArrayRegion,
     [f64]: "double" jdouble NewDoubleArray GetDoubleArrayRegion SetDoubleArrayRegion,
 }
+
+// Arrays of objects, including arrays of arrays (e.g. `int[][]` is a `JavaArray<JavaArray<i32>>`).
+
+impl<E, JE> ToJavaImpl<java::Array<JE>> for [E]
+where
+    E: ToJavaImpl<JE>,
+    JE: Upcast<java::lang::Object>,
+{
+    fn to_java_impl<'jvm>(
+        rust: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Option<Local<'jvm, java::Array<JE>>>> {
+        let Ok(len) = rust.len().try_into() else {
+            return Err(Error::SliceTooLong(rust.len()));
+        };
+
+        let array = new_object_array::<JE>(jvm, len)?;
+        for (index, element) in (0..len).zip(rust) {
+            let element = E::to_java_impl(element, jvm)?;
+            set_object_array_element(jvm, &array, index, element.as_deref())?;
+        }
+
+        Ok(Some(array))
+    }
+}
+
+/// Allocates a Java array of `len` objects of type `E`, all null.
+pub(crate) fn new_object_array<'jvm, E: JavaObject>(
+    jvm: &mut Jvm<'jvm>,
+    len: jni_sys::jsize,
+) -> crate::Result<'jvm, Local<'jvm, JavaArray<E>>> {
+    let element_class = E::class(jvm)?;
+    let env = jvm.env();
+    let array = unsafe {
+        env.invoke(
+            |env| env.NewObjectArray,
+            |env, f| {
+                f(
+                    env,
+                    len,
+                    element_class.as_raw().as_ptr(),
+                    std::ptr::null_mut(),
+                )
+            },
+        )
+    };
+    let Some(array) = ObjectPtr::new(array) else {
+        check_exception(jvm)?; // Likely threw OutOfMemoryError
+        return Err(Error::JvmInternal(format!(
+            "failed to allocate an array of {len} objects"
+        )));
+    };
+    Ok(unsafe { Local::from_raw(env, array) })
+}
+
+/// Stores `element` (or null) at `index` of `array`.
+pub(crate) fn set_object_array_element<'jvm, E: JavaObject>(
+    jvm: &mut Jvm<'jvm>,
+    array: &JavaArray<E>,
+    index: jni_sys::jsize,
+    element: Option<&E>,
+) -> crate::Result<'jvm, ()> {
+    let element = element.map_or(std::ptr::null_mut(), |e| e.as_raw().as_ptr());
+    unsafe {
+        jvm.env().invoke(
+            |env| env.SetObjectArrayElement,
+            |env, f| f(env, array.as_raw().as_ptr(), index, element),
+        );
+    }
+    check_exception(jvm)
+}
+
+impl<E, JE> ToJavaImpl<java::Array<JE>> for Vec<E>
+where
+    E: ToJavaImpl<JE>,
+    JE: Upcast<java::lang::Object>,
+{
+    fn to_java_impl<'jvm>(
+        rust: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Option<Local<'jvm, java::Array<JE>>>> {
+        <[E]>::to_java_impl(rust, jvm)
+    }
+}
+
+/// Converts each element of the array; a null element is an [`Error::NullDeref`].
+impl<J, R> IntoRust<Vec<R>> for &JavaArray<J>
+where
+    J: JavaObject,
+    for<'a> &'a J: IntoRust<R>,
+{
+    fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Vec<R>> {
+        let len = self.length().execute_with(jvm)?;
+        let mut vec = Vec::with_capacity(len as usize);
+
+        for index in 0..len {
+            let env = jvm.env();
+            let element = unsafe {
+                env.invoke(
+                    |env| env.GetObjectArrayElement,
+                    |env, f| f(env, self.as_raw().as_ptr(), index),
+                )
+            };
+            let Some(element) = ObjectPtr::new(element) else {
+                check_exception(jvm)?;
+                return Err(Error::NullDeref);
+            };
+            let element: Local<'jvm, J> = unsafe { Local::from_raw(env, element) };
+            vec.push(element.into_rust(jvm)?);
+        }
+
+        Ok(vec)
+    }
+}
+
+/// A Java `byte[]`. Generated code uses this name for `byte[]`; it is the same type as
+/// `JavaArray<i8>`, so everything available on arrays is available here too.
+pub type JavaByteArray = JavaArray<i8>;
+
+impl JavaByteArray {
+    /// Copies the first `dst.len()` bytes of the array into `dst` with a single JNI call.
+    /// Fails with the JVM's `ArrayIndexOutOfBoundsException` if the array is shorter than `dst`.
+    pub fn copy_to_slice<'jvm>(
+        &self,
+        jvm: &mut Jvm<'jvm>,
+        dst: &mut [u8],
+    ) -> crate::Result<'jvm, ()> {
+        let Ok(len) = dst.len().try_into() else {
+            return Err(Error::SliceTooLong(dst.len()));
+        };
+        unsafe {
+            jvm.env().invoke(
+                |env| env.GetByteArrayRegion,
+                |env, f| f(env, self.as_raw().as_ptr(), 0, len, dst.as_mut_ptr().cast()),
+            );
+        }
+        check_exception(jvm)
+    }
+
+    /// Overwrites the first `src.len()` bytes of the array with `src` with a single JNI call.
+    /// Fails with the JVM's `ArrayIndexOutOfBoundsException` if the array is shorter than `src`.
+    pub fn copy_from_slice<'jvm>(
+        &self,
+        jvm: &mut Jvm<'jvm>,
+        src: &[u8],
+    ) -> crate::Result<'jvm, ()> {
+        let Ok(len) = src.len().try_into() else {
+            return Err(Error::SliceTooLong(src.len()));
+        };
+        unsafe {
+            jvm.env().invoke(
+                |env| env.SetByteArrayRegion,
+                |env, f| f(env, self.as_raw().as_ptr(), 0, len, src.as_ptr().cast()),
+            );
+        }
+        check_exception(jvm)
+    }
+
+    /// Gives direct access to the array's bytes, without copying them if the JVM allows it.
+    ///
+    /// The JVM may suspend garbage collection while the returned guard is live, and no other
+    /// JNI calls may be made, which is enforced by borrowing `jvm`. Keep critical sections short.
+    /// Changes are written back to the array when the guard is dropped.
+    #[cfg(feature = "critical")]
+    pub fn critical<'a, 'jvm>(
+        &'a self,
+        jvm: &'a mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, CriticalBytes<'a>> {
+        let len = self.length().execute_with(jvm)?;
+        let env = jvm.env();
+        let array = self.as_raw();
+        let ptr = unsafe {
+            env.invoke(
+                |env| env.GetPrimitiveArrayCritical,
+                |env, f| f(env, array.as_ptr(), std::ptr::null_mut()),
+            )
+        };
+        let Some(ptr) = std::ptr::NonNull::new(ptr.cast::<i8>()) else {
+            check_exception(jvm)?; // Likely threw OutOfMemoryError
+            return Err(Error::JvmInternal(
+                "JVM failed to access the contents of a byte array".into(),
+            ));
+        };
+        Ok(CriticalBytes {
+            env,
+            array,
+            ptr,
+            len: len as usize,
+        })
+    }
+}
+
+/// The contents of a [`JavaByteArray`] during a critical section, see [`JavaByteArray::critical`].
+#[cfg(feature = "critical")]
+pub struct CriticalBytes<'a> {
+    env: crate::raw::EnvPtr<'a>,
+    array: ObjectPtr,
+    ptr: std::ptr::NonNull<i8>,
+    len: usize,
+}
+
+#[cfg(feature = "critical")]
+impl CriticalBytes<'_> {
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr().cast(), self.len) }
+    }
+
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr().cast(), self.len) }
+    }
+}
+
+#[cfg(feature = "critical")]
+impl std::ops::Deref for CriticalBytes<'_> {
+    type Target = [i8];
+
+    fn deref(&self) -> &[i8] {
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+#[cfg(feature = "critical")]
+impl std::ops::DerefMut for CriticalBytes<'_> {
+    fn deref_mut(&mut self) -> &mut [i8] {
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+#[cfg(feature = "critical")]
+impl Drop for CriticalBytes<'_> {
+    fn drop(&mut self) {
+        // Mode 0 copies any changes back (if the JVM made a copy) and releases the elements.
+        unsafe {
+            self.env.invoke(
+                |env| env.ReleasePrimitiveArrayCritical,
+                |env, f| f(env, self.array.as_ptr(), self.ptr.as_ptr().cast(), 0),
+            );
+        }
+    }
+}
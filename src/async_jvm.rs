@@ -0,0 +1,172 @@
+//! Executing JVM operations from async code, enabled by the `async` feature.
+//!
+//! A JNI call blocks the thread making it, which in async code would be an executor thread,
+//! starving the other tasks scheduled on it. A [`JvmFuture`] instead hands its operation to a
+//! pool of threads that duchess keeps attached to the JVM, and completes when the operation
+//! has run there. It works with any executor, as it only relies on [`std::task::Waker`].
+//!
+//! ```ignore
+//! let date = JvmFuture::new(java::util::Date::new().global()).await?;
+//! let millis = JvmFuture::with(move |jvm| date.get_time().execute_with(jvm)).await?;
+//! ```
+
+use std::{
+    any::Any,
+    future::Future,
+    panic::AssertUnwindSafe,
+    pin::Pin,
+    sync::{mpsc, Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use once_cell::sync::OnceCell;
+
+use crate::{Error, GlobalResult, Jvm, JvmOp};
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// The result of a [`JvmOp`] executed on duchess's pool of JVM threads, as a [`Future`].
+///
+/// The operation is submitted to the pool when the `JvmFuture` is created, not when it is
+/// first polled, and runs to completion even if the `JvmFuture` is dropped first. As the
+/// output crosses threads, it must be owned, e.g. a Rust value or a
+/// [`Global`](crate::Global) reference, like that of [`JvmOp::execute`].
+///
+/// A panic in the operation is reported as [`Error::JvmInternal`].
+pub struct JvmFuture<O> {
+    shared: Arc<Mutex<Shared<O>>>,
+}
+
+/// The state shared by a [`JvmFuture`] and the job that completes it: a oneshot channel.
+struct Shared<O> {
+    result: Option<GlobalResult<O>>,
+    waker: Option<Waker>,
+}
+
+impl<O> JvmFuture<O>
+where
+    O: Send + 'static,
+{
+    /// Submits `op` to the pool of JVM threads (starting the pool, and the JVM, if necessary),
+    /// returning a future of its output.
+    pub fn new<J>(op: J) -> Self
+    where
+        J: Send + 'static,
+        for<'jvm> J: JvmOp<Output<'jvm> = O>,
+    {
+        Self::with(move |jvm| op.execute_with(jvm))
+    }
+
+    /// Like [`new`][Self::new], for a closure that is given a [`Jvm`], as with [`Jvm::with`].
+    pub fn with(
+        op: impl for<'jvm> FnOnce(&mut Jvm<'jvm>) -> crate::Result<'jvm, O> + Send + 'static,
+    ) -> Self {
+        let shared = Arc::new(Mutex::new(Shared {
+            result: None,
+            waker: None,
+        }));
+
+        let job_shared = shared.clone();
+        let job: Job = Box::new(move || {
+            let result = std::panic::catch_unwind(AssertUnwindSafe(|| Jvm::with(op)))
+                .unwrap_or_else(|panic| Err(Error::JvmInternal(panic_message(panic))));
+            complete(&job_shared, result);
+        });
+        if let Err(error) = submit(job) {
+            complete(&shared, Err(error));
+        }
+
+        JvmFuture { shared }
+    }
+}
+
+impl<O> Future for JvmFuture<O> {
+    type Output = GlobalResult<O>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut shared = self.shared.lock().unwrap();
+        match shared.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                shared.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+fn complete<O>(shared: &Mutex<Shared<O>>, result: GlobalResult<O>) {
+    let waker = {
+        let mut shared = shared.lock().unwrap();
+        shared.result = Some(result);
+        shared.waker.take()
+    };
+    if let Some(waker) = waker {
+        waker.wake();
+    }
+}
+
+/// Sends `job` to the pool of JVM threads, starting it on first use.
+fn submit(job: Job) -> GlobalResult<()> {
+    static POOL: OnceCell<Result<Mutex<mpsc::Sender<Job>>, String>> = OnceCell::new();
+    let sender = POOL
+        .get_or_init(|| start_pool().map(Mutex::new))
+        .as_ref()
+        .map_err(|message| Error::JvmInternal(message.clone()))?;
+    sender
+        .lock()
+        .unwrap()
+        .send(job)
+        .map_err(|_| Error::JvmInternal("the JVM thread pool is no longer running".to_string()))
+}
+
+/// Spawns one thread per available core, each permanently attached to the JVM, that run the
+/// jobs sent on the returned channel. The threads live as long as the process.
+fn start_pool() -> Result<mpsc::Sender<Job>, String> {
+    let threads = std::thread::available_parallelism().map_or(4, |n| n.get());
+    let (sender, receiver) = mpsc::channel::<Job>();
+    let receiver = Arc::new(Mutex::new(receiver));
+    let (ready_sender, ready_receiver) = mpsc::channel();
+
+    for i in 0..threads {
+        let receiver = receiver.clone();
+        let ready_sender = ready_sender.clone();
+        std::thread::Builder::new()
+            .name(format!("duchess-async-jvm-{i}"))
+            .spawn(move || {
+                let attached = Jvm::attach_thread_permanently().map_err(|e| format!("{e:?}"));
+                let ok = attached.is_ok();
+                let _ = ready_sender.send(attached);
+                if !ok {
+                    return;
+                }
+
+                loop {
+                    // Only hold the lock while waiting for a job, not while running it.
+                    let job = receiver.lock().unwrap().recv();
+                    match job {
+                        Ok(job) => job(),
+                        Err(_) => return,
+                    }
+                }
+            })
+            .map_err(|e| format!("unable to spawn JVM thread: {e}"))?;
+    }
+    drop(ready_sender);
+
+    for attached in ready_receiver.iter().take(threads) {
+        attached.map_err(|e| format!("unable to attach JVM thread: {e}"))?;
+    }
+    Ok(sender)
+}
+
+fn panic_message(panic: Box<dyn Any + Send + 'static>) -> String {
+    let message = if let Some(s) = panic.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = panic.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "<unknown panic payload>".to_string()
+    };
+    format!("operation on JVM thread pool panicked: {message}")
+}
@@ -0,0 +1,50 @@
+//! Conversions between Rust scalars and the boxed primitive types of `java.lang`,
+//! so that e.g. an `i32` can be passed where a `java.lang.Integer` is expected.
+
+use crate::{into_rust::IntoRust, java, ops::IntoJava, to_java::ToJavaImpl, Jvm, JvmOp, Local};
+
+macro_rules! boxed_scalar {
+    ($($rust:ty => $java:ident, $unbox:ident;)*) => {
+        $(
+            /// Boxes the value with `valueOf`.
+            impl IntoJava<java::lang::$java> for $rust {
+                type Output<'jvm> = Local<'jvm, java::lang::$java>;
+
+                fn into_java<'jvm>(
+                    self,
+                    jvm: &mut Jvm<'jvm>,
+                ) -> crate::Result<'jvm, Self::Output<'jvm>> {
+                    java::lang::$java::value_of(self).execute_with(jvm)
+                }
+            }
+
+            /// Boxes the value with `valueOf`, e.g. for the result of a
+            /// [`callback::function`][crate::callback::function].
+            impl ToJavaImpl<java::lang::$java> for $rust {
+                fn to_java_impl<'jvm>(
+                    rust: &Self,
+                    jvm: &mut Jvm<'jvm>,
+                ) -> crate::Result<'jvm, Option<Local<'jvm, java::lang::$java>>> {
+                    java::lang::$java::value_of(*rust).execute_with(jvm).map(Some)
+                }
+            }
+
+            impl IntoRust<$rust> for &java::lang::$java {
+                fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, $rust> {
+                    self.$unbox().execute_with(jvm)
+                }
+            }
+        )*
+    };
+}
+
+boxed_scalar! {
+    bool => Boolean, boolean_value;
+    u16 => Character, char_value; // java char
+    i8 => Byte, byte_value;
+    i16 => Short, short_value;
+    i32 => Integer, int_value;
+    i64 => Long, long_value;
+    f32 => Float, float_value;
+    f64 => Double, double_value;
+}
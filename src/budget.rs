@@ -0,0 +1,164 @@
+//! Accounting for [`Global`](crate::Global) references, enabled by the `global-ref-budget` feature.
+//!
+//! JVMs limit the number of global references that can be live at once, and exceeding the limit
+//! aborts the process with little explanation. With this feature enabled, every `Global` is
+//! counted against a *category*: either one given explicitly with
+//! [`Global::new_tagged`](crate::Global::new_tagged), or the one set on the current thread with
+//! [`enter`] (and [`UNTAGGED`] otherwise). A soft limit can be configured with [`set_soft_limit`]
+//! to find out which categories are responsible well before the JVM's own limit is reached.
+
+use std::{
+    cell::Cell,
+    collections::BTreeMap,
+    marker::PhantomData,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+/// The category of globals created outside of any [`enter`] scope.
+pub const UNTAGGED: &str = "untagged";
+
+/// How many categories are included in a [`BudgetReport`].
+const TOP_CATEGORIES: usize = 5;
+
+thread_local! {
+    static CURRENT_CATEGORY: Cell<&'static str> = const { Cell::new(UNTAGGED) };
+}
+
+type Callback = Arc<dyn Fn(&BudgetReport) + Send + Sync>;
+
+struct Budget {
+    counts: BTreeMap<&'static str, usize>,
+    total: usize,
+    soft_limit: Option<(usize, Callback)>,
+}
+
+static BUDGET: Mutex<Budget> = Mutex::new(Budget {
+    counts: BTreeMap::new(),
+    total: 0,
+    soft_limit: None,
+});
+
+fn budget() -> MutexGuard<'static, Budget> {
+    // The counts are updated atomically under the lock, so they remain consistent even if a
+    // callback panicked while another thread held it.
+    BUDGET.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Passed to the callback of [`set_soft_limit`] when the limit is reached.
+#[derive(Clone, Debug)]
+pub struct BudgetReport {
+    /// The configured soft limit.
+    pub limit: usize,
+
+    /// Number of live global references created by duchess.
+    pub total: usize,
+
+    /// The categories with the most live global references, largest first.
+    pub top_categories: Vec<(&'static str, usize)>,
+}
+
+/// Sets a soft limit on the number of live global references. Whenever the total reaches `limit`,
+/// a warning is logged and `on_limit` is invoked with the largest categories. It is invoked again
+/// only after the total has dropped below `limit` and reached it once more.
+///
+/// Replaces any previously configured limit.
+pub fn set_soft_limit(limit: usize, on_limit: impl Fn(&BudgetReport) + Send + Sync + 'static) {
+    budget().soft_limit = Some((limit, Arc::new(on_limit)));
+}
+
+/// Removes the limit configured by [`set_soft_limit`].
+pub fn clear_soft_limit() {
+    budget().soft_limit = None;
+}
+
+/// Number of live global references created by duchess.
+pub fn total() -> usize {
+    budget().total
+}
+
+/// Number of live global references in each category, largest first.
+pub fn counts() -> Vec<(&'static str, usize)> {
+    sorted_counts(&budget())
+}
+
+fn sorted_counts(budget: &Budget) -> Vec<(&'static str, usize)> {
+    let mut counts: Vec<_> = budget.counts.iter().map(|(&c, &n)| (c, n)).collect();
+    counts.sort_by(|(c1, n1), (c2, n2)| n2.cmp(n1).then(c1.cmp(c2)));
+    counts
+}
+
+/// Attributes global references created on this thread to `category` until the returned guard
+/// is dropped, at which point the previous category is restored.
+pub fn enter(category: &'static str) -> CategoryGuard {
+    let previous = CURRENT_CATEGORY.with(|c| c.replace(category));
+    CategoryGuard {
+        previous,
+        _not_send: PhantomData,
+    }
+}
+
+/// Returned by [`enter`].
+#[must_use = "the category only applies while the guard is live"]
+pub struct CategoryGuard {
+    previous: &'static str,
+    // The guard restores the category of the thread that created it.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for CategoryGuard {
+    fn drop(&mut self) {
+        CURRENT_CATEGORY.with(|c| c.set(self.previous));
+    }
+}
+
+/// The category that a global reference created now on this thread belongs to.
+pub(crate) fn current_category() -> &'static str {
+    CURRENT_CATEGORY.with(|c| c.get())
+}
+
+/// Counts a new global reference against `category`, returning the category to later [`release`].
+pub(crate) fn acquire(category: &'static str) -> &'static str {
+    let reached = {
+        let mut budget = budget();
+        *budget.counts.entry(category).or_default() += 1;
+        budget.total += 1;
+        match &budget.soft_limit {
+            Some((limit, on_limit)) if budget.total == *limit => {
+                let mut top_categories = sorted_counts(&budget);
+                top_categories.truncate(TOP_CATEGORIES);
+                let report = BudgetReport {
+                    limit: *limit,
+                    total: budget.total,
+                    top_categories,
+                };
+                Some((report, on_limit.clone()))
+            }
+            _ => None,
+        }
+    };
+
+    // Invoke the callback without holding the lock, so that it may inspect the budget
+    // (or create global references of its own).
+    if let Some((report, on_limit)) = reached {
+        tracing::warn!(
+            limit = report.limit,
+            top_categories = ?report.top_categories,
+            "soft limit on global references reached"
+        );
+        on_limit(&report);
+    }
+
+    category
+}
+
+/// Releases a global reference counted by [`acquire`].
+pub(crate) fn release(category: &'static str) {
+    let mut budget = budget();
+    if let Some(count) = budget.counts.get_mut(category) {
+        *count -= 1;
+        if *count == 0 {
+            budget.counts.remove(category);
+        }
+    }
+    budget.total -= 1;
+}
@@ -0,0 +1,78 @@
+//! Helpers for build scripts, enabled by the `build` feature.
+//!
+//! The classpath built up with [`add_classpath_entry`] and [`add_jar`] is passed to the compiler
+//! as the `DUCHESS_CLASSPATH` environment variable, which `java_package!` uses (in preference to
+//! `CLASSPATH`) to find the classes it reflects. The environment of a build script's crate is only
+//! set at compile time, so use `env!("DUCHESS_CLASSPATH")` to pass the same classpath to the JVM
+//! at runtime.
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::Mutex,
+};
+
+static CLASSPATH: Mutex<Vec<PathBuf>> = Mutex::new(vec![]);
+
+/// Adds a directory of class files (or a JAR) to the classpath of the crate being built.
+pub fn add_classpath_entry(path: impl AsRef<Path>) {
+    let mut entries = CLASSPATH.lock().unwrap_or_else(|e| e.into_inner());
+    entries.push(path.as_ref().to_path_buf());
+
+    // Cargo uses the last value printed for a variable, so print all entries every time.
+    let classpath = join(&entries);
+    println!("cargo:rustc-env=DUCHESS_CLASSPATH={classpath}");
+}
+
+/// Adds a JAR to the classpath of the crate being built, see [`add_classpath_entry`].
+pub fn add_jar(path: impl AsRef<Path>) {
+    add_classpath_entry(path)
+}
+
+/// The classpath built so far, with entries separated by the platform path separator.
+pub fn classpath() -> String {
+    join(&CLASSPATH.lock().unwrap_or_else(|e| e.into_inner()))
+}
+
+fn join(entries: &[PathBuf]) -> String {
+    let separator = if cfg!(windows) { ";" } else { ":" };
+    entries
+        .iter()
+        .map(|entry| entry.display().to_string())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// Finds the JDK to build against: `JAVA_HOME` if it is set, otherwise the JDK that
+/// `/usr/libexec/java_home` reports (on macOS) or that provides the `javac` on the `PATH`.
+pub fn find_java_home() -> io::Result<PathBuf> {
+    if let Some(java_home) = std::env::var_os("JAVA_HOME") {
+        return Ok(PathBuf::from(java_home));
+    }
+
+    if cfg!(target_os = "macos") {
+        let output = Command::new("/usr/libexec/java_home").output()?;
+        if output.status.success() {
+            let java_home = String::from_utf8_lossy(&output.stdout);
+            return Ok(PathBuf::from(java_home.trim()));
+        }
+    }
+
+    // `javac` is usually a symlink to `$JAVA_HOME/bin/javac`.
+    let javac = if cfg!(windows) { "javac.exe" } else { "javac" };
+    let path = std::env::var_os("PATH").unwrap_or_default();
+    for dir in std::env::split_paths(&path) {
+        let Ok(javac) = dir.join(javac).canonicalize() else {
+            continue;
+        };
+        if let Some(java_home) = javac.parent().and_then(Path::parent) {
+            return Ok(java_home.to_path_buf());
+        }
+    }
+
+    Err(io::Error::new(
+        io::ErrorKind::NotFound,
+        "unable to find a JDK: set `JAVA_HOME` or put `javac` on the `PATH`",
+    ))
+}
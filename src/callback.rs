@@ -0,0 +1,371 @@
+use std::ffi::{c_void, CStr};
+
+use jni_sys::{jclass, jlong, jobject, jvalue, JNINativeMethod};
+use once_cell::sync::OnceCell;
+
+use crate::{
+    cast::{JavaDowncast, Upcast},
+    error::check_exception,
+    find::{find_constructor, find_method},
+    java::{
+        lang::{Class, Object, Runnable, Throwable},
+        util::function::Function,
+    },
+    jvm::{native_function_returning_result, native_function_returning_unit, JavaObjectExt},
+    raw::{EnvPtr, HasEnvPtr, MethodPtr, ObjectPtr},
+    to_java::ToJavaImpl,
+    AsJRef, Error, Global, IntoRust, JavaObject, Jvm, Local,
+};
+
+/// The closure owned by a `duchess.RustRunnable`, whose address is stored in its `closure` field.
+type RunnableClosure = Box<dyn FnMut() + Send>;
+
+/// The closure owned by a `duchess.RustFunction`: the user's closure, wrapped to convert its
+/// argument from Java and its result to Java (as a new local reference, or null).
+type FunctionClosure = Box<
+    dyn for<'jvm> FnMut(&mut Jvm<'jvm>, Option<Local<'jvm, Object>>) -> crate::Result<'jvm, jobject>
+        + Send,
+>;
+
+/// A class that implements a Java functional interface by calling a Rust closure, whose address
+/// it keeps in a `long` field. It has a private constructor taking that address, a `destroy()`
+/// method that drops the closure, and the native methods `invoke` and `drop(long)`.
+///
+/// The class files are compiled from `callback/*.java` (with `javac --release 8`) and embedded
+/// in the crate. They are not on the classpath, so each is defined from its bytes the first
+/// time it is needed.
+struct CallbackClass {
+    name: &'static CStr,
+    class_file: &'static [u8],
+    native_methods: fn() -> [JNINativeMethod; 2],
+    class: OnceCell<Global<Class<Object>>>,
+    constructor: OnceCell<MethodPtr>,
+    destroy: OnceCell<MethodPtr>,
+}
+
+static RUST_RUNNABLE: CallbackClass = CallbackClass {
+    name: c"duchess/RustRunnable",
+    class_file: include_bytes!("callback/duchess/RustRunnable.class"),
+    native_methods: || {
+        [
+            JNINativeMethod {
+                name: c"invoke".as_ptr().cast_mut(),
+                signature: c"(J)V".as_ptr().cast_mut(),
+                fnPtr: invoke_runnable as *mut c_void,
+            },
+            JNINativeMethod {
+                name: c"drop".as_ptr().cast_mut(),
+                signature: c"(J)V".as_ptr().cast_mut(),
+                fnPtr: drop_runnable as *mut c_void,
+            },
+        ]
+    },
+    class: OnceCell::new(),
+    constructor: OnceCell::new(),
+    destroy: OnceCell::new(),
+};
+
+static RUST_FUNCTION: CallbackClass = CallbackClass {
+    name: c"duchess/RustFunction",
+    class_file: include_bytes!("callback/duchess/RustFunction.class"),
+    native_methods: || {
+        [
+            JNINativeMethod {
+                name: c"invoke".as_ptr().cast_mut(),
+                signature: c"(JLjava/lang/Object;)Ljava/lang/Object;"
+                    .as_ptr()
+                    .cast_mut(),
+                fnPtr: invoke_function as *mut c_void,
+            },
+            JNINativeMethod {
+                name: c"drop".as_ptr().cast_mut(),
+                signature: c"(J)V".as_ptr().cast_mut(),
+                fnPtr: drop_function as *mut c_void,
+            },
+        ]
+    },
+    class: OnceCell::new(),
+    constructor: OnceCell::new(),
+    destroy: OnceCell::new(),
+};
+
+/// Creates a Java `Runnable` whose `run()` method calls `f`.
+///
+/// The returned object is an instance of `duchess.RustRunnable`, a class that duchess defines in
+/// the JVM on first use. It owns `f` (boxed) until its `destroy()` method is called, from Java or
+/// with [`destroy`], which drops `f`; `run()` throws an `IllegalStateException` after that.
+///
+/// # Lifetime of the closure
+///
+/// Java code may hold on to the `Runnable`, and call it, for as long as it likes, so:
+///
+/// * `f` must be `'static`: it cannot borrow anything from the Rust stack.
+/// * `f` must be `Send`, since `run()` may be called from any Java thread. Calls never overlap
+///   (`run()` and `destroy()` are `synchronized`), so `f` need not be `Sync`. If `f` calls
+///   `run()` or `destroy()` on its own `Runnable`, that call throws an `IllegalStateException`.
+/// * `f` is only dropped by `destroy()`. If the `Runnable` is garbage collected without being
+///   destroyed, `f` is leaked.
+///
+/// Within `f`, duchess operations can be executed as usual, e.g. with [`Jvm::with`].
+/// If `f` panics, `run()` throws a `RuntimeException`.
+///
+/// ```ignore
+/// let task = Jvm::with(|jvm| {
+///     let task = duchess::callback::runnable(move || println!("called from Java"), jvm)?;
+///     Ok(jvm.global(&task))
+/// })?;
+/// ```
+pub fn runnable<'jvm>(
+    f: impl FnMut() + Send + 'static,
+    jvm: &mut Jvm<'jvm>,
+) -> crate::Result<'jvm, Local<'jvm, Runnable>> {
+    let closure: RunnableClosure = Box::new(f);
+    let closure = Box::into_raw(Box::new(closure));
+    RUST_RUNNABLE
+        .new_object(closure as jlong, jvm)
+        .inspect_err(|_| {
+            // The object was not created, so nothing else refers to the closure.
+            drop(unsafe { Box::from_raw(closure) });
+        })
+}
+
+/// Creates a Java `Function<T, R>` whose `apply(t)` method converts `t` to Rust, calls `f` with
+/// it, and converts the result to an `R`, which may be null (e.g. for `None`).
+///
+/// The returned object is an instance of `duchess.RustFunction`, which owns `f` in the same way
+/// as the `Runnable` returned by [`runnable`] does; see there for why `f` must be
+/// `Send + 'static`, when it is dropped, and why `f` cannot call its own `Function`.
+///
+/// `apply` throws a `RuntimeException` if `f` panics, or if it cannot call `f` because its
+/// argument is null, is not a `T` (Java generics being erased, it can be anything), or cannot
+/// be converted to `A`, or if the result cannot be converted to `R`. An exception thrown by
+/// Java code during these conversions is rethrown as is.
+///
+/// ```ignore
+/// let length: Local<Function<java::lang::String, java::lang::Integer>> =
+///     duchess::callback::function(|s: String| s.len() as i32, jvm)?;
+/// ```
+pub fn function<'jvm, T, R, A, B>(
+    mut f: impl FnMut(A) -> B + Send + 'static,
+    jvm: &mut Jvm<'jvm>,
+) -> crate::Result<'jvm, Local<'jvm, Function<T, R>>>
+where
+    T: Upcast<Object>,
+    R: Upcast<Object>,
+    for<'a> &'a T: IntoRust<A>,
+    B: ToJavaImpl<R>,
+    A: 'static,
+    B: 'static,
+{
+    let closure: FunctionClosure = Box::new(move |jvm, argument| {
+        let argument = argument.ok_or(Error::NullDeref)?;
+        let Ok(argument) = argument.try_downcast::<T>()? else {
+            return Err(Error::JvmInternal(
+                "the argument of `duchess.RustFunction.apply` has the wrong class".to_string(),
+            ));
+        };
+        let result = f(argument.into_rust(jvm)?);
+        let result = <B as ToJavaImpl<R>>::to_java_impl(&result, jvm)?;
+        // SAFETY: the reference is returned to the JVM, which then owns it.
+        Ok(result.map_or(std::ptr::null_mut(), |r| unsafe { r.into_raw() }.as_ptr()))
+    });
+    let closure = Box::into_raw(Box::new(closure));
+    RUST_FUNCTION
+        .new_object(closure as jlong, jvm)
+        .inspect_err(|_| {
+            // The object was not created, so nothing else refers to the closure.
+            drop(unsafe { Box::from_raw(closure) });
+        })
+}
+
+/// Calls the `destroy()` method of a callback created by [`runnable`] or [`function`], which
+/// drops its closure. Does nothing if `callback` was already destroyed, or is some other object.
+pub fn destroy<'jvm>(
+    callback: &impl AsJRef<Object>,
+    jvm: &mut Jvm<'jvm>,
+) -> crate::Result<'jvm, ()> {
+    const METHOD_NAME: &CStr = c"destroy";
+    const METHOD_DESCRIPTOR: &CStr = c"()V";
+
+    let callback = callback.as_jref()?;
+    for callback_class in [&RUST_RUNNABLE, &RUST_FUNCTION] {
+        // If the class was never defined, `callback` cannot be one of its instances.
+        let Some(class) = callback_class.class.get() else {
+            continue;
+        };
+
+        let env = jvm.env();
+        let is_instance = unsafe {
+            env.invoke(
+                |env| env.IsInstanceOf,
+                |env, f| f(env, callback.as_raw().as_ptr(), class.as_raw().as_ptr()),
+            )
+        };
+        if is_instance == jni_sys::JNI_FALSE {
+            continue;
+        }
+
+        let method = callback_class
+            .destroy
+            .get_or_try_init(|| find_method(jvm, class, METHOD_NAME, METHOD_DESCRIPTOR, false))?;
+        unsafe {
+            env.invoke(
+                |env| env.CallVoidMethodA,
+                |env, f| {
+                    f(
+                        env,
+                        callback.as_raw().as_ptr(),
+                        method.as_ptr(),
+                        std::ptr::null(),
+                    )
+                },
+            )
+        };
+        return check_exception(jvm);
+    }
+    Ok(())
+}
+
+/// Defines the class `name` (in JNI form) in the JVM from `class_file`, with the bootstrap class
+/// loader. Fails if it is already defined, so callers define each class once.
+pub(crate) fn define_class<'jvm>(
+    jvm: &mut Jvm<'jvm>,
+    name: &CStr,
+    class_file: &[u8],
+) -> crate::Result<'jvm, Local<'jvm, Class<Object>>> {
+    let env = jvm.env();
+    let class = unsafe {
+        env.invoke(
+            |env| env.DefineClass,
+            |env, f| {
+                f(
+                    env,
+                    name.as_ptr(),
+                    std::ptr::null_mut(),
+                    class_file.as_ptr().cast(),
+                    class_file.len() as i32,
+                )
+            },
+        )
+    };
+    let Some(class) = ObjectPtr::new(class) else {
+        check_exception(jvm)?;
+        return Err(crate::Error::JvmInternal(format!(
+            "failed to define class `{}`",
+            name.to_string_lossy()
+        )));
+    };
+    // SAFETY: `DefineClass` returns a local reference to the new class object.
+    Ok(unsafe { Local::from_raw(env, class) })
+}
+
+impl CallbackClass {
+    /// Defines this class in the JVM (with the bootstrap class loader) and registers its native
+    /// methods, the first time it is called.
+    fn load<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Local<'jvm, Class<Object>>> {
+        let global = self
+            .class
+            .get_or_try_init::<_, crate::Error<Local<Throwable>>>(|| {
+                let class = define_class(jvm, self.name, self.class_file)?;
+                let env = jvm.env();
+                let native_methods = (self.native_methods)();
+                unsafe { env.register_native_methods(class.as_raw(), &native_methods)? };
+
+                Ok(jvm.global(&class))
+            })?;
+        Ok(jvm.local(global))
+    }
+
+    /// Creates an instance of this class that owns `closure`, as a `J`, which the class must
+    /// implement. If this fails, the caller still owns `closure`.
+    fn new_object<'jvm, J: JavaObject>(
+        &self,
+        closure: jlong,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Local<'jvm, J>> {
+        const CONSTRUCTOR_DESCRIPTOR: &CStr = c"(J)V";
+
+        let class = self.load(jvm)?;
+        let constructor = self
+            .constructor
+            .get_or_try_init(|| find_constructor(jvm, &class, CONSTRUCTOR_DESCRIPTOR))?;
+
+        let env = jvm.env();
+        let obj = unsafe {
+            env.invoke(
+                |env| env.NewObjectA,
+                |env, f| {
+                    f(
+                        env,
+                        class.as_raw().as_ptr(),
+                        constructor.as_ptr(),
+                        [jvalue { j: closure }].as_ptr(),
+                    )
+                },
+            )
+        };
+
+        if let Some(obj) = ObjectPtr::new(obj) {
+            // SAFETY: `obj` is a new local reference to an instance of this class, which
+            // implements `J`.
+            Ok(unsafe { Local::from_raw(env, obj) })
+        } else {
+            check_exception(jvm)?;
+            Err(crate::Error::JvmInternal(format!(
+                "failed to create new `{}`",
+                self.name.to_string_lossy()
+            )))
+        }
+    }
+}
+
+/// The native method `RustRunnable.invoke(long)`, called by `run()`.
+extern "system" fn invoke_runnable(env: EnvPtr<'_>, _class: jclass, closure: jlong) {
+    // SAFETY: This is called by the JVM, and `closure` is the address of a live `RunnableClosure`
+    // that no one else is using: `RustRunnable` clears its field (in `destroy()`) before the
+    // closure is dropped, `run()` and `destroy()` are synchronized, and both throw instead of
+    // reaching here (or `drop_runnable`) while the closure is running.
+    unsafe {
+        native_function_returning_unit(env, || {
+            let closure = &mut *(closure as *mut RunnableClosure);
+            closure()
+        })
+    }
+}
+
+/// The native method `RustRunnable.drop(long)`, called by `destroy()`.
+extern "system" fn drop_runnable(env: EnvPtr<'_>, _class: jclass, closure: jlong) {
+    // SAFETY: As for `invoke_runnable`; `destroy()` has cleared the field, so the closure is
+    // never used again.
+    unsafe {
+        native_function_returning_unit(env, || drop(Box::from_raw(closure as *mut RunnableClosure)))
+    }
+}
+
+/// The native method `RustFunction.invoke(long, Object)`, called by `apply(Object)`.
+extern "system" fn invoke_function(
+    env: EnvPtr<'_>,
+    _class: jclass,
+    closure: jlong,
+    argument: jobject,
+) -> jobject {
+    // SAFETY: As for `invoke_runnable`, for a `FunctionClosure`. `argument` is null or a local
+    // reference passed by the JVM.
+    unsafe {
+        native_function_returning_result(env, |jvm| {
+            let closure = &mut *(closure as *mut FunctionClosure);
+            let argument: Option<&Object> =
+                ObjectPtr::new(argument).map(|a| JavaObjectExt::from_raw(a));
+            let argument = argument.map(|argument| jvm.local(argument));
+            closure(jvm, argument)
+        })
+    }
+}
+
+/// The native method `RustFunction.drop(long)`, called by `destroy()`.
+extern "system" fn drop_function(env: EnvPtr<'_>, _class: jclass, closure: jlong) {
+    // SAFETY: As for `drop_runnable`, for a `FunctionClosure`.
+    unsafe {
+        native_function_returning_unit(env, || drop(Box::from_raw(closure as *mut FunctionClosure)))
+    }
+}
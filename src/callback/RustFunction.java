@@ -0,0 +1,52 @@
+package duchess;
+
+import java.util.function.Function;
+
+/**
+ * A {@link Function} that calls a Rust closure, created by {@code duchess::callback::function}.
+ * The class is defined by duchess at runtime, from the class file embedded in the crate.
+ *
+ * <p>The object owns the closure until {@link #destroy()} drops it; {@link #apply(Object)}
+ * throws an {@link IllegalStateException} after that. Both methods are {@code synchronized},
+ * so the closure is never called concurrently. As that lock is reentrant, both also throw an
+ * {@link IllegalStateException} if called by the closure itself, which would otherwise alias
+ * or free the closure while it runs.
+ */
+public final class RustFunction implements Function<Object, Object> {
+    private long closure;
+    private boolean running;
+
+    private RustFunction(long closure) {
+        this.closure = closure;
+    }
+
+    public synchronized Object apply(Object argument) {
+        if (closure == 0) {
+            throw new IllegalStateException("the Rust closure of this Function has been destroyed");
+        }
+        if (running) {
+            throw new IllegalStateException("the Rust closure of this Function is already running");
+        }
+        running = true;
+        try {
+            return invoke(closure, argument);
+        } finally {
+            running = false;
+        }
+    }
+
+    public synchronized void destroy() {
+        if (running) {
+            throw new IllegalStateException("cannot destroy a Function while its Rust closure is running");
+        }
+        if (closure != 0) {
+            long closure = this.closure;
+            this.closure = 0;
+            drop(closure);
+        }
+    }
+
+    private static native Object invoke(long closure, Object argument);
+
+    private static native void drop(long closure);
+}
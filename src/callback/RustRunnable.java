@@ -0,0 +1,50 @@
+package duchess;
+
+/**
+ * A {@link Runnable} that calls a Rust closure, created by {@code duchess::callback::runnable}.
+ * The class is defined by duchess at runtime, from the class file embedded in the crate.
+ *
+ * <p>The object owns the closure until {@link #destroy()} drops it; {@link #run()} throws an
+ * {@link IllegalStateException} after that. Both methods are {@code synchronized}, so the
+ * closure is never called concurrently. As that lock is reentrant, both also throw an
+ * {@link IllegalStateException} if called by the closure itself, which would otherwise alias
+ * or free the closure while it runs.
+ */
+public final class RustRunnable implements Runnable {
+    private long closure;
+    private boolean running;
+
+    private RustRunnable(long closure) {
+        this.closure = closure;
+    }
+
+    public synchronized void run() {
+        if (closure == 0) {
+            throw new IllegalStateException("the Rust closure of this Runnable has been destroyed");
+        }
+        if (running) {
+            throw new IllegalStateException("the Rust closure of this Runnable is already running");
+        }
+        running = true;
+        try {
+            invoke(closure);
+        } finally {
+            running = false;
+        }
+    }
+
+    public synchronized void destroy() {
+        if (running) {
+            throw new IllegalStateException("cannot destroy a Runnable while its Rust closure is running");
+        }
+        if (closure != 0) {
+            long closure = this.closure;
+            this.closure = 0;
+            drop(closure);
+        }
+    }
+
+    private static native void invoke(long closure);
+
+    private static native void drop(long closure);
+}
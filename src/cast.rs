@@ -2,7 +2,10 @@ use std::marker::PhantomData;
 
 use crate::Jvm;
 use crate::{
-    jvm::JavaObjectExt, raw::HasEnvPtr, refs::AsJRef, JavaObject, JvmOp, Local, TryJDeref,
+    jvm::{JavaObjectExt, JavaSealed},
+    raw::HasEnvPtr,
+    refs::AsJRef,
+    JavaObject, JvmOp, Local, TryJDeref,
 };
 
 /// A trait to represent safe upcast operations for a [`JavaObject`].
@@ -116,3 +119,126 @@ where
         Ok(jvm.local(instance.as_jref()?))
     }
 }
+
+#[derive_where::derive_where(Copy, Clone)]
+pub struct InstanceOf<J: JvmOp, To> {
+    op: J,
+    _marker: PhantomData<To>,
+}
+
+impl<J, To> InstanceOf<J, To>
+where
+    J: JvmOp,
+    for<'jvm> J::Output<'jvm>: TryJDeref,
+    To: JavaObject,
+{
+    pub(crate) fn new(op: J) -> Self {
+        Self {
+            op,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<J, To> JvmOp for InstanceOf<J, To>
+where
+    J: JvmOp,
+    for<'jvm> J::Output<'jvm>: TryJDeref,
+    To: JavaObject,
+{
+    type Output<'jvm> = bool;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        let instance = self.op.execute_with(jvm)?;
+        let instance_raw = instance.try_jderef()?.as_raw();
+
+        let class = To::class(jvm)?;
+        let class_raw = class.as_raw();
+
+        let env = jvm.env();
+        let is_inst = unsafe {
+            env.invoke(
+                |env| env.IsInstanceOf,
+                |env, f| f(env, instance_raw.as_ptr(), class_raw.as_ptr()),
+            ) == jni_sys::JNI_TRUE
+        };
+
+        Ok(is_inst)
+    }
+}
+
+#[derive_where::derive_where(Copy, Clone)]
+pub struct Seal<J: JvmOp, T> {
+    op: J,
+    _marker: PhantomData<T>,
+}
+
+impl<J, T> Seal<J, T>
+where
+    J: JvmOp,
+    for<'jvm> J::Output<'jvm>: AsJRef<T>,
+    T: JavaSealed,
+{
+    pub(crate) fn new(op: J) -> Self {
+        Self {
+            op,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<J, T> JvmOp for Seal<J, T>
+where
+    J: JvmOp,
+    for<'jvm> J::Output<'jvm>: AsJRef<T>,
+    T: JavaSealed,
+{
+    type Output<'jvm> = T::Sealed<'jvm>;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        let instance = self.op.execute_with(jvm)?;
+        let local = jvm.local(instance.as_jref()?);
+        T::seal(local)
+    }
+}
+
+/// Checked downcasts of object references, the counterpart to [`Upcast`].
+pub trait JavaDowncast<'jvm, T: JavaObject>: Sized {
+    /// Downcasts this reference to `D` if the object is an instance of `D`,
+    /// otherwise returns the original reference as `Err`.
+    /// Fails only if the class of `D` cannot be loaded.
+    fn try_downcast<D>(self) -> crate::Result<'jvm, Result<Local<'jvm, D>, Self>>
+    where
+        D: Upcast<T>;
+}
+
+impl<'jvm, T> JavaDowncast<'jvm, T> for Local<'jvm, T>
+where
+    T: JavaObject,
+{
+    fn try_downcast<D>(self) -> crate::Result<'jvm, Result<Local<'jvm, D>, Self>>
+    where
+        D: Upcast<T>,
+    {
+        let env = self.env();
+        // SAFETY: `self` is a local reference from the env, so that env is live on this thread.
+        let mut jvm = unsafe { Jvm::from_env(env) };
+        let class = D::class(&mut jvm)?;
+        let class_raw = class.as_raw();
+
+        let is_inst = unsafe {
+            env.invoke(
+                |env| env.IsInstanceOf,
+                |env, f| f(env, self.as_raw().as_ptr(), class_raw.as_ptr()),
+            ) == jni_sys::JNI_TRUE
+        };
+
+        if is_inst {
+            // SAFETY: just shown that the object is an instance of `D`;
+            // ownership of the local reference is transferred to the new `Local`.
+            Ok(Ok(unsafe { Local::from_raw(env, self.into_raw()) }))
+        } else {
+            Ok(Err(self))
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use std::marker::PhantomData;
+
+use crate::{
+    java::lang::Class, jvm::JavaView, plumbing::FromRef, raw::HasEnvPtr, JavaObject, Jvm, JvmOp,
+    Local,
+};
+
+/// The operation returned by [`JavaObject::class_object`].
+#[derive_where::derive_where(Copy, Clone)]
+pub struct ClassObject<T> {
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T: JavaObject> ClassObject<T> {
+    pub(crate) fn new() -> Self {
+        ClassObject {
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: JavaObject> JvmOp for ClassObject<T> {
+    type Output<'jvm> = Local<'jvm, Class<T>>;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        let class = T::class(jvm)?;
+        // SAFETY: `class` is a local reference to the class object of `T`, so it is a `Class<T>`.
+        Ok(unsafe { Local::from_raw(jvm.env(), class.into_raw()) })
+    }
+}
+
+impl<T: JavaObject> std::ops::Deref for ClassObject<T> {
+    type Target = <Class<T> as JavaView>::OfOp<Self>;
+
+    fn deref(&self) -> &Self::Target {
+        <Self::Target as FromRef<_>>::from_ref(self)
+    }
+}
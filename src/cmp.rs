@@ -0,0 +1,135 @@
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+use crate::{
+    java::lang::{ComparableTrait, Object},
+    jvm::JavaObjectExt,
+    raw::HasEnvPtr,
+    ref_::{is_same_object, java_equals, java_hash_code},
+    AsJRef, IntoJava, JDeref, JavaObject, Jvm, JvmOp,
+};
+
+/// Equality of Java objects, for Java objects and `Local` or `Global` references to them.
+pub trait JavaEqExt {
+    /// Compares this object with `other` using Java's `equals`.
+    ///
+    /// ```ignore
+    /// assert!(a.java_eq(&b).execute()?);
+    /// ```
+    fn java_eq<'a>(
+        &'a self,
+        other: impl IntoJava<Object> + 'a,
+    ) -> impl for<'jvm> JvmOp<Output<'jvm> = bool> + 'a;
+
+    /// Checks whether `other` is the same object as this one (Java's `==`), which is not
+    /// the case for distinct objects that are `equals` to each other. `other` may be null.
+    fn reference_eq<O>(&self, other: O) -> ReferenceEq<&Self, O>
+    where
+        Self: Sized,
+        O: JvmOp;
+}
+
+impl<T> JavaEqExt for T
+where
+    for<'a> &'a T: IntoJava<Object>,
+{
+    fn java_eq<'a>(
+        &'a self,
+        other: impl IntoJava<Object> + 'a,
+    ) -> impl for<'jvm> JvmOp<Output<'jvm> = bool> + 'a {
+        Object::equals(self, other)
+    }
+
+    fn reference_eq<O>(&self, other: O) -> ReferenceEq<&Self, O>
+    where
+        O: JvmOp,
+    {
+        ReferenceEq { a: self, b: other }
+    }
+}
+
+/// The operation returned by [`JavaEqExt::reference_eq`].
+#[derive(Copy, Clone)]
+pub struct ReferenceEq<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A, B> JvmOp for ReferenceEq<A, B>
+where
+    A: IntoJava<Object>,
+    B: IntoJava<Object>,
+{
+    type Output<'jvm> = bool;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        let a = self.a.into_java(jvm)?;
+        let b = self.b.into_java(jvm)?;
+        Ok(match (a.as_jref(), b.as_jref()) {
+            (Ok(a), Ok(b)) => is_same_object(jvm.env(), a.as_raw(), b.as_raw()),
+            (Err(_), Err(_)) => true,
+            _ => false,
+        })
+    }
+}
+
+/// Comparison of Java objects that implement `java.lang.Comparable<T>`, such as `String`,
+/// `Integer`, or any reflected class that implements it.
+pub trait JavaComparableExt<T: JavaObject> {
+    /// Compares this object with `other` using `compareTo`, producing an [`Ordering`].
+    /// For instance, to sort a `Vec<Global<BigDecimal>>`:
+    ///
+    /// ```ignore
+    /// values.sort_by(|a, b| a.jcmp(b).execute().expect("compareTo threw"));
+    /// ```
+    fn jcmp<'a>(
+        &'a self,
+        other: impl IntoJava<T> + 'a,
+    ) -> impl for<'jvm> JvmOp<Output<'jvm> = Ordering> + 'a;
+}
+
+impl<C, T> JavaComparableExt<T> for C
+where
+    C: ComparableTrait<T>,
+    T: JavaObject,
+{
+    fn jcmp<'a>(
+        &'a self,
+        other: impl IntoJava<T> + 'a,
+    ) -> impl for<'jvm> JvmOp<Output<'jvm> = Ordering> + 'a {
+        self.compare_to(other).map(|n: i32| n.cmp(&0))
+    }
+}
+
+/// Wraps a reference to a Java object so that `Hash`, `PartialEq`, and `Eq` use the object's
+/// Java `hashCode()` and `equals()`, e.g. to key a `HashMap` with `Local` references to classes
+/// that do not override them.
+///
+/// The calls go through the current thread's JNI environment; a thread that is not attached to
+/// the JVM is attached for the duration of each call. If `hashCode()` or `equals()` throws,
+/// these fall back exactly like [`Global`][crate::Global]'s impls.
+#[derive(Copy, Clone, Debug)]
+pub struct ByJavaHash<R>(pub R);
+
+impl<R: JDeref> ByJavaHash<R> {
+    fn object(&self) -> &Object {
+        // SAFETY: every Java object is an instance of `java.lang.Object`.
+        unsafe { JavaObjectExt::from_raw(self.0.jderef().as_raw()) }
+    }
+}
+
+impl<R: JDeref> Hash for ByJavaHash<R> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        java_hash_code(self.object()).hash(state)
+    }
+}
+
+impl<R: JDeref> PartialEq for ByJavaHash<R> {
+    fn eq(&self, other: &Self) -> bool {
+        java_equals(self.object(), other.object())
+    }
+}
+
+impl<R: JDeref> Eq for ByJavaHash<R> {}
@@ -0,0 +1,34 @@
+use crate::{java, prelude::*, Error, JavaObject, Jvm, Local};
+
+#[derive_where::derive_where(Copy, Clone)]
+pub struct Collect<J: JvmOp> {
+    j: J,
+}
+
+impl<J, T> Collect<J>
+where
+    J: for<'jvm> JvmOp<Output<'jvm> = Local<'jvm, java::util::Iterator<T>>>,
+    T: JavaObject,
+{
+    pub(crate) fn new(j: J) -> Collect<J> {
+        Collect { j }
+    }
+}
+
+impl<J, T> JvmOp for Collect<J>
+where
+    J: for<'jvm> JvmOp<Output<'jvm> = Local<'jvm, java::util::Iterator<T>>>,
+    T: JavaObject,
+{
+    type Output<'jvm> = Vec<Local<'jvm, T>>;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        let iterator = self.j.execute_with(jvm)?;
+        let mut elements = vec![];
+        while iterator.has_next().execute_with(jvm)? {
+            let element = iterator.next().execute_with(jvm)?;
+            elements.push(element.ok_or(Error::NullDeref)?);
+        }
+        Ok(elements)
+    }
+}
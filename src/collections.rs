@@ -0,0 +1,126 @@
+#[cfg(feature = "collections")]
+use std::{collections::HashMap, hash::Hash};
+
+#[cfg(feature = "collections")]
+use crate::java::util::Map;
+use crate::{
+    cast::Upcast,
+    java::{self, util::List},
+    to_java::ToJavaImpl,
+    AsJRef, IntoRust, JavaObject, Jvm, JvmOp, Local,
+};
+
+/// Converts each element of a Java `List` to Rust, in order, and collects them into a `Vec`.
+///
+/// The vector is allocated up front with the `size()` of the list, and the elements are read
+/// with `get(i)`; each element's local reference is released once it has been converted.
+/// A `null` element gives [`Error::NullDeref`](crate::Error::NullDeref).
+///
+/// ```ignore
+/// let names: Vec<String> = Jvm::with(|jvm| list_to_vec(&list, jvm))?;
+/// ```
+pub fn list_to_vec<'jvm, L, T, R>(list: &L, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Vec<R>>
+where
+    L: AsJRef<List<T>>,
+    T: JavaObject,
+    for<'l> Local<'l, T>: IntoRust<R>,
+{
+    let list = list.as_jref()?;
+    let size = list.size().execute_with(jvm)?;
+    let mut vec = Vec::with_capacity(size.try_into().unwrap_or(0));
+    for index in 0..size {
+        let element = list.get(index).assert_not_null().execute_with(jvm)?;
+        vec.push(element.into_rust(jvm)?);
+    }
+    Ok(vec)
+}
+
+/// Converts each element of `elements` to Java, in order, and adds them to a new `ArrayList`,
+/// which is created with a capacity for all of them.
+///
+/// ```ignore
+/// let list = vec_to_list::<_, java::lang::String>(&names, jvm)?;
+/// ```
+pub fn vec_to_list<'jvm, E, J>(
+    elements: &[E],
+    jvm: &mut Jvm<'jvm>,
+) -> crate::Result<'jvm, Local<'jvm, java::util::ArrayList<J>>>
+where
+    E: ToJavaImpl<J>,
+    J: Upcast<java::lang::Object> + Upcast<J>,
+{
+    let capacity = i32::try_from(elements.len()).unwrap_or(i32::MAX);
+    let list: Local<'jvm, java::util::ArrayList<J>> =
+        java::util::ArrayList::new_from_i32(capacity).execute_with(jvm)?;
+    for element in elements {
+        let element = E::to_java_impl(element, jvm)?;
+        list.add(&element).execute_with(jvm)?;
+    }
+    Ok(list)
+}
+
+/// Converts each entry of a Java `Map` to Rust and collects them into a `HashMap`.
+///
+/// The entries are read from `entrySet().iterator()` with `getKey()` and `getValue()`;
+/// the local references of each entry are released once it has been converted.
+/// A `null` key or value gives [`Error::NullDeref`](crate::Error::NullDeref); to convert maps
+/// that may contain `null`, use [`JavaMapExt::for_each_rust`](crate::prelude::JavaMapExt::for_each_rust).
+///
+/// ```ignore
+/// let ages: HashMap<String, i32> = Jvm::with(|jvm| map_to_hashmap(&map, jvm))?;
+/// ```
+#[cfg(feature = "collections")]
+pub fn map_to_hashmap<'jvm, M, K, V, RK, RV>(
+    map: &M,
+    jvm: &mut Jvm<'jvm>,
+) -> crate::Result<'jvm, HashMap<RK, RV>>
+where
+    M: AsJRef<Map<K, V>>,
+    K: JavaObject,
+    V: JavaObject,
+    RK: Eq + Hash,
+    for<'l> Local<'l, K>: IntoRust<RK>,
+    for<'l> Local<'l, V>: IntoRust<RV>,
+{
+    let map = map.as_jref()?;
+    let size = map.size().execute_with(jvm)?;
+    let mut hashmap = HashMap::with_capacity(size.try_into().unwrap_or(0));
+    let entries = map
+        .entry_set()
+        .iterator()
+        .assert_not_null()
+        .execute_with(jvm)?;
+    while entries.has_next().execute_with(jvm)? {
+        let entry = entries.next().assert_not_null().execute_with(jvm)?;
+        let key = entry.get_key().assert_not_null().execute_with(jvm)?;
+        let value = entry.get_value().assert_not_null().execute_with(jvm)?;
+        hashmap.insert(key.into_rust(jvm)?, value.into_rust(jvm)?);
+    }
+    Ok(hashmap)
+}
+
+/// Converts each key and value of `entries` to Java and puts them into a new `java.util.HashMap`.
+///
+/// ```ignore
+/// let map = hashmap_to_map::<_, _, java::lang::String, java::lang::Integer, _>(&ages, jvm)?;
+/// ```
+#[cfg(feature = "collections")]
+pub fn hashmap_to_map<'jvm, K, V, JK, JV, S>(
+    entries: &HashMap<K, V, S>,
+    jvm: &mut Jvm<'jvm>,
+) -> crate::Result<'jvm, Local<'jvm, java::util::HashMap<JK, JV>>>
+where
+    K: ToJavaImpl<JK>,
+    V: ToJavaImpl<JV>,
+    JK: Upcast<java::lang::Object> + Upcast<JK>,
+    JV: Upcast<java::lang::Object> + Upcast<JV>,
+{
+    let map: Local<'jvm, java::util::HashMap<JK, JV>> =
+        java::util::HashMap::new().execute_with(jvm)?;
+    for (key, value) in entries {
+        let key = K::to_java_impl(key, jvm)?;
+        let value = V::to_java_impl(value, jvm)?;
+        map.put(&key, &value).execute_with(jvm)?;
+    }
+    Ok(map)
+}
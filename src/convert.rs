@@ -0,0 +1,52 @@
+use std::marker::PhantomData;
+
+use crate::{cast::Upcast, java, to_java::ToJavaImpl, IntoRust, JavaObject, Jvm, Local};
+
+/// Checks whether the Rust type `R` converts to and from the Java class `J`, for the classes
+/// that a `convert` directive maps to Rust types (see [`java_package!`][`crate::java_package`]).
+///
+/// The inherent items below are only found if their `where` clauses hold; otherwise, those of
+/// [`MissingConversion`] are used. The generated code asserts `TO_JAVA` and `TO_RUST` in a
+/// `const` item, so that a missing conversion is a single error naming the class and member
+/// that need it, rather than unsatisfied trait bounds in the generated code.
+pub struct Conversion<J, R>(PhantomData<(J, R)>);
+
+impl<J, R> Conversion<J, R>
+where
+    J: Upcast<java::lang::Object>,
+    R: ToJavaImpl<J>,
+{
+    pub const TO_JAVA: bool = true;
+}
+
+impl<J, R> Conversion<J, R>
+where
+    J: JavaObject,
+    for<'a> &'a J: IntoRust<R>,
+{
+    pub const TO_RUST: bool = true;
+
+    /// Converts the (possibly null) object returned by a member to `R`.
+    pub fn into_rust<'jvm>(
+        output: Option<Local<'jvm, J>>,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Option<R>> {
+        output.into_rust(jvm)
+    }
+}
+
+/// The items of [`Conversion`] for conversions that do not exist.
+pub trait MissingConversion<J: JavaObject, R> {
+    const TO_JAVA: bool = false;
+    const TO_RUST: bool = false;
+
+    /// Never called: the generated code asserts `TO_RUST` before calling this.
+    fn into_rust<'jvm>(
+        _output: Option<Local<'jvm, J>>,
+        _jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Option<R>> {
+        unreachable!("missing conversion to a Rust type")
+    }
+}
+
+impl<J: JavaObject, R> MissingConversion<J, R> for Conversion<J, R> {}
@@ -6,7 +6,10 @@ use std::{
 use thiserror::Error;
 
 use crate::AsJRef;
-use crate::{java::lang::Throwable, raw::HasEnvPtr, Global, Jvm, JvmOp, Local};
+use crate::{
+    cast::Upcast, java::lang::Throwable, jvm::JavaObjectExt, raw::HasEnvPtr,
+    ref_::with_current_env, Global, Jvm, JvmOp, Local,
+};
 
 /// Result returned by most Java operations that may contain a local reference
 /// to a thrown exception.
@@ -30,6 +33,17 @@ pub enum Error<T: AsJRef<Throwable>> {
     #[error("attempted to deref a null Java object pointer")]
     NullDeref,
 
+    #[error("time is out of the range of `std::time::SystemTime` or `java.time.Instant`")]
+    TimeOutOfRange,
+
+    #[error(
+        "`{0:?}` is outside the Basic Multilingual Plane, so it does not fit into a Java `char`"
+    )]
+    CharOutOfRange(char),
+
+    #[error("Java `char` `{0:#06x}` is a UTF-16 surrogate, which is not a Rust `char` on its own")]
+    SurrogateChar(u16),
+
     #[error("attempted to nest `Jvm::with` calls")]
     NestedUsage,
 
@@ -47,7 +61,7 @@ pub enum Error<T: AsJRef<Throwable>> {
 fn try_extract_message(exception: &impl AsJRef<Throwable>) -> String {
     let message = Jvm::with(|jvm| {
         let exception = jvm.local(exception.as_jref()?);
-        exception
+        (*exception)
             .to_string()
             .assert_not_null()
             .to_rust()
@@ -56,6 +70,39 @@ fn try_extract_message(exception: &impl AsJRef<Throwable>) -> String {
     message.unwrap_or_else(|_| "<unable to get exception message>".into())
 }
 
+impl<T: AsJRef<Throwable>> Error<T> {
+    /// Whether this is a thrown Java exception that is an instance of `J`, e.g. whether
+    /// `err.is::<java::io::FileNotFoundException>()` for a method declared to throw
+    /// `java.io.IOException`. The doc comment of each generated method lists the exceptions it
+    /// declares; to use the exception itself, see [`JvmOp::catch`].
+    ///
+    /// A thread that is not attached to the JVM is attached for the check. False for other
+    /// errors, and if the class of `J` cannot be loaded.
+    pub fn is<J: Upcast<Throwable>>(&self) -> bool {
+        let Error::Thrown(exception) = self else {
+            return false;
+        };
+        let Ok(exception) = exception.as_jref() else {
+            return false;
+        };
+        let is_instance = with_current_env(|env| {
+            // SAFETY: `env` stays attached to this thread while the closure runs.
+            let jvm = &mut unsafe { Jvm::from_env(env) };
+            let Ok(class) = J::class(jvm) else {
+                return false;
+            };
+            // SAFETY: both are live references; `IsInstanceOf` does not throw.
+            unsafe {
+                env.invoke(
+                    |env| env.IsInstanceOf,
+                    |env, f| f(env, exception.as_raw().as_ptr(), class.as_raw().as_ptr()),
+                ) == jni_sys::JNI_TRUE
+            }
+        });
+        is_instance.unwrap_or(false)
+    }
+}
+
 impl<T> Debug for Error<T>
 where
     T: AsJRef<Throwable>,
@@ -71,6 +118,9 @@ impl<'jvm> Error<Local<'jvm, Throwable>> {
             Error::Thrown(t) => Error::Thrown(jvm.global(&t)),
             Error::SliceTooLong(s) => Error::SliceTooLong(s),
             Error::NullDeref => Error::NullDeref,
+            Error::TimeOutOfRange => Error::TimeOutOfRange,
+            Error::CharOutOfRange(c) => Error::CharOutOfRange(c),
+            Error::SurrogateChar(c) => Error::SurrogateChar(c),
             Error::NestedUsage => Error::NestedUsage,
             Error::JvmAlreadyExists => Error::JvmAlreadyExists,
             #[cfg(feature = "dylibjvm")]
@@ -11,7 +11,7 @@ use crate::{
 pub fn find_class<'jvm>(
     jvm: &mut Jvm<'jvm>,
     jni_name: &CStr,
-) -> Result<'jvm, Local<'jvm, java::lang::Class>> {
+) -> Result<'jvm, Local<'jvm, java::lang::Class<java::lang::Object>>> {
     let env = jvm.env();
     let class = unsafe { env.invoke(|env| env.FindClass, |env, f| f(env, jni_name.as_ptr())) };
     if let Some(class) = ObjectPtr::new(class) {
@@ -28,7 +28,7 @@ pub fn find_class<'jvm>(
 
 pub fn find_method<'jvm>(
     jvm: &mut Jvm<'jvm>,
-    class: impl AsRef<java::lang::Class>,
+    class: impl AsRef<java::lang::Class<java::lang::Object>>,
     jni_name: &CStr,
     jni_descriptor: &CStr,
     is_static: bool,
@@ -70,7 +70,7 @@ pub fn find_method<'jvm>(
 
 pub fn find_field<'jvm>(
     jvm: &mut Jvm<'jvm>,
-    class: impl AsRef<java::lang::Class>,
+    class: impl AsRef<java::lang::Class<java::lang::Object>>,
     jni_name: &CStr,
     jni_descriptor: &CStr,
     is_static: bool,
@@ -112,7 +112,7 @@ pub fn find_field<'jvm>(
 
 pub fn find_constructor<'jvm>(
     jvm: &mut Jvm<'jvm>,
-    class: impl AsRef<java::lang::Class>,
+    class: impl AsRef<java::lang::Class<java::lang::Object>>,
     jni_descriptor: &CStr,
 ) -> Result<'jvm, MethodPtr> {
     const METHOD_NAME: &CStr = unsafe { CStr::from_bytes_with_nul_unchecked(b"<init>\0") };
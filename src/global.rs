@@ -72,3 +72,14 @@ where
         Ok(self.map(|p| jvm.global::<T>(&p)))
     }
 }
+
+impl<'jvm, T> IntoGlobal<'jvm> for Vec<Local<'jvm, T>>
+where
+    T: JavaObject,
+{
+    type Output = Vec<Global<T>>;
+
+    fn into_global(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output> {
+        Ok(self.iter().map(|p| jvm.global::<T>(p)).collect())
+    }
+}
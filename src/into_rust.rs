@@ -32,6 +32,14 @@ identity_rust_op! {
     i64,
 }
 
+/// A Java `char` is a UTF-16 code unit; the halves of a surrogate pair, used for characters
+/// outside the Basic Multilingual Plane, give [`Error::SurrogateChar`](crate::Error::SurrogateChar).
+impl IntoRust<char> for u16 {
+    fn into_rust<'jvm>(self, _jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, char> {
+        char::from_u32(self.into()).ok_or(crate::Error::SurrogateChar(self))
+    }
+}
+
 impl<O, E, JO, JE> IntoRust<Result<O, E>> for Result<JO, JE>
 where
     JO: IntoRust<O>,
@@ -57,6 +65,15 @@ where
     }
 }
 
+impl<R, J> IntoRust<Vec<R>> for Vec<J>
+where
+    J: IntoRust<R>,
+{
+    fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Vec<R>> {
+        self.into_iter().map(|j| j.into_rust(jvm)).collect()
+    }
+}
+
 impl<R, J> IntoRust<R> for Local<'_, J>
 where
     J: JavaObject,
@@ -0,0 +1,170 @@
+use std::io;
+
+use crate::{java, prelude::*, Error, Global, Jvm, Local};
+
+/// Size of the Java buffers that [`JavaReader`] and [`JavaWriter`] copy through,
+/// and so the most that one call to `read` or `write` transfers.
+const BUFFER_LEN: usize = 8 * 1024;
+
+/// Reads from a Java `java.io.InputStream` through [`std::io::Read`].
+///
+/// Each call to `read` enters the JVM once: it calls `InputStream.read(byte[], int, int)`
+/// with a Java buffer that is allocated when the reader is created and reused afterwards,
+/// then copies the bytes that were read into the caller's buffer.
+/// Exceptions thrown by the stream are mapped to [`std::io::Error`]s, see [`to_io_error`].
+pub struct JavaReader {
+    stream: Global<java::io::InputStream>,
+    buffer: Global<java::ByteArray>,
+}
+
+impl JavaReader {
+    pub fn new(stream: Global<java::io::InputStream>) -> crate::GlobalResult<Self> {
+        Ok(Self {
+            stream,
+            buffer: new_buffer()?,
+        })
+    }
+
+    /// Returns the underlying stream, without closing it.
+    pub fn into_inner(self) -> Global<java::io::InputStream> {
+        self.stream
+    }
+}
+
+impl io::Read for JavaReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        let len = buf.len().min(BUFFER_LEN);
+        with_jvm(|jvm| {
+            let count = self
+                .stream
+                .read(&self.buffer, 0, len as i32)
+                .execute_with(jvm)?;
+
+            // Java signals the end of the stream with -1.
+            let Ok(count) = usize::try_from(count) else {
+                return Ok(0);
+            };
+            self.buffer.copy_to_slice(jvm, &mut buf[..count])?;
+            Ok(count)
+        })
+    }
+}
+
+/// Writes to a Java `java.io.OutputStream` through [`std::io::Write`].
+///
+/// Each call to `write` enters the JVM once: it copies the caller's bytes into a Java buffer
+/// that is allocated when the writer is created and reused afterwards, then calls
+/// `OutputStream.write(byte[], int, int)`. `flush` calls `OutputStream.flush()`.
+/// Exceptions thrown by the stream are mapped to [`std::io::Error`]s, see [`to_io_error`].
+pub struct JavaWriter {
+    stream: Global<java::io::OutputStream>,
+    buffer: Global<java::ByteArray>,
+}
+
+impl JavaWriter {
+    pub fn new(stream: Global<java::io::OutputStream>) -> crate::GlobalResult<Self> {
+        Ok(Self {
+            stream,
+            buffer: new_buffer()?,
+        })
+    }
+
+    /// Returns the underlying stream, without flushing or closing it.
+    pub fn into_inner(self) -> Global<java::io::OutputStream> {
+        self.stream
+    }
+}
+
+impl io::Write for JavaWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        let len = buf.len().min(BUFFER_LEN);
+        with_jvm(|jvm| {
+            self.buffer.copy_from_slice(jvm, &buf[..len])?;
+            self.stream
+                .write(&self.buffer, 0, len as i32)
+                .execute_with(jvm)?;
+            Ok(len)
+        })
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        with_jvm(|jvm| self.stream.flush().execute_with(jvm))
+    }
+}
+
+fn new_buffer() -> crate::GlobalResult<Global<java::ByteArray>> {
+    [0_i8; BUFFER_LEN].as_slice().global().execute()
+}
+
+/// Runs `op` on the JVM, converting its errors with [`to_io_error`].
+fn with_jvm<R>(
+    op: impl for<'jvm> FnOnce(&mut Jvm<'jvm>) -> crate::Result<'jvm, R>,
+) -> io::Result<R> {
+    let result = Jvm::with(|jvm| match op(jvm) {
+        Ok(value) => Ok(Ok(value)),
+        Err(error) => Ok(Err(to_io_error(jvm, error))),
+    });
+    result.unwrap_or_else(|error| Err(io::Error::other(error)))
+}
+
+/// Converts an error from a Java I/O operation into a [`std::io::Error`] with the
+/// corresponding [`io::ErrorKind`]; the original error is kept as its source.
+///
+/// | Java exception                                     | `io::ErrorKind`    |
+/// | -------------------------------------------------- | ------------------ |
+/// | `java.io.EOFException`                             | `UnexpectedEof`    |
+/// | `java.nio.file.AccessDeniedException`              | `PermissionDenied` |
+/// | any other, with "Permission denied" in its message | `PermissionDenied` |
+/// | any other, with "Broken pipe" in its message       | `BrokenPipe`       |
+/// | `java.io.FileNotFoundException`                    | `NotFound`         |
+/// | anything else                                      | `Other`            |
+///
+/// The JDK reports permission errors and broken pipes as plain `IOException`s (or, when
+/// opening files, `FileNotFoundException`s) carrying the operating system's message,
+/// hence the checks on the message.
+pub fn to_io_error<'jvm>(
+    jvm: &mut Jvm<'jvm>,
+    error: Error<Local<'jvm, java::lang::Throwable>>,
+) -> io::Error {
+    let kind = match &error {
+        Error::Thrown(exception) => exception_kind(jvm, exception).unwrap_or(io::ErrorKind::Other),
+        _ => io::ErrorKind::Other,
+    };
+    io::Error::new(kind, error.into_global(jvm))
+}
+
+fn exception_kind<'jvm>(
+    jvm: &mut Jvm<'jvm>,
+    exception: &Local<'jvm, java::lang::Throwable>,
+) -> crate::Result<'jvm, io::ErrorKind> {
+    let message: Option<String> = exception.get_message().to_rust().execute_with(jvm)?;
+    let message = message.unwrap_or_default();
+
+    let exception = jvm.local(&**exception);
+    let exception = match exception.try_downcast::<java::io::EOFException>()? {
+        Ok(_) => return Ok(io::ErrorKind::UnexpectedEof),
+        Err(exception) => exception,
+    };
+    let exception = match exception.try_downcast::<java::nio::file::AccessDeniedException>()? {
+        Ok(_) => return Ok(io::ErrorKind::PermissionDenied),
+        Err(exception) => exception,
+    };
+    if message.contains("Permission denied") {
+        return Ok(io::ErrorKind::PermissionDenied);
+    }
+    if message.contains("Broken pipe") {
+        return Ok(io::ErrorKind::BrokenPipe);
+    }
+    match exception.try_downcast::<java::io::FileNotFoundException>()? {
+        Ok(_) => Ok(io::ErrorKind::NotFound),
+        Err(_) => Ok(io::ErrorKind::Other),
+    }
+}
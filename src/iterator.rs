@@ -0,0 +1,119 @@
+use crate::{
+    java::{lang::Iterable, util::Iterator as JavaIterator},
+    AsJRef, JavaObject, Jvm, JvmOp, Local,
+};
+
+/// Iteration from Rust over anything that implements `java.lang.Iterable`, such as a
+/// `java.util.List` or a class that implements `Iterable<T>` itself.
+pub trait JavaIterableExt<T: JavaObject> {
+    /// Calls `iterator()` and returns a Rust iterator over its elements,
+    /// see [`JavaIteratorAdapter`].
+    fn iter<'a, 'jvm>(
+        &self,
+        jvm: &'a mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, JavaIteratorAdapter<'a, 'jvm, T>>;
+}
+
+impl<I, T> JavaIterableExt<T> for I
+where
+    I: AsJRef<Iterable<T>>,
+    T: JavaObject,
+{
+    fn iter<'a, 'jvm>(
+        &self,
+        jvm: &'a mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, JavaIteratorAdapter<'a, 'jvm, T>> {
+        let iterator = self
+            .as_jref()?
+            .iterator()
+            .assert_not_null()
+            .execute_with(jvm)?;
+        Ok(JavaIteratorAdapter::new(jvm, iterator))
+    }
+}
+
+/// Adapts a Java `Iterator<T>` into a Rust [`Iterator`], calling `hasNext()` and `next()`
+/// on the Java side for each item.
+///
+/// Each item is a `Result`: Java iterators may return `null`, and if `hasNext()` or `next()`
+/// throws (e.g., a `ConcurrentModificationException`), the exception is yielded as an error
+/// and iteration stops.
+///
+/// ```ignore
+/// Jvm::with(|jvm| {
+///     let iterator = list.iterator().assert_not_null().execute_with(jvm)?;
+///     for element in JavaIteratorAdapter::new(jvm, iterator) {
+///         let element: Option<Local<'_, T>> = element?;
+///         // ...
+///     }
+///     Ok(())
+/// })
+/// ```
+///
+/// # Borrowing the `Jvm`
+///
+/// [`Iterator::next`] takes no arguments, so the adapter holds on to the `&mut Jvm<'jvm>` it is
+/// created with, in the same way as [`MapEntries`](crate::MapEntries). The `Jvm` stays
+/// reachable in between items through [`JavaIteratorAdapter::jvm`].
+///
+/// The adapter does not instead look up the `Jvm` through a thread-local: a `Jvm<'jvm>` is
+/// only valid for the `Jvm::with` (or `execute_with`) call that created it, and the local
+/// references it hands out are released when that call returns. Borrowing the `Jvm` ties
+/// every item to that `'jvm`, so the borrow checker rejects items that would outlive their
+/// local frame, and the exclusive borrow rules out another use of the `Jvm` (for example,
+/// a nested `Jvm::with`) pushing or popping a frame while the adapter is iterating.
+pub struct JavaIteratorAdapter<'a, 'jvm, T: JavaObject> {
+    jvm: &'a mut Jvm<'jvm>,
+
+    /// `None` once the Java iterator is exhausted or has thrown.
+    iterator: Option<Local<'jvm, JavaIterator<T>>>,
+}
+
+impl<'a, 'jvm, T> JavaIteratorAdapter<'a, 'jvm, T>
+where
+    T: JavaObject,
+{
+    /// Creates an adapter that iterates over `iterator` using `jvm`.
+    pub fn new(jvm: &'a mut Jvm<'jvm>, iterator: Local<'jvm, JavaIterator<T>>) -> Self {
+        Self {
+            jvm,
+            iterator: Some(iterator),
+        }
+    }
+
+    /// Gives access to the [`Jvm`] borrowed by this iterator,
+    /// so that it can be used in between calls to `next`.
+    pub fn jvm(&mut self) -> &mut Jvm<'jvm> {
+        self.jvm
+    }
+}
+
+impl<'a, 'jvm, T> Iterator for JavaIteratorAdapter<'a, 'jvm, T>
+where
+    T: JavaObject,
+{
+    type Item = crate::Result<'jvm, Option<Local<'jvm, T>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let iterator = self.iterator.as_ref()?;
+        let jvm = &mut *self.jvm;
+        let step = (|| {
+            if !iterator.has_next().execute_with(jvm)? {
+                return Ok(None);
+            }
+            Ok(Some(iterator.next().execute_with(jvm)?))
+        })();
+
+        match step {
+            Ok(Some(element)) => Some(Ok(element)),
+            Ok(None) => {
+                self.iterator = None;
+                None
+            }
+            Err(e) => {
+                self.iterator = None;
+                Some(Err(e))
+            }
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use std::{ffi::c_char, ops::Deref, ptr::NonNull};
+
+use crate::{error::check_exception, java, jvm::JavaObjectExt, raw::HasEnvPtr, Error, Jvm, Local};
+
+/// Read-only access to the contents of a Java `String` without copying them into a Rust
+/// `String`, created by [`java::lang::String::as_java_str`].
+///
+/// The characters are pinned with JNI's `GetStringUTFChars` and released when the
+/// `JavaString` is dropped (or [`release`][Self::release]d). `JavaString` holds its own
+/// local reference to the string, so it stays alive for as long as the `JavaString` does,
+/// which cannot outlive the [`Jvm`] it was created with.
+///
+/// A `JavaString` dereferences to a `str`. The JVM hands out the characters as Modified
+/// UTF-8, which is also valid UTF-8 unless the string contains a `'\0'` or a character
+/// outside the Basic Multilingual Plane (like most emoji); only those strings are decoded
+/// into a Rust string when the `JavaString` is created.
+pub struct JavaString<'jvm> {
+    string: Local<'jvm, java::lang::String>,
+    chars: NonNull<c_char>,
+
+    /// Set if the Modified UTF-8 in `chars` is not valid UTF-8.
+    decoded: Option<String>,
+
+    /// Length of `chars` in bytes, without the trailing nul byte.
+    len: usize,
+}
+
+impl java::lang::String {
+    /// Pins the characters of this string for reading them as a `str`, see [`JavaString`].
+    pub fn as_java_str<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, JavaString<'jvm>> {
+        let string = jvm.local(self);
+        let str_raw = string.as_raw();
+        let env = jvm.env();
+
+        // SAFETY: `str_raw` points to a non-null Java String.
+        let len = unsafe {
+            env.invoke(
+                |env| env.GetStringUTFLength,
+                |env, f| f(env, str_raw.as_ptr()),
+            )
+        };
+        assert!(len >= 0);
+
+        // SAFETY: as above; we pass a null `isCopy` pointer since we don't care whether the JVM copied.
+        let chars = unsafe {
+            env.invoke(
+                |env| env.GetStringUTFChars,
+                |env, f| f(env, str_raw.as_ptr(), std::ptr::null_mut()),
+            )
+        };
+        let Some(chars) = NonNull::new(chars.cast_mut()) else {
+            check_exception(jvm)?; // likely threw an OutOfMemoryError
+            return Err(Error::JvmInternal(
+                "JVM failed to access the contents of a String".into(),
+            ));
+        };
+
+        let mut java_string = JavaString {
+            string,
+            chars,
+            decoded: None,
+            len: len as usize,
+        };
+        if std::str::from_utf8(java_string.bytes()).is_err() {
+            let decoded = cesu8::from_java_cesu8(java_string.bytes()).map_err(|e| {
+                Error::JvmInternal(format!("Java String contained invalid Modified UTF-8: {e}"))
+            })?;
+            java_string.decoded = Some(decoded.into_owned());
+        }
+        Ok(java_string)
+    }
+}
+
+impl<'jvm> JavaString<'jvm> {
+    /// The length of the string in bytes of UTF-8 (not in Java `char`s), in constant time.
+    pub fn len(&self) -> usize {
+        match &self.decoded {
+            Some(decoded) => decoded.len(),
+            None => self.len,
+        }
+    }
+
+    /// Whether the string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Copies the string into a Rust `String`.
+    pub fn to_owned(&self) -> String {
+        String::from(&**self)
+    }
+
+    /// Releases the characters of the string back to the JVM, like dropping it.
+    pub fn release(self) {}
+
+    /// The string whose characters these are.
+    pub fn java_string(&self) -> &Local<'jvm, java::lang::String> {
+        &self.string
+    }
+
+    fn bytes(&self) -> &[u8] {
+        // SAFETY: `chars` points to `len` bytes, which stay valid until they are released on drop.
+        unsafe { std::slice::from_raw_parts(self.chars.as_ptr().cast::<u8>(), self.len) }
+    }
+}
+
+impl Deref for JavaString<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        match &self.decoded {
+            Some(decoded) => decoded,
+            // SAFETY: checked to be valid UTF-8 on creation.
+            None => unsafe { std::str::from_utf8_unchecked(self.bytes()) },
+        }
+    }
+}
+
+impl std::fmt::Debug for JavaString<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl std::fmt::Display for JavaString<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&**self, f)
+    }
+}
+
+impl Drop for JavaString<'_> {
+    fn drop(&mut self) {
+        let str_raw = self.string.as_raw();
+        // SAFETY: `chars` was returned by GetStringUTFChars for this very string and is released exactly once.
+        unsafe {
+            self.string.env().invoke(
+                |env| env.ReleaseStringUTFChars,
+                |env, f| f(env, str_raw.as_ptr(), self.chars.as_ptr()),
+            )
+        };
+    }
+}
+
+#[cfg(feature = "string-critical")]
+impl java::lang::String {
+    /// Gives direct access to the string's UTF-16 code units, without copying them if the JVM
+    /// allows it (see [`JavaStringCritical::is_copy`]).
+    ///
+    /// The JVM may suspend garbage collection while the returned guard is live, and no other
+    /// JNI calls may be made, which is enforced by borrowing `jvm`. Keep critical sections short.
+    pub fn critical<'a, 'jvm>(
+        &'a self,
+        jvm: &'a mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, JavaStringCritical<'a>> {
+        let string = self.as_raw();
+        let env = jvm.env();
+
+        // SAFETY: `string` points to a non-null Java String; no critical section is open yet.
+        let len =
+            unsafe { env.invoke(|env| env.GetStringLength, |env, f| f(env, string.as_ptr())) };
+        assert!(len >= 0);
+
+        let mut is_copy = jni_sys::JNI_FALSE;
+        // SAFETY: as above.
+        let chars = unsafe {
+            env.invoke(
+                |env| env.GetStringCritical,
+                |env, f| f(env, string.as_ptr(), &mut is_copy),
+            )
+        };
+        let Some(chars) = NonNull::new(chars.cast_mut()) else {
+            check_exception(jvm)?; // likely threw an OutOfMemoryError
+            return Err(Error::JvmInternal(
+                "JVM failed to access the contents of a String".into(),
+            ));
+        };
+
+        Ok(JavaStringCritical {
+            env,
+            string,
+            chars,
+            len: len as usize,
+            is_copy: is_copy != jni_sys::JNI_FALSE,
+        })
+    }
+}
+
+/// The UTF-16 code units of a Java `String` during a critical section, see
+/// [`java::lang::String::critical`]. Derefs to `[u16]`, and releases the characters when
+/// dropped.
+#[cfg(feature = "string-critical")]
+pub struct JavaStringCritical<'a> {
+    env: crate::raw::EnvPtr<'a>,
+    string: crate::raw::ObjectPtr,
+    chars: NonNull<u16>,
+    len: usize,
+    is_copy: bool,
+}
+
+#[cfg(feature = "string-critical")]
+impl JavaStringCritical<'_> {
+    /// Whether the JVM copied the characters rather than handing out its own, which it does if
+    /// it does not store strings as UTF-16 (e.g. compact Latin-1 strings in recent JDKs).
+    /// The guard works the same either way.
+    pub fn is_copy(&self) -> bool {
+        self.is_copy
+    }
+
+    /// Decodes the characters into a Rust `String`, replacing unpaired surrogates with `�`.
+    pub fn to_string_lossy(&self) -> String {
+        String::from_utf16_lossy(self)
+    }
+}
+
+#[cfg(feature = "string-critical")]
+impl Deref for JavaStringCritical<'_> {
+    type Target = [u16];
+
+    fn deref(&self) -> &[u16] {
+        // SAFETY: `chars` points to `len` code units, which stay valid until they are released on drop.
+        unsafe { std::slice::from_raw_parts(self.chars.as_ptr(), self.len) }
+    }
+}
+
+#[cfg(feature = "string-critical")]
+impl std::fmt::Debug for JavaStringCritical<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&self.to_string_lossy(), f)
+    }
+}
+
+#[cfg(feature = "string-critical")]
+impl Drop for JavaStringCritical<'_> {
+    fn drop(&mut self) {
+        // SAFETY: `chars` was returned by GetStringCritical for this very string and is released exactly once.
+        unsafe {
+            self.env.invoke(
+                |env| env.ReleaseStringCritical,
+                |env, f| f(env, self.string.as_ptr(), self.chars.as_ptr()),
+            )
+        };
+    }
+}
@@ -1,13 +1,19 @@
 use crate::{
-    cast::{AsUpcast, TryDowncast, Upcast},
+    and_then::AndThen,
+    cast::{AsUpcast, InstanceOf, Seal, TryDowncast, Upcast},
+    class_object::ClassObject,
+    collect::Collect,
     find::find_class,
     global::{GlobalOp, IntoGlobal},
     into_rust::ToRustOp,
-    java::lang::{Class, Throwable},
+    java::lang::{Class, Object, RuntimeException, Throwable},
     link::{IntoJavaFns, JavaFunction},
+    map_op::MapOp,
     not_null::NotNull,
+    or_null::OrNull,
     plumbing::{FromRef, ToJavaImpl},
     raw::{self, EnvPtr, HasEnvPtr, JvmPtr, ObjectPtr},
+    ref_::is_same_object,
     thread,
     try_catch::TryCatch,
     AsJRef, Error, Global, GlobalResult, IntoRust, Local, ToJava, TryJDeref,
@@ -15,8 +21,7 @@ use crate::{
 
 use std::{
     any::Any,
-    collections::HashMap,
-    ffi::{c_char, c_void, CStr},
+    ffi::{c_char, c_void, CStr, CString},
     fmt::Display,
     panic::AssertUnwindSafe,
     ptr::NonNull,
@@ -43,6 +48,24 @@ pub trait JvmOp: Copy {
         NotNull::new(self)
     }
 
+    /// When this operation produces Java `null`, runs `default` and produces its output instead.
+    /// `default` only runs if it is needed, within the same call into the JVM:
+    ///
+    /// ```ignore
+    /// let value = map.get("key").or_null(map.get("fallback")).execute()?;
+    /// ```
+    ///
+    /// The result is still `null` if `default` produces `null`;
+    /// see also [`null_op`][`crate::null_op`].
+    fn or_null<D, T>(self, default: D) -> OrNull<Self, D>
+    where
+        T: JavaObject,
+        for<'jvm> Self: JvmOp<Output<'jvm> = Option<Local<'jvm, T>>>,
+        for<'jvm> D: JvmOp<Output<'jvm> = Option<Local<'jvm, T>>>,
+    {
+        OrNull::new(self, default)
+    }
+
     /// Tries to downcast output of this operation to `To`, otherwise returning
     /// the output as is. Equivalent to
     /// ```java
@@ -61,6 +84,38 @@ pub trait JvmOp: Copy {
         TryDowncast::new(self)
     }
 
+    /// Checks whether the output of this operation is an instance of `To`.
+    /// Equivalent to
+    /// ```java
+    /// x instanceof To
+    /// ```
+    /// Produces an error if the output is null.
+    fn instanceof<To>(self) -> InstanceOf<Self, To>
+    where
+        for<'jvm> Self::Output<'jvm>: TryJDeref,
+        To: JavaObject,
+    {
+        InstanceOf::new(self)
+    }
+
+    /// Determines which permitted subclass of the sealed class `T` the output of this
+    /// operation is an instance of, producing the variant of the enum generated for `T`:
+    /// ```ignore
+    /// match Shape::circle(1.0).seal::<Shape>().execute_with(jvm)? {
+    ///     ShapeSealed::Circle(circle) => ...,
+    ///     ShapeSealed::Square(square) => ...,
+    ///     ShapeSealed::Unknown(shape) => ...,
+    /// }
+    /// ```
+    /// Produces an error if the output is null.
+    fn seal<T>(self) -> Seal<Self, T>
+    where
+        for<'jvm> Self::Output<'jvm>: AsJRef<T>,
+        T: JavaSealed,
+    {
+        Seal::new(self)
+    }
+
     /// Most duchess-wrapped Java objects will automatically be able to call all
     /// methods defined on any of its super classes or interfaces it implements,
     /// but this can be used to "force" the output of the operation to be typed
@@ -99,6 +154,57 @@ pub trait JvmOp: Copy {
         ToRustOp::new(self)
     }
 
+    /// Transforms the output of this operation with `f`, which runs as part of the
+    /// same call into the JVM (e.g., `list.size().map(|n| n as usize)`).
+    /// The result of `f` cannot borrow from the JVM; use [`global()`][`Self::global`]
+    /// first to keep a reference to a Java object.
+    fn map<F, O>(self, f: F) -> MapOp<Self, F, O>
+    where
+        F: for<'jvm> FnOnce(Self::Output<'jvm>) -> O + Copy,
+    {
+        MapOp::new(self, f)
+    }
+
+    /// Runs `f` on the output of this operation, as part of the same call into the JVM.
+    /// Unlike [`map()`][`Self::map`], `f` is given the `jvm`, so it can run further ops
+    /// with [`execute_with`][`Self::execute_with`]:
+    ///
+    /// ```ignore
+    /// let size = java::util::ArrayList::<java::lang::String>::new()
+    ///     .and_then(|list, jvm| {
+    ///         list.add("one").execute_with(jvm)?;
+    ///         list.size().execute_with(jvm)
+    ///     })
+    ///     .execute()?;
+    /// ```
+    ///
+    /// Like for `map`, the result of `f` cannot borrow from the JVM, so `f` cannot return
+    /// a `Local`; convert it to a [`Global`] or to a Rust value first.
+    fn and_then<F, O>(self, f: F) -> AndThen<Self, F, O>
+    where
+        F: for<'jvm> FnOnce(Self::Output<'jvm>, &mut Jvm<'jvm>) -> crate::Result<'jvm, O> + Copy,
+    {
+        AndThen::new(self, f)
+    }
+
+    /// Given a JVM op that produces a `java.util.Iterator`, calls `hasNext()` and `next()`
+    /// until the iterator is exhausted, all within the same call into the JVM, and produces
+    /// the elements. A null element is an [`Error::NullDeref`].
+    ///
+    /// The elements are `Local` references; use [`global()`][`Self::global`] or
+    /// [`to_rust()`][`Self::to_rust`] to keep them after the call:
+    ///
+    /// ```ignore
+    /// let strings: Vec<String> = set.iterator().assert_not_null().collect().to_rust().execute()?;
+    /// ```
+    fn collect<T>(self) -> Collect<Self>
+    where
+        T: JavaObject,
+        for<'jvm> Self: JvmOp<Output<'jvm> = Local<'jvm, crate::java::util::Iterator<T>>>,
+    {
+        Collect::new(self)
+    }
+
     /// Execute the jvm op, starting a JVM instance if necessary.
     /// To use this method, the result type cannot be tied to the JVM.
     /// Typically this is achieved by a call to [`to_rust()`][`Self::to_rust`],
@@ -121,7 +227,7 @@ impl IsVoid for () {}
 
 static GLOBAL_JVM: OnceCell<JvmPtr> = OnceCell::new();
 
-fn get_or_default_init_jvm() -> crate::GlobalResult<JvmPtr> {
+pub(crate) fn get_or_default_init_jvm() -> crate::GlobalResult<JvmPtr> {
     match GLOBAL_JVM.get() {
         Some(jvm) => Ok(*jvm),
         None => {
@@ -159,12 +265,15 @@ where
             match obj {
                 Ok(Some(p)) => p.into_raw().as_ptr(),
                 Ok(None) => std::ptr::null_mut(),
-                Err(e) => todo!("convert from exception `{e:?}` into java exception"),
+                Err(e) => {
+                    error_to_java_exception(env, e);
+                    std::ptr::null_mut()
+                }
             }
         }
 
         Err(e) => {
-            rust_panic_to_java_exception(e);
+            rust_panic_to_java_exception(env, e);
             std::ptr::null_mut()
         }
     };
@@ -180,9 +289,8 @@ where
 ///
 /// Must be invoked as the entire body of a JNI native function, with
 /// `env` being the `EnvPtr` argument provided.
-pub unsafe fn native_function_returning_scalar<J, R>(env: EnvPtr<'_>, op: impl FnOnce() -> R) -> R
+pub unsafe fn native_function_returning_scalar<R>(env: EnvPtr<'_>, op: impl FnOnce() -> R) -> R
 where
-    J: Upcast<crate::java::lang::Object> + Upcast<J>,
     R: JavaScalar,
 {
     init_jvm_from_native_function(env);
@@ -191,7 +299,7 @@ where
     let result = match std::panic::catch_unwind(AssertUnwindSafe(|| op())) {
         Ok(result) => result,
         Err(e) => {
-            rust_panic_to_java_exception(e);
+            rust_panic_to_java_exception(env, e);
             R::default()
         }
     };
@@ -199,6 +307,48 @@ where
     result
 }
 
+/// Like [`native_function_returning_scalar`], for native functions returning `void`.
+///
+/// # Safety condition
+///
+/// Must be invoked as the entire body of a JNI native function, with
+/// `env` being the `EnvPtr` argument provided.
+pub(crate) unsafe fn native_function_returning_unit(env: EnvPtr<'_>, op: impl FnOnce()) {
+    init_jvm_from_native_function(env);
+    let _callback_guard = thread::attach_from_jni_callback(env);
+
+    if let Err(e) = std::panic::catch_unwind(AssertUnwindSafe(op)) {
+        rust_panic_to_java_exception(env, e);
+    }
+}
+
+/// Like [`native_function_returning_object`], for an `op` that produces the returned object
+/// (or null) itself and may fail, in which case the error is thrown to Java.
+///
+/// # Safety condition
+///
+/// Must be invoked as the entire body of a JNI native function, with
+/// `env` being the `EnvPtr` argument provided.
+pub(crate) unsafe fn native_function_returning_result(
+    env: EnvPtr<'_>,
+    op: impl for<'jvm> FnOnce(&mut Jvm<'jvm>) -> crate::Result<'jvm, jni_sys::jobject>,
+) -> jni_sys::jobject {
+    init_jvm_from_native_function(env);
+    let _callback_guard = thread::attach_from_jni_callback(env);
+
+    match std::panic::catch_unwind(AssertUnwindSafe(|| op(&mut Jvm(env)))) {
+        Ok(Ok(obj)) => obj,
+        Ok(Err(e)) => {
+            error_to_java_exception(env, e);
+            std::ptr::null_mut()
+        }
+        Err(e) => {
+            rust_panic_to_java_exception(env, e);
+            std::ptr::null_mut()
+        }
+    }
+}
+
 /// Invoked from inside a JNI native function when it is called by the JVM.
 /// If `GLOBAL_JVM` is not yet set, initializes it to use the provided `jvm`.
 /// Otherwise, does nothing.
@@ -219,8 +369,54 @@ unsafe fn init_jvm_from_native_function(env: EnvPtr<'_>) -> Jvm<'_> {
     Jvm(env)
 }
 
-fn rust_panic_to_java_exception(_panic: Box<dyn Any + Send + 'static>) {
-    todo!("rust_panic_to_java_exception")
+/// Throws a `java.lang.RuntimeException` for a panic caught in a native function, so that
+/// Java sees it when the function returns. Its message is the panic message, if that is a string.
+fn rust_panic_to_java_exception(env: EnvPtr<'_>, panic: Box<dyn Any + Send + 'static>) {
+    let message = if let Some(message) = panic.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message.as_str()
+    } else {
+        "Box<dyn Any>"
+    };
+    throw_runtime_exception(env, &format!("Rust panic: {message}"));
+}
+
+/// Throws `error` from a native function, so that Java sees it when the function returns:
+/// an exception thrown by Java is rethrown as is, any other error becomes a
+/// `java.lang.RuntimeException` with the error's message.
+pub(crate) fn error_to_java_exception<'jvm>(
+    env: EnvPtr<'jvm>,
+    error: Error<Local<'jvm, Throwable>>,
+) {
+    match error {
+        Error::Thrown(exception) => unsafe {
+            env.invoke(
+                |env| env.Throw,
+                |env, f| f(env, exception.as_raw().as_ptr()),
+            );
+        },
+        error => throw_runtime_exception(env, &error.to_string()),
+    }
+}
+
+fn throw_runtime_exception(env: EnvPtr<'_>, message: &str) {
+    // SAFETY: `env` is the environment of the current native function.
+    let jvm = &mut unsafe { Jvm::from_env(env) };
+    let class = match RuntimeException::class(jvm) {
+        Ok(class) => class,
+        // Loading the class failed (e.g. we are out of memory), so throw that instead.
+        Err(error @ Error::Thrown(_)) => return error_to_java_exception(env, error),
+        Err(_) => return,
+    };
+    // A Rust string may contain NUL, unlike a C string.
+    let message = CString::new(message.replace('\0', "\\0")).unwrap();
+    unsafe {
+        env.invoke(
+            |env| env.ThrowNew,
+            |env, f| f(env, class.as_raw().as_ptr(), message.as_ptr()),
+        );
+    }
 }
 
 /// Get the global [`JvmPtr`] assuming that the JVM has already been initialized. Expected to be used with values
@@ -251,6 +447,7 @@ impl<'jvm> Jvm<'jvm> {
         let jvm = get_or_default_init_jvm()?;
         // SAFTEY: we won't deinitialize the JVM while the guard is live
         let mut guard = unsafe { thread::attach(jvm)? };
+        tracing::trace!("entered the JVM");
 
         let mut jvm = Jvm(guard.env());
         op(&mut jvm).map_err(|e| e.into_global(&mut jvm))
@@ -270,23 +467,79 @@ impl<'jvm> Jvm<'jvm> {
         Global::new(self.0, r)
     }
 
+    /// Runs `op` in a new frame of local references, with room for at least `capacity` of them
+    /// (see JNI's `PushLocalFrame`). When `op` returns or panics, all local references created
+    /// within the frame are freed at once, so loops creating many of them do not exhaust the
+    /// JVM's table of local references.
+    ///
+    /// The result of `op` cannot be tied to the frame, so it cannot contain a [`Local`];
+    /// convert Java objects to a [`Global`] or to a Rust value first. An exception thrown
+    /// within `op` is moved to the enclosing frame.
+    pub fn with_local_frame<R>(
+        &mut self,
+        capacity: i32,
+        op: impl for<'frame> FnOnce(&mut Jvm<'frame>) -> crate::Result<'frame, R>,
+    ) -> crate::Result<'jvm, R> {
+        /// Pops the frame if `op` panics.
+        struct PopOnUnwind<'jvm>(EnvPtr<'jvm>);
+
+        impl Drop for PopOnUnwind<'_> {
+            fn drop(&mut self) {
+                // SAFETY: as below; the references of the frame were dropped while unwinding.
+                unsafe { self.0.pop_local_frame(None) };
+            }
+        }
+
+        let env = self.0;
+        // SAFETY: the frame is popped below (or by the guard, if `op` panics), and `op` cannot
+        // delete references from this frame as we lend out `self`.
+        unsafe { env.push_local_frame(capacity)? };
+
+        let guard = PopOnUnwind(env);
+        let result = op(&mut Jvm(env));
+        std::mem::forget(guard);
+
+        // SAFETY: as `op` works for any `'frame`, none of the references created in the frame
+        // can outlive it, except for the exception, which is moved to this frame.
+        unsafe {
+            match result {
+                Err(Error::Thrown(exception)) => {
+                    let exception = env.pop_local_frame(Some(exception.into_raw()));
+                    Err(Error::Thrown(Local::from_raw(env, exception.unwrap())))
+                }
+                result => {
+                    env.pop_local_frame(None);
+                    result
+                }
+            }
+        }
+    }
+
     fn register_native_methods(
         &mut self,
         java_functions: &[JavaFunction],
     ) -> crate::Result<'jvm, ()> {
-        let mut sorted_by_class: HashMap<Local<'_, Class>, Vec<jni_sys::JNINativeMethod>> =
-            HashMap::default();
+        let mut sorted_by_class: Vec<(Local<'_, Class<Object>>, Vec<jni_sys::JNINativeMethod>)> =
+            vec![];
 
         for java_function in java_functions {
             let class = (java_function.class_fn)(self)?;
-            sorted_by_class
-                .entry(class)
-                .or_insert(vec![])
-                .push(jni_sys::JNINativeMethod {
-                    name: java_function.name.as_ptr() as *mut c_char,
-                    signature: java_function.signature.as_ptr() as *mut c_char,
-                    fnPtr: java_function.pointer.as_ptr() as *mut c_void,
-                });
+            let env = self.env();
+            let index = match sorted_by_class
+                .iter()
+                .position(|(c, _)| is_same_object(env, c.as_raw(), class.as_raw()))
+            {
+                Some(index) => index,
+                None => {
+                    sorted_by_class.push((class, vec![]));
+                    sorted_by_class.len() - 1
+                }
+            };
+            sorted_by_class[index].1.push(jni_sys::JNINativeMethod {
+                name: java_function.name.as_ptr() as *mut c_char,
+                signature: java_function.signature.as_ptr() as *mut c_char,
+                fnPtr: java_function.pointer.as_ptr() as *mut c_void,
+            });
         }
 
         for (class, native_methods) in &sorted_by_class {
@@ -300,6 +553,18 @@ impl<'jvm> Jvm<'jvm> {
     }
 }
 
+impl<'jvm> Jvm<'jvm> {
+    /// Recreates the `Jvm` handle for an environment that is already in use on this thread,
+    /// e.g. the one stored in a [`Local`].
+    ///
+    /// # Safety
+    ///
+    /// `env` must be the environment of a `Jvm` that is live on the current thread.
+    pub(crate) unsafe fn from_env(env: EnvPtr<'jvm>) -> Self {
+        Jvm(env)
+    }
+}
+
 impl<'jvm> HasEnvPtr<'jvm> for Jvm<'jvm> {
     fn env(&self) -> EnvPtr<'jvm> {
         self.0
@@ -313,6 +578,21 @@ pub struct JvmBuilder {
     java_functions: Vec<JavaFunction>,
 }
 
+const DUCHESS_CLASSPATH: &str = "DUCHESS_CLASSPATH";
+
+/// Start the global JVM (or attach to an existing one) using the classpath from the
+/// `DUCHESS_CLASSPATH` environment variable, which takes precedence over `CLASSPATH`.
+/// Entries are separated with the platform path separator (`;` on Windows, `:` elsewhere).
+///
+/// Options set explicitly on a [`JvmBuilder`] win over the environment, so use
+/// [`Jvm::builder`] instead if you need more control.
+pub fn init_from_env() -> GlobalResult<()> {
+    if std::env::var_os(DUCHESS_CLASSPATH).is_none() {
+        tracing::warn!("{DUCHESS_CLASSPATH} is not set, falling back to the default classpath");
+    }
+    Jvm::builder().launch_or_use_existing()
+}
+
 impl JvmBuilder {
     fn new() -> Self {
         let mut this = Self {
@@ -325,7 +605,9 @@ impl JvmBuilder {
         if cfg!(debug_assertions) {
             this = this.custom("-Xcheck:jni");
         }
-        if let Ok(classpath) = std::env::var("CLASSPATH") {
+        if let Ok(classpath) =
+            std::env::var(DUCHESS_CLASSPATH).or_else(|_| std::env::var("CLASSPATH"))
+        {
             this = this.add_classpath(classpath);
         }
 
@@ -336,6 +618,18 @@ impl JvmBuilder {
         self.custom(format!("-Djava.class.path={classpath}"))
     }
 
+    /// Set the classpath from a list of directories or JARs, joined with the
+    /// platform's path separator (`;` on Windows, `:` elsewhere).
+    pub fn classpath(self, paths: &[&str]) -> Self {
+        let separator = if cfg!(windows) { ";" } else { ":" };
+        self.add_classpath(paths.join(separator))
+    }
+
+    /// Pass a raw option such as `-Xmx512m` to the JVM. Equivalent to [`Self::custom`].
+    pub fn option(self, flag: &str) -> Self {
+        self.custom(flag)
+    }
+
     pub fn custom(mut self, opt_string: impl Into<String>) -> Self {
         self.options.push(opt_string.into());
         self
@@ -424,7 +718,42 @@ pub unsafe trait JavaObject: 'static + Sized + JavaType + JavaView {
     // XX: can't be put on extension trait nor define a default because we want to cache the resolved
     // class in a static OnceCell.
     /// Returns Java Class object for this type.
-    fn class<'jvm>(jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Local<'jvm, Class>>;
+    fn class<'jvm>(jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Local<'jvm, Class<Object>>>;
+
+    /// An operation producing the `java.lang.Class<T>` object for this type, as needed by
+    /// Java APIs like `EnumSet.noneOf(Class<E>)`. Reuses the class cached by [`Self::class`];
+    /// for the runtime class of an object, use `get_class()`.
+    fn class_object() -> ClassObject<Self> {
+        ClassObject::new()
+    }
+}
+
+/// Marker trait for Java record classes (`record Point(int x, int y)`). Their generated
+/// structs have an accessor method for each component (`point.x()`), and compare with the
+/// record's `equals()`.
+pub trait JavaRecord: JavaObject + PartialEq {}
+
+/// Marker trait for Java classes that override `equals(Object)`, which is generated for classes
+/// whose members include it. [`Local`] references to them compare with `equals()`.
+pub trait ImplementsEquals: JavaObject {}
+
+/// Marker trait for Java classes that override `hashCode()`, which is generated for classes
+/// whose members include it. [`Local`] references to them hash with `hashCode()`.
+///
+/// Java's contract requires `hashCode()` to be consistent with `equals()`: equal objects must
+/// have equal hash codes. Duchess cannot check this, so a class that breaks the contract makes
+/// `HashMap`s and `HashSet`s of its locals misbehave, as it would in Java.
+pub trait ImplementsHashCode: JavaObject {}
+
+/// Implemented for sealed Java classes and interfaces (`sealed class Shape permits Circle, Square`).
+/// The generated enum has a variant for each permitted subclass declared in the same
+/// `java_package!`, and an `Unknown` variant for any other subclass; see [`JvmOp::seal`].
+pub trait JavaSealed: JavaObject {
+    /// The generated enum, e.g. `ShapeSealed<'jvm>` for `Shape`.
+    type Sealed<'jvm>;
+
+    /// Picks the variant for the class of `obj`.
+    fn seal<'jvm>(obj: Local<'jvm, Self>) -> crate::Result<'jvm, Self::Sealed<'jvm>>;
 }
 
 pub trait JavaView {
@@ -477,11 +806,11 @@ impl<T: JavaObject> JavaObjectExt for T {
 pub unsafe trait JavaType: 'static {
     /// Returns the Java Class object for a Java array containing elements of
     /// `Self`. All Java types, even scalars can be elements of an array object.
-    fn array_class<'jvm>(jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Local<'jvm, Class>>;
+    fn array_class<'jvm>(jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Local<'jvm, Class<Object>>>;
 }
 
 unsafe impl<T: JavaObject> JavaType for T {
-    fn array_class<'jvm>(jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Local<'jvm, Class>> {
+    fn array_class<'jvm>(jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Local<'jvm, Class<Object>>> {
         T::class(jvm)?
             .array_type()
             .assert_not_null()
@@ -495,10 +824,10 @@ macro_rules! scalar {
     ($($rust:ty: $array_class:literal,)*) => {
         $(
             unsafe impl JavaType for $rust {
-                fn array_class<'jvm>(jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Local<'jvm, Class>> {
+                fn array_class<'jvm>(jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Local<'jvm, Class<Object>>> {
                     // XX: Safety
                     const CLASS_NAME: &CStr = unsafe { CStr::from_bytes_with_nul_unchecked($array_class) };
-                    static CLASS: OnceCell<Global<crate::java::lang::Class>> = OnceCell::new();
+                    static CLASS: OnceCell<Global<Class<Object>>> = OnceCell::new();
 
                     let global = CLASS.get_or_try_init::<_, crate::Error<Local<Throwable>>>(|| {
                         let class = find_class(jvm, CLASS_NAME)?;
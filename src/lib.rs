@@ -1,17 +1,31 @@
 //! Experiments with Java-Rust interop.
 
+mod and_then;
 mod array;
+mod boxed;
 mod cast;
+mod class_object;
+mod cmp;
+mod collect;
+mod convert;
 mod error;
 mod find;
 mod from_ref;
 mod global;
 mod into_rust;
+mod iterator;
+mod java_string;
 mod jvm;
 mod libjvm;
 mod link;
+mod map;
+mod map_op;
 mod not_null;
+mod object;
 mod ops;
+mod optional;
+mod or_null;
+mod pinned;
 mod raw;
 mod ref_;
 mod refs;
@@ -19,31 +33,83 @@ mod str;
 mod thread;
 mod to_java;
 mod try_catch;
+mod varargs;
+mod wild;
 
 /// Contains reusable declarations for classes distributed by the JDK under the `java.*` packages.
 pub mod java;
 
-pub use duchess_macro::{java_function, java_package, ToJava, ToRust};
+/// Eager conversions between Java lists and Rust vectors, and (with the `collections` feature)
+/// between Java maps and Rust hash maps.
+pub mod collections;
+
+/// Java callbacks implemented by Rust closures.
+pub mod callback;
+
+/// Adapters between Java streams and [`std::io`].
+pub mod io;
+
+/// Conversions between Java's time types and [`std::time::SystemTime`].
+pub mod time;
+
+#[cfg(feature = "async")]
+pub mod async_jvm;
+
+#[cfg(feature = "global-ref-budget")]
+pub mod budget;
+
+#[cfg(feature = "build")]
+pub mod build;
+
+/// Runtime support for the [`test`] attribute, and [`testing::run_junit`] to run JUnit tests.
+pub mod testing;
+
+pub use class_object::ClassObject;
+pub use cmp::ByJavaHash;
+pub use duchess_macro::{java_function, java_package, test, ToJava, ToRust};
 pub use error::{Error, GlobalResult, Result};
 pub use into_rust::IntoRust;
+pub use iterator::JavaIteratorAdapter;
+pub use java_string::JavaString;
+#[cfg(feature = "string-critical")]
+pub use java_string::JavaStringCritical;
+pub use jvm::init_from_env;
+pub use jvm::ImplementsEquals;
+pub use jvm::ImplementsHashCode;
 pub use jvm::JavaObject;
+pub use jvm::JavaRecord;
+pub use jvm::JavaSealed;
 pub use jvm::JavaType;
 pub use jvm::Jvm;
 pub use link::JavaFunction;
-pub use ref_::{Global, Local};
+pub use map::MapEntries;
+pub use or_null::null_op;
+pub use pinned::PinnedJvm;
+#[cfg(feature = "display")]
+pub use ref_::JavaDisplay;
+pub use ref_::{Global, Local, WeakGlobal};
 pub use refs::{AsJRef, JDeref, NullJRef, Nullable, TryJDeref};
+pub use thread::spawn;
 pub use try_catch::TryCatch;
+pub use varargs::IntoVarargs;
 
 pub use prelude::*;
 
-/// Contains traits with methods expected to be invoked by end-users.
+/// Contains traits with methods expected to be invoked by end-users,
+/// along with the reference types and traits most commonly named in signatures.
 pub mod prelude {
-    pub use crate::jvm::JvmOp;
+    pub use crate::cast::{JavaDowncast, Upcast};
+    pub use crate::cmp::{JavaComparableExt, JavaEqExt};
+    pub use crate::into_rust::IntoRust;
+    pub use crate::iterator::JavaIterableExt;
+    pub use crate::jvm::{JavaObject, Jvm, JvmOp};
     pub use crate::link::JavaFn;
+    pub use crate::map::JavaMapExt;
     pub use crate::ops::{
         IntoJava, IntoScalar, IntoVoid, JavaConstructor, JavaField, JavaMethod, ScalarField,
         ScalarMethod, VoidMethod,
     };
+    pub use crate::ref_::{Global, Local};
     pub use crate::refs::{AsJRef, JDeref, TryJDeref};
     pub use crate::to_java::ToJava;
 }
@@ -53,6 +119,8 @@ pub mod prelude {
 #[doc(hidden)]
 pub mod plumbing {
     pub use crate::cast::Upcast;
+    pub use crate::cmp::ByJavaHash;
+    pub use crate::convert::{Conversion, MissingConversion};
     pub use crate::error::check_exception;
     pub use crate::find::{find_class, find_constructor, find_field, find_method};
     pub use crate::from_ref::FromRef;
@@ -63,11 +131,14 @@ pub mod plumbing {
     pub use crate::jvm::JavaView;
     pub use crate::link::JavaFn;
     pub use crate::link::JavaFunction;
+    pub use crate::object::ObjectMethods;
+    pub use crate::ops::Unset;
     pub use crate::raw::{
         EnvPtr, FieldPtr, FromJniValue, HasEnvPtr, IntoJniValue, MethodPtr, ObjectPtr,
     };
+    pub use crate::ref_::{debug_java_class, debug_java_object, java_equals};
     pub use crate::refs::NullJRef;
-    pub use crate::to_java::ToJavaImpl;
+    pub use crate::to_java::{ConvertToJava, ToJavaImpl};
     pub use jni_sys;
     pub use once_cell;
 }
@@ -15,6 +15,11 @@ pub(crate) struct Libjvm {
     ) -> jni_sys::jint,
 }
 
+/// Shared with the build script, which doesn't use all of it (and vice versa).
+#[cfg(feature = "dylibjvm")]
+#[allow(dead_code)]
+mod probe;
+
 #[cfg(feature = "dylibjvm")]
 mod dynlib {
     use std::path::{Path, PathBuf};
@@ -39,7 +44,63 @@ mod dynlib {
                 JNI_GetCreatedJavaVMs,
             })
         })()
-        .map_err(|e: libloading::Error| Error::UnableToLoadLibjvm(Box::new(e)))
+        .map_err(|e: libloading::Error| {
+            Error::UnableToLoadLibjvm(Box::new(LoadError {
+                path: path.to_path_buf(),
+                diagnosis: diagnose(path),
+                source: e,
+            }))
+        })
+    }
+
+    /// Inspects the library at `path` to explain why the dynamic loader may have rejected it.
+    fn diagnose(path: &Path) -> String {
+        match probe::probe_path(path) {
+            Ok(info) => {
+                let target = probe::Target::current();
+                let problems = probe::mismatches(&info, &target);
+                if problems.is_empty() {
+                    format!(
+                        "`{}` is a {info}, which matches the target `{target}`",
+                        path.display()
+                    )
+                } else {
+                    let mut diagnosis = format!("`{}` is a {info}:", path.display());
+                    for problem in problems {
+                        diagnosis.push_str("\n  - ");
+                        diagnosis.push_str(&problem);
+                    }
+                    diagnosis
+                }
+            }
+            Err(e) => format!("unable to inspect `{}`: {e}", path.display()),
+        }
+    }
+
+    /// Failure to load libjvm, along with what we could learn about the library from its headers.
+    #[derive(Debug)]
+    struct LoadError {
+        path: PathBuf,
+        diagnosis: String,
+        source: libloading::Error,
+    }
+
+    impl std::fmt::Display for LoadError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(
+                f,
+                "unable to load libjvm from `{}`: {}\n{}",
+                self.path.display(),
+                self.source,
+                self.diagnosis
+            )
+        }
+    }
+
+    impl std::error::Error for LoadError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            Some(&self.source)
+        }
     }
 
     pub(crate) fn libjvm_or_load() -> GlobalResult<&'static Libjvm> {
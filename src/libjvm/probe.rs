@@ -0,0 +1,540 @@
+//! Inspects the headers of a native library (ELF, PE or Mach-O) to find out which
+//! architecture and libc it was built for, without loading it or running external tools.
+//!
+//! A libjvm that doesn't match the Rust target (e.g., a 32-bit JDK with a 64-bit build,
+//! or a glibc JDK with a musl build) otherwise fails with an opaque dynamic-loader error.
+//! This module is shared by the build script (which checks the JDK that will be linked
+//! statically) and by the runtime (which explains why loading libjvm failed).
+
+use std::{fmt, path::Path};
+
+/// Container format of a native library.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BinaryFormat {
+    Elf,
+    Pe,
+    MachO,
+}
+
+impl BinaryFormat {
+    /// The format used for native libraries on the given `target_os`, if we know it.
+    pub fn for_target_os(os: &str) -> Option<BinaryFormat> {
+        match os {
+            "windows" => Some(BinaryFormat::Pe),
+            "macos" | "ios" => Some(BinaryFormat::MachO),
+            "linux" | "android" | "freebsd" | "netbsd" | "openbsd" | "solaris" | "illumos" => {
+                Some(BinaryFormat::Elf)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for BinaryFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BinaryFormat::Elf => write!(f, "ELF"),
+            BinaryFormat::Pe => write!(f, "PE"),
+            BinaryFormat::MachO => write!(f, "Mach-O"),
+        }
+    }
+}
+
+/// CPU architecture a library was compiled for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Arch {
+    X86,
+    X86_64,
+    Arm,
+    Aarch64,
+
+    /// Machine code we don't recognize, as recorded in the header.
+    Other(u32),
+}
+
+impl Arch {
+    /// The name of this architecture as used by `cfg(target_arch)`.
+    pub fn target_arch(&self) -> Option<&'static str> {
+        match self {
+            Arch::X86 => Some("x86"),
+            Arch::X86_64 => Some("x86_64"),
+            Arch::Arm => Some("arm"),
+            Arch::Aarch64 => Some("aarch64"),
+            Arch::Other(_) => None,
+        }
+    }
+
+    pub fn pointer_width(&self) -> Option<u32> {
+        match self {
+            Arch::X86 | Arch::Arm => Some(32),
+            Arch::X86_64 | Arch::Aarch64 => Some(64),
+            Arch::Other(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Arch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Arch::X86 => write!(f, "x86 (32-bit)"),
+            Arch::X86_64 => write!(f, "x86_64 (64-bit)"),
+            Arch::Arm => write!(f, "arm (32-bit)"),
+            Arch::Aarch64 => write!(f, "aarch64 (64-bit)"),
+            Arch::Other(machine) => write!(f, "unknown architecture (machine type {machine:#x})"),
+        }
+    }
+}
+
+/// The C library an ELF library was linked against.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Libc {
+    Glibc,
+    Musl,
+}
+
+impl Libc {
+    /// The value of `cfg(target_env)` for targets using this libc.
+    pub fn target_env(&self) -> &'static str {
+        match self {
+            Libc::Glibc => "gnu",
+            Libc::Musl => "musl",
+        }
+    }
+}
+
+impl fmt::Display for Libc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Libc::Glibc => write!(f, "glibc"),
+            Libc::Musl => write!(f, "musl"),
+        }
+    }
+}
+
+/// What we learned from the headers of a library.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LibraryInfo {
+    pub format: BinaryFormat,
+
+    /// Architectures the library contains code for; more than one for
+    /// universal ("fat") Mach-O binaries.
+    pub archs: Vec<Arch>,
+
+    /// The libc the library depends on, if it could be determined (ELF only).
+    pub libc: Option<Libc>,
+}
+
+impl fmt::Display for LibraryInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} library for ", self.format)?;
+        for (i, arch) in self.archs.iter().enumerate() {
+            if i > 0 {
+                write!(f, " + ")?;
+            }
+            write!(f, "{arch}")?;
+        }
+        if let Some(libc) = self.libc {
+            write!(f, ", linked against {libc}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The platform that libjvm needs to be compatible with, in terms of `cfg(target_*)` values.
+#[derive(Clone, Debug)]
+pub struct Target {
+    pub arch: String,
+    pub os: String,
+    pub env: String,
+}
+
+impl Target {
+    /// The target this code was compiled for.
+    pub fn current() -> Target {
+        Target {
+            arch: std::env::consts::ARCH.to_string(),
+            os: std::env::consts::OS.to_string(),
+            env: if cfg!(target_env = "musl") {
+                "musl"
+            } else if cfg!(target_env = "gnu") {
+                "gnu"
+            } else {
+                ""
+            }
+            .to_string(),
+        }
+    }
+
+    /// The target being compiled for, as described by cargo to build scripts.
+    pub fn from_cargo_env() -> Option<Target> {
+        Some(Target {
+            arch: std::env::var("CARGO_CFG_TARGET_ARCH").ok()?,
+            os: std::env::var("CARGO_CFG_TARGET_OS").ok()?,
+            env: std::env::var("CARGO_CFG_TARGET_ENV").unwrap_or_default(),
+        })
+    }
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.arch, self.os)?;
+        if !self.env.is_empty() {
+            write!(f, "-{}", self.env)?;
+        }
+        Ok(())
+    }
+}
+
+/// Reasons the headers of a library could not be understood.
+#[derive(Debug)]
+pub enum ProbeError {
+    Io(std::io::Error),
+    UnknownFormat,
+    Truncated(BinaryFormat),
+}
+
+impl fmt::Display for ProbeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProbeError::Io(e) => write!(f, "unable to read library: {e}"),
+            ProbeError::UnknownFormat => write!(f, "not an ELF, PE or Mach-O library"),
+            ProbeError::Truncated(format) => write!(f, "truncated or malformed {format} header"),
+        }
+    }
+}
+
+impl std::error::Error for ProbeError {}
+
+/// Reads the library at `path` and inspects its headers.
+pub fn probe_path(path: &Path) -> Result<LibraryInfo, ProbeError> {
+    let bytes = std::fs::read(path).map_err(ProbeError::Io)?;
+    probe_bytes(&bytes)
+}
+
+/// Inspects the headers of a library whose contents are `bytes`.
+pub fn probe_bytes(bytes: &[u8]) -> Result<LibraryInfo, ProbeError> {
+    match bytes {
+        [0x7f, b'E', b'L', b'F', ..] => {
+            probe_elf(bytes).ok_or(ProbeError::Truncated(BinaryFormat::Elf))
+        }
+        [b'M', b'Z', ..] => probe_pe(bytes).ok_or(ProbeError::Truncated(BinaryFormat::Pe)),
+        [0xcf, 0xfa, 0xed, 0xfe, ..]
+        | [0xce, 0xfa, 0xed, 0xfe, ..]
+        | [0xfe, 0xed, 0xfa, 0xcf, ..]
+        | [0xfe, 0xed, 0xfa, 0xce, ..]
+        | [0xca, 0xfe, 0xba, 0xbe, ..] => {
+            probe_mach_o(bytes).ok_or(ProbeError::Truncated(BinaryFormat::MachO))
+        }
+        _ => Err(ProbeError::UnknownFormat),
+    }
+}
+
+/// Describes each way in which `info` is incompatible with `target`, as actionable messages.
+/// Returns an empty vector if no problems were found.
+pub fn mismatches(info: &LibraryInfo, target: &Target) -> Vec<String> {
+    let mut problems = vec![];
+
+    if let Some(format) = BinaryFormat::for_target_os(&target.os) {
+        if format != info.format {
+            problems.push(format!(
+                "libjvm is a {} library, but the target `{target}` uses {format} libraries; \
+                 use a JDK built for {}",
+                info.format, target.os,
+            ));
+            // Nothing else is meaningful to compare across platforms.
+            return problems;
+        }
+    }
+
+    if !info
+        .archs
+        .iter()
+        .any(|a| a.target_arch() == Some(&target.arch[..]))
+    {
+        let found = info
+            .archs
+            .iter()
+            .map(|a| a.to_string())
+            .collect::<Vec<_>>()
+            .join(" + ");
+        let width_hint = match info.archs.iter().find_map(|a| a.pointer_width()) {
+            Some(width) if target_pointer_width(&target.arch) != Some(width) => {
+                " (the JDK and the Rust target must both be 32-bit or both be 64-bit)"
+            }
+            _ => "",
+        };
+        problems.push(format!(
+            "libjvm is built for {found}, but the target `{target}` is {}{width_hint}; \
+             install a JDK for {} and point `JAVA_HOME` at it, or build for a matching target",
+            target.arch, target.arch,
+        ));
+    }
+
+    if let Some(libc) = info.libc {
+        if (target.env == "gnu" || target.env == "musl") && libc.target_env() != target.env {
+            problems.push(format!(
+                "libjvm is linked against {libc}, but the target `{target}` uses {}; \
+                 use a JDK built for {} (or a `*-linux-{}` target)",
+                if target.env == "musl" {
+                    "musl"
+                } else {
+                    "glibc"
+                },
+                if target.env == "musl" {
+                    "musl (e.g., an Alpine build)"
+                } else {
+                    "glibc"
+                },
+                libc.target_env(),
+            ));
+        }
+    }
+
+    problems
+}
+
+fn target_pointer_width(target_arch: &str) -> Option<u32> {
+    match target_arch {
+        "x86" | "arm" => Some(32),
+        "x86_64" | "aarch64" => Some(64),
+        _ => None,
+    }
+}
+
+/// Little helper for reading integers of either endianness at an offset,
+/// returning `None` if the input is too short.
+#[derive(Copy, Clone)]
+struct Reader<'b> {
+    bytes: &'b [u8],
+    big_endian: bool,
+}
+
+impl<'b> Reader<'b> {
+    fn slice<const N: usize>(&self, offset: usize) -> Option<[u8; N]> {
+        let end = offset.checked_add(N)?;
+        self.bytes.get(offset..end)?.try_into().ok()
+    }
+
+    fn u16(&self, offset: usize) -> Option<u16> {
+        let b = self.slice(offset)?;
+        Some(if self.big_endian {
+            u16::from_be_bytes(b)
+        } else {
+            u16::from_le_bytes(b)
+        })
+    }
+
+    fn u32(&self, offset: usize) -> Option<u32> {
+        let b = self.slice(offset)?;
+        Some(if self.big_endian {
+            u32::from_be_bytes(b)
+        } else {
+            u32::from_le_bytes(b)
+        })
+    }
+
+    fn u64(&self, offset: usize) -> Option<u64> {
+        let b = self.slice(offset)?;
+        Some(if self.big_endian {
+            u64::from_be_bytes(b)
+        } else {
+            u64::from_le_bytes(b)
+        })
+    }
+
+    /// Reads a 32- or 64-bit word, depending on the ELF class.
+    fn word(&self, offset: usize, is_64: bool) -> Option<usize> {
+        if is_64 {
+            self.u64(offset)?.try_into().ok()
+        } else {
+            Some(self.u32(offset)? as usize)
+        }
+    }
+
+    /// Reads a NUL-terminated string.
+    fn c_str(&self, offset: usize) -> Option<&'b str> {
+        let rest = self.bytes.get(offset..)?;
+        let len = rest.iter().position(|&b| b == 0)?;
+        std::str::from_utf8(&rest[..len]).ok()
+    }
+}
+
+fn probe_elf(bytes: &[u8]) -> Option<LibraryInfo> {
+    const PT_LOAD: u32 = 1;
+    const PT_DYNAMIC: u32 = 2;
+    const PT_INTERP: u32 = 3;
+    const DT_NULL: usize = 0;
+    const DT_NEEDED: usize = 1;
+    const DT_STRTAB: usize = 5;
+
+    let is_64 = match bytes.get(4)? {
+        1 => false,
+        2 => true,
+        _ => return None,
+    };
+    let r = Reader {
+        bytes,
+        big_endian: *bytes.get(5)? == 2,
+    };
+
+    let arch = match r.u16(18)? {
+        3 => Arch::X86,
+        62 => Arch::X86_64,
+        40 => Arch::Arm,
+        183 => Arch::Aarch64,
+        machine => Arch::Other(machine.into()),
+    };
+
+    // Program headers tell us where the interpreter and dynamic section are.
+    let (phoff, phentsize, phnum) = if is_64 {
+        (r.word(32, true)?, r.u16(54)? as usize, r.u16(56)? as usize)
+    } else {
+        (r.word(28, false)?, r.u16(42)? as usize, r.u16(44)? as usize)
+    };
+
+    struct Segment {
+        kind: u32,
+        offset: usize,
+        vaddr: usize,
+        filesz: usize,
+    }
+    let segments: Vec<Segment> = (0..phnum)
+        .map(|i| {
+            let ph = phoff.checked_add(i.checked_mul(phentsize)?)?;
+            Some(if is_64 {
+                Segment {
+                    kind: r.u32(ph)?,
+                    offset: r.word(ph + 8, true)?,
+                    vaddr: r.word(ph + 16, true)?,
+                    filesz: r.word(ph + 32, true)?,
+                }
+            } else {
+                Segment {
+                    kind: r.u32(ph)?,
+                    offset: r.word(ph + 4, false)?,
+                    vaddr: r.word(ph + 8, false)?,
+                    filesz: r.word(ph + 16, false)?,
+                }
+            })
+        })
+        .collect::<Option<_>>()?;
+
+    let mut libs: Vec<&str> = vec![];
+    for segment in &segments {
+        if segment.kind == PT_INTERP {
+            libs.extend(r.c_str(segment.offset));
+        }
+    }
+
+    if let Some(dynamic) = segments.iter().find(|s| s.kind == PT_DYNAMIC) {
+        let entry_size = if is_64 { 16 } else { 8 };
+        let entries: Vec<(usize, usize)> = (0..dynamic.filesz / entry_size)
+            .map(|i| {
+                let e = dynamic.offset + i * entry_size;
+                Some((r.word(e, is_64)?, r.word(e + entry_size / 2, is_64)?))
+            })
+            .take_while(|e| !matches!(e, Some((DT_NULL, _))))
+            .collect::<Option<_>>()?;
+
+        // DT_STRTAB is a virtual address; map it back to a file offset.
+        let strtab = entries
+            .iter()
+            .find(|(tag, _)| *tag == DT_STRTAB)
+            .and_then(|&(_, vaddr)| {
+                segments
+                    .iter()
+                    .filter(|s| s.kind == PT_LOAD)
+                    .find(|s| s.vaddr <= vaddr && vaddr < s.vaddr + s.filesz)
+                    .map(|s| s.offset + (vaddr - s.vaddr))
+            });
+
+        if let Some(strtab) = strtab {
+            for &(tag, name) in &entries {
+                if tag == DT_NEEDED {
+                    libs.extend(r.c_str(strtab + name));
+                }
+            }
+        }
+    }
+
+    let libc = if libs.iter().any(|l| l.contains("musl") || *l == "libc.so") {
+        Some(Libc::Musl)
+    } else if libs
+        .iter()
+        .any(|l| l.starts_with("libc.so.6") || l.contains("ld-linux"))
+    {
+        Some(Libc::Glibc)
+    } else {
+        None
+    };
+
+    Some(LibraryInfo {
+        format: BinaryFormat::Elf,
+        archs: vec![arch],
+        libc,
+    })
+}
+
+fn probe_pe(bytes: &[u8]) -> Option<LibraryInfo> {
+    let r = Reader {
+        bytes,
+        big_endian: false,
+    };
+    let pe = r.u32(0x3c)? as usize;
+    if r.slice::<4>(pe)? != *b"PE\0\0" {
+        return None;
+    }
+    let arch = match r.u16(pe + 4)? {
+        0x014c => Arch::X86,
+        0x8664 => Arch::X86_64,
+        0x01c4 => Arch::Arm,
+        0xaa64 => Arch::Aarch64,
+        machine => Arch::Other(machine.into()),
+    };
+    Some(LibraryInfo {
+        format: BinaryFormat::Pe,
+        archs: vec![arch],
+        libc: None,
+    })
+}
+
+fn probe_mach_o(bytes: &[u8]) -> Option<LibraryInfo> {
+    fn arch(cputype: u32) -> Arch {
+        match cputype {
+            0x0000_0007 => Arch::X86,
+            0x0100_0007 => Arch::X86_64,
+            0x0000_000c => Arch::Arm,
+            0x0100_000c => Arch::Aarch64,
+            cputype => Arch::Other(cputype),
+        }
+    }
+
+    let be = Reader {
+        bytes,
+        big_endian: true,
+    };
+    let archs = match be.u32(0)? {
+        // Universal binary: a big-endian table of the architectures it contains.
+        0xcafe_babe => {
+            let count = be.u32(4)? as usize;
+            (0..count)
+                .map(|i| Some(arch(be.u32(8 + i * 20)?)))
+                .collect::<Option<Vec<_>>>()?
+        }
+        0xfeed_face | 0xfeed_facf => vec![arch(be.u32(4)?)],
+        0xcefa_edfe | 0xcffa_edfe => {
+            let le = Reader {
+                bytes,
+                big_endian: false,
+            };
+            vec![arch(le.u32(4)?)]
+        }
+        _ => return None,
+    };
+
+    Some(LibraryInfo {
+        format: BinaryFormat::MachO,
+        archs,
+        libc: None,
+    })
+}
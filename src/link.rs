@@ -1,6 +1,9 @@
 use std::{ffi::CString, ptr::NonNull};
 
-use crate::{java::lang::Class, Jvm, Local};
+use crate::{
+    java::lang::{Class, Object},
+    Jvm, Local,
+};
 
 pub struct JavaFunction {
     pub(crate) name: CString,
@@ -9,7 +12,8 @@ pub struct JavaFunction {
     pub(crate) class_fn: ClassFn,
 }
 
-pub type ClassFn = for<'jvm> fn(jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Local<'jvm, Class>>;
+pub type ClassFn =
+    for<'jvm> fn(jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Local<'jvm, Class<Object>>>;
 
 impl JavaFunction {
     /// Create a new `JavaFunction` value with an appropriate name, signature, and function pointer.
@@ -0,0 +1,131 @@
+use crate::{
+    java::util::{Iterator as JavaIterator, Map, Map__Entry as MapEntry},
+    AsJRef, IntoRust, JavaObject, Jvm, JvmOp, Local,
+};
+
+/// Streaming iteration over the entries of a Java `Map`, driven by
+/// `entrySet().iterator()`. Unlike converting to a Rust `HashMap`,
+/// entries are produced one at a time, so memory use is bounded.
+pub trait JavaMapExt<K: JavaObject, V: JavaObject> {
+    /// Returns an iterator over the `(key, value)` pairs of this map.
+    ///
+    /// Each item is a `Result`: if the Java iterator throws (e.g., a
+    /// `ConcurrentModificationException` because the map was modified), the
+    /// exception is yielded as an error and iteration stops.
+    fn entries<'a, 'jvm>(
+        &self,
+        jvm: &'a mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, MapEntries<'a, 'jvm, K, V>>;
+
+    /// Invokes `op` with each key and value of this map, converted to Rust.
+    /// The local references for each entry are released before moving on to the next.
+    fn for_each_rust<'jvm, RK, RV>(
+        &self,
+        jvm: &mut Jvm<'jvm>,
+        op: impl FnMut(RK, RV),
+    ) -> crate::Result<'jvm, ()>
+    where
+        for<'l> Option<Local<'l, K>>: IntoRust<RK>,
+        for<'l> Option<Local<'l, V>>: IntoRust<RV>;
+}
+
+impl<M, K, V> JavaMapExt<K, V> for M
+where
+    M: AsJRef<Map<K, V>>,
+    K: JavaObject,
+    V: JavaObject,
+{
+    fn entries<'a, 'jvm>(
+        &self,
+        jvm: &'a mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, MapEntries<'a, 'jvm, K, V>> {
+        let iterator = entry_iterator(self.as_jref()?, jvm)?;
+        Ok(MapEntries {
+            jvm,
+            iterator: Some(iterator),
+        })
+    }
+
+    fn for_each_rust<'jvm, RK, RV>(
+        &self,
+        jvm: &mut Jvm<'jvm>,
+        mut op: impl FnMut(RK, RV),
+    ) -> crate::Result<'jvm, ()>
+    where
+        for<'l> Option<Local<'l, K>>: IntoRust<RK>,
+        for<'l> Option<Local<'l, V>>: IntoRust<RV>,
+    {
+        let iterator = entry_iterator(self.as_jref()?, jvm)?;
+        while iterator.has_next().execute_with(jvm)? {
+            let entry = iterator.next().assert_not_null().execute_with(jvm)?;
+            let key = entry.get_key().execute_with(jvm)?.into_rust(jvm)?;
+            let value = entry.get_value().execute_with(jvm)?.into_rust(jvm)?;
+            op(key, value);
+        }
+        Ok(())
+    }
+}
+
+fn entry_iterator<'jvm, K: JavaObject, V: JavaObject>(
+    map: &Map<K, V>,
+    jvm: &mut Jvm<'jvm>,
+) -> crate::Result<'jvm, Local<'jvm, JavaIterator<MapEntry<K, V>>>> {
+    map.entry_set()
+        .iterator()
+        .assert_not_null()
+        .execute_with(jvm)
+}
+
+/// Iterator over the entries of a Java `Map`, see [`JavaMapExt::entries`].
+pub struct MapEntries<'a, 'jvm, K: JavaObject, V: JavaObject> {
+    jvm: &'a mut Jvm<'jvm>,
+
+    /// `None` once the Java iterator is exhausted or has thrown.
+    iterator: Option<Local<'jvm, JavaIterator<MapEntry<K, V>>>>,
+}
+
+impl<'a, 'jvm, K, V> MapEntries<'a, 'jvm, K, V>
+where
+    K: JavaObject,
+    V: JavaObject,
+{
+    /// Gives access to the [`Jvm`] borrowed by this iterator,
+    /// so that it can be used in between calls to `next`.
+    pub fn jvm(&mut self) -> &mut Jvm<'jvm> {
+        self.jvm
+    }
+}
+
+impl<'a, 'jvm, K, V> Iterator for MapEntries<'a, 'jvm, K, V>
+where
+    K: JavaObject,
+    V: JavaObject,
+{
+    type Item = crate::Result<'jvm, (Option<Local<'jvm, K>>, Option<Local<'jvm, V>>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let iterator = self.iterator.as_ref()?;
+        let jvm = &mut *self.jvm;
+        let step = (|| {
+            if !iterator.has_next().execute_with(jvm)? {
+                return Ok(None);
+            }
+            let entry = iterator.next().assert_not_null().execute_with(jvm)?;
+            let key = entry.get_key().execute_with(jvm)?;
+            let value = entry.get_value().execute_with(jvm)?;
+            Ok(Some((key, value)))
+        })();
+
+        match step {
+            Ok(Some(pair)) => Some(Ok(pair)),
+            Ok(None) => {
+                self.iterator = None;
+                None
+            }
+            Err(e) => {
+                self.iterator = None;
+                Some(Err(e))
+            }
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use std::marker::PhantomData;
+
+use crate::{Jvm, JvmOp};
+
+#[derive_where::derive_where(Copy, Clone; J, F)]
+pub struct MapOp<J, F, O> {
+    j: J,
+    f: F,
+    phantom: PhantomData<fn() -> O>,
+}
+
+impl<J, F, O> MapOp<J, F, O>
+where
+    J: JvmOp,
+    F: for<'jvm> FnOnce(J::Output<'jvm>) -> O + Copy,
+{
+    pub(crate) fn new(j: J, f: F) -> Self {
+        MapOp {
+            j,
+            f,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<J, F, O> JvmOp for MapOp<J, F, O>
+where
+    J: JvmOp,
+    F: for<'jvm> FnOnce(J::Output<'jvm>) -> O + Copy,
+{
+    type Output<'jvm> = O;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        let output = self.j.execute_with(jvm)?;
+        Ok((self.f)(output))
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{
+    java::lang::{Class, Object, String},
+    jvm::JavaObjectExt,
+    IntoJava, JavaMethod, JavaObject, ScalarMethod, VoidMethod,
+};
+
+/// The methods of `java.lang.Object`, for every Java object type.
+///
+/// Generated types already have these methods, as they deref to `Object` last, but code
+/// that is generic over `T: JavaObject` cannot rely on that; with this trait in scope, it
+/// can call e.g. `obj.hash_code()` on a `&T`. The calls are those of `Object` itself, so
+/// their method IDs are looked up (and cached) once, against `Object`.
+///
+/// This trait is not in the prelude, so that it does not shadow methods of the same name
+/// (but different parameters) declared by other classes.
+///
+/// ```ignore
+/// use duchess::plumbing::ObjectMethods;
+///
+/// fn describe<'jvm, T: JavaObject>(
+///     obj: &T,
+///     jvm: &mut Jvm<'jvm>,
+/// ) -> duchess::Result<'jvm, String> {
+///     obj.to_string().assert_not_null().to_rust().execute_with(jvm)
+/// }
+/// ```
+pub trait ObjectMethods: JavaObject {
+    /// Calls `toString()`.
+    fn to_string<'a>(&'a self) -> impl JavaMethod<String> + 'a;
+
+    /// Calls `hashCode()`.
+    fn hash_code<'a>(&'a self) -> impl ScalarMethod<i32> + 'a;
+
+    /// Calls `equals(other)`.
+    fn equals<'a>(&'a self, other: impl IntoJava<Object> + 'a) -> impl ScalarMethod<bool> + 'a;
+
+    /// Calls `getClass()`, which returns the runtime class of this object.
+    fn get_class<'a>(&'a self) -> impl JavaMethod<Class<Object>> + 'a;
+
+    /// Calls `notify()`, which throws an `IllegalMonitorStateException` unless the current
+    /// thread holds this object's monitor; so do `notifyAll()` and `wait()`.
+    fn notify<'a>(&'a self) -> impl VoidMethod + 'a;
+
+    /// Calls `notifyAll()`.
+    fn notify_all<'a>(&'a self) -> impl VoidMethod + 'a;
+
+    /// Calls `wait()`.
+    fn wait<'a>(&'a self) -> impl VoidMethod + 'a;
+}
+
+impl<T: JavaObject> ObjectMethods for T {
+    fn to_string<'a>(&'a self) -> impl JavaMethod<String> + 'a {
+        Object::to_string(as_object(self))
+    }
+
+    fn hash_code<'a>(&'a self) -> impl ScalarMethod<i32> + 'a {
+        Object::hash_code(as_object(self))
+    }
+
+    fn equals<'a>(&'a self, other: impl IntoJava<Object> + 'a) -> impl ScalarMethod<bool> + 'a {
+        Object::equals(as_object(self), other)
+    }
+
+    fn get_class<'a>(&'a self) -> impl JavaMethod<Class<Object>> + 'a {
+        Object::get_class(as_object(self))
+    }
+
+    fn notify<'a>(&'a self) -> impl VoidMethod + 'a {
+        Object::notify(as_object(self))
+    }
+
+    fn notify_all<'a>(&'a self) -> impl VoidMethod + 'a {
+        Object::notify_all(as_object(self))
+    }
+
+    fn wait<'a>(&'a self) -> impl VoidMethod + 'a {
+        Object::wait(as_object(self))
+    }
+}
+
+/// Views `obj` as a `java.lang.Object`.
+fn as_object<T: JavaObject>(obj: &T) -> &Object {
+    // SAFETY: every Java object is an instance of `java.lang.Object`.
+    unsafe { JavaObjectExt::from_raw(obj.as_raw()) }
+}
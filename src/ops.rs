@@ -94,14 +94,28 @@ where
 {
 }
 
-/// A [`JvmOp`] that produces a scalar value, like `i8` or `i32`.
-pub trait IntoScalar<T: JavaScalar>: for<'jvm> JvmOp<Output<'jvm> = T> {}
+/// Types that are able to be used as a Java scalar `T`, like `i8` or `i32`: [`JvmOp`]s that
+/// produce a `T`, and Rust `char`s where a Java `char` (`u16`) is expected.
+pub trait IntoScalar<T: JavaScalar>: Copy {
+    fn into_scalar<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, T>;
+}
 
 impl<J, T> IntoScalar<T> for J
 where
     T: JavaScalar,
     J: for<'jvm> JvmOp<Output<'jvm> = T>,
 {
+    fn into_scalar<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, T> {
+        self.execute_with(jvm)
+    }
+}
+
+/// A Java `char` is a UTF-16 code unit, so only characters in the Basic Multilingual Plane
+/// fit; others give [`Error::CharOutOfRange`](crate::Error::CharOutOfRange).
+impl IntoScalar<u16> for char {
+    fn into_scalar<'jvm>(self, _jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, u16> {
+        u16::try_from(self).map_err(|_| crate::Error::CharOutOfRange(self))
+    }
 }
 
 /// A [`JvmOp`] that produces a void (`()`)
@@ -179,3 +193,9 @@ where
     for<'jvm> Self: JvmOp<Output<'jvm> = T>,
 {
 }
+
+/// A parameter of a constructor builder that has not been set yet. Builders are generated for
+/// long constructors of classes in a package with the `builder_arity` option; their `build`
+/// method is only available once no parameter is `Unset`.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Unset;
@@ -0,0 +1,17 @@
+use crate::{into_rust::IntoRust, java, JavaObject, Jvm, JvmOp};
+
+/// An empty `Optional` is `None`, and one with a value is `Some` of the converted value.
+impl<T, R> IntoRust<Option<R>> for &java::util::Optional<T>
+where
+    T: JavaObject,
+    for<'a> &'a T: IntoRust<R>,
+{
+    fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Option<R>> {
+        if !self.is_present().execute_with(jvm)? {
+            return Ok(None);
+        }
+        // `get` never returns null for an `Optional` with a value.
+        let value = self.get().assert_not_null().execute_with(jvm)?;
+        Ok(Some(value.into_rust(jvm)?))
+    }
+}
@@ -0,0 +1,58 @@
+use std::marker::PhantomData;
+
+use crate::{JavaObject, Jvm, JvmOp, Local};
+
+#[derive_where::derive_where(Copy, Clone)]
+pub struct OrNull<J: JvmOp, D: JvmOp> {
+    j: J,
+    default: D,
+}
+
+impl<J, D, T> OrNull<J, D>
+where
+    J: for<'jvm> JvmOp<Output<'jvm> = Option<Local<'jvm, T>>>,
+    D: for<'jvm> JvmOp<Output<'jvm> = Option<Local<'jvm, T>>>,
+    T: JavaObject,
+{
+    pub(crate) fn new(j: J, default: D) -> OrNull<J, D> {
+        OrNull { j, default }
+    }
+}
+
+impl<J, D, T> JvmOp for OrNull<J, D>
+where
+    J: for<'jvm> JvmOp<Output<'jvm> = Option<Local<'jvm, T>>>,
+    D: for<'jvm> JvmOp<Output<'jvm> = Option<Local<'jvm, T>>>,
+    T: JavaObject,
+{
+    type Output<'jvm> = Option<Local<'jvm, T>>;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        match self.j.execute_with(jvm)? {
+            Some(j) => Ok(Some(j)),
+            None => self.default.execute_with(jvm),
+        }
+    }
+}
+
+/// An operation that always produces Java `null`, typed as a `T`.
+/// Useful as the last fallback of a chain of [`or_null`][`JvmOp::or_null`] calls,
+/// or wherever a nullable object is expected.
+pub fn null_op<T: JavaObject>() -> NullOp<T> {
+    NullOp {
+        phantom: PhantomData,
+    }
+}
+
+#[derive_where::derive_where(Copy, Clone)]
+pub struct NullOp<T> {
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T: JavaObject> JvmOp for NullOp<T> {
+    type Output<'jvm> = Option<Local<'jvm, T>>;
+
+    fn execute_with<'jvm>(self, _jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        Ok(None)
+    }
+}
@@ -0,0 +1,149 @@
+use std::{
+    any::Any,
+    panic::AssertUnwindSafe,
+    sync::mpsc,
+    thread::{JoinHandle, ThreadId},
+};
+
+use crate::{Error, GlobalResult, Jvm, JvmOp};
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// A handle to a dedicated thread that is permanently attached to the JVM.
+///
+/// Some Java libraries (e.g., UI toolkits) require that all interaction happens
+/// on one designated thread. Work submitted via [`run`][`Self::run`] or
+/// [`run_with`][`Self::run_with`] is shipped to the pinned thread and executed
+/// there in FIFO order; the caller blocks until its result is available.
+///
+/// Results must be owned (e.g., Rust values or [`Global`][`crate::Global`] references),
+/// since local references cannot leave the pinned thread.
+pub struct PinnedJvm {
+    sender: Option<mpsc::Sender<Job>>,
+    thread: Option<JoinHandle<()>>,
+    thread_id: ThreadId,
+}
+
+impl PinnedJvm {
+    /// Spawns a new thread, attaches it permanently to the JVM (starting the JVM
+    /// if necessary), and returns a handle that executes work on it.
+    pub fn spawn() -> GlobalResult<Self> {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let (ready_sender, ready_receiver) = mpsc::channel();
+
+        let thread = std::thread::Builder::new()
+            .name("duchess-pinned-jvm".into())
+            .spawn(move || {
+                let attached = Jvm::attach_thread_permanently();
+                let ok = attached.is_ok();
+                let _ = ready_sender.send(attached);
+                if !ok {
+                    return;
+                }
+
+                // Runs until every sender is dropped, so all queued work is drained.
+                for job in receiver {
+                    job();
+                }
+            })
+            .map_err(|e| Error::JvmInternal(format!("unable to spawn pinned thread: {e}")))?;
+
+        let thread_id = thread.thread().id();
+        match ready_receiver.recv() {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => return Err(e),
+            Err(_) => {
+                return Err(Error::JvmInternal(
+                    "pinned thread exited before attaching to the JVM".into(),
+                ))
+            }
+        }
+
+        Ok(PinnedJvm {
+            sender: Some(sender),
+            thread: Some(thread),
+            thread_id,
+        })
+    }
+
+    /// Executes `op` on the pinned thread and returns its (owned) output.
+    pub fn run<J, R>(&self, op: J) -> GlobalResult<R>
+    where
+        J: Send + 'static,
+        for<'jvm> J: JvmOp<Output<'jvm> = R>,
+        R: Send + 'static,
+    {
+        self.run_with(move |jvm| op.execute_with(jvm))
+    }
+
+    /// Executes `op` with a [`Jvm`] on the pinned thread and returns its (owned) result.
+    ///
+    /// Returns [`Error::NestedUsage`] if invoked from the pinned thread itself,
+    /// since waiting on the thread we are running on would deadlock.
+    /// Panics raised by `op` are reported as [`Error::JvmInternal`].
+    pub fn run_with<R>(
+        &self,
+        op: impl for<'a> FnOnce(&mut Jvm<'a>) -> crate::Result<'a, R> + Send + 'static,
+    ) -> GlobalResult<R>
+    where
+        R: Send + 'static,
+    {
+        if std::thread::current().id() == self.thread_id {
+            return Err(Error::NestedUsage);
+        }
+
+        let (result_sender, result_receiver) = mpsc::sync_channel(1);
+        let job: Job = Box::new(move || {
+            let result = std::panic::catch_unwind(AssertUnwindSafe(|| Jvm::with(op)))
+                .unwrap_or_else(|panic| Err(Error::JvmInternal(panic_message(panic))));
+            let _ = result_sender.send(result);
+        });
+
+        let sender = self
+            .sender
+            .as_ref()
+            .expect("sender is only taken on shutdown");
+        if sender.send(job).is_err() {
+            return Err(Error::JvmInternal(
+                "pinned thread is no longer running".into(),
+            ));
+        }
+
+        result_receiver.recv().unwrap_or_else(|_| {
+            Err(Error::JvmInternal(
+                "pinned thread dropped the operation without completing it".into(),
+            ))
+        })
+    }
+
+    /// Stops accepting work, waits for all queued work to complete, and joins the pinned thread.
+    pub fn shutdown(mut self) {
+        self.shutdown_impl();
+    }
+
+    fn shutdown_impl(&mut self) {
+        drop(self.sender.take());
+        if let Some(thread) = self.thread.take() {
+            if std::thread::current().id() != self.thread_id {
+                let _ = thread.join();
+            }
+        }
+    }
+}
+
+impl Drop for PinnedJvm {
+    fn drop(&mut self) {
+        self.shutdown_impl();
+    }
+}
+
+fn panic_message(panic: Box<dyn Any + Send + 'static>) -> String {
+    let message = if let Some(s) = panic.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = panic.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "<unknown panic payload>".to_string()
+    };
+    format!("operation on pinned JVM thread panicked: {message}")
+}
@@ -288,6 +288,37 @@ impl<'jvm> EnvPtr<'jvm> {
         }
     }
 
+    /// Creates a new frame of local references, with room for at least `capacity` of them.
+    ///
+    /// # Safety
+    ///
+    /// The frame must be popped with [`Self::pop_local_frame`] before any local reference
+    /// of the enclosing frame is deleted.
+    pub unsafe fn push_local_frame(self, capacity: i32) -> crate::Result<'jvm, ()> {
+        let result = self.invoke(|f| f.PushLocalFrame, |env, f| f(env, capacity));
+
+        if result == 0 {
+            Ok(())
+        } else {
+            self.check_exception()?;
+            Err(crate::Error::JvmInternal(format!(
+                "pushing a frame of {capacity} local references failed"
+            )))
+        }
+    }
+
+    /// Pops the current frame of local references, freeing all of them except `result`,
+    /// for which a new local reference in the enclosing frame is returned.
+    ///
+    /// # Safety
+    ///
+    /// There must be a frame created by [`Self::push_local_frame`], and none of its local
+    /// references (other than through the returned pointer) may be used afterwards.
+    pub unsafe fn pop_local_frame(self, result: Option<ObjectPtr>) -> Option<ObjectPtr> {
+        let result = result.map_or(std::ptr::null_mut(), |r| r.as_ptr());
+        ObjectPtr::new(self.invoke(|f| f.PopLocalFrame, |env, f| f(env, result)))
+    }
+
     pub fn check_exception(self) -> crate::Result<'jvm, ()> {
         // SAFETY: we don't hold on to the return env ptr
         let thrown = unsafe { self.invoke(|env| env.ExceptionOccurred, |env, f| f(env)) };
@@ -482,6 +513,7 @@ impl<'jvm> FromJniValue<'jvm> for bool {
     type JniValue = jni_sys::jboolean;
 
     unsafe fn from_jni_value(_jvm: &mut Jvm<'jvm>, value: Self::JniValue) -> Self {
-        value == jni_sys::JNI_TRUE
+        // Per the JNI spec, any non-zero `jboolean` is true, not only `JNI_TRUE`.
+        value != jni_sys::JNI_FALSE
     }
 }
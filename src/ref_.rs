@@ -1,12 +1,28 @@
-use std::{marker::PhantomData, ops::Deref, ptr::NonNull};
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::Deref,
+    ptr::NonNull,
+};
 
 use crate::jvm::JavaObjectExt;
 use crate::thread;
-use crate::{cast::Upcast, jvm::CloneIn, plumbing::ObjectPtr, raw::EnvPtr, JavaObject, Jvm};
+use crate::JvmOp;
+use crate::{
+    cast::Upcast,
+    jvm::CloneIn,
+    plumbing::ObjectPtr,
+    raw::{EnvPtr, HasEnvPtr},
+    GlobalResult, ImplementsEquals, ImplementsHashCode, JavaObject, Jvm,
+};
 
 /// An owned local reference to a non-null Java object of type `T`. The reference will be freed when
 /// dropped. Cannot be shared across threads or [`Jvm::with`] invocations.
-#[derive_where::derive_where(PartialEq, Eq, Hash, Debug)]
+///
+/// `{:?}` shows the object's Java `toString()`. For classes that override `equals()`
+/// (see [`ImplementsEquals`]), `==` compares the objects with it, and for classes that override
+/// `hashCode()` (see [`ImplementsHashCode`]), `Hash` uses it.
 pub struct Local<'jvm, T: JavaObject> {
     env: EnvPtr<'jvm>,
     obj: ObjectPtr,
@@ -43,6 +59,11 @@ impl<'jvm, T: JavaObject> Local<'jvm, T> {
         }
     }
 
+    /// Creates a [`WeakGlobal`] reference to the same object.
+    pub fn downgrade(&self) -> WeakGlobal<T> {
+        WeakGlobal::new(self.env, &**self)
+    }
+
     /// Convert this `Local` into a raw object pointer *without* running the Local destructor (which would release it from the JVM).
     ///
     /// # Safety
@@ -56,6 +77,12 @@ impl<'jvm, T: JavaObject> Local<'jvm, T> {
     }
 }
 
+impl<'jvm, T: JavaObject> HasEnvPtr<'jvm> for Local<'jvm, T> {
+    fn env(&self) -> EnvPtr<'jvm> {
+        self.env
+    }
+}
+
 impl<T: JavaObject> Drop for Local<'_, T> {
     fn drop(&mut self) {
         // SAFETY: Local owns the local ref and it's no longer possible to dereference the object pointer.
@@ -75,10 +102,29 @@ impl<T: JavaObject> Deref for Local<'_, T> {
     }
 }
 
+impl<T: JavaObject> fmt::Debug for Local<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_java_object(&**self, f)
+    }
+}
+
 /// An owned global reference to a non-null Java object of type `T`. The reference will be freed when dropped.
-#[derive_where::derive_where(PartialEq, Eq, Hash)]
+///
+/// `{:?}` shows the object's Java `toString()`. `==` compares the objects with Java's `equals()`,
+/// and hashing uses `hashCode()`, attaching the current thread to the JVM if needed. To check
+/// whether two references point to the same object, use
+/// [`reference_eq`][crate::prelude::JavaEqExt::reference_eq].
+///
+/// `PartialEq` and `Hash` cannot report errors, so this is what they, and the same impls of
+/// [`Local`], Java records, and [`ByJavaHash`][crate::ByJavaHash], do when Java fails: if
+/// `equals()` throws, the references are only equal if they point to the same object; if
+/// `hashCode()` throws, the hash is that of `0`. If the thread cannot be attached to the JVM,
+/// references are only equal if they are the same reference, and the hash is that of `0`.
 pub struct Global<T: JavaObject> {
     obj: ObjectPtr,
+    /// The category this reference is counted against, see [`crate::budget`].
+    #[cfg(feature = "global-ref-budget")]
+    category: &'static str,
     _marker: PhantomData<T>,
 }
 
@@ -93,6 +139,8 @@ impl<T: JavaObject> Global<T> {
     pub(crate) unsafe fn from_raw(obj: ObjectPtr) -> Self {
         Self {
             obj,
+            #[cfg(feature = "global-ref-budget")]
+            category: crate::budget::acquire(crate::budget::current_category()),
             _marker: PhantomData,
         }
     }
@@ -105,36 +153,270 @@ impl<T: JavaObject> Global<T> {
             Self::from_raw(NonNull::new(new_ref).unwrap().into())
         }
     }
+
+    /// Creates a [`WeakGlobal`] reference to the same object.
+    pub fn downgrade(&self) -> GlobalResult<WeakGlobal<T>> {
+        with_current_env(|env| WeakGlobal::new(env, &**self))
+    }
+
+    /// Creates a new global reference to `obj`, counted against `category` rather than the
+    /// current thread's category (see [`crate::budget`]).
+    #[cfg(feature = "global-ref-budget")]
+    pub fn new_tagged(jvm: &mut Jvm<'_>, obj: &T, category: &'static str) -> Self {
+        let _guard = crate::budget::enter(category);
+        Self::new(jvm.env(), obj)
+    }
 }
 
 impl<T: JavaObject> Drop for Global<T> {
     fn drop(&mut self) {
-        let jvm = crate::jvm::unwrap_global_jvm();
-
         // SAFETY: Global owns the global ref and it's no longer possible to dereference the object pointer.
-        let delete = |env: EnvPtr<'_>| unsafe {
+        let result = with_current_env(|env| unsafe {
             env.invoke(
                 |jni| jni.DeleteGlobalRef,
                 |jni, f| f(jni, self.obj.as_ptr()),
             )
-        };
+        });
+        if let Err(err) = result {
+            tracing::warn!(
+                ?err,
+                "unable to get JNI interface for local thread to delete global ref"
+            );
+        }
+
+        #[cfg(feature = "global-ref-budget")]
+        crate::budget::release(self.category);
+    }
+}
 
-        match unsafe { jvm.env() } {
-            Ok(Some(env)) => delete(env),
-            Ok(None) => {
-                // SAFETY: jvm is a valid pointer since duchess will not deinitialize a JVM once created
-                match unsafe { thread::attach(jvm) } {
-                    Ok(mut attached) => delete(attached.env()),
-                    Err(err) => {
-                        tracing::warn!(?err, "unable to attach current thread to delete global ref")
-                    }
+/// Runs `f` with the JNI interface of the current thread, attaching the thread for the duration
+/// of the call if needed (e.g. when a [`Global`] is dropped outside of [`Jvm::with`]).
+pub(crate) fn with_current_env<R>(f: impl FnOnce(EnvPtr<'_>) -> R) -> GlobalResult<R> {
+    let jvm = crate::jvm::unwrap_global_jvm();
+
+    // SAFETY: jvm is a valid pointer since duchess will not deinitialize a JVM once created,
+    // and the env is only used while the thread is attached.
+    match unsafe { jvm.env()? } {
+        Some(env) => Ok(f(env)),
+        None => {
+            let mut attached = unsafe { thread::attach(jvm)? };
+            Ok(f(attached.env()))
+        }
+    }
+}
+
+/// Formats `obj` with its Java `toString()`, for the `Debug` impls of Java objects and of
+/// references to them. `Debug` must not panic, so if `toString()` throws or returns `null`,
+/// or the JVM cannot be reached from this thread, this formats the Rust type and the
+/// reference instead (e.g. `my_crate::printing::Broken@0x7f3c2c00d0a8`).
+///
+/// The alternate form (`{:#?}`) also shows the runtime class of the object, as
+/// `JavaObject { class: "java.util.ArrayList", str: "[lamp, desk]" }`.
+#[doc(hidden)]
+pub fn debug_java_object<T: JavaObject>(obj: &T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if f.alternate() {
+        let class = java_class_name(obj);
+        let class = class.as_deref().unwrap_or(std::any::type_name::<T>());
+        return debug_java_struct(obj, class, f);
+    }
+    debug_to_string(obj, f)
+}
+
+/// Like [`debug_java_object`], for the `Debug` impls of generated classes, which know the
+/// name of the Java class they mirror and so need not ask the JVM for it.
+#[doc(hidden)]
+pub fn debug_java_class<T: JavaObject>(
+    obj: &T,
+    class_name: &'static str,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    if f.alternate() {
+        return debug_java_struct(obj, class_name, f);
+    }
+    debug_to_string(obj, f)
+}
+
+fn debug_java_struct<T: JavaObject>(
+    obj: &T,
+    class_name: &str,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    let mut debug = f.debug_struct("JavaObject");
+    debug.field("class", &class_name);
+    match java_to_string(obj) {
+        Ok(string) => debug.field("str", &string),
+        Err(error) => debug.field("error", &error),
+    };
+    debug.finish()
+}
+
+fn debug_to_string<T: JavaObject>(obj: &T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match java_to_string(obj) {
+        Ok(string) => f.write_str(&string),
+        Err(_) => write!(
+            f,
+            "{}@{:?}",
+            std::any::type_name::<T>(),
+            obj.as_raw().as_ptr()
+        ),
+    }
+}
+
+/// The Java `toString()` of `obj`, or why it could not be had: the exception that
+/// `toString()` threw (as formatted by its own `toString()`), a `null` result, or the error
+/// reaching the JVM from this thread.
+fn java_to_string<T: JavaObject>(obj: &T) -> Result<String, String> {
+    let string = with_current_env(|env| {
+        // SAFETY: `env` stays attached to this thread while the closure runs.
+        let jvm = &mut unsafe { Jvm::from_env(env) };
+        match object_to_string(obj.as_raw(), jvm) {
+            Ok(Some(string)) => Ok(string),
+            Ok(None) => Err("`toString()` returned null".to_string()),
+            Err(crate::Error::Thrown(exception)) => {
+                match object_to_string(exception.as_raw(), jvm) {
+                    Ok(Some(message)) => Err(message),
+                    _ => Err("`toString()` threw an exception".to_string()),
                 }
             }
-            Err(err) => tracing::warn!(
-                ?err,
-                "unable to get JNI interface for local thread to delete global ref"
-            ),
+            Err(error) => Err(error.to_string()),
+        }
+    });
+    string.unwrap_or_else(|e| Err(e.to_string()))
+}
+
+/// The name of the runtime class of `obj`, from `getClass().getName()`.
+fn java_class_name<T: JavaObject>(obj: &T) -> Result<String, String> {
+    let name = with_current_env(|env| {
+        // SAFETY: `env` stays attached to this thread while the closure runs.
+        let jvm = &mut unsafe { Jvm::from_env(env) };
+        // SAFETY: every Java object is an instance of `java.lang.Object`.
+        let object: &crate::java::lang::Object = unsafe { JavaObjectExt::from_raw(obj.as_raw()) };
+        match object
+            .get_class()
+            .get_name()
+            .to_rust::<Option<String>>()
+            .execute_with(jvm)
+        {
+            Ok(Some(name)) => Ok(name),
+            Ok(None) => Err("`getName()` returned null".to_string()),
+            Err(error) => Err(error.to_string()),
+        }
+    });
+    name.unwrap_or_else(|e| Err(e.to_string()))
+}
+
+fn object_to_string<'jvm>(
+    obj: ObjectPtr,
+    jvm: &mut Jvm<'jvm>,
+) -> crate::Result<'jvm, Option<String>> {
+    // SAFETY: every Java object is an instance of `java.lang.Object`.
+    let object: &crate::java::lang::Object = unsafe { JavaObjectExt::from_raw(obj) };
+    object
+        .to_string()
+        .to_rust::<Option<String>>()
+        .execute_with(jvm)
+}
+
+#[cfg(feature = "display")]
+impl<'jvm, T: JavaObject> Local<'jvm, T> {
+    /// Returns an adapter that implements [`Display`](fmt::Display) with the object's Java
+    /// `toString()`, as in `println!("{}", list.display())`.
+    pub fn display(&self) -> JavaDisplay<'_, T> {
+        JavaDisplay(self)
+    }
+}
+
+/// Formats a Java object with its `toString()`; see [`Local::display`]. If that cannot be had,
+/// this shows `<java error: ...>` with the reason, e.g. the exception that `toString()` threw,
+/// rather than failing.
+#[cfg(feature = "display")]
+pub struct JavaDisplay<'a, T: JavaObject>(&'a T);
+
+#[cfg(feature = "display")]
+impl<T: JavaObject> fmt::Display for JavaDisplay<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match java_to_string(self.0) {
+            Ok(string) => f.write_str(&string),
+            Err(error) => write!(f, "<java error: {error}>"),
+        }
+    }
+}
+
+/// Compares `a` and `b` with Java's `a.equals(b)`, for the `PartialEq` impls of Java records,
+/// of [`Local`], of [`Global`], and of [`ByJavaHash`][crate::ByJavaHash], with the fallbacks
+/// described on [`Global`].
+#[doc(hidden)]
+pub fn java_equals<T: JavaObject>(a: &T, b: &T) -> bool {
+    let equals = with_current_env(|env| {
+        // SAFETY: `env` stays attached to this thread while the closure runs.
+        let jvm = &mut unsafe { Jvm::from_env(env) };
+        // SAFETY: every Java object is an instance of `java.lang.Object`.
+        let [a_object, b_object]: [&crate::java::lang::Object; 2] =
+            [a, b].map(|obj| unsafe { JavaObjectExt::from_raw(obj.as_raw()) });
+        match a_object.equals(b_object).execute_with(jvm) {
+            Ok(equals) => equals,
+            Err(_) => is_same_object(env, a.as_raw(), b.as_raw()),
         }
+    });
+    equals.unwrap_or_else(|_| a.as_raw() == b.as_raw())
+}
+
+/// Whether `a` and `b` point to the same Java object (Java's `a == b`).
+pub(crate) fn is_same_object(env: EnvPtr<'_>, a: ObjectPtr, b: ObjectPtr) -> bool {
+    // SAFETY: `a` and `b` are live references to Java objects.
+    unsafe {
+        env.invoke(
+            |env| env.IsSameObject,
+            |env, f| f(env, a.as_ptr(), b.as_ptr()),
+        ) == jni_sys::JNI_TRUE
+    }
+}
+
+impl<T: ImplementsEquals> PartialEq for Local<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        java_equals(&**self, &**other)
+    }
+}
+
+// Java's `equals()` contract requires it to be reflexive.
+impl<T: ImplementsEquals> Eq for Local<'_, T> {}
+
+impl<T: ImplementsHashCode> Hash for Local<'_, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        java_hash_code(&**self).hash(state)
+    }
+}
+
+impl<T: JavaObject> PartialEq for Global<T> {
+    fn eq(&self, other: &Self) -> bool {
+        java_equals(&**self, &**other)
+    }
+}
+
+impl<T: JavaObject> Eq for Global<T> {}
+
+impl<T: JavaObject> Hash for Global<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        java_hash_code(&**self).hash(state)
+    }
+}
+
+/// The Java `hashCode()` of `obj`, for the `Hash` impls of [`Local`], of [`Global`], and of
+/// [`ByJavaHash`][crate::ByJavaHash], with the fallbacks described on [`Global`].
+pub(crate) fn java_hash_code<T: JavaObject>(obj: &T) -> i32 {
+    let hash_code = with_current_env(|env| {
+        // SAFETY: `env` stays attached to this thread while the closure runs.
+        let jvm = &mut unsafe { Jvm::from_env(env) };
+        // SAFETY: every Java object is an instance of `java.lang.Object`.
+        let object: &crate::java::lang::Object = unsafe { JavaObjectExt::from_raw(obj.as_raw()) };
+        object.hash_code().execute_with(jvm).ok()
+    });
+    hash_code.ok().flatten().unwrap_or(0)
+}
+
+impl<T: JavaObject> fmt::Debug for Global<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_java_object(&**self, f)
     }
 }
 
@@ -151,6 +433,62 @@ impl<T: JavaObject> Deref for Global<T> {
     }
 }
 
+/// An owned weak global reference to a Java object of type `T`, which does not keep the object
+/// from being garbage collected, e.g. for caches. Use [`upgrade`][`Self::upgrade`] to get a
+/// [`Local`] reference to the object while it is still alive. The reference will be freed
+/// when dropped.
+#[derive_where::derive_where(Debug)]
+pub struct WeakGlobal<T: JavaObject> {
+    obj: ObjectPtr,
+    _marker: PhantomData<T>,
+}
+
+impl<T: JavaObject> WeakGlobal<T> {
+    /// Creates a new weak global reference to `obj` via a `NewWeakGlobalRef` JNI call.
+    fn new(env: EnvPtr<'_>, obj: &T) -> Self {
+        // SAFETY: The JavaObject trait contract ensures that &T points to a Java object that is an instance of T.
+        let new_ref =
+            unsafe { env.invoke(|e| e.NewWeakGlobalRef, |e, f| f(e, obj.as_raw().as_ptr())) };
+        Self {
+            obj: NonNull::new(new_ref).unwrap().into(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a new local reference to the object, or `None` if it has been garbage collected.
+    pub fn upgrade<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> Option<Local<'jvm, T>> {
+        let env = jvm.env();
+        // SAFETY: `NewLocalRef` returns null if the object of a weak reference was collected,
+        // and otherwise a new local reference to an instance of T.
+        unsafe {
+            let new_ref = env.invoke(|e| e.NewLocalRef, |e, f| f(e, self.obj.as_ptr()));
+            ObjectPtr::new(new_ref).map(|obj| Local::from_raw(env, obj))
+        }
+    }
+}
+
+impl<T: JavaObject> Drop for WeakGlobal<T> {
+    fn drop(&mut self) {
+        // SAFETY: WeakGlobal owns the weak global ref and it's no longer possible to use the object pointer.
+        let result = with_current_env(|env| unsafe {
+            env.invoke(
+                |jni| jni.DeleteWeakGlobalRef,
+                |jni, f| f(jni, self.obj.as_ptr()),
+            )
+        });
+        if let Err(err) = result {
+            tracing::warn!(
+                ?err,
+                "unable to get JNI interface for local thread to delete weak global ref"
+            );
+        }
+    }
+}
+
+// SAFETY: The JNI promises weak global refs are shareable across threads, like global refs
+unsafe impl<T: JavaObject> Send for WeakGlobal<T> {}
+unsafe impl<T: JavaObject> Sync for WeakGlobal<T> {}
+
 impl<'a, R, S> AsRef<S> for Local<'a, R>
 where
     R: Upcast<S>,
@@ -168,9 +506,10 @@ impl<'a, R: JavaObject> Local<'a, R> {
         R: Upcast<S>,
         S: JavaObject + 'a,
     {
-        // SAFETY: From the Upcast trait contract, we know R is also an instance of S
-        let upcast = unsafe { Local::<S>::from_raw(self.env, self.obj) };
-        upcast
+        // SAFETY: From the Upcast trait contract, we know R is also an instance of S.
+        // `into_raw` transfers ownership of the local reference to the new `Local`.
+        let env = self.env;
+        unsafe { Local::<S>::from_raw(env, self.into_raw()) }
     }
 }
 
@@ -191,8 +530,16 @@ impl<R: JavaObject> Global<R> {
         R: Upcast<S>,
         S: JavaObject + 'static,
     {
-        // SAFETY: From the Upcast trait contract, we know R is also an instance of S
-        let upcast = unsafe { Global::<S>::from_raw(self.obj) };
+        // From the Upcast trait contract, we know R is also an instance of S.
+        // Ownership of the global reference, and its place in the budget, is transferred
+        // to the new `Global`.
+        let upcast = Global {
+            obj: self.obj,
+            #[cfg(feature = "global-ref-budget")]
+            category: self.category,
+            _marker: PhantomData,
+        };
+        std::mem::forget(self);
         upcast
     }
 }
@@ -1,4 +1,4 @@
-use std::ffi::{c_char, CString};
+use std::ffi::CString;
 
 use crate::{
     error::check_exception, into_rust::IntoRust, java::lang::String as JavaString,
@@ -46,10 +46,6 @@ impl IntoRust<String> for &JavaString {
 
         let env = jvm.env();
 
-        // Note: we need to pull both the Modified UTF-8 length and the UTF-16 length of the string. The indexes to
-        // GetStringUTFRegion are UTF-16, while the space we need to allocate for the output pointer is the Modified
-        // UTF-8 length!
-
         // SAFETY: J::Output impls AsRef<JavaString>, so we know str_raw points to a non-null Java String
         let cesu8_len = unsafe {
             env.invoke(
@@ -59,30 +55,34 @@ impl IntoRust<String> for &JavaString {
         };
         assert!(cesu8_len >= 0);
 
-        // SAFETY: same as for cesu8_len
-        let utf16_len =
-            unsafe { env.invoke(|env| env.GetStringLength, |env, f| f(env, str_raw.as_ptr())) };
-        assert!(utf16_len >= 0);
+        // SAFETY: same as for cesu8_len; we pass a null `isCopy` pointer since we don't care whether the JVM copied.
+        let chars = unsafe {
+            env.invoke(
+                |env| env.GetStringUTFChars,
+                |env, f| f(env, str_raw.as_ptr(), std::ptr::null_mut()),
+            )
+        };
+        if chars.is_null() {
+            check_exception(jvm)?; // likely threw an OutOfMemoryError
+            return Err(Error::JvmInternal(
+                "JVM failed to access the contents of a String".into(),
+            ));
+        }
 
-        let mut cesu_bytes = Vec::<u8>::with_capacity(cesu8_len as usize);
-        // SAFETY: cesu_bytes is a non-null pointer with enough capacity for the entire string when encoded in Modified
-        // UTF-8 (with no trailing nul byte).
+        // Copy the Modified UTF-8 bytes out in a single allocation of exactly the right size, then hand the
+        // JVM's buffer back immediately.
+        //
+        // SAFETY: chars is non-null and points to `cesu8_len` bytes of Modified UTF-8 (plus a trailing nul byte,
+        // which we don't copy). It stays valid until the matching ReleaseStringUTFChars below.
+        let cesu_bytes =
+            unsafe { std::slice::from_raw_parts(chars.cast::<u8>(), cesu8_len as usize) }.to_vec();
+        // SAFETY: chars was returned by GetStringUTFChars for this very string and is released exactly once.
         unsafe {
             env.invoke(
-                |env| env.GetStringUTFRegion,
-                |env, f| {
-                    f(
-                        env,
-                        str_raw.as_ptr(),
-                        0,
-                        utf16_len,
-                        cesu_bytes.as_mut_ptr().cast::<c_char>(),
-                    )
-                },
-            );
-            cesu_bytes.set_len(cesu8_len as usize);
+                |env| env.ReleaseStringUTFChars,
+                |env, f| f(env, str_raw.as_ptr(), chars),
+            )
         };
-        check_exception(jvm)?;
 
         // In the common case where there are no surrogate bytes, we can do a (checked) conversion of the Vec into a
         // Rust String. Otherwise, we'll need to use the cesu8 crate to convert properly. Note that this is the same
@@ -0,0 +1,47 @@
+use crate::{prelude::*, Error, GlobalResult, Jvm};
+
+mod junit;
+
+pub use junit::{run_junit, JunitFailure, JunitOutcome, JunitReport, JunitSelector, JunitTest};
+
+/// What a `#[duchess::test]` function may return.
+pub trait TestOutput {
+    fn into_global_result(self) -> GlobalResult<()>;
+}
+
+impl TestOutput for () {
+    fn into_global_result(self) -> GlobalResult<()> {
+        Ok(())
+    }
+}
+
+impl TestOutput for GlobalResult<()> {
+    fn into_global_result(self) -> GlobalResult<()> {
+        self
+    }
+}
+
+/// Starts the JVM (or uses the one already running) and runs `test`, panicking if it fails.
+/// Used by code generated for `#[duchess::test]`.
+///
+/// The `classpath` only takes effect if this starts the JVM: all tests in a test binary share
+/// one JVM, so they should agree on the classpath. If `test` returns a Java exception, its stack
+/// trace is printed (by Java, to stderr) before panicking.
+pub fn run_test_with_jvm<O: TestOutput>(classpath: Option<&str>, test: impl FnOnce() -> O) {
+    let mut builder = Jvm::builder();
+    if let Some(classpath) = classpath {
+        builder = builder.add_classpath(classpath);
+    }
+    if let Err(err) = builder.launch_or_use_existing() {
+        panic!("failed to start the JVM: {err}");
+    }
+
+    match test().into_global_result() {
+        Ok(()) => {}
+        Err(Error::Thrown(exception)) => {
+            let _ = exception.print_stack_trace().execute();
+            panic!("{}", Error::Thrown(exception));
+        }
+        Err(err) => panic!("{err}"),
+    }
+}
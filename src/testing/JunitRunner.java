@@ -0,0 +1,137 @@
+package duchess;
+
+import java.io.PrintWriter;
+import java.io.StringWriter;
+import java.lang.reflect.Array;
+import java.lang.reflect.InvocationHandler;
+import java.lang.reflect.InvocationTargetException;
+import java.lang.reflect.Method;
+import java.lang.reflect.Proxy;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.Optional;
+import java.util.function.Function;
+
+/**
+ * Runs tests on the JUnit Platform for {@code duchess::testing::run_junit}, passing the events
+ * of each test and container to a Rust closure. The class is defined by duchess at runtime, from
+ * the class file embedded in the crate, so it cannot link against JUnit: it calls the launcher
+ * API found on the classpath through reflection, and implements its
+ * {@code TestExecutionListener} with a {@link Proxy}.
+ *
+ * <p>Each event is a {@code String[]} of the kind ({@code started}, {@code skipped}, or
+ * {@code finished}), the unique ID, the display name, whether it is a test ({@code true}) or a
+ * container ({@code false}), the status ({@code SUCCESSFUL}, {@code ABORTED}, or
+ * {@code FAILED}, for {@code finished}), the message (the reason for {@code skipped}, the
+ * exception for {@code finished}), and the stack trace. Missing values are empty strings.
+ */
+public final class JunitRunner implements InvocationHandler {
+    private final Function<String[], ?> events;
+
+    private JunitRunner(Function<String[], ?> events) {
+        this.events = events;
+    }
+
+    /**
+     * Runs the tests picked by the selectors, where {@code selectors[i]} is the name of a method
+     * of {@code DiscoverySelectors} taking a string (e.g. {@code selectClass}) and
+     * {@code values[i]} its argument.
+     */
+    public static void run(String[] selectors, String[] values, Function<String[], ?> events)
+            throws Throwable {
+        ClassLoader loader = Thread.currentThread().getContextClassLoader();
+        if (loader == null) {
+            loader = ClassLoader.getSystemClassLoader();
+        }
+        try {
+            Class<?> discoverySelectors =
+                    Class.forName("org.junit.platform.engine.discovery.DiscoverySelectors", true, loader);
+            List<Object> selected = new ArrayList<>();
+            for (int i = 0; i < selectors.length; i++) {
+                selected.add(discoverySelectors.getMethod(selectors[i], String.class).invoke(null, values[i]));
+            }
+
+            Class<?> builderClass =
+                    Class.forName("org.junit.platform.launcher.core.LauncherDiscoveryRequestBuilder", true, loader);
+            Object builder = builderClass.getMethod("request").invoke(null);
+            builderClass.getMethod("selectors", List.class).invoke(builder, selected);
+            Object request = builderClass.getMethod("build").invoke(builder);
+
+            Class<?> listenerClass =
+                    Class.forName("org.junit.platform.launcher.TestExecutionListener", true, loader);
+            Object listeners = Array.newInstance(listenerClass, 1);
+            Array.set(
+                    listeners,
+                    0,
+                    Proxy.newProxyInstance(
+                            listenerClass.getClassLoader(), new Class<?>[] {listenerClass}, new JunitRunner(events)));
+
+            Class<?> launcherClass = Class.forName("org.junit.platform.launcher.Launcher", true, loader);
+            Class<?> requestClass = Class.forName("org.junit.platform.launcher.LauncherDiscoveryRequest", true, loader);
+            Object launcher = Class.forName("org.junit.platform.launcher.core.LauncherFactory", true, loader)
+                    .getMethod("create")
+                    .invoke(null);
+            launcherClass.getMethod("execute", requestClass, listeners.getClass()).invoke(launcher, request, listeners);
+        } catch (InvocationTargetException e) {
+            throw e.getCause();
+        }
+    }
+
+    public Object invoke(Object proxy, Method method, Object[] args) throws Throwable {
+        int arity = args == null ? 0 : args.length;
+        switch (method.getName() + "/" + arity) {
+            case "executionStarted/1":
+                report("started", args[0], "", null, null);
+                return null;
+            case "executionSkipped/2":
+                report("skipped", args[0], "", (String) args[1], null);
+                return null;
+            case "executionFinished/2": {
+                Object status = call(args[1], "getStatus");
+                Optional<?> throwable = (Optional<?>) call(args[1], "getThrowable");
+                report("finished", args[0], status.toString(), null, (Throwable) throwable.orElse(null));
+                return null;
+            }
+            case "equals/1":
+                return proxy == args[0];
+            case "hashCode/0":
+                return System.identityHashCode(proxy);
+            case "toString/0":
+                return "duchess.JunitRunner listener";
+            default:
+                // The other events, which all return `void`.
+                return null;
+        }
+    }
+
+    private void report(String kind, Object identifier, String status, String message, Throwable throwable)
+            throws Throwable {
+        String stackTrace = "";
+        if (throwable != null) {
+            message = throwable.toString();
+            StringWriter writer = new StringWriter();
+            throwable.printStackTrace(new PrintWriter(writer));
+            stackTrace = writer.toString();
+        }
+        if (message == null) {
+            message = "";
+        }
+        events.apply(new String[] {
+            kind,
+            (String) call(identifier, "getUniqueId"),
+            (String) call(identifier, "getDisplayName"),
+            String.valueOf(call(identifier, "isTest")),
+            status,
+            message,
+            stackTrace,
+        });
+    }
+
+    private static Object call(Object target, String method) throws Throwable {
+        try {
+            return target.getClass().getMethod(method).invoke(target);
+        } catch (InvocationTargetException e) {
+            throw e.getCause();
+        }
+    }
+}
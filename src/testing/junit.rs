@@ -0,0 +1,290 @@
+use std::ffi::CStr;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use jni_sys::jvalue;
+use once_cell::sync::OnceCell;
+
+use crate::{
+    callback::{self, define_class},
+    error::check_exception,
+    find::find_method,
+    java::{
+        self,
+        lang::{Class, Object, Throwable},
+        util::function::Function,
+    },
+    jvm::JavaObjectExt,
+    prelude::*,
+    raw::{HasEnvPtr, MethodPtr},
+    Global, Jvm, Local,
+};
+
+/// The class `duchess.JunitRunner`, compiled from `JunitRunner.java` (with `javac --release 8`)
+/// and defined the first time it is needed, like the classes of the `callback` module, and its
+/// static `run` method.
+static JUNIT_RUNNER: OnceCell<(Global<Class<Object>>, MethodPtr)> = OnceCell::new();
+
+/// Which tests [`run_junit`] runs, as with the `--select-*` options of JUnit's console launcher.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JunitSelector {
+    /// The tests of a class, by its fully qualified name.
+    Class(String),
+    /// One test method, e.g. `com.example.CalculatorTest#adds`, or `...#adds(int, int)` for
+    /// one overload.
+    Method(String),
+    /// The tests of the classes in a package and its subpackages.
+    Package(String),
+}
+
+impl JunitSelector {
+    /// The method of JUnit's `DiscoverySelectors` that makes this selector, and its argument.
+    fn discovery_selector(&self) -> (String, String) {
+        let (method, value) = match self {
+            JunitSelector::Class(name) => ("selectClass", name),
+            JunitSelector::Method(name) => ("selectMethod", name),
+            JunitSelector::Package(name) => ("selectPackage", name),
+        };
+        (method.to_string(), value.clone())
+    }
+}
+
+/// The results of [`run_junit`]: one entry per test, and one per container (such as a test
+/// class), each in the order they finished or were skipped.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct JunitReport {
+    pub tests: Vec<JunitTest>,
+    pub containers: Vec<JunitTest>,
+}
+
+/// A test or container that JUnit ran or skipped.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JunitTest {
+    /// JUnit's unique ID, e.g. `[engine:junit-jupiter]/[class:com.example.CalculatorTest]/[method:adds()]`.
+    pub unique_id: String,
+    /// The name JUnit shows for it, e.g. `adds()`.
+    pub display_name: String,
+    pub outcome: JunitOutcome,
+}
+
+/// How a test or container ended.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JunitOutcome {
+    Successful,
+    /// Not run, e.g. because of `@Disabled`, with the reason JUnit gives.
+    Skipped(String),
+    /// Stopped early, e.g. by a failed assumption. This does not fail the run.
+    Aborted(JunitFailure),
+    Failed(JunitFailure),
+}
+
+/// Why a test or container failed or was aborted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JunitFailure {
+    /// The exception, as formatted by its `toString()`, e.g.
+    /// `org.opentest4j.AssertionFailedError: expected: <3> but was: <4>`.
+    pub message: String,
+    /// The exception's stack trace, as printed by `printStackTrace()`.
+    pub stack_trace: String,
+}
+
+impl JunitReport {
+    /// Whether no test or container failed. As with JUnit's console launcher, aborted and
+    /// skipped tests do not count as failures.
+    pub fn is_success(&self) -> bool {
+        self.failures().next().is_none()
+    }
+
+    /// The tests and containers that failed.
+    pub fn failures(&self) -> impl Iterator<Item = (&JunitTest, &JunitFailure)> {
+        self.containers
+            .iter()
+            .chain(&self.tests)
+            .filter_map(|test| match &test.outcome {
+                JunitOutcome::Failed(failure) => Some((test, failure)),
+                _ => None,
+            })
+    }
+
+    /// The number of tests that succeeded.
+    pub fn succeeded(&self) -> usize {
+        self.count(|outcome| matches!(outcome, JunitOutcome::Successful))
+    }
+
+    /// The number of tests that failed.
+    pub fn failed(&self) -> usize {
+        self.count(|outcome| matches!(outcome, JunitOutcome::Failed(_)))
+    }
+
+    /// The number of tests that were aborted.
+    pub fn aborted(&self) -> usize {
+        self.count(|outcome| matches!(outcome, JunitOutcome::Aborted(_)))
+    }
+
+    /// The number of tests that were skipped.
+    pub fn skipped(&self) -> usize {
+        self.count(|outcome| matches!(outcome, JunitOutcome::Skipped(_)))
+    }
+
+    fn count(&self, f: impl Fn(&JunitOutcome) -> bool) -> usize {
+        self.tests.iter().filter(|test| f(&test.outcome)).count()
+    }
+
+    /// Adds an event reported by `duchess.JunitRunner`; see there for its elements.
+    fn record(&mut self, event: Vec<String>) {
+        let Ok([kind, unique_id, display_name, is_test, status, message, stack_trace]) =
+            <[String; 7]>::try_from(event)
+        else {
+            panic!("malformed event from `duchess.JunitRunner`");
+        };
+        let outcome = match (&kind[..], &status[..]) {
+            ("started", _) => {
+                tracing::debug!(test = %display_name, "JUnit test started");
+                return;
+            }
+            ("skipped", _) => JunitOutcome::Skipped(message),
+            (_, "SUCCESSFUL") => JunitOutcome::Successful,
+            (_, "ABORTED") => JunitOutcome::Aborted(JunitFailure {
+                message,
+                stack_trace,
+            }),
+            _ => JunitOutcome::Failed(JunitFailure {
+                message,
+                stack_trace,
+            }),
+        };
+        tracing::debug!(test = %display_name, ?outcome, "JUnit test {kind}");
+
+        let test = JunitTest {
+            unique_id,
+            display_name,
+            outcome,
+        };
+        if is_test == "true" {
+            self.tests.push(test);
+        } else {
+            self.containers.push(test);
+        }
+    }
+}
+
+/// Shows the number of tests with each outcome, followed by each failure with its stack trace,
+/// e.g. for `assert!(report.is_success(), "{report}")`.
+impl fmt::Display for JunitReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} tests: {} succeeded, {} failed, {} aborted, {} skipped",
+            self.tests.len(),
+            self.succeeded(),
+            self.failed(),
+            self.aborted(),
+            self.skipped(),
+        )?;
+        for (test, failure) in self.failures() {
+            write!(
+                f,
+                "\n\n`{}` failed: {}",
+                test.unique_id, failure.stack_trace
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs the JUnit tests picked by `selectors` with the JUnit Platform launcher, and reports the
+/// outcome of each test and container, so that a Rust test can gate on a suite written in Java:
+///
+/// ```ignore
+/// #[duchess::test]
+/// fn java_conformance_tests() -> duchess::GlobalResult<()> {
+///     let selectors = [JunitSelector::Package("com.example.conformance".to_string())];
+///     let report = Jvm::with(|jvm| run_junit(jvm, &selectors))?;
+///     assert!(report.is_success(), "{report}");
+///     Ok(())
+/// }
+/// ```
+///
+/// The JUnit Platform launcher (`junit-platform-launcher`, included in the console launcher's
+/// standalone jar), the test engines, and the test classes must be on the JVM's classpath.
+/// duchess does not depend on JUnit: it calls the launcher through reflection, and receives the
+/// events of a `TestExecutionListener` with a [`callback::function`]. The tests run on the
+/// current thread, unless JUnit is configured to run them in parallel; each event is also
+/// logged at the `DEBUG` level as it happens. Returns an error if the launcher cannot be found
+/// or throws, e.g. because no test engine is on the classpath.
+pub fn run_junit<'jvm>(
+    jvm: &mut Jvm<'jvm>,
+    selectors: &[JunitSelector],
+) -> crate::Result<'jvm, JunitReport> {
+    const RUN_DESCRIPTOR: &CStr =
+        c"([Ljava/lang/String;[Ljava/lang/String;Ljava/util/function/Function;)V";
+
+    let (class, run) = JUNIT_RUNNER.get_or_try_init::<_, crate::Error<Local<Throwable>>>(|| {
+        let class = define_class(
+            jvm,
+            c"duchess/JunitRunner",
+            include_bytes!("duchess/JunitRunner.class"),
+        )?;
+        let run = find_method(jvm, &class, c"run", RUN_DESCRIPTOR, true)?;
+        Ok((jvm.global(&class), run))
+    })?;
+
+    let (methods, values): (Vec<String>, Vec<String>) = selectors
+        .iter()
+        .map(JunitSelector::discovery_selector)
+        .unzip();
+    let methods = methods
+        .to_java::<java::Array<java::lang::String>>()
+        .assert_not_null()
+        .execute_with(jvm)?;
+    let values = values
+        .to_java::<java::Array<java::lang::String>>()
+        .assert_not_null()
+        .execute_with(jvm)?;
+
+    let report = Arc::new(Mutex::new(JunitReport::default()));
+    let events: Local<Function<java::Array<java::lang::String>, java::lang::String>> = {
+        let report = Arc::clone(&report);
+        callback::function(
+            move |event: Vec<String>| {
+                let mut report = report.lock().unwrap_or_else(|e| e.into_inner());
+                report.record(event);
+                None::<String>
+            },
+            jvm,
+        )?
+    };
+
+    let env = jvm.env();
+    unsafe {
+        env.invoke(
+            |env| env.CallStaticVoidMethodA,
+            |env, f| {
+                f(
+                    env,
+                    class.as_raw().as_ptr(),
+                    run.as_ptr(),
+                    [
+                        jvalue {
+                            l: methods.as_raw().as_ptr(),
+                        },
+                        jvalue {
+                            l: values.as_raw().as_ptr(),
+                        },
+                        jvalue {
+                            l: events.as_raw().as_ptr(),
+                        },
+                    ]
+                    .as_ptr(),
+                )
+            },
+        )
+    };
+    let ran = check_exception(jvm);
+    let destroyed = callback::destroy(&events, jvm);
+    ran?;
+    destroyed?;
+
+    let mut report = report.lock().unwrap_or_else(|e| e.into_inner());
+    Ok(std::mem::take(&mut *report))
+}
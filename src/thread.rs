@@ -1,6 +1,7 @@
-use std::cell::Cell;
+use std::{cell::Cell, thread::JoinHandle};
 
 use crate::{
+    jvm::get_or_default_init_jvm,
     raw::{EnvPtr, JvmPtr},
     Error, GlobalResult,
 };
@@ -140,3 +141,47 @@ impl AttachGuard {
         self.env
     }
 }
+
+/// Attaches the current thread to the JVM until the returned guard is dropped.
+/// In the meantime the thread behaves as if permanently attached.
+pub fn attach_until_dropped(jvm: JvmPtr) -> GlobalResult<DetachGuard> {
+    attach_permanently(jvm)?;
+    Ok(DetachGuard { jvm })
+}
+
+/// When dropped, detaches the current thread from the JVM, even while unwinding from a panic.
+pub struct DetachGuard {
+    jvm: JvmPtr,
+}
+
+impl Drop for DetachGuard {
+    fn drop(&mut self) {
+        STATE.with(|state| match state.replace(State::Detached) {
+            State::AttachedPermanently(_) => {
+                if let Err(err) = unsafe { self.jvm.detach_thread() } {
+                    tracing::warn!(?err, "couldn't detach thread from JVM");
+                }
+            }
+            // Still inside a duchess frame (can't happen, as frames are scoped within
+            // the guard's lifetime), so leave the thread attached.
+            old_state => state.set(old_state),
+        })
+    }
+}
+
+/// Spawns a new thread, like [`std::thread::spawn`], that is attached to the JVM
+/// (starting it if necessary) while `f` runs. Calls to [`Jvm::with`](crate::Jvm::with)
+/// on the thread therefore don't attach and detach it each time.
+///
+/// The thread is detached once `f` returns or panics. If it can't be attached,
+/// `f` is not run and the thread's result is the error.
+pub fn spawn<F, R>(f: F) -> JoinHandle<GlobalResult<R>>
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    std::thread::spawn(move || {
+        let _guard = attach_until_dropped(get_or_default_init_jvm()?)?;
+        Ok(f())
+    })
+}
@@ -0,0 +1,68 @@
+//! Conversions between `java.time.Instant`/`java.util.Date` and [`std::time::SystemTime`].
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{into_rust::IntoRust, java, ops::IntoJava, Error, Jvm, JvmOp, Local};
+
+const NANOS_PER_SECOND: u32 = 1_000_000_000;
+const NANOS_PER_MILLI: u32 = 1_000_000;
+
+impl IntoRust<SystemTime> for &java::time::Instant {
+    fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, SystemTime> {
+        let seconds = self.get_epoch_second().execute_with(jvm)?;
+        let nanos = self.get_nano().execute_with(jvm)?;
+        // `getNano` is always in `0..1_000_000_000`, also for instants before the epoch.
+        from_epoch(seconds, nanos as u32)
+    }
+}
+
+impl IntoRust<SystemTime> for &java::util::Date {
+    fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, SystemTime> {
+        let millis = self.get_time().execute_with(jvm)?;
+        // Round towards negative infinity, so that e.g. -1ms is -1s + 999ms.
+        let seconds = millis.div_euclid(1000);
+        let nanos = millis.rem_euclid(1000) as u32 * NANOS_PER_MILLI;
+        from_epoch(seconds, nanos)
+    }
+}
+
+/// Creates an `Instant` with `Instant.ofEpochSecond(long, long)`.
+impl IntoJava<java::time::Instant> for SystemTime {
+    type Output<'jvm> = Local<'jvm, java::time::Instant>;
+
+    fn into_java<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        let (seconds, nanos) = to_epoch(self)?;
+        java::time::Instant::of_epoch_second(seconds, i64::from(nanos)).execute_with(jvm)
+    }
+}
+
+/// The time that is `seconds` (possibly negative) plus `nanos` after the epoch.
+fn from_epoch<'jvm>(seconds: i64, nanos: u32) -> crate::Result<'jvm, SystemTime> {
+    let time = if seconds >= 0 {
+        UNIX_EPOCH.checked_add(Duration::from_secs(seconds as u64))
+    } else {
+        UNIX_EPOCH.checked_sub(Duration::from_secs(seconds.unsigned_abs()))
+    };
+    time.and_then(|time| time.checked_add(Duration::from_nanos(nanos.into())))
+        .ok_or(Error::TimeOutOfRange)
+}
+
+/// The inverse of [`from_epoch`]: seconds since the epoch (rounded towards negative infinity)
+/// and the remaining nanoseconds.
+fn to_epoch<'jvm>(time: SystemTime) -> crate::Result<'jvm, (i64, u32)> {
+    let (seconds, nanos) = match time.duration_since(UNIX_EPOCH) {
+        Ok(after) => (i64::try_from(after.as_secs()).ok(), after.subsec_nanos()),
+        Err(before) => {
+            let before = before.duration();
+            let seconds = i64::try_from(before.as_secs()).ok().map(|s| -s);
+            match before.subsec_nanos() {
+                0 => (seconds, 0),
+                nanos => (
+                    seconds.and_then(|s| s.checked_sub(1)),
+                    NANOS_PER_SECOND - nanos,
+                ),
+            }
+        }
+    };
+    Ok((seconds.ok_or(Error::TimeOutOfRange)?, nanos))
+}
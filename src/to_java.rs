@@ -47,6 +47,32 @@ impl<R> ToJava for R {
     }
 }
 
+/// Argument accepted by methods whose Java parameter type `J` is converted from the
+/// Rust type `R` (see `convert` in [`java_package!`][`crate::java_package`]).
+/// Implemented for `&R` whenever `R` can be converted to a `J`.
+pub trait ConvertToJava<J, R>: Copy
+where
+    J: Upcast<java::lang::Object>,
+{
+    fn convert_to_java<'jvm>(
+        self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Option<Local<'jvm, J>>>;
+}
+
+impl<J, R> ConvertToJava<J, R> for &R
+where
+    J: Upcast<java::lang::Object>,
+    R: ToJavaImpl<J>,
+{
+    fn convert_to_java<'jvm>(
+        self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Option<Local<'jvm, J>>> {
+        R::to_java_impl(self, jvm)
+    }
+}
+
 #[derive_where::derive_where(Copy, Clone)]
 pub struct ToJavaOp<'a, R, J> {
     rust: &'a R,
@@ -249,8 +275,12 @@ where
                 Error::Thrown(t) => Err(Error::Thrown(jvm.local(t))),
                 Error::SliceTooLong(t) => Err(Error::SliceTooLong(*t)),
                 Error::NullDeref => Err(Error::NullDeref),
+                Error::TimeOutOfRange => Err(Error::TimeOutOfRange),
+                Error::CharOutOfRange(c) => Err(Error::CharOutOfRange(*c)),
+                Error::SurrogateChar(c) => Err(Error::SurrogateChar(*c)),
                 Error::NestedUsage => Err(Error::NestedUsage),
                 Error::JvmAlreadyExists => Err(Error::JvmAlreadyExists),
+                #[cfg(feature = "dylibjvm")]
                 Error::UnableToLoadLibjvm(t) => Err(Error::UnableToLoadLibjvm(
                     format!("UnableToLoadLibjvm({t:?})").as_str().into(), // FIXME: should to_java_impl be `self` ?
                 )),
@@ -275,8 +305,12 @@ where
                 Error::Thrown(t) => Err(Error::Thrown(jvm.local(t))),
                 Error::SliceTooLong(t) => Err(Error::SliceTooLong(*t)),
                 Error::NullDeref => Err(Error::NullDeref),
+                Error::TimeOutOfRange => Err(Error::TimeOutOfRange),
+                Error::CharOutOfRange(c) => Err(Error::CharOutOfRange(*c)),
+                Error::SurrogateChar(c) => Err(Error::SurrogateChar(*c)),
                 Error::NestedUsage => Err(Error::NestedUsage),
                 Error::JvmAlreadyExists => Err(Error::JvmAlreadyExists),
+                #[cfg(feature = "dylibjvm")]
                 Error::UnableToLoadLibjvm(t) => Err(Error::UnableToLoadLibjvm(
                     format!("UnableToLoadLibjvm({t:?})").as_str().into(), // FIXME: should to_java_impl be `self` ?
                 )),
@@ -0,0 +1,95 @@
+use crate::{
+    array::{new_object_array, set_object_array_element, JavaArray},
+    AsJRef, IntoJava, JavaObject, Jvm, JvmOp, Local,
+};
+
+/// Arguments for a Java varargs parameter `E...`, which are collected into a Java array `E[]`
+/// when the method is called:
+///
+/// * a tuple of values that are [`IntoJava<E>`], e.g. `(name, 42_i32.to_java::<Integer>())`,
+///   or `()` for no arguments;
+/// * a Rust array of such values, e.g. `[a, b, c]`;
+/// * like in Java, an existing Java array `E[]` (or an operation producing one), which is passed
+///   as is; to pass a `Vec`, convert it with `to_java::<java::Array<E>>()`.
+///
+/// ```ignore
+/// let s = JavaString::format("%s is %s", (&name, &mood)).to_rust().execute()?;
+/// ```
+pub trait IntoVarargs<E: JavaObject>: Copy {
+    type Output<'jvm>: AsJRef<JavaArray<E>>;
+
+    fn into_varargs<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>>;
+}
+
+impl<J, E> IntoVarargs<E> for J
+where
+    E: JavaObject,
+    J: JvmOp,
+    for<'jvm> J::Output<'jvm>: AsJRef<JavaArray<E>>,
+{
+    type Output<'jvm> = J::Output<'jvm>;
+
+    fn into_varargs<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        self.execute_with(jvm)
+    }
+}
+
+macro_rules! tuple_varargs {
+    ($(($($a:ident),*),)*) => {
+        $(
+            #[allow(non_snake_case)]
+            impl<E, $($a,)*> IntoVarargs<E> for ($($a,)*)
+            where
+                E: JavaObject,
+                $($a: IntoJava<E>,)*
+            {
+                type Output<'jvm> = Local<'jvm, JavaArray<E>>;
+
+                fn into_varargs<'jvm>(
+                    self,
+                    jvm: &mut Jvm<'jvm>,
+                ) -> crate::Result<'jvm, Self::Output<'jvm>> {
+                    let ($($a,)*) = self;
+                    let array = new_object_array::<E>(jvm, 0 $(+ tuple_varargs!(@one $a))*)?;
+                    let mut _index = 0;
+                    $(
+                        let element = $a.into_java(jvm)?;
+                        set_object_array_element(jvm, &array, _index, element.as_jref().ok())?;
+                        _index += 1;
+                    )*
+                    Ok(array)
+                }
+            }
+        )*
+    };
+    (@one $a:ident) => { 1 };
+}
+
+tuple_varargs! {
+    (),
+    (A0),
+    (A0, A1),
+    (A0, A1, A2),
+    (A0, A1, A2, A3),
+    (A0, A1, A2, A3, A4),
+    (A0, A1, A2, A3, A4, A5),
+    (A0, A1, A2, A3, A4, A5, A6),
+    (A0, A1, A2, A3, A4, A5, A6, A7),
+}
+
+impl<E, A, const N: usize> IntoVarargs<E> for [A; N]
+where
+    E: JavaObject,
+    A: IntoJava<E>,
+{
+    type Output<'jvm> = Local<'jvm, JavaArray<E>>;
+
+    fn into_varargs<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        let array = new_object_array::<E>(jvm, N as i32)?;
+        for (index, element) in (0..).zip(self) {
+            let element = element.into_java(jvm)?;
+            set_object_array_element(jvm, &array, index, element.as_jref().ok())?;
+        }
+        Ok(array)
+    }
+}
@@ -0,0 +1,71 @@
+use std::marker::PhantomData;
+
+use crate::{
+    cast::Upcast,
+    java::{self, lang::Class},
+    jvm::JavaView,
+    plumbing::FromRef,
+    JDeref, JavaObject, Jvm, Local, Nullable, TryJDeref,
+};
+
+/// Some unknown subtype of `T`, as produced by a Java wildcard in a return or field type.
+///
+/// Duchess can't name the type a wildcard stands for, so a method returning
+/// `ArrayList<? extends Foo>` is mirrored as returning `ArrayList<Wild<Foo>>`, and
+/// `? super Foo` and `?` become `Wild<java::lang::Object>`. The only thing known
+/// about a `Wild<T>` is that it is a `T`, so all it supports is upcasting to `T`
+/// (and to `T`'s own supertypes). In particular it can't be passed back where a
+/// `Wild<T>` is expected, just as Java won't let you add to an `ArrayList<? extends Foo>`.
+pub struct Wild<T> {
+    _bound: PhantomData<T>,
+}
+
+unsafe impl<T: JavaObject> JavaObject for Wild<T> {
+    fn class<'jvm>(
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Local<'jvm, Class<java::lang::Object>>> {
+        T::class(jvm)
+    }
+}
+
+impl<T> JavaView for Wild<T> {
+    type OfOp<J> = <java::lang::Object as JavaView>::OfOp<J>;
+
+    type OfOpWith<J, N> = <java::lang::Object as JavaView>::OfOpWith<J, N>
+    where
+        N: FromRef<J>;
+
+    type OfObj<J> = <java::lang::Object as JavaView>::OfObj<J>;
+
+    type OfObjWith<J, N> = <java::lang::Object as JavaView>::OfObjWith<J, N>
+    where
+        N: FromRef<J>;
+}
+
+// A `Wild<T>` is a `T`, and so is anything `T` is.
+unsafe impl<T, S> Upcast<S> for Wild<T>
+where
+    T: Upcast<S>,
+    S: JavaObject,
+{
+}
+
+impl<T: JavaObject> JDeref for Wild<T> {
+    fn jderef(&self) -> &Self {
+        self
+    }
+}
+
+impl<T: JavaObject> TryJDeref for Wild<T> {
+    type Java = Self;
+
+    fn try_jderef(&self) -> Nullable<&Self> {
+        Ok(self)
+    }
+}
+
+impl<T: JavaObject> std::fmt::Debug for Wild<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::plumbing::debug_java_object(self, f)
+    }
+}
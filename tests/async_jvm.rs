@@ -0,0 +1,79 @@
+use std::future::Future;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake};
+use std::thread::Thread;
+
+use duchess::async_jvm::JvmFuture;
+use duchess::{java, prelude::*, Error};
+
+/// A minimal executor, so the test does not depend on an async runtime.
+fn block_on<F: Future>(future: F) -> F::Output {
+    struct Unpark(Thread);
+
+    impl Wake for Unpark {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Arc::new(Unpark(std::thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+#[test]
+fn runs_ops() -> duchess::GlobalResult<()> {
+    let length = block_on(JvmFuture::with(|jvm| {
+        let hello = String::from("hello");
+        hello
+            .to_java::<java::lang::String>()
+            .length()
+            .execute_with(jvm)
+    }))?;
+    assert_eq!(length, 5);
+
+    let list = block_on(JvmFuture::new(
+        java::util::ArrayList::<java::lang::String>::new().global(),
+    ))?;
+    let size = block_on(JvmFuture::with(move |jvm| {
+        list.add("duchess").execute_with(jvm)?;
+        list.size().execute_with(jvm)
+    }))?;
+    assert_eq!(size, 1);
+    Ok(())
+}
+
+#[test]
+fn runs_off_the_calling_thread() -> duchess::GlobalResult<()> {
+    let name = block_on(JvmFuture::with(|_jvm| {
+        Ok(std::thread::current().name().map(str::to_string))
+    }))?;
+    assert!(name.unwrap().starts_with("duchess-async-jvm-"));
+    Ok(())
+}
+
+#[test]
+fn many_futures() -> duchess::GlobalResult<()> {
+    let futures: Vec<_> = (0..32)
+        .map(|i: i32| JvmFuture::new(java::lang::Integer::value_of(i).int_value()))
+        .collect();
+    for (i, future) in (0..).zip(futures) {
+        assert_eq!(block_on(future)?, i);
+    }
+    Ok(())
+}
+
+#[test]
+fn panics_are_reported() {
+    let result = block_on(JvmFuture::<()>::with(|_jvm| panic!("boom")));
+    match result {
+        Err(Error::JvmInternal(message)) => assert!(message.contains("boom")),
+        other => panic!("expected JvmInternal, got {other:?}"),
+    }
+}
@@ -0,0 +1,20 @@
+use duchess::Jvm;
+
+#[test]
+fn test_libjvm_load_error_explains_mismatch() {
+    // Not a loadable library anywhere: only the headers of a 32-bit Windows DLL.
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/libjvm_headers/pe-x86.bin"
+    );
+    let res = Jvm::builder().load_libjvm_at(path).try_launch();
+    let Err(duchess::Error::UnableToLoadLibjvm(e)) = res else {
+        panic!("expected libjvm to fail to load");
+    };
+    let message = e.to_string();
+    assert!(message.contains("unable to load libjvm from"), "{message}");
+    assert!(
+        message.contains("is a PE library for x86 (32-bit)"),
+        "{message}"
+    );
+}
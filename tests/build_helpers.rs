@@ -0,0 +1,18 @@
+use duchess::build;
+
+#[test]
+fn classpath_accumulates_entries() {
+    build::add_classpath_entry("target/java");
+    build::add_jar("lib/foo.jar");
+    let separator = if cfg!(windows) { ";" } else { ":" };
+    assert_eq!(
+        build::classpath(),
+        format!("target/java{separator}lib/foo.jar")
+    );
+}
+
+#[test]
+fn finds_a_java_home_with_javap() {
+    let java_home = build::find_java_home().unwrap();
+    assert!(java_home.join("bin").is_dir(), "{java_home:?}");
+}
@@ -0,0 +1,19 @@
+use duchess::{java, prelude::*, IntoRust, Jvm, Local};
+
+#[test]
+fn critical_section_reads_and_writes() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let array: Local<'_, java::ByteArray> = [1_i8, 2, 3].as_slice().execute_with(jvm)?;
+
+        {
+            let mut bytes = array.critical(jvm)?;
+            assert_eq!(&bytes[..], &[1, 2, 3]);
+            bytes[0] = 10;
+            bytes.as_bytes_mut()[2] = 30;
+        }
+
+        let contents: Vec<i8> = array.into_rust(jvm)?;
+        assert_eq!(contents, vec![10, 2, 30]);
+        Ok(())
+    })
+}
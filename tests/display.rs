@@ -0,0 +1,50 @@
+use duchess::{java, prelude::*, Jvm};
+
+duchess::java_package! {
+    package printing;
+
+    public class printing.Label { * }
+    public class printing.Broken { * }
+}
+
+#[test]
+fn formats_with_to_string() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let label = printing::Label::new("desk").execute_with(jvm)?;
+        assert_eq!(format!("{}", label.display()), "Label(desk)");
+        assert_eq!(label.display().to_string(), "Label(desk)");
+
+        let list = java::util::ArrayList::<java::lang::String>::new().execute_with(jvm)?;
+        list.add("a").execute_with(jvm)?;
+        list.add("b").execute_with(jvm)?;
+        assert_eq!(format!("[{}]", list.display()), "[[a, b]]");
+        Ok(())
+    })
+}
+
+#[test]
+fn java_to_string_is_still_available() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let label = printing::Label::new("lamp").execute_with(jvm)?;
+        // `Local` does not implement `Display`, so this is the Java operation.
+        let text: String = label
+            .to_string()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(text, "Label(lamp)");
+        Ok(())
+    })
+}
+
+#[test]
+fn exceptions_are_formatted_as_errors() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let broken = printing::Broken::new().execute_with(jvm)?;
+        assert_eq!(
+            format!("{}", broken.display()),
+            "<java error: java.lang.IllegalStateException: no text>"
+        );
+        Ok(())
+    })
+}
@@ -0,0 +1,28 @@
+use duchess::{java, prelude::*};
+
+#[duchess::test]
+fn returns_unit() {
+    let list = java::util::ArrayList::<java::lang::Object>::new()
+        .global()
+        .execute()
+        .unwrap();
+    assert!(list.is_empty().execute().unwrap());
+}
+
+#[duchess::test(classpath = "target/java")]
+fn returns_result() -> duchess::GlobalResult<()> {
+    let builder = java::lang::StringBuilder::new().global().execute()?;
+    builder.append("hi").global().execute()?;
+    assert_eq!(builder.length().execute()?, 2);
+    Ok(())
+}
+
+#[duchess::test]
+#[should_panic(expected = "java.lang.IndexOutOfBoundsException")]
+fn java_exception_fails_the_test() -> duchess::GlobalResult<()> {
+    let list = java::util::ArrayList::<java::lang::Object>::new()
+        .global()
+        .execute()?;
+    list.get(0).global().execute()?;
+    Ok(())
+}
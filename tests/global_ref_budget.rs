@@ -0,0 +1,98 @@
+use std::sync::{Arc, Mutex};
+
+use duchess::{budget, java, prelude::*, Global, Jvm};
+
+#[test]
+fn soft_limit_reports_top_categories() -> duchess::GlobalResult<()> {
+    // Create one object first so that any classes duchess caches are already loaded.
+    let warm_up = java::util::ArrayList::<java::lang::Object>::new()
+        .global()
+        .execute()?;
+    let baseline = budget::total();
+
+    let reports = Arc::new(Mutex::new(vec![]));
+    budget::set_soft_limit(baseline + 10, {
+        let reports = reports.clone();
+        move |report| reports.lock().unwrap().push(report.clone())
+    });
+
+    let mut globals = vec![];
+    Jvm::with(|jvm| {
+        let list = java::util::ArrayList::<java::lang::Object>::new().execute_with(jvm)?;
+
+        // Explicitly tagged.
+        for _ in 0..5 {
+            globals.push(Global::new_tagged(jvm, &*list, "cache"));
+        }
+
+        // Tagged via the scoped category of this thread.
+        {
+            let _guard = budget::enter("listeners");
+            for _ in 0..4 {
+                globals.push(jvm.global(&*list));
+            }
+        }
+        assert_eq!(budget::total(), baseline + 9);
+        assert!(reports.lock().unwrap().is_empty());
+
+        // Back to untagged; this one reaches the limit.
+        globals.push(jvm.global(&*list));
+        Ok(())
+    })?;
+
+    {
+        let reports = reports.lock().unwrap();
+        assert_eq!(reports.len(), 1);
+        let report = &reports[0];
+        assert_eq!(report.limit, baseline + 10);
+        assert_eq!(report.total, baseline + 10);
+        assert_eq!(report.top_categories[0], ("cache", 5));
+        assert_eq!(report.top_categories[1], ("listeners", 4));
+        assert!(report
+            .top_categories
+            .iter()
+            .any(|&(category, n)| category == budget::UNTAGGED && n >= 2));
+    }
+
+    // Dropping releases the references from their categories.
+    globals.clear();
+    assert_eq!(budget::total(), baseline);
+    assert!(budget::counts()
+        .iter()
+        .all(|&(c, _)| c != "cache" && c != "listeners"));
+
+    // Once below the limit, reaching it again reports again.
+    Jvm::with(|jvm| {
+        for _ in 0..10 {
+            globals.push(Global::new_tagged(jvm, &*warm_up, "cache"));
+        }
+        Ok(())
+    })?;
+    assert_eq!(reports.lock().unwrap().len(), 2);
+
+    budget::clear_soft_limit();
+    Ok(())
+}
+
+#[test]
+fn upcast_keeps_the_count() -> duchess::GlobalResult<()> {
+    let count = || {
+        budget::counts()
+            .into_iter()
+            .find(|&(category, _)| category == "upcast")
+            .map_or(0, |(_, n)| n)
+    };
+
+    let list = Jvm::with(|jvm| {
+        let list = java::util::ArrayList::<java::lang::Object>::new().execute_with(jvm)?;
+        Ok(Global::new_tagged(jvm, &*list, "upcast"))
+    })?;
+    assert_eq!(count(), 1);
+
+    let object: Global<java::lang::Object> = list.upcast();
+    assert_eq!(count(), 1);
+
+    drop(object);
+    assert_eq!(count(), 0);
+    Ok(())
+}
@@ -0,0 +1,14 @@
+use duchess::Jvm;
+
+#[test]
+fn test_jvm_construction_with_classpath_and_options() {
+    Jvm::builder()
+        .classpath(&["target/java", "does/not/exist.jar"])
+        .option("-Xmx64m")
+        .try_launch()
+        .unwrap();
+    Jvm::with(|_jvm| Ok(())).unwrap();
+
+    let res = Jvm::builder().classpath(&["target/java"]).try_launch();
+    assert!(matches!(res, Err(duchess::Error::JvmAlreadyExists)));
+}
@@ -0,0 +1,12 @@
+use duchess::Jvm;
+
+#[test]
+fn test_init_from_env() {
+    std::env::set_var("DUCHESS_CLASSPATH", "target/java");
+    duchess::init_from_env().unwrap();
+    duchess::init_from_env().unwrap();
+    Jvm::with(|_jvm| Ok(())).unwrap();
+
+    let res = Jvm::builder().try_launch();
+    assert!(matches!(res, Err(duchess::Error::JvmAlreadyExists)));
+}
@@ -0,0 +1,101 @@
+//! Runs the tests in `java/junit/conformance` with `run_junit`. `java/junit` also holds a
+//! stand-in for the parts of the JUnit Platform launcher API that `run_junit` uses, with a tiny
+//! engine, as JUnit's jars are not available to the build.
+
+use duchess::testing::{run_junit, JunitOutcome, JunitSelector};
+use duchess::{Error, Jvm};
+
+#[duchess::test(classpath = "java/junit")]
+fn reports_each_test() -> duchess::GlobalResult<()> {
+    let selectors = [JunitSelector::Class(
+        "conformance.CalculatorTest".to_string(),
+    )];
+    let report = Jvm::with(|jvm| run_junit(jvm, &selectors))?;
+
+    let names: Vec<&str> = report
+        .tests
+        .iter()
+        .map(|test| &test.display_name[..])
+        .collect();
+    assert_eq!(
+        names,
+        [
+            "adds()",
+            "disabledDivides()",
+            "needsNetwork()",
+            "subtracts()"
+        ]
+    );
+    assert_eq!(report.succeeded(), 1);
+    assert_eq!(report.failed(), 1);
+    assert_eq!(report.aborted(), 1);
+    assert_eq!(report.skipped(), 1);
+    assert!(!report.is_success());
+
+    assert_eq!(
+        report.tests[1].outcome,
+        JunitOutcome::Skipped("disabledDivides() is disabled".to_string())
+    );
+    let JunitOutcome::Aborted(aborted) = &report.tests[2].outcome else {
+        panic!("unexpected outcome: {:?}", report.tests[2]);
+    };
+    assert!(aborted.message.contains("no network"));
+
+    // The failure details are attached to the test.
+    let [(test, failure)] = report.failures().collect::<Vec<_>>()[..] else {
+        panic!("expected one failure: {report}");
+    };
+    assert_eq!(
+        test.unique_id,
+        "[engine:fake]/[class:conformance.CalculatorTest]/[method:subtracts()]"
+    );
+    assert_eq!(
+        failure.message,
+        "java.lang.AssertionError: expected: <2> but was: <1>"
+    );
+    assert!(failure
+        .stack_trace
+        .contains("at conformance.CalculatorTest.subtracts"));
+
+    let summary = report.to_string();
+    assert!(summary.starts_with("4 tests: 1 succeeded, 1 failed, 1 aborted, 1 skipped"));
+    assert!(summary.contains("expected: <2> but was: <1>"));
+
+    assert_eq!(report.containers.len(), 1);
+    assert_eq!(report.containers[0].outcome, JunitOutcome::Successful);
+    Ok(())
+}
+
+#[duchess::test(classpath = "java/junit")]
+fn runs_selected_methods() -> duchess::GlobalResult<()> {
+    let selectors = [JunitSelector::Method(
+        "conformance.CalculatorTest#adds".to_string(),
+    )];
+    let report = Jvm::with(|jvm| run_junit(jvm, &selectors))?;
+    assert_eq!(report.tests.len(), 1);
+    assert_eq!(report.tests[0].outcome, JunitOutcome::Successful);
+    assert!(report.is_success(), "{report}");
+    Ok(())
+}
+
+#[duchess::test(classpath = "java/junit")]
+fn reports_failed_containers() -> duchess::GlobalResult<()> {
+    let selectors = [JunitSelector::Class(
+        "conformance.BrokenSetupTest".to_string(),
+    )];
+    let report = Jvm::with(|jvm| run_junit(jvm, &selectors))?;
+    assert!(report.tests.is_empty());
+    assert!(!report.is_success());
+    let JunitOutcome::Failed(failure) = &report.containers[0].outcome else {
+        panic!("unexpected outcome: {:?}", report.containers[0]);
+    };
+    assert!(failure.message.contains("fixture is missing"));
+    Ok(())
+}
+
+#[duchess::test(classpath = "java/junit")]
+fn launcher_exceptions_are_errors() {
+    let selectors = [JunitSelector::Class("conformance.MissingTest".to_string())];
+    let result = Jvm::with(|jvm| run_junit(jvm, &selectors));
+    assert!(matches!(result, Err(Error::Thrown(_))));
+}
@@ -0,0 +1,88 @@
+#!/usr/bin/env python3
+"""Generates the header fixtures used by `tests/libjvm_probe.rs`.
+
+Each fixture contains just enough of a native library's headers for the probe
+to identify its format, architecture and (for ELF) libc; there is no code.
+"""
+
+import struct
+
+LOAD_VADDR = 0x10000
+
+
+def elf(is_64, machine, needed, interp=None, big_endian=False):
+    e = ">" if big_endian else "<"
+    word = "Q" if is_64 else "I"
+    ehsize, phentsize = (64, 56) if is_64 else (52, 32)
+    dyn_entry = 16 if is_64 else 8
+
+    strtab = b"\0"
+    offsets = []
+    for lib in needed:
+        offsets.append(len(strtab))
+        strtab += lib.encode() + b"\0"
+    interp_bytes = interp.encode() + b"\0" if interp else b""
+
+    phnum = 3 if interp else 2
+    phoff = ehsize
+    interp_off = phoff + phnum * phentsize
+    strtab_off = interp_off + len(interp_bytes)
+    dyn_off = strtab_off + len(strtab)
+    dynamic = [(1, o) for o in offsets] + [(5, LOAD_VADDR + strtab_off), (0, 0)]
+    size = dyn_off + len(dynamic) * dyn_entry
+
+    ident = b"\x7fELF" + bytes([2 if is_64 else 1, 2 if big_endian else 1, 1]) + bytes(9)
+    header = ident + struct.pack(
+        e + "HHI" + word + word + word + "IHHHHHH",
+        3, machine, 1, 0, phoff, 0, 0, ehsize, phentsize, phnum, 0, 0, 0,
+    )
+
+    def phdr(kind, offset, vaddr, filesz):
+        if is_64:
+            return struct.pack(e + "IIQQQQQQ", kind, 4, offset, vaddr, vaddr, filesz, filesz, 8)
+        return struct.pack(e + "IIIIIIII", kind, offset, vaddr, vaddr, filesz, filesz, 4, 4)
+
+    phdrs = phdr(1, 0, LOAD_VADDR, size)
+    phdrs += phdr(2, dyn_off, LOAD_VADDR + dyn_off, len(dynamic) * dyn_entry)
+    if interp:
+        phdrs += phdr(3, interp_off, LOAD_VADDR + interp_off, len(interp_bytes))
+
+    out = header + phdrs + interp_bytes + strtab
+    for tag, val in dynamic:
+        out += struct.pack(e + word + word, tag, val)
+    assert len(out) == size
+    return out
+
+
+def pe(machine):
+    dos = b"MZ" + bytes(0x3a) + struct.pack("<I", 0x40)
+    return dos + b"PE\0\0" + struct.pack("<HH", machine, 0) + bytes(16)
+
+
+def mach_o(cputype):
+    return struct.pack("<IIIIIII", 0xFEEDFACF, cputype, 0, 6, 0, 0, 0) + bytes(4)
+
+
+def universal(*cputypes):
+    out = struct.pack(">II", 0xCAFEBABE, len(cputypes))
+    for i, cputype in enumerate(cputypes):
+        out += struct.pack(">IIIII", cputype, 0, 0x4000 * (i + 1), 0x4000, 14)
+    return out
+
+
+FIXTURES = {
+    "elf64-x86_64-glibc.bin": elf(True, 62, ["libc.so.6", "libdl.so.2"]),
+    "elf64-x86_64-musl.bin": elf(True, 62, ["libc.musl-x86_64.so.1"]),
+    "elf64-aarch64-glibc.bin": elf(True, 183, ["libm.so.6", "libc.so.6"]),
+    "elf32-x86-glibc.bin": elf(False, 3, ["libc.so.6"], interp="/lib/ld-linux.so.2"),
+    "elf32-arm-unknown-libc.bin": elf(False, 40, ["libfoo.so"]),
+    "pe-x86_64.bin": pe(0x8664),
+    "pe-x86.bin": pe(0x14C),
+    "macho-aarch64.bin": mach_o(0x0100000C),
+    "macho-universal.bin": universal(0x01000007, 0x0100000C),
+}
+
+if __name__ == "__main__":
+    for name, data in FIXTURES.items():
+        with open(name, "wb") as f:
+            f.write(data)
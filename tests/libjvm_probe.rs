@@ -0,0 +1,139 @@
+//! Checks the libjvm header probe against the fixtures in `tests/libjvm_headers`,
+//! which are produced by `tests/libjvm_headers/generate.py`.
+
+#[allow(dead_code)]
+#[path = "../src/libjvm/probe.rs"]
+mod probe;
+
+use probe::{Arch, BinaryFormat, Libc, LibraryInfo, ProbeError, Target};
+
+fn fixture(name: &str) -> LibraryInfo {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/libjvm_headers")
+        .join(name);
+    probe::probe_path(&path).unwrap_or_else(|e| panic!("failed to probe {name}: {e}"))
+}
+
+fn target(arch: &str, os: &str, env: &str) -> Target {
+    Target {
+        arch: arch.to_string(),
+        os: os.to_string(),
+        env: env.to_string(),
+    }
+}
+
+#[test]
+fn elf_64_bit_glibc() {
+    let info = fixture("elf64-x86_64-glibc.bin");
+    assert_eq!(
+        info,
+        LibraryInfo {
+            format: BinaryFormat::Elf,
+            archs: vec![Arch::X86_64],
+            libc: Some(Libc::Glibc),
+        }
+    );
+    assert!(probe::mismatches(&info, &target("x86_64", "linux", "gnu")).is_empty());
+}
+
+#[test]
+fn elf_musl_on_glibc_target() {
+    let info = fixture("elf64-x86_64-musl.bin");
+    assert_eq!(info.libc, Some(Libc::Musl));
+    assert!(probe::mismatches(&info, &target("x86_64", "linux", "musl")).is_empty());
+
+    let problems = probe::mismatches(&info, &target("x86_64", "linux", "gnu"));
+    assert_eq!(problems.len(), 1, "{problems:?}");
+    assert!(problems[0].contains("linked against musl"), "{problems:?}");
+}
+
+#[test]
+fn elf_glibc_on_musl_target() {
+    let info = fixture("elf64-aarch64-glibc.bin");
+    assert_eq!(info.archs, vec![Arch::Aarch64]);
+    let problems = probe::mismatches(&info, &target("aarch64", "linux", "musl"));
+    assert_eq!(problems.len(), 1, "{problems:?}");
+    assert!(problems[0].contains("linked against glibc"), "{problems:?}");
+}
+
+#[test]
+fn elf_32_bit_on_64_bit_target() {
+    let info = fixture("elf32-x86-glibc.bin");
+    assert_eq!(info.archs, vec![Arch::X86]);
+    assert_eq!(info.libc, Some(Libc::Glibc));
+
+    let problems = probe::mismatches(&info, &target("x86_64", "linux", "gnu"));
+    assert_eq!(problems.len(), 1, "{problems:?}");
+    assert!(
+        problems[0].contains("both be 32-bit or both be 64-bit"),
+        "{problems:?}"
+    );
+}
+
+#[test]
+fn elf_unknown_libc_is_not_reported() {
+    let info = fixture("elf32-arm-unknown-libc.bin");
+    assert_eq!(info.archs, vec![Arch::Arm]);
+    assert_eq!(info.libc, None);
+    assert!(probe::mismatches(&info, &target("arm", "linux", "musl")).is_empty());
+}
+
+#[test]
+fn pe() {
+    let info = fixture("pe-x86_64.bin");
+    assert_eq!(info.format, BinaryFormat::Pe);
+    assert_eq!(info.archs, vec![Arch::X86_64]);
+    assert!(probe::mismatches(&info, &target("x86_64", "windows", "msvc")).is_empty());
+
+    let info = fixture("pe-x86.bin");
+    assert_eq!(info.archs, vec![Arch::X86]);
+    assert_eq!(
+        probe::mismatches(&info, &target("x86_64", "windows", "msvc")).len(),
+        1
+    );
+}
+
+#[test]
+fn mach_o() {
+    let info = fixture("macho-aarch64.bin");
+    assert_eq!(info.format, BinaryFormat::MachO);
+    assert_eq!(info.archs, vec![Arch::Aarch64]);
+    assert!(probe::mismatches(&info, &target("aarch64", "macos", "")).is_empty());
+    assert_eq!(
+        probe::mismatches(&info, &target("x86_64", "macos", "")).len(),
+        1
+    );
+}
+
+#[test]
+fn mach_o_universal() {
+    let info = fixture("macho-universal.bin");
+    assert_eq!(info.archs, vec![Arch::X86_64, Arch::Aarch64]);
+    assert!(probe::mismatches(&info, &target("x86_64", "macos", "")).is_empty());
+    assert!(probe::mismatches(&info, &target("aarch64", "macos", "")).is_empty());
+}
+
+#[test]
+fn wrong_platform() {
+    let info = fixture("pe-x86_64.bin");
+    let problems = probe::mismatches(&info, &target("x86_64", "linux", "gnu"));
+    assert_eq!(problems.len(), 1, "{problems:?}");
+    assert!(problems[0].contains("uses ELF libraries"), "{problems:?}");
+}
+
+#[test]
+fn malformed() {
+    assert!(matches!(
+        probe::probe_bytes(b"not a library"),
+        Err(ProbeError::UnknownFormat)
+    ));
+    let truncated = std::fs::read(
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/libjvm_headers/elf64-x86_64-glibc.bin"),
+    )
+    .unwrap();
+    assert!(matches!(
+        probe::probe_bytes(&truncated[..40]),
+        Err(ProbeError::Truncated(BinaryFormat::Elf))
+    ));
+}
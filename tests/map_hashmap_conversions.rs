@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+
+use duchess::collections::{hashmap_to_map, map_to_hashmap};
+use duchess::{java, prelude::*, Jvm};
+
+type JavaString = java::lang::String;
+
+#[test]
+fn round_trip() -> duchess::GlobalResult<()> {
+    let capitals: HashMap<String, String> = [("France", "Paris"), ("Peru", "Lima")]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    let converted: HashMap<String, String> = Jvm::with(|jvm| {
+        let map = hashmap_to_map::<_, _, JavaString, JavaString, _>(&capitals, jvm)?;
+        assert_eq!(map.size().execute_with(jvm)?, 2);
+        map_to_hashmap(&map, jvm)
+    })?;
+    assert_eq!(converted, capitals);
+    Ok(())
+}
+
+#[test]
+fn boxed_values() -> duchess::GlobalResult<()> {
+    let ages: HashMap<String, i32> = Jvm::with(|jvm| {
+        let map =
+            java::util::HashMap::<JavaString, java::lang::Integer>::new().execute_with(jvm)?;
+        map.put("ferris", 8).execute_with(jvm)?;
+        map.put("duchess", 2).execute_with(jvm)?;
+        map_to_hashmap(&map, jvm)
+    })?;
+    assert_eq!(ages.len(), 2);
+    assert_eq!(ages["ferris"], 8);
+    assert_eq!(ages["duchess"], 2);
+    Ok(())
+}
+
+#[test]
+fn null_value_is_an_error() {
+    let result: duchess::GlobalResult<HashMap<String, String>> = Jvm::with(|jvm| {
+        let map = java::util::HashMap::<JavaString, JavaString>::new().execute_with(jvm)?;
+        map.put("key", duchess::null_op::<JavaString>())
+            .execute_with(jvm)?;
+        map_to_hashmap(&map, jvm)
+    });
+    assert!(matches!(result, Err(duchess::Error::NullDeref)));
+}
@@ -0,0 +1,79 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use duchess::java;
+use duchess::prelude::*;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+/// Counts the "entered the JVM" events of `Jvm::with` on the current thread.
+#[derive(Clone, Default)]
+struct JvmEntries(Arc<AtomicUsize>);
+
+impl JvmEntries {
+    fn count(&self, f: impl FnOnce()) -> usize {
+        tracing::subscriber::with_default(self.clone(), f);
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+impl Subscriber for JvmEntries {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.target() == "duchess::jvm"
+    }
+
+    fn new_span(&self, _span: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut message = Message::default();
+        event.record(&mut message);
+        if message.0 == "entered the JVM" {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+/// The `message` field of an event.
+#[derive(Default)]
+struct Message(String);
+
+impl Visit for Message {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.0 = format!("{value:?}");
+        }
+    }
+}
+
+#[test]
+fn method_chain_enters_the_jvm_once() {
+    // Each call takes the previous op as its receiver; nothing runs until
+    // the composed op is executed.
+    let size = java::util::ArrayList::<java::lang::Object>::new()
+        .sub_list(0, 0)
+        .sub_list(0, 0)
+        .size();
+
+    let entries = JvmEntries::default().count(|| {
+        assert_eq!(size.execute().unwrap(), 0);
+    });
+    assert_eq!(entries, 1);
+
+    // Ops are `Copy`, so the same chain can be executed again.
+    let entries = JvmEntries::default().count(|| {
+        assert_eq!(size.execute().unwrap(), 0);
+        assert_eq!(size.execute().unwrap(), 0);
+    });
+    assert_eq!(entries, 2);
+}
@@ -0,0 +1,29 @@
+use duchess::{prelude::*, Jvm};
+
+#[test]
+fn critical_section_reads_utf16() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        for data in ["", "Hello, Duchess!", "crab 🦀 and ☃"] {
+            let string = data.execute_with(jvm)?;
+            let chars = string.critical(jvm)?;
+            let expected: Vec<u16> = data.encode_utf16().collect();
+            assert_eq!(&chars[..], &expected[..]);
+            assert_eq!(chars.to_string_lossy(), data);
+        }
+        Ok(())
+    })
+}
+
+#[test]
+fn calls_can_be_made_after_the_critical_section() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let string = "abc".execute_with(jvm)?;
+        let len = {
+            let chars = string.critical(jvm)?;
+            chars.len()
+        };
+        assert_eq!(len, 3);
+        assert_eq!(string.length().execute_with(jvm)?, 3);
+        Ok(())
+    })
+}
@@ -0,0 +1,38 @@
+//@run
+use duchess::{java, prelude::*, Global};
+
+pub fn main() -> duchess::GlobalResult<()> {
+    // The closure runs further ops on the output, within the same call into the JVM.
+    let size = java::util::ArrayList::<java::lang::String>::new()
+        .and_then(|list, jvm| {
+            list.add("one").execute_with(jvm)?;
+            list.add("two").execute_with(jvm)?;
+            list.size().execute_with(jvm)
+        })
+        .execute()?;
+    assert_eq!(size, 2);
+
+    // Java objects can be kept as global references.
+    let list: Global<java::util::ArrayList<java::lang::String>> =
+        java::util::ArrayList::<java::lang::String>::new()
+            .and_then(|list, jvm| {
+                list.add("three").execute_with(jvm)?;
+                Ok(jvm.global(&*list))
+            })
+            .execute()?;
+    let first: String = list
+        .get(0)
+        .assert_not_null()
+        .to_rust()
+        .and_then(|first: String, _jvm| Ok(first.to_uppercase()))
+        .execute()?;
+    assert_eq!(first, "THREE");
+
+    // Errors from the closure are those of the op.
+    let result = list
+        .size()
+        .and_then(|size, jvm| list.get(size).execute_with(jvm).map(|_| ()))
+        .execute();
+    assert!(matches!(result, Err(duchess::Error::Thrown(_))));
+    Ok(())
+}
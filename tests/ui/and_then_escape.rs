@@ -0,0 +1,10 @@
+use duchess::{java, prelude::*};
+
+fn main() -> duchess::GlobalResult<()> {
+    // The local reference cannot outlive the call into the JVM.
+    let list = java::util::ArrayList::<java::lang::String>::new()
+        .and_then(|list, _jvm| Ok(list)) //~ ERROR: lifetime may not live long enough
+        .execute()?;
+    drop(list);
+    Ok(())
+}
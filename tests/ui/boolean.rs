@@ -0,0 +1,45 @@
+//@run
+use duchess::plumbing::FromJniValue;
+use duchess::{java, prelude::*};
+
+duchess::java_package! {
+    package switches;
+
+    public class switches.Switch { * }
+}
+
+#[duchess::java_function(switches.Switch::flip)]
+fn flip(_this: &switches::Switch, on: bool) -> bool {
+    !on
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    duchess::Jvm::builder().link(vec![flip::java_fn()]).try_launch()?;
+
+    // `boolean` arguments and results of Java methods and native functions.
+    let switch = switches::Switch::new(true).global().execute()?;
+    assert!(switch.is_on().execute()?);
+    assert!(!switch.toggled().is_on().execute()?);
+    assert!(switch.toggled().toggled().is_on().execute()?);
+
+    // `boolean[]` round-trips through `Vec<bool>`.
+    let flags = vec![true, false, true];
+    let round_trip: Vec<bool> = flags
+        .to_java::<java::Array<bool>>()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(round_trip, flags);
+
+    // Any non-zero `jboolean` is true, not only `JNI_TRUE`.
+    duchess::Jvm::with(|jvm| {
+        for (value, expected) in [(0, false), (1, true), (2, true), (0xff, true)] {
+            assert_eq!(
+                unsafe { <bool as FromJniValue>::from_jni_value(jvm, value) },
+                expected
+            );
+        }
+        Ok(())
+    })?;
+    Ok(())
+}
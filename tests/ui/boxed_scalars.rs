@@ -0,0 +1,45 @@
+//@run
+use duchess::java;
+use duchess::prelude::*;
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let list = java::util::ArrayList::<java::lang::Integer>::new()
+        .global()
+        .execute()?;
+    list.add(22_i32).execute()?;
+    list.add(-1_i32).execute()?;
+    let first: Option<i32> = list.get(0).to_rust().execute()?;
+    assert_eq!(first, Some(22));
+    let second: i32 = list.get(1).assert_not_null().to_rust().execute()?;
+    assert_eq!(second, -1);
+
+    let map = java::util::HashMap::<java::lang::String, java::lang::Double>::new()
+        .global()
+        .execute()?;
+    duchess::Jvm::with(|jvm| {
+        map.put("pi", 3.5_f64).execute_with(jvm)?;
+        Ok(())
+    })?;
+    let pi: Option<f64> = map.get("pi").to_rust().execute()?;
+    assert_eq!(pi, Some(3.5));
+
+    let flags = java::util::ArrayList::<java::lang::Boolean>::new()
+        .global()
+        .execute()?;
+    flags.add(true).execute()?;
+    let flag: Option<bool> = flags.get(0).to_rust().execute()?;
+    assert_eq!(flag, Some(true));
+
+    let chars = java::util::ArrayList::<java::lang::Character>::new()
+        .global()
+        .execute()?;
+    chars.add('x' as u16).execute()?;
+    let c: Option<u16> = chars.get(0).to_rust().execute()?;
+    assert_eq!(c, Some('x' as u16));
+
+    // Boxed numbers inherit the conversions of `java.lang.Number`.
+    let short = java::lang::Short::value_of(7_i16).global().execute()?;
+    let n: i64 = short.long_value().execute()?;
+    assert_eq!(n, 7);
+    Ok(())
+}
@@ -0,0 +1,56 @@
+//@run
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use duchess::{java, prelude::*, ByJavaHash};
+
+duchess::java_package! {
+    package hashing;
+
+    public class hashing.Unhashable { * }
+}
+
+fn hash_of(value: &impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    // Distinct `Local` references to equal strings are the same key.
+    let counts = duchess::Jvm::with(|jvm| {
+        let mut counts = HashMap::new();
+        for word in ["duchess", "ferris", "duchess"] {
+            let word = word
+                .to_string()
+                .to_java::<java::lang::String>()
+                .assert_not_null()
+                .execute_with(jvm)?;
+            *counts.entry(ByJavaHash(word)).or_insert(0) += 1;
+        }
+        Ok(counts.into_values().max())
+    })?;
+    assert_eq!(counts, Some(2));
+
+    // `hash_code()` is available on every class, and a thread that is not attached to the
+    // JVM is attached to compute the same hash.
+    let word = "duchess"
+        .to_string()
+        .to_java::<java::lang::String>()
+        .assert_not_null()
+        .global()
+        .execute()?;
+    let hash_code: i32 = word.hash_code().execute()?;
+    assert_eq!(hash_of(&ByJavaHash(&word)), hash_of(&hash_code));
+    let from_thread = std::thread::spawn(move || hash_of(&ByJavaHash(&word)))
+        .join()
+        .unwrap();
+    assert_eq!(from_thread, hash_of(&hash_code));
+
+    // A throwing `hashCode()` falls back like `Global`'s own `Hash`, to the hash of `0`.
+    let unhashable = hashing::Unhashable::new().global().execute()?;
+    assert_eq!(hash_of(&ByJavaHash(&unhashable)), hash_of(&0_i32));
+    assert_eq!(hash_of(&ByJavaHash(&unhashable)), hash_of(&unhashable));
+    Ok(())
+}
@@ -0,0 +1,34 @@
+//@run
+use duchess::java;
+use duchess::prelude::*;
+use duchess::Local;
+
+pub fn main() -> duchess::GlobalResult<()> {
+    duchess::Jvm::with(|jvm| {
+        let array: Local<'_, java::ByteArray> = [0_i8; 5].as_slice().execute_with(jvm)?;
+
+        array.copy_from_slice(jvm, b"hello")?;
+        let mut bytes = [0_u8; 5];
+        array.copy_to_slice(jvm, &mut bytes)?;
+        assert_eq!(&bytes, b"hello");
+
+        // Partial copies start at the beginning of the array.
+        let mut prefix = [0_u8; 2];
+        array.copy_to_slice(jvm, &mut prefix)?;
+        assert_eq!(&prefix, b"he");
+
+        // Copying past the end of the array fails in the JVM.
+        let mut too_long = [0_u8; 6];
+        assert!(matches!(
+            array.copy_to_slice(jvm, &mut too_long),
+            Err(duchess::Error::Thrown(_))
+        ));
+
+        // `byte[]` parameters take a `java::ByteArray`.
+        let string: String = java::lang::String::new(&array)
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(string, "hello");
+        Ok(())
+    })
+}
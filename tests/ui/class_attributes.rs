@@ -0,0 +1,25 @@
+//@check-pass
+#![deny(unused_attributes)]
+
+pub mod our_java {
+    duchess::java_package! {
+        package java.util;
+
+        /// A point in time, with millisecond precision.
+        #[allow(dead_code)]
+        public class java.util.Date {
+            public java.util.Date();
+        }
+
+        // Compiled out entirely, including the impls generated for it.
+        #[cfg(any())]
+        public class java.util.Random {
+            public java.util.Random();
+        }
+    }
+    pub use java::*;
+}
+
+pub fn main() {
+    let _date: Option<duchess::Global<our_java::util::Date>> = None;
+}
@@ -0,0 +1,41 @@
+//@run
+use duchess::java;
+use duchess::prelude::*;
+use duchess::Jvm;
+
+duchess::java_package! {
+    package nested;
+
+    public class nested.Outer { * }
+    public class nested.Outer$Inner { * }
+    public class nested.Outer$Nested { * }
+}
+
+type List = java::util::ArrayList<java::lang::Object>;
+
+pub fn main() -> duchess::GlobalResult<()> {
+    assert_eq!(java::util::ArrayList::CLASS_NAME, "java/util/ArrayList");
+    assert_eq!(java::util::ArrayList::DOTTED_NAME, "java.util.ArrayList");
+    assert_eq!(java::lang::String::CLASS_NAME, "java/lang/String");
+    assert_eq!(nested::Outer__Inner::CLASS_NAME, "nested/Outer$Inner");
+    assert_eq!(nested::Outer__Inner::DOTTED_NAME, "nested.Outer$Inner");
+
+    Jvm::with(|jvm| {
+        // The class is looked up once and then handed out as the same global reference.
+        let class = java::util::ArrayList::cached_class(jvm)?;
+        assert!(std::ptr::eq(class, java::util::ArrayList::cached_class(jvm)?));
+        let name: String = class
+            .get_name()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(name, java::util::ArrayList::DOTTED_NAME);
+
+        // `JavaObject::class` gives out local references to it.
+        let list = List::new().execute_with(jvm)?;
+        assert!(class.is_instance(&list).execute_with(jvm)?);
+        let local = <List as duchess::JavaObject>::class(jvm)?;
+        assert!(local.equals(class).execute_with(jvm)?);
+        Ok(())
+    })
+}
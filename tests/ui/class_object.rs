@@ -0,0 +1,45 @@
+//@run
+use duchess::{java, prelude::*};
+
+duchess::java_package! {
+    package colors;
+
+    public final class colors.Color { * }
+    public class colors.Palette { * }
+}
+
+use colors::{Color, Palette};
+
+pub fn main() -> duchess::GlobalResult<()> {
+    // The class object of a bound type is a `Class<T>`, so it can be passed where Java
+    // expects a `Class<E>`, here to `EnumSet.noneOf` and `EnumSet.allOf`.
+    let none: String = Palette::none(Color::class_object())
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(none, "[]");
+    let all: String = Palette::all(Color::class_object())
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(all, "[RED, GREEN, BLUE]");
+
+    let name: String = Color::class_object()
+        .get_name()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(name, "colors.Color");
+
+    // `get_class` produces the runtime class of an object.
+    let name: String = "duchess"
+        .to_string()
+        .to_java::<java::lang::String>()
+        .get_class()
+        .get_name()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(name, "java.lang.String");
+    Ok(())
+}
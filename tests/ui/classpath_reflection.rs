@@ -0,0 +1,62 @@
+//@run
+use duchess::prelude::*;
+
+// Classes found under the classpath are read from their class files, without `javap`.
+duchess::java_package! {
+    classpath = "java";
+
+    package constants;
+
+    public class constants.Protocol { * }
+
+    package signatures;
+
+    public class signatures.Overloads {
+        count(int[]);
+        max("[J");
+    }
+
+    package legacy (omit_deprecated);
+
+    public class legacy.Ledger { * }
+
+    package records;
+
+    public final class records.Point { new; y; }
+
+    package shapes;
+
+    public abstract class shapes.Shape { circle; }
+    public final class shapes.Circle { radius; }
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    assert_eq!(constants::Protocol::VERSION, 3);
+    assert_eq!(constants::Protocol::GREETING, "hello \"world\"\né");
+
+    duchess::Jvm::with(|jvm| {
+        assert_eq!(constants::Protocol::get_started().execute_with(jvm)?, 42);
+
+        let values = [1i32, 2, 3];
+        assert_eq!(signatures::Overloads::count(&values[..]).execute_with(jvm)?, 3);
+        let values = [4i64, 9, 2];
+        assert_eq!(signatures::Overloads::max(&values[..]).execute_with(jvm)?, 9);
+
+        let ledger = legacy::Ledger::new().execute_with(jvm)?;
+        ledger.add(2).execute_with(jvm)?;
+        assert_eq!(ledger.get_total().execute_with(jvm)?, 2);
+
+        let point = records::Point::new(1, 2).execute_with(jvm)?;
+        assert_eq!(point.y().execute_with(jvm)?, 2);
+        assert!(*point == *records::Point::new(1, 2).execute_with(jvm)?);
+
+        let shape = shapes::Shape::circle(1.5).seal::<shapes::Shape>();
+        match shape.execute_with(jvm)? {
+            shapes::ShapeSealed::Circle(circle) => {
+                assert_eq!(circle.radius().execute_with(jvm)?, 1.5)
+            }
+            shapes::ShapeSealed::Unknown(_) => panic!("expected a circle"),
+        }
+        Ok(())
+    })
+}
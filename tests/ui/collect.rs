@@ -0,0 +1,32 @@
+//@run
+use duchess::{java, prelude::*, Global};
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let list = java::util::ArrayList::<java::lang::String>::new()
+        .global()
+        .execute()?;
+    list.add("one").execute()?;
+    list.add("two").execute()?;
+    list.add("three").execute()?;
+
+    // The whole iteration happens within one call into the JVM.
+    let strings: Vec<String> = list
+        .iterator()
+        .assert_not_null()
+        .collect()
+        .to_rust()
+        .execute()?;
+    assert_eq!(strings, ["one", "two", "three"]);
+
+    // The elements can be kept as global references.
+    let globals: Vec<Global<java::lang::String>> = list
+        .iterator()
+        .assert_not_null()
+        .collect()
+        .global()
+        .execute()?;
+    assert_eq!(globals.len(), 3);
+    assert_eq!(list.index_of(&globals[2]).execute()?, 2);
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+//@run
+use std::cmp::Ordering;
+
+use duchess::{java, prelude::*, Global};
+
+duchess::java_package! {
+    package ordering;
+
+    // `implements java.lang.Comparable<ordering.Version>` is reflected.
+    public class ordering.Version { * }
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let mut versions: Vec<Global<ordering::Version>> = vec![];
+    for (major, minor) in [(1, 10), (0, 9), (1, 2)] {
+        versions.push(ordering::Version::new(major, minor).global().execute()?);
+    }
+    versions.sort_by(|a, b| a.jcmp(b).execute().unwrap());
+    let sorted: Vec<String> = versions.iter().map(|v| format!("{v:?}")).collect();
+    assert_eq!(sorted, ["0.9", "1.2", "1.10"]);
+
+    // Through `java.lang.Comparable`, `compare_to` gives the `int` of `compareTo`.
+    assert!(versions[0].compare_to(&versions[1]).execute()? < 0);
+    assert_eq!(versions[2].jcmp(&versions[2]).execute()?, Ordering::Equal);
+
+    // JDK classes implement it as well.
+    let (a, b) = ("apple".to_string(), "banana".to_string());
+    let apple = a
+        .to_java::<java::lang::String>()
+        .assert_not_null()
+        .global()
+        .execute()?;
+    assert_eq!(apple.jcmp(&b).execute()?, Ordering::Less);
+    let five = java::lang::Integer::value_of(5).global().execute()?;
+    let seven = java::lang::Integer::value_of(7).global().execute()?;
+    assert_eq!(seven.jcmp(&five).execute()?, Ordering::Greater);
+    Ok(())
+}
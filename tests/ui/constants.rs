@@ -0,0 +1,38 @@
+//@run
+use duchess::prelude::*;
+
+duchess::java_package! {
+    package constants;
+
+    public class constants.Protocol { * }
+}
+
+// Constants with a value in the class file can be used without a JVM...
+const VERSION: i32 = constants::Protocol::VERSION;
+
+fn describe(version: i32) -> &'static str {
+    match version {
+        constants::Protocol::VERSION => "current",
+        _ => "other",
+    }
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    assert_eq!(VERSION, 3);
+    assert_eq!(describe(3), "current");
+    assert_eq!(constants::Protocol::MAX_LENGTH, i64::MAX);
+    assert_eq!(constants::Protocol::PORT, -1);
+    assert_eq!(constants::Protocol::FLAGS, 127);
+    assert_eq!(constants::Protocol::TIMEOUT_SECONDS, 1.5);
+    assert_eq!(constants::Protocol::RETRY_FACTOR, f32::INFINITY);
+    assert!(constants::Protocol::COMPRESSED);
+    assert_eq!(constants::Protocol::SEPARATOR, b';' as u16);
+    assert_eq!(constants::Protocol::GREETING, "hello \"world\"\né");
+    assert_eq!(duchess::java::lang::Integer::MAX_VALUE, i32::MAX);
+    assert_eq!(duchess::java::lang::Integer::MIN_VALUE, i32::MIN);
+    assert_eq!(duchess::java::lang::Long::MIN_VALUE, i64::MIN);
+
+    // ...while those computed in a static initializer are read at runtime.
+    assert_eq!(constants::Protocol::get_started().execute()?, 42);
+    Ok(())
+}
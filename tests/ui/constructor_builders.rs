@@ -0,0 +1,69 @@
+//@run
+use duchess::prelude::*;
+
+duchess::java_package! {
+    package builders (builder_arity = 6);
+
+    public class builders.Connection { * }
+    public class builders.Endpoint { * }
+}
+
+use builders::{Connection, Endpoint};
+
+pub fn main() -> duchess::GlobalResult<()> {
+    // The setters are named after the parameters and can be called in any order.
+    let connection = Connection::builder()
+        .host("db.example.com")
+        .user("duchess")
+        .password("secret")
+        .port(6543)
+        .database("crates")
+        .timeout_millis(250)
+        .secure(true)
+        .retries(3)
+        .build()
+        .global()
+        .execute()?;
+    let described: String = connection
+        .describe()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(
+        described,
+        "duchess@db.example.com:6543/crates timeout=250 secure=true retries=3 password=true"
+    );
+
+    // Any argument a constructor takes can be set, including other operations.
+    let host = "localhost".global().execute()?;
+    let described: String = Connection::builder()
+        .host(&host)
+        .port(5432)
+        .user("admin")
+        .password("")
+        .timeout_millis(1000)
+        .secure(false)
+        .retries(0)
+        .database(connection.describe())
+        .build()
+        .describe()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert!(described.starts_with("admin@localhost:5432/duchess@db.example.com"));
+
+    // Without `javac -parameters`, the parameters are called `arg0`, `arg1`, ...
+    let endpoint = Endpoint::builder()
+        .arg0("https")
+        .arg1("example.com")
+        .arg2(443)
+        .arg3("/search")
+        .arg4("q=duchess")
+        .arg5("results")
+        .build()
+        .global()
+        .execute()?;
+    let url: String = endpoint.url().assert_not_null().to_rust().execute()?;
+    assert_eq!(url, "https://example.com:443/search?q=duchess#results");
+    Ok(())
+}
@@ -0,0 +1,42 @@
+//@run
+use duchess::{java, prelude::*};
+
+// `LineReader` has several constructors; only the one taking an `InputStream` is generated.
+mod from_stream {
+    duchess::java_package! {
+        package lines;
+
+        use java.io.InputStream;
+
+        public class lines.LineReader { new(InputStream); hasNextLine; nextLine; }
+    }
+    pub use lines::LineReader;
+}
+
+// `new;` selects every constructor.
+mod customer {
+    duchess::java_package! {
+        package beans;
+
+        public class beans.Customer { new; getName; }
+    }
+    pub use beans::Customer;
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let bytes: Vec<i8> = b"first\nsecond\n".iter().map(|&b| b as i8).collect();
+    let input = java::io::ByteArrayInputStream::new(bytes.as_slice())
+        .global()
+        .execute()?;
+    let reader = from_stream::LineReader::new(&input).global().execute()?;
+    let mut lines: Vec<String> = vec![];
+    while reader.has_next_line().execute()? {
+        lines.push(reader.next_line().assert_not_null().to_rust().execute()?);
+    }
+    assert_eq!(lines, ["first", "second"]);
+
+    let customer = customer::Customer::new("Ferris", 8).global().execute()?;
+    let name: String = customer.get_name().assert_not_null().to_rust().execute()?;
+    assert_eq!(name, "Ferris");
+    Ok(())
+}
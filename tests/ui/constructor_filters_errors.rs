@@ -0,0 +1,29 @@
+mod no_match {
+    duchess::java_package! {
+        package beans;
+
+        public class beans.Customer { new(java.lang.String); getName; } //~ ERROR: no constructor of `beans.Customer` matches `new(java.lang.String)`; the constructors are `(Ljava/lang/String;I)V`
+    }
+}
+
+mod bad_type {
+    duchess::java_package! {
+        package beans;
+
+        public class beans.Customer { new(java.lang.); getName; } //~ ERROR: unexpected end of input
+    }
+}
+
+mod not_selected {
+    duchess::java_package! {
+        package beans;
+
+        public class beans.Customer { getName; }
+    }
+
+    fn construct() {
+        beans::Customer::new("Ferris", 8); //~ ERROR: no function or associated item named `new`
+    }
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+//@compile-flags: --crate-type lib
+
+duchess::java_package! {
+    package flags;
+
+    public class flags.Flags {
+        public int publicMethod();
+        public flags.Flags(int); //~ ERROR: does not match any constructors in the reflected class
+    }
+}
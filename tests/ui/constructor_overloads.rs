@@ -0,0 +1,51 @@
+//@run
+use duchess::{java, prelude::*};
+
+duchess::java_package! {
+    package clients;
+
+    public class Connection { new; describe; }
+
+    package clients.foo;
+
+    public class Client { * }
+
+    package clients.bar;
+
+    public class Client { * }
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    // The constructor without parameters keeps `new`, the others are named after their
+    // parameter types...
+    let list = java::util::ArrayList::<java::lang::String>::new_from_i32(10)
+        .global()
+        .execute()?;
+    assert!(list.is_empty().execute()?);
+
+    let describe = |connection| -> duchess::GlobalResult<String> {
+        clients::Connection::describe(connection)
+            .assert_not_null()
+            .to_rust()
+            .execute()
+    };
+
+    let none = clients::Connection::new().global().execute()?;
+    assert_eq!(describe(&none)?, "none");
+    let remote = clients::Connection::new_from_string_i32("localhost", 8080)
+        .global()
+        .execute()?;
+    assert_eq!(describe(&remote)?, "localhost:8080");
+
+    // ...and numbered in the order of their descriptors if that is not enough:
+    // `(Lclients/bar/Client;)V` comes before `(Lclients/foo/Client;)V`.
+    let bar = clients::Connection::new_from_client_1(clients::bar::Client::new())
+        .global()
+        .execute()?;
+    assert_eq!(describe(&bar)?, "bar");
+    let foo = clients::Connection::new_from_client_2(clients::foo::Client::new())
+        .global()
+        .execute()?;
+    assert_eq!(describe(&foo)?, "foo");
+    Ok(())
+}
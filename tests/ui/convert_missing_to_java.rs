@@ -0,0 +1,22 @@
+//@compile-flags: --crate-type lib
+
+// `Person` is converted, but `Name` has no `ToJava` impl, so `greet` can't take it.
+duchess::java_package! {
+    convert proto.Person => crate::Name;
+
+    package proto;
+
+    class Person { * }
+    class Greeting { * }
+
+    package service;
+
+    public class service.Greeter {
+        public service.Greeter();
+        public proto.Greeting greet(proto.Person); //~ ERROR: the method `service.Greeter.greet` takes a `proto.Person`
+    }
+}
+
+pub struct Name {
+    pub name: String,
+}
@@ -0,0 +1,22 @@
+//@compile-flags: --crate-type lib
+
+// `Greeting` is converted, but `Text` has no `ToRust` impl, so `greet` can't return it.
+duchess::java_package! {
+    convert proto.Greeting => crate::Text;
+
+    package proto;
+
+    class Person { * }
+    class Greeting { * }
+
+    package service;
+
+    public class service.Greeter {
+        public service.Greeter();
+        public proto.Greeting greet(proto.Person); //~ ERROR: the method `service.Greeter.greet` returns a `proto.Greeting`
+    }
+}
+
+pub struct Text {
+    pub text: String,
+}
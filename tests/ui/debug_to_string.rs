@@ -0,0 +1,44 @@
+//@run
+use duchess::prelude::*;
+
+duchess::java_package! {
+    package printing;
+
+    public class printing.Label { * }
+    public class printing.Broken { * }
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let label = printing::Label::new("lamp").global().execute()?;
+    assert_eq!(format!("{label:?}"), "Label(lamp)");
+
+    duchess::Jvm::with(|jvm| {
+        let label = printing::Label::new("desk").execute_with(jvm)?;
+        assert_eq!(format!("{label:?}"), "Label(desk)");
+        // The Java object itself formats the same way.
+        assert_eq!(format!("{:?}", &*label), "Label(desk)");
+
+        // The alternate form also shows the class: the one the struct mirrors for the Java
+        // object, and the runtime class for references, which may be upcast.
+        let expected = "JavaObject {\n    class: \"printing.Label\",\n    str: \"Label(desk)\",\n}";
+        assert_eq!(format!("{:#?}", &*label), expected);
+        assert_eq!(format!("{label:#?}"), expected);
+        let object: duchess::Local<'_, duchess::java::lang::Object> = label.upcast();
+        assert_eq!(format!("{object:#?}"), expected);
+        Ok(())
+    })?;
+
+    // A thread that is not attached to the JVM yet is attached to format the object.
+    std::thread::scope(|s| {
+        s.spawn(|| assert_eq!(format!("{label:?}"), "Label(lamp)"));
+    });
+
+    // When `toString()` throws, the type name and the reference are shown instead.
+    let broken = printing::Broken::new().global().execute()?;
+    let debug = format!("{broken:?}");
+    assert!(debug.contains("printing::Broken@0x"), "{debug}");
+    let debug = format!("{broken:#?}");
+    assert!(debug.contains("class: \"printing.Broken\""), "{debug}");
+    assert!(debug.contains("error: "), "{debug}");
+    Ok(())
+}
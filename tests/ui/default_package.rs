@@ -0,0 +1,23 @@
+//@ run
+
+use duchess::prelude::*;
+
+duchess::java_package! {
+    package <default>;
+
+    public class DefaultPackageGreeter { * }
+    public class DefaultPackageGreeting { * }
+}
+
+fn main() -> duchess::GlobalResult<()> {
+    let greeter = DefaultPackageGreeter::new("Ferris").global().execute()?;
+    let text: String = greeter
+        .combine(DefaultPackageGreeter::for_world())
+        .greeting()
+        .text()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(text, "Hello, Ferris and World");
+    Ok(())
+}
@@ -0,0 +1,33 @@
+//@ run
+
+use duchess::prelude::*;
+
+// Classes declared outside of any `package` block are in the default package.
+duchess::java_package! {
+    public class DefaultPackageGreeter {
+        public DefaultPackageGreeter(java.lang.String);
+        public DefaultPackageGreeting greeting();
+    }
+
+    public class DefaultPackageGreeting {
+        public java.lang.String text();
+    }
+
+    package java.util;
+
+    public class java.util.ArrayList<E> {
+        public java.util.ArrayList();
+        public boolean add(E);
+    }
+}
+
+fn main() -> duchess::GlobalResult<()> {
+    let text: String = DefaultPackageGreeter::new("Ferris")
+        .greeting()
+        .text()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(text, "Hello, Ferris");
+    Ok(())
+}
@@ -0,0 +1,29 @@
+#![deny(deprecated)]
+
+duchess::java_package! {
+    package legacy;
+
+    public class legacy.Ledger { * }
+    public class legacy.Register { * }
+}
+
+duchess::java_package! {
+    package java.util;
+
+    public class Observable { new; hasChanged; }
+}
+
+pub fn ledger(ledger: &legacy::Ledger) {
+    ledger.get_total();
+    ledger.get_sum(); //~ ERROR: use of deprecated method
+}
+
+pub fn register(register: &legacy::Register) { //~ ERROR: use of deprecated struct
+    register.increment(); //~ ERROR: use of deprecated method
+}
+
+pub fn observable() {
+    java::util::Observable::new(); //~ ERROR: use of deprecated struct
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+//@compile-flags: --crate-type lib
+
+// The descriptors are only known for classes read from their class files.
+duchess::java_package! {
+    classpath = "java";
+
+    package generics;
+
+    public class generics.Gauge<T extends java.lang.Number> {
+        public generics.Gauge(T); //~ ERROR: has the JVM descriptor
+    }
+}
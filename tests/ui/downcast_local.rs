@@ -0,0 +1,29 @@
+//@run
+use duchess::java;
+use duchess::prelude::*;
+use duchess::Jvm;
+
+pub fn main() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let list = java::util::ArrayList::<java::lang::String>::new().execute_with(jvm)?;
+        let object: duchess::Local<'_, java::lang::Object> = list.upcast();
+
+        // Failed casts give back the original reference...
+        let object = match object
+            .try_downcast::<java::util::HashMap<java::lang::String, java::lang::String>>()?
+        {
+            Ok(_) => panic!("an `ArrayList` is not a `HashMap`"),
+            Err(object) => object,
+        };
+
+        // ...which can then be downcast to what it really is.
+        let list = match object.try_downcast::<java::util::ArrayList<java::lang::String>>()? {
+            Ok(list) => list,
+            Err(_) => panic!("expected an `ArrayList`"),
+        };
+        list.add("hello").execute_with(jvm)?;
+        assert_eq!(list.size().execute_with(jvm)?, 1);
+
+        Ok(())
+    })
+}
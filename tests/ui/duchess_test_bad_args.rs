@@ -0,0 +1,7 @@
+#[duchess::test(jar = "foo.jar")] //~ ERROR: expected `classpath = "..."`
+fn unknown_argument() {}
+
+#[duchess::test]
+fn takes_arguments(_x: i32) {} //~ ERROR: `#[duchess::test]` functions cannot take arguments
+
+fn main() {}
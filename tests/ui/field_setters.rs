@@ -0,0 +1,30 @@
+//@run
+use duchess::prelude::*;
+
+duchess::java_package! {
+    package fields;
+
+    public class fields.Point { * }
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let point = fields::Point::new(3, 4, "p").global().execute()?;
+
+    // Setters of non-final fields are ops returning `()`...
+    point.set_y(10).execute()?;
+    assert_eq!(point.get_y().execute()?, 10);
+
+    point.set_label("renamed").execute()?;
+    let label: String = point.get_label().assert_not_null().to_rust().execute()?;
+    assert_eq!(label, "renamed");
+
+    // ...that can take the output of other ops as their value.
+    fields::Point::set_visible(&point, point.get_visible()).execute()?;
+    point.set_x(point.get_y()).execute()?;
+    assert_eq!(point.get_x().execute()?, 10.0);
+
+    // Static fields have setters too (but the final `ORIGIN` does not).
+    fields::Point::set_created(100).execute()?;
+    assert_eq!(fields::Point::get_created().execute()?, 100);
+    Ok(())
+}
@@ -0,0 +1,13 @@
+use duchess::prelude::*;
+
+duchess::java_package! {
+    package fields;
+
+    public class fields.Point { * }
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let origin = fields::Point::get_origin();
+    fields::Point::set_origin(origin).execute()?; //~ ERROR: no function or associated item named `set_origin`
+    Ok(())
+}
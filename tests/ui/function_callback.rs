@@ -0,0 +1,103 @@
+//@run
+use std::sync::{Arc, Mutex};
+
+use duchess::{
+    java::{
+        lang::{Integer, String as JavaString},
+        util::function::Function,
+    },
+    prelude::*,
+    Jvm,
+};
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let length: Global<Function<JavaString, Integer>> = Jvm::with(|jvm| {
+        let length = duchess::callback::function(
+            |s: String| {
+                assert_ne!(s, "boom", "not this one");
+                s.chars().count() as i32
+            },
+            jvm,
+        )?;
+        Ok(jvm.global(&*length))
+    })?;
+
+    let n: i32 = length
+        .apply("duchess")
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(n, 7);
+
+    // A panic in the closure is thrown to Java as a `RuntimeException`, with the panic message.
+    std::panic::set_hook(Box::new(|_| {}));
+    let result: duchess::GlobalResult<i32> =
+        length.apply("boom").assert_not_null().to_rust().execute();
+    let _ = std::panic::take_hook();
+    let error = result.unwrap_err();
+    assert!(matches!(error, duchess::Error::Thrown(_)));
+    assert!(error.to_string().contains("not this one"), "{error}");
+
+    // The function can still be called after that, until it is destroyed.
+    let n: i32 = length.apply("").assert_not_null().to_rust().execute()?;
+    assert_eq!(n, 0);
+
+    // Functions from Java can be called the same way.
+    let identity: Global<Function<JavaString, JavaString>> =
+        Function::identity().global().execute()?;
+    let same: String = identity
+        .apply("duchess")
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(same, "duchess");
+
+    Jvm::with(|jvm| {
+        duchess::callback::destroy(&length, jvm)?;
+        let result = length.apply("duchess").execute_with(jvm);
+        assert!(matches!(result, Err(duchess::Error::Thrown(_))));
+        Ok(())
+    })?;
+
+    // The closure can neither apply itself again nor destroy itself while it is running.
+    let this: Arc<Mutex<Option<Global<Function<JavaString, Integer>>>>> = Arc::default();
+    let errors: Arc<Mutex<Vec<String>>> = Arc::default();
+    let length: Global<Function<JavaString, Integer>> = Jvm::with(|jvm| {
+        let slot = this.clone();
+        let errors = errors.clone();
+        let length = duchess::callback::function(
+            move |s: String| {
+                let this = slot.lock().unwrap();
+                let this = this.as_ref().unwrap();
+                let apply: duchess::GlobalResult<i32> =
+                    this.apply("again").assert_not_null().to_rust().execute();
+                let destroy = Jvm::with(|jvm| duchess::callback::destroy(this, jvm));
+                let mut errors = errors.lock().unwrap();
+                errors.push(apply.unwrap_err().to_string());
+                errors.push(destroy.unwrap_err().to_string());
+                s.len() as i32
+            },
+            jvm,
+        )?;
+        *this.lock().unwrap() = Some(jvm.global(&*length));
+        Ok(jvm.global(&*length))
+    })?;
+    let n: i32 = length
+        .apply("duchess")
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(n, 7);
+    {
+        let errors = errors.lock().unwrap();
+        assert!(errors[0].contains("already running"), "{}", errors[0]);
+        assert!(errors[1].contains("closure is running"), "{}", errors[1]);
+    }
+
+    // The closure is still there, and can be destroyed once it has returned.
+    let n: i32 = length.apply("").assert_not_null().to_rust().execute()?;
+    assert_eq!(n, 0);
+    assert_eq!(errors.lock().unwrap().len(), 4);
+    Jvm::with(|jvm| duchess::callback::destroy(&length, jvm))?;
+    Ok(())
+}
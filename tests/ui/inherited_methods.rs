@@ -0,0 +1,30 @@
+//@run
+use duchess::prelude::*;
+
+// `Leaf` extends `Derived`, which extends `Base`.
+duchess::java_package! {
+    package inheritance;
+
+    public class inheritance.Base { * }
+    public class inheritance.Derived { * }
+    public class inheritance.Leaf { * }
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    // `name` is declared on `Base`, and is called without upcasting,
+    // both on a reference and on an operation.
+    let leaf = inheritance::Leaf::new().global().execute()?;
+    let name: String = leaf.name().assert_not_null().to_rust().execute()?;
+    assert_eq!(name, "base");
+    let name: String = inheritance::Leaf::new()
+        .name()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(name, "base");
+
+    // `Derived` overrides `copy` with a covariant return type. That is the
+    // declaration used for a `Leaf`, so the copy is known to be a `Derived`.
+    assert_eq!(leaf.copy().generation().execute()?, 2);
+    Ok(())
+}
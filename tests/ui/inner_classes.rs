@@ -0,0 +1,71 @@
+//@run
+use duchess::{java, prelude::*};
+
+duchess::java_package! {
+    package nested;
+
+    public class nested.Outer { * }
+    public class nested.Outer$Inner { * }
+    public class nested.Outer$Nested { * }
+}
+
+// `Outer$Inner` is mirrored as `Outer__Inner`, next to `Outer`.
+use java::lang::String as JavaString;
+use nested::{Outer, Outer__Inner as Inner, Outer__Nested as Nested};
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let outer = Outer::new("outer").global().execute()?;
+
+    // The constructors of an inner class take the enclosing instance first, also when
+    // the Java signature is generic (and so leaves it out).
+    let inner = Inner::new_from_outer_string(&outer, "a")
+        .global()
+        .execute()?;
+    let described: String = inner.describe().assert_not_null().to_rust().execute()?;
+    assert_eq!(described, "outer/a");
+    let enclosing = inner.outer().assert_not_null().global().execute()?;
+    assert!(enclosing.equals(&outer).execute()?);
+
+    let parts = java::util::Collections::singleton_list::<JavaString>("b");
+    let inner = Inner::new_from_outer_list(&outer, parts)
+        .global()
+        .execute()?;
+    let described: String = inner.describe().assert_not_null().to_rust().execute()?;
+    assert_eq!(described, "outer/b");
+
+    // Inner classes appear in the signatures of other classes.
+    let described: String = outer
+        .inner("c")
+        .describe()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(described, "outer/c");
+
+    // Static nested classes are constructed like any other class.
+    let nested = Nested::new().global().execute()?;
+    let inner = nested
+        .outer("other")
+        .inner("d")
+        .assert_not_null()
+        .global()
+        .execute()?;
+    let described: String = inner.describe().assert_not_null().to_rust().execute()?;
+    assert_eq!(described, "other/d");
+
+    // So is `java.util.Map$Entry`, from the JDK.
+    let map = java::util::HashMap::<JavaString, JavaString>::new()
+        .global()
+        .execute()?;
+    map.put("key", "value").global().execute()?;
+    let entry: Option<duchess::Global<java::util::Map__Entry<JavaString, JavaString>>> =
+        map.entry_set().iterator().next().global().execute()?;
+    let key: String = entry
+        .unwrap()
+        .get_key()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(key, "key");
+    Ok(())
+}
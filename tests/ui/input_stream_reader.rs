@@ -0,0 +1,53 @@
+//@run
+use std::io::{ErrorKind, Read};
+
+use duchess::io::JavaReader;
+use duchess::{java, prelude::*};
+
+duchess::java_package! {
+    package streams;
+
+    public class streams.Truncated extends java.io.InputStream {
+        public streams.Truncated(byte[]);
+    }
+}
+
+fn reader(bytes: &[u8]) -> duchess::GlobalResult<JavaReader> {
+    let bytes: Vec<i8> = bytes.iter().map(|&b| b as i8).collect();
+    let stream = java::io::ByteArrayInputStream::new(bytes.as_slice())
+        .upcast::<java::io::InputStream>()
+        .global()
+        .execute()?;
+    JavaReader::new(stream)
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    // Small reads return at most what fits into the caller's buffer.
+    let mut small = reader(b"hello")?;
+    let mut buf = [0_u8; 3];
+    assert_eq!(small.read(&mut buf).unwrap(), 3);
+    assert_eq!(&buf, b"hel");
+    assert_eq!(small.read(&mut buf).unwrap(), 2);
+    assert_eq!(&buf[..2], b"lo");
+    assert_eq!(small.read(&mut buf).unwrap(), 0);
+
+    // Longer streams take several calls, each reusing the same Java buffer.
+    let data: Vec<u8> = (0..100_000).map(|i| (i % 251) as u8).collect();
+    let mut contents = vec![];
+    reader(&data)?.read_to_end(&mut contents).unwrap();
+    assert_eq!(contents, data);
+
+    // Java exceptions become `io::Error`s.
+    let prefix: Vec<i8> = b"abc".iter().map(|&b| b as i8).collect();
+    let stream = streams::Truncated::new(prefix.as_slice())
+        .upcast::<java::io::InputStream>()
+        .global()
+        .execute()?;
+    let mut truncated = JavaReader::new(stream)?;
+    let mut contents = vec![];
+    let error = truncated.read_to_end(&mut contents).unwrap_err();
+    assert_eq!(contents, b"abc");
+    assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+    assert!(error.to_string().contains("stream was truncated"));
+    Ok(())
+}
@@ -0,0 +1,33 @@
+//@run
+use duchess::prelude::*;
+
+duchess::java_package! {
+    package fields;
+
+    public class fields.Point { * }
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let point = fields::Point::new(3, 4, "p").global().execute()?;
+
+    // Instance fields are read through `get_<field>`...
+    assert_eq!(point.get_y().execute()?, 4);
+    assert!(point.get_visible().execute()?);
+    let label: String = point.get_label().assert_not_null().to_rust().execute()?;
+    assert_eq!(label, "p");
+
+    // ...and reflect changes made by Java code.
+    point.move_by(1, 1).execute()?;
+    assert_eq!(fields::Point::get_y(&point).execute()?, 5);
+
+    // `getX()` keeps its name, so the getter of the field `x` gets a `_field` suffix.
+    assert_eq!(point.get_x().execute()?, 4.0);
+    assert_eq!(point.get_x_field().execute()?, 4);
+
+    // Getters also work on ops, and for static fields.
+    let origin = fields::Point::get_origin().assert_not_null();
+    let origin_y = fields::Point::get_y(origin).execute()?;
+    assert_eq!(origin_y, 0);
+    assert_eq!(fields::Point::get_created().execute()?, 2);
+    Ok(())
+}
@@ -0,0 +1,19 @@
+//@run
+use duchess::java;
+use duchess::prelude::*;
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let list = java::util::ArrayList::<java::lang::Object>::new()
+        .global()
+        .execute()?;
+
+    let is_list: bool = list.instanceof::<java::util::List<java::lang::Object>>().execute()?;
+    assert!(is_list);
+
+    let is_map: bool = list
+        .instanceof::<java::util::Map<java::lang::Object, java::lang::Object>>()
+        .execute()?;
+    assert!(!is_map);
+
+    Ok(())
+}
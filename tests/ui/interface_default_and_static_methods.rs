@@ -0,0 +1,30 @@
+//@run
+use duchess::java;
+use duchess::prelude::*;
+
+type Comparator = java::util::Comparator<java::lang::String>;
+
+pub fn main() -> duchess::GlobalResult<()> {
+    // Implemented by a private class nested in `String`.
+    let order = java::lang::String::get_case_insensitive_order()
+        .assert_not_null()
+        .global()
+        .execute()?;
+    assert!(order.compare("a", "B").execute()? < 0);
+
+    // `reversed` is a default method: it is called on the receiver, and the body
+    // comes from `Comparator` unless the class overrides it.
+    let reversed = order.reversed().assert_not_null().global().execute()?;
+    assert!(reversed.compare("a", "B").execute()? > 0);
+    assert!(order.reversed().compare("b", "A").execute()? < 0);
+
+    // `nullsFirst` is a static method, called on the interface itself. Static members do
+    // not depend on the type arguments of the class, so none are given, and the type
+    // parameter `T` of the method is inferred.
+    let nulls_first: Global<Comparator> = java::util::Comparator::nulls_first(&order)
+        .global()
+        .execute()?;
+    assert!(nulls_first.compare("a", "B").execute()? < 0);
+    assert_eq!(nulls_first.compare("a", "A").execute()?, 0);
+    Ok(())
+}
@@ -0,0 +1,26 @@
+//@run
+use duchess::java::util::ListOpTrait;
+use duchess::{java, prelude::*};
+
+type JavaString = java::lang::String;
+
+// Generic over every operation producing a `java.util.List`, such as a reference to an
+// `ArrayList`, without upcasting first.
+fn push_and_get<L>(list: L, value: &str) -> duchess::GlobalResult<String>
+where
+    L: ListOpTrait<JavaString>,
+{
+    list.add(value).execute()?;
+    let last = list.size().execute()? - 1;
+    list.get(last).assert_not_null().to_rust().execute()
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let list = java::util::ArrayList::<JavaString>::new()
+        .global()
+        .execute()?;
+    assert_eq!(push_and_get(&list, "ferris")?, "ferris");
+    assert_eq!(push_and_get(&list, "duchess")?, "duchess");
+    assert_eq!(list.size().execute()?, 2);
+    Ok(())
+}
@@ -0,0 +1,39 @@
+//@run
+use duchess::java::util::{ListTrait, MapTrait};
+use duchess::{java, prelude::*};
+
+// Generic over every class implementing `java.util.List`, without boxing.
+fn first_two<L>(list: &L) -> duchess::GlobalResult<i32>
+where
+    L: ListTrait<java::lang::String>,
+{
+    list.add("a").execute()?;
+    list.add("b").execute()?;
+    list.size().execute()
+}
+
+// `getOrDefault` is a default method of `java.util.Map`.
+fn lookup<M>(map: &M, key: &str) -> duchess::GlobalResult<String>
+where
+    M: MapTrait<java::lang::String, java::lang::String>,
+{
+    map.get_or_default(key, "none")
+        .assert_not_null()
+        .to_rust()
+        .execute()
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let list = java::util::ArrayList::<java::lang::String>::new()
+        .global()
+        .execute()?;
+    assert_eq!(first_two(&*list)?, 2);
+
+    let map = java::util::HashMap::<java::lang::String, java::lang::String>::new()
+        .global()
+        .execute()?;
+    map.put("k", "v").global().execute()?;
+    assert_eq!(lookup(&*map, "k")?, "v");
+    assert_eq!(lookup(&*map, "missing")?, "none");
+    Ok(())
+}
@@ -0,0 +1,32 @@
+//@run
+use duchess::prelude::*;
+
+// `catalog` is only compiled into a JAR, which the macro reads without `javap`;
+// the Javadoc of the generated items comes from `catalog-sources.jar` next to it.
+duchess::java_package! {
+    package catalog (jar = "java/jars/catalog.jar");
+
+    public class catalog.Item { * }
+    public class catalog.Catalog<T> { * }
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    duchess::Jvm::builder()
+        .add_classpath(concat!(env!("CARGO_MANIFEST_DIR"), "/java/jars/catalog.jar"))
+        .try_launch()?;
+
+    assert_eq!(catalog::Item::MAX_NAME_LENGTH, 64);
+
+    let catalog = catalog::Catalog::<catalog::Item>::new().global().execute()?;
+    catalog.add(catalog::Item::new("lamp")).execute()?;
+    assert_eq!(catalog.size().execute()?, 1);
+
+    let name: String = catalog
+        .get(0)
+        .get_name()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(name, "lamp");
+    Ok(())
+}
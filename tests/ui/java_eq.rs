@@ -0,0 +1,35 @@
+//@run
+use std::collections::HashSet;
+
+use duchess::{java, prelude::*, Global};
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let new_string = |s: &str| -> duchess::GlobalResult<Global<java::lang::String>> {
+        s.to_string()
+            .to_java::<java::lang::String>()
+            .assert_not_null()
+            .global()
+            .execute()
+    };
+    let a = new_string("duchess")?;
+    let b = new_string("duchess")?;
+    let c = new_string("ferris")?;
+
+    // `java_eq` calls `equals`, `reference_eq` checks for the same object.
+    assert!(a.java_eq(&b).execute()?);
+    assert!(!a.java_eq(&c).execute()?);
+    assert!(!a.reference_eq(&b).execute()?);
+    assert!(a.reference_eq(&a).execute()?);
+    let a_again = duchess::Jvm::with(|jvm| Ok(jvm.global(&*a)))?;
+    assert!(a.reference_eq(&a_again).execute()?);
+    assert!(!a
+        .reference_eq(duchess::null_op::<java::lang::Object>())
+        .execute()?);
+
+    // `Global` handles compare and hash with `equals` and `hashCode`.
+    assert!(a == b);
+    assert!(a != c);
+    let set: HashSet<Global<java::lang::String>> = [a, b, c].into_iter().collect();
+    assert_eq!(set.len(), 2);
+    Ok(())
+}
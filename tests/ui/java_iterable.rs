@@ -0,0 +1,40 @@
+//@run
+use duchess::java;
+use duchess::prelude::*;
+use duchess::{IntoRust, Jvm};
+
+duchess::java_package! {
+    package iterables;
+
+    public class iterables.Countdown { * }
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let list = java::util::ArrayList::<java::lang::String>::new().execute_with(jvm)?;
+        let names = ["ferris", "duchess", "crab"];
+        for name in names {
+            list.add(name).execute_with(jvm)?;
+        }
+
+        // Elements arrive in the order of the list.
+        let mut elements = vec![];
+        let mut iter = list.iter(jvm)?;
+        while let Some(element) = iter.next() {
+            let element: Option<String> = element?.into_rust(iter.jvm())?;
+            elements.push(element.unwrap());
+        }
+        assert_eq!(elements, names);
+
+        // Classes that implement `Iterable<T>` themselves can be iterated as well.
+        let countdown = iterables::Countdown::new(3).execute_with(jvm)?;
+        let mut numbers = vec![];
+        let mut iter = countdown.iter(jvm)?;
+        while let Some(number) = iter.next() {
+            let number = number?.unwrap();
+            numbers.push(number.int_value().execute_with(iter.jvm())?);
+        }
+        assert_eq!(numbers, [3, 2, 1]);
+        Ok(())
+    })
+}
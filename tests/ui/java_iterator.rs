@@ -0,0 +1,55 @@
+//@run
+use duchess::java;
+use duchess::prelude::*;
+use duchess::{Error, IntoRust, JavaIteratorAdapter, Jvm};
+
+type JavaString = java::lang::String;
+
+pub fn main() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let list = java::util::ArrayList::<JavaString>::new().execute_with(jvm)?;
+        for name in ["ferris", "duchess"] {
+            list.add(name).execute_with(jvm)?;
+        }
+
+        // Elements are yielded as (nullable) local references.
+        let iterator = list.iterator().assert_not_null().execute_with(jvm)?;
+        let mut elements = JavaIteratorAdapter::new(jvm, iterator);
+        let mut names = vec![];
+        while let Some(element) = elements.next() {
+            let name: Option<String> = element?.into_rust(elements.jvm())?;
+            names.push(name);
+        }
+        assert_eq!(
+            names,
+            [Some("ferris".to_string()), Some("duchess".to_string())]
+        );
+
+        // The adapter composes with standard adapters.
+        let iterator = list.iterator().assert_not_null().execute_with(jvm)?;
+        let count = JavaIteratorAdapter::new(jvm, iterator)
+            .filter(|element| matches!(element, Ok(Some(_))))
+            .count();
+        assert_eq!(count, 2);
+        Ok(())
+    })?;
+
+    // Modifying the list while iterating yields the exception as an error item.
+    Jvm::with(|jvm| {
+        let list = java::util::ArrayList::<JavaString>::new().execute_with(jvm)?;
+        list.add("a").execute_with(jvm)?;
+        list.add("b").execute_with(jvm)?;
+
+        let iterator = list.iterator().assert_not_null().execute_with(jvm)?;
+        let mut elements = JavaIteratorAdapter::new(jvm, iterator);
+        assert!(matches!(elements.next(), Some(Ok(Some(_)))));
+        list.add("c").execute_with(elements.jvm())?;
+        let Some(Err(Error::Thrown(_))) = elements.next() else {
+            panic!("expected a `ConcurrentModificationException`");
+        };
+        assert!(elements.next().is_none());
+        Ok(())
+    })?;
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+//@run
+use duchess::java;
+use duchess::prelude::*;
+use duchess::Jvm;
+
+fn main() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        // The characters are read in place, as a `str`.
+        let text = "Hello, Duchess!".execute_with(jvm)?;
+        let chars = text.as_java_str(jvm)?;
+        assert_eq!(chars.len(), 15);
+        assert!(chars.starts_with("Hello"));
+        assert_eq!(&*chars, "Hello, Duchess!");
+        assert_eq!(chars.to_owned(), "Hello, Duchess!");
+        chars.release();
+
+        // Strings that are not valid UTF-8 in Modified UTF-8 are decoded.
+        for data in ["", "nul \0 byte", "crab 🦀 and ☃"] {
+            let text = data.execute_with(jvm)?;
+            let chars = text.as_java_str(jvm)?;
+            assert_eq!(&*chars, data);
+            assert_eq!(chars.len(), data.len());
+            assert_eq!(chars.is_empty(), data.is_empty());
+        }
+
+        // The `JavaString` keeps the Java string alive, and other calls can be made meanwhile.
+        let map = java::util::HashMap::<java::lang::String, java::lang::String>::new()
+            .execute_with(jvm)?;
+        map.put("key", "value").execute_with(jvm)?;
+        let value = map.get("key").assert_not_null().execute_with(jvm)?;
+        let chars = value.as_java_str(jvm)?;
+        drop(value);
+        map.clear().execute_with(jvm)?;
+        assert_eq!(format!("{chars}"), "value");
+        Ok(())
+    })
+}
@@ -0,0 +1,24 @@
+//@run
+use duchess::collections::{list_to_vec, vec_to_list};
+use duchess::{java, prelude::*, Jvm};
+
+type JavaString = java::lang::String;
+
+fn round_trip(names: &[&str]) -> duchess::GlobalResult<Vec<String>> {
+    let names: Vec<String> = names.iter().map(|s| s.to_string()).collect();
+    Jvm::with(|jvm| {
+        let list = vec_to_list::<_, JavaString>(&names, jvm)?;
+        assert_eq!(list.size().execute_with(jvm)?, names.len() as i32);
+        list_to_vec(&list, jvm)
+    })
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    assert_eq!(round_trip(&[])?, Vec::<String>::new());
+    assert_eq!(round_trip(&["ferris"])?, ["ferris"]);
+    assert_eq!(
+        round_trip(&["ferris", "duchess", "crab"])?,
+        ["ferris", "duchess", "crab"]
+    );
+    Ok(())
+}
@@ -0,0 +1,37 @@
+//@run
+use duchess::{java, prelude::*, ImplementsEquals, Jvm};
+
+duchess::java_package! {
+    package records;
+
+    public final class records.Point { * }
+}
+
+fn assert_implements_equals<T: ImplementsEquals>() {}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    assert_implements_equals::<java::lang::String>();
+    assert_implements_equals::<java::util::ArrayList<java::lang::String>>();
+    assert_implements_equals::<records::Point>();
+
+    Jvm::with(|jvm| {
+        // Distinct `Local` references to equal objects are equal.
+        let a = "duchess".execute_with(jvm)?;
+        let b = "duchess".execute_with(jvm)?;
+        let c = "ferris".execute_with(jvm)?;
+        assert!(!a.reference_eq(&b).execute_with(jvm)?);
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+
+        let point = records::Point::new(3, 4).execute_with(jvm)?;
+        assert_eq!(point, records::Point::new(3, 4).execute_with(jvm)?);
+        assert_ne!(point, records::Point::new(4, 3).execute_with(jvm)?);
+
+        let list = java::util::ArrayList::<java::lang::String>::new().execute_with(jvm)?;
+        let empty = java::util::ArrayList::<java::lang::String>::new().execute_with(jvm)?;
+        assert_eq!(list, empty);
+        list.add(&a).execute_with(jvm)?;
+        assert_ne!(list, empty);
+        Ok(())
+    })
+}
@@ -0,0 +1,76 @@
+//@run
+use std::panic::AssertUnwindSafe;
+
+use duchess::{java, prelude::*, Global, Jvm};
+
+mod our_java {
+    duchess::java_package! {
+        package java.lang;
+
+        public class System { gc; }
+    }
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let list = java::util::ArrayList::<java::lang::Object>::new()
+        .global()
+        .execute()?;
+
+    Jvm::with(|jvm| {
+        // Each frame holds many local references at once; they are all freed when it is popped.
+        for _ in 0..10 {
+            let count = jvm.with_local_frame(1000, |jvm| {
+                let objects = (0..1000)
+                    .map(|_| java::lang::Object::new().execute_with(jvm))
+                    .collect::<duchess::Result<'_, Vec<_>>>()?;
+                Ok(objects.len())
+            })?;
+            assert_eq!(count, 1000);
+        }
+
+        // Java objects leave the frame as global references.
+        let object: Global<java::lang::Object> = jvm.with_local_frame(16, |jvm| {
+            let object = java::lang::Object::new().execute_with(jvm)?;
+            list.add(&object).execute_with(jvm)?;
+            Ok(jvm.global(&*object))
+        })?;
+        assert_eq!(list.index_of(&object).execute_with(jvm)?, 0);
+
+        // Exceptions are moved to the enclosing frame and can still be inspected.
+        let result = jvm.with_local_frame(16, |jvm| list.get(5).global().execute_with(jvm));
+        let Err(duchess::Error::Thrown(exception)) = result else {
+            panic!("expected an exception");
+        };
+        let message: String = exception
+            .to_string()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert!(message.contains("IndexOutOfBoundsException"), "{message}");
+
+        // The frame is popped if `op` panics too. The object below is only referenced by a
+        // local reference of the frame, so it can be collected once the frame is gone.
+        let mut weak = None;
+        std::panic::set_hook(Box::new(|_| {}));
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            jvm.with_local_frame::<()>(16, |jvm| {
+                let object = java::lang::Object::new().execute_with(jvm)?;
+                weak = Some(object.downgrade());
+                std::mem::forget(object);
+                panic!("in the frame")
+            })
+        }));
+        let _ = std::panic::take_hook();
+        assert!(result.is_err());
+
+        let weak = weak.unwrap();
+        let mut tries = 0;
+        while weak.upgrade(jvm).is_some() {
+            tries += 1;
+            assert!(tries < 100, "object was not collected");
+            our_java::java::lang::System::gc().execute_with(jvm)?;
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,12 @@
+use duchess::{java, prelude::*, Jvm};
+
+fn main() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        // Local references are freed with their frame, so they cannot be returned from it.
+        let object = jvm.with_local_frame(16, |jvm| {
+            java::lang::Object::new().execute_with(jvm) //~ ERROR: lifetime may not live long enough
+        })?;
+        drop(object);
+        Ok(())
+    })
+}
@@ -0,0 +1,40 @@
+//@run
+use std::collections::{HashMap, HashSet};
+
+use duchess::{java, prelude::*, ImplementsHashCode, Jvm};
+
+duchess::java_package! {
+    package records;
+
+    public final class records.Point { * }
+}
+
+fn assert_implements_hash_code<T: ImplementsHashCode>() {}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    assert_implements_hash_code::<java::lang::String>();
+    assert_implements_hash_code::<records::Point>();
+
+    Jvm::with(|jvm| {
+        // Distinct `Local` references to equal strings are the same key.
+        let mut counts: HashMap<duchess::Local<java::lang::String>, i32> = HashMap::new();
+        for word in ["duchess", "ferris", "duchess"] {
+            let word = word
+                .to_string()
+                .to_java::<java::lang::String>()
+                .assert_not_null()
+                .execute_with(jvm)?;
+            *counts.entry(word).or_insert(0) += 1;
+        }
+        assert_eq!(counts.len(), 2);
+        let duchess = "duchess".execute_with(jvm)?;
+        assert_eq!(counts.get(&duchess), Some(&2));
+
+        let mut points = HashSet::new();
+        points.insert(records::Point::new(3, 4).execute_with(jvm)?);
+        points.insert(records::Point::new(3, 4).execute_with(jvm)?);
+        points.insert(records::Point::new(4, 3).execute_with(jvm)?);
+        assert_eq!(points.len(), 2);
+        Ok(())
+    })
+}
@@ -0,0 +1,68 @@
+//@run
+use duchess::java;
+use duchess::prelude::*;
+use duchess::{Error, IntoRust, Jvm};
+
+const ENTRIES: i32 = 1_000_000;
+
+pub fn main() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let map = java::util::HashMap::<java::lang::String, java::lang::String>::new()
+            .execute_with(jvm)?;
+        for i in 0..ENTRIES {
+            let key = format!("key{i}");
+            let value = format!("value{i}");
+            map.put(key.as_str(), value.as_str()).execute_with(jvm)?;
+        }
+
+        // Stream over all entries; local references are released as we go.
+        let mut count = 0;
+        map.for_each_rust(jvm, |key: Option<String>, value: Option<String>| {
+            let (key, value) = (key.unwrap(), value.unwrap());
+            assert_eq!(key["key".len()..], value["value".len()..]);
+            count += 1;
+        })?;
+        assert_eq!(count, ENTRIES);
+
+        // The entries iterator composes with standard adapters.
+        let non_null = map
+            .entries(jvm)?
+            .filter(|entry| matches!(entry, Ok((Some(_), Some(_)))))
+            .count();
+        assert_eq!(non_null, ENTRIES as usize);
+
+        let firsts: Vec<_> = map.entries(jvm)?.take(3).collect::<Result<_, _>>()?;
+        assert_eq!(firsts.len(), 3);
+        for (key, _value) in firsts {
+            let key: String = key.unwrap().into_rust(jvm)?;
+            assert!(key.starts_with("key"));
+        }
+        Ok(())
+    })?;
+
+    // Modifying the map while iterating yields the exception as an error item.
+    Jvm::with(|jvm| {
+        let map = java::util::HashMap::<java::lang::String, java::lang::String>::new()
+            .execute_with(jvm)?;
+        map.put("a", "1").execute_with(jvm)?;
+        map.put("b", "2").execute_with(jvm)?;
+
+        let mut entries = map.entries(jvm)?;
+        assert!(matches!(entries.next(), Some(Ok(_))));
+        map.put("c", "3").execute_with(entries.jvm())?;
+        let Some(Err(Error::Thrown(exception))) = entries.next() else {
+            panic!("expected a `ConcurrentModificationException`");
+        };
+        assert!(entries.next().is_none());
+
+        let message: String = exception
+            .to_string()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert!(message.contains("ConcurrentModificationException"));
+        Ok(())
+    })?;
+
+    Ok(())
+}
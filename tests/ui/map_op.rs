@@ -0,0 +1,31 @@
+//@run
+use duchess::{java, prelude::*, Local};
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let list = java::util::ArrayList::<java::lang::String>::new()
+        .global()
+        .execute()?;
+    list.add("one").execute()?;
+    list.add("two").execute()?;
+
+    // The closure sees the output of the op, within the same call into the JVM.
+    let len: usize = list.size().map(|size| size as usize).execute()?;
+    assert_eq!(len, 2);
+
+    // Outputs that are tied to the JVM can be inspected, as long as the result is not.
+    let is_null = list
+        .get(0)
+        .map(|element: Option<Local<'_, java::lang::String>>| element.is_none())
+        .execute()?;
+    assert!(!is_null);
+
+    // Ops can be chained after `map`.
+    let shout: String = list
+        .get(1)
+        .assert_not_null()
+        .to_rust()
+        .map(|s: String| s.to_uppercase())
+        .execute()?;
+    assert_eq!(shout, "TWO");
+    Ok(())
+}
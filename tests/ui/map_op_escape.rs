@@ -0,0 +1,15 @@
+use duchess::{java, prelude::*, Local};
+
+fn main() -> duchess::GlobalResult<()> {
+    let list = java::util::ArrayList::<java::lang::String>::new()
+        .global()
+        .execute()?;
+
+    // The local reference cannot outlive the call into the JVM.
+    let element = list
+        .get(0)
+        .map(|element: Option<Local<'_, java::lang::String>>| element) //~ ERROR: lifetime may not live long enough
+        .execute()?;
+    drop(element);
+    Ok(())
+}
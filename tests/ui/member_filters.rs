@@ -0,0 +1,19 @@
+//@run
+use duchess::prelude::*;
+
+// Only the getters, plus the `of` factory. `getName` is matched twice
+// but generated once.
+duchess::java_package! {
+    package beans;
+
+    public class beans.Customer { of; get*; is*; getName; }
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let customer = beans::Customer::of("Ferris", 8).global().execute()?;
+    let name: String = customer.get_name().assert_not_null().to_rust().execute()?;
+    assert_eq!(name, "Ferris");
+    assert_eq!(customer.get_age().execute()?, 8);
+    assert!(customer.is_active().execute()?);
+    Ok(())
+}
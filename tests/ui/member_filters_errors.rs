@@ -0,0 +1,33 @@
+mod stale {
+    duchess::java_package! {
+        package beans;
+
+        public class beans.Customer { get*; has*; } //~ ERROR: no field or method of `beans.Customer` matches `has*`
+    }
+}
+
+mod excluded {
+    duchess::java_package! {
+        package beans;
+
+        public class beans.Customer { of; get*; }
+    }
+
+    fn set_name(customer: &beans::Customer) {
+        customer.set_name("Ferris"); //~ ERROR: no method named `set_name`
+    }
+}
+
+mod record {
+    duchess::java_package! {
+        package records;
+
+        public final class records.Point { new; x; }
+    }
+
+    fn y(point: &records::Point) {
+        point.y(); //~ ERROR: no method named `y`
+    }
+}
+
+fn main() {}
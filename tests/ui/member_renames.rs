@@ -0,0 +1,45 @@
+//@run
+use duchess::prelude::*;
+
+// `toString` is renamed so that it does not clash with `ToString::to_string`.
+duchess::java_package! {
+    package beans;
+
+    public class beans.Customer {
+        of;
+        getName as name;
+        getAge as age;
+        isActive;
+        toString as to_java_string;
+    }
+}
+
+// `getValue` and `get_value` would both be `get_value` in snake case, so one is renamed.
+duchess::java_package! {
+    package naming;
+
+    public class naming.Legacy {
+        new;
+        getValue;
+        get_value as get_value_raw;
+    }
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let customer = beans::Customer::of("Ferris", 8).global().execute()?;
+    let name: String = customer.name().assert_not_null().to_rust().execute()?;
+    assert_eq!(name, "Ferris");
+    assert_eq!(customer.age().execute()?, 8);
+    assert!(customer.is_active().execute()?);
+    let text: String = customer
+        .to_java_string()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(text, "Customer(Ferris)");
+
+    let legacy = naming::Legacy::new().global().execute()?;
+    assert_eq!(legacy.get_value().execute()?, 1);
+    assert_eq!(legacy.get_value_raw().execute()?, 2);
+    Ok(())
+}
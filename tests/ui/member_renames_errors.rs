@@ -0,0 +1,46 @@
+mod same_rust_name {
+    duchess::java_package! {
+        package beans;
+
+        public class beans.Customer {
+            getName as name; //~ ERROR: `getName` and `toString` would both be named `name` in Rust
+            toString as name;
+        }
+    }
+}
+
+mod clashes_with_other_member {
+    duchess::java_package! {
+        package beans;
+
+        public class beans.Customer {
+            getName;
+            getAge as get_name; //~ ERROR: `getAge` and `getName` would both be named `get_name` in Rust
+        }
+    }
+}
+
+mod renamed_twice {
+    duchess::java_package! {
+        package beans;
+
+        public class beans.Customer {
+            getName as name;
+            getName as customer_name; //~ ERROR: `getName` is already renamed to `name`
+        }
+    }
+}
+
+mod java_name_not_usable {
+    duchess::java_package! {
+        package beans;
+
+        public class beans.Customer { of; getName as name; }
+    }
+
+    fn get_name(customer: &beans::Customer) {
+        customer.get_name(); //~ ERROR: no method named `get_name`
+    }
+}
+
+fn main() {}
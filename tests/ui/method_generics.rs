@@ -0,0 +1,51 @@
+//@run
+use duchess::{java, prelude::*};
+
+duchess::java_package! {
+    package generics;
+
+    public class generics.Holder<T> { * }
+}
+
+use generics::Holder;
+use java::lang::{Integer, Object, String as JavaString};
+
+pub fn main() -> duchess::GlobalResult<()> {
+    // Static methods with a type parameter of their own.
+    let empty = java::util::Collections::empty_list::<JavaString>()
+        .assert_not_null()
+        .global()
+        .execute()?;
+    assert_eq!(empty.size().execute()?, 0);
+
+    let one = java::util::Collections::singleton_list::<JavaString>("one")
+        .assert_not_null()
+        .global()
+        .execute()?;
+    let first: String = one.get(0).assert_not_null().to_rust().execute()?;
+    assert_eq!(first, "one");
+
+    // Instance methods whose type parameters are named like the class's (`T`), or like those
+    // of the generated code (`J` and `N`).
+    let holder = Holder::<JavaString>::new("held").global().execute()?;
+    let echoed: i32 = holder
+        .echo::<Integer>(1)
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(echoed, 1);
+    let first: String = holder
+        .first::<JavaString, Object>(&one, &holder)
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(first, "one");
+
+    // A bounded type parameter erases to its bound in the JNI descriptor.
+    let larger: i32 = Holder::<Object>::larger::<Integer>(2, 3)
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(larger, 3);
+    Ok(())
+}
@@ -0,0 +1,50 @@
+//@run
+use duchess::prelude::*;
+
+duchess::java_package! {
+    package signatures;
+
+    public class signatures.Text { * }
+
+    package clients.foo;
+
+    public class Client { * }
+
+    package clients.bar;
+
+    public class Client { * }
+}
+
+use signatures::Text;
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let text = Text::new().global().execute()?;
+
+    // Overloads are named after their parameter types...
+    let string: String = text
+        .append_string("duchess, ")
+        .append_i32(42)
+        .append_u16_array(&[b',' as u16, b' ' as u16])
+        .to_string()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(string, "duchess, 42, ");
+
+    // ...and numbered in the order of their descriptors if that is not enough:
+    // `(Lclients/bar/Client;)` comes before `(Lclients/foo/Client;)`.
+    let string: String = text
+        .append_client_1(clients::bar::Client::new())
+        .append_client_2(clients::foo::Client::new())
+        .to_string()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(string, "duchess, 42, barfoo");
+
+    // The overload with the fewest parameters keeps the name if no other has as many.
+    assert_eq!(text.index_of("42").execute()?, 9);
+    assert_eq!(text.index_of_string_i32(", ", 8).execute()?, 11);
+    assert_eq!(Text::index_of(&text, "duchess").execute()?, 0);
+    Ok(())
+}
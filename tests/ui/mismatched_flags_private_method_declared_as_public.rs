@@ -1,10 +1,10 @@
 duchess::java_package! {
     package flags;
 
-    public class flags.Flags { //~ ERROR: member declared as `public`
+    public class flags.Flags {
         private int privateField;
         public flags.Flags();
-        public int privateMethod();
+        public int privateMethod(); //~ ERROR: member declared as `public`
         public int publicMethod();
     }
 }
@@ -1,11 +1,11 @@
 duchess::java_package! {
     package flags;
 
-    public class flags.Flags { //~ ERROR: member declared as `private`
+    public class flags.Flags {
         private int privateField;
         public flags.Flags();
         private int privateMethod();
-        private int publicMethod();
+        private int publicMethod(); //~ ERROR: member declared as `private`
     }
 }
 
@@ -0,0 +1,37 @@
+//@run
+use duchess::java;
+use duchess::prelude::*;
+
+duchess::java_package! {
+    package arrays;
+
+    public class arrays.Matrix { * }
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let identity: Vec<Vec<i32>> = arrays::Matrix::identity(3)
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(identity, vec![vec![1, 0, 0], vec![0, 1, 0], vec![0, 0, 1]]);
+
+    let rows = vec![vec![1, 2, 3], vec![], vec![4]];
+    let sum = arrays::Matrix::sum(rows.to_java::<java::Array<java::Array<i32>>>()).execute()?;
+    assert_eq!(sum, 10);
+
+    let words: Vec<Vec<String>> = arrays::Matrix::words()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(words, vec![vec!["a", "b"], vec!["c"]]);
+
+    let rows = vec![vec!["x".to_string(), "y".to_string()], vec!["z".to_string()]];
+    let joined: String = arrays::Matrix::join(
+        rows.to_java::<java::Array<java::Array<java::lang::String>>>(),
+    )
+    .assert_not_null()
+    .to_rust()
+    .execute()?;
+    assert_eq!(joined, "x,y;z;");
+    Ok(())
+}
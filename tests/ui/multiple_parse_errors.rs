@@ -0,0 +1,21 @@
+// All of the syntax errors below are reported by one expansion.
+duchess::java_package! {
+    package java.lang //~ ERROR: expected `;` after package name
+
+    use String; //~ ERROR: expected a fully qualified class name
+
+    public class java.lang.Object {
+        public java.lang.Object();
+        public int hashCode() //~ ERROR: unexpected token
+        public boolean equals(java.lang.Object);
+        public java.lang.String toString(int int); //~ ERROR: unexpected token
+    }
+
+    oops; //~ ERROR: unexpected `oops`, expected a class definition
+
+    public clas java.lang.Throwable { * } //~ ERROR: unexpected token
+
+    public class java.lang.String { * }
+}
+
+fn main() {}
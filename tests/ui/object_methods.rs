@@ -0,0 +1,57 @@
+//@run
+use duchess::{java, plumbing::ObjectMethods, prelude::*, IntoJava, Jvm};
+
+/// Only knows that `T` is some Java object.
+fn describe<'jvm, T: JavaObject>(
+    obj: &T,
+    jvm: &mut Jvm<'jvm>,
+) -> duchess::Result<'jvm, (String, String, i32)> {
+    let string: String = obj
+        .to_string()
+        .assert_not_null()
+        .to_rust()
+        .execute_with(jvm)?;
+    let class: String = obj
+        .get_class()
+        .get_name()
+        .assert_not_null()
+        .to_rust()
+        .execute_with(jvm)?;
+    let hash_code = obj.hash_code().execute_with(jvm)?;
+    Ok((string, class, hash_code))
+}
+
+fn same<'jvm, T: JavaObject>(
+    a: &T,
+    b: impl IntoJava<java::lang::Object>,
+    jvm: &mut Jvm<'jvm>,
+) -> duchess::Result<'jvm, bool> {
+    a.equals(b).execute_with(jvm)
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let list = java::util::ArrayList::<java::lang::String>::new().execute_with(jvm)?;
+        list.add("a").execute_with(jvm)?;
+        let (string, class, hash_code) = describe(&*list, jvm)?;
+        assert_eq!(string, "[a]");
+        assert_eq!(class, "java.util.ArrayList");
+        assert_eq!(hash_code, list.hash_code().execute_with(jvm)?);
+
+        let a = "duchess".execute_with(jvm)?;
+        let b = "duchess".execute_with(jvm)?;
+        assert!(same(&*a, &b, jvm)?);
+
+        // Without holding the object's monitor, these throw `IllegalMonitorStateException`.
+        assert!(matches!(
+            ObjectMethods::notify(&*a).execute_with(jvm),
+            Err(duchess::Error::Thrown(_))
+        ));
+        assert!(matches!(
+            ObjectMethods::wait(&*a).execute_with(jvm),
+            Err(duchess::Error::Thrown(_))
+        ));
+        Ok(())
+    })?;
+    Ok(())
+}
@@ -0,0 +1,41 @@
+//@run
+use duchess::{java, prelude::*, Local};
+
+// The deprecated constructor, field, and methods of `Ledger` are left out.
+mod all {
+    duchess::java_package! {
+        package legacy (omit_deprecated);
+
+        public class legacy.Ledger { * }
+    }
+    pub use legacy::*;
+}
+
+// Naming a deprecated member keeps it; globs do not.
+mod named {
+    duchess::java_package! {
+        package legacy (omit_deprecated);
+
+        public class legacy.Ledger { get*; getSum; }
+    }
+    pub use legacy::*;
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    duchess::Jvm::with(|jvm| {
+        let ledger = all::Ledger::new().execute_with(jvm)?;
+        ledger.add(2).execute_with(jvm)?;
+        ledger.add(3).execute_with(jvm)?;
+        assert_eq!(ledger.get_total().execute_with(jvm)?, 5);
+
+        let object: Local<'_, java::lang::Object> = ledger.upcast();
+        let Ok(ledger) = object.try_downcast::<named::Ledger>()? else {
+            panic!("not a `Ledger`");
+        };
+        // It is still marked `#[deprecated]`.
+        #[allow(deprecated)]
+        let sum = ledger.get_sum().execute_with(jvm)?;
+        assert_eq!(sum, 5);
+        Ok(())
+    })
+}
@@ -0,0 +1,22 @@
+mod omitted {
+    duchess::java_package! {
+        package legacy (omit_deprecated);
+
+        public class legacy.Ledger { * }
+    }
+
+    fn deprecated(ledger: &legacy::Ledger) {
+        ledger.get_sum(); //~ ERROR: no method named `get_sum`
+        ledger.add_all(); //~ ERROR: no method named `add_all`
+    }
+}
+
+mod unknown_option {
+    duchess::java_package! {
+        package legacy (omit_deprecated, omit_everything); //~ ERROR: unexpected `omit_everything`, expected a package option like `omit_deprecated`
+
+        public class legacy.Ledger { * }
+    }
+}
+
+fn main() {}
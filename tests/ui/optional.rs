@@ -0,0 +1,48 @@
+//@run
+use duchess::prelude::*;
+
+duchess::java_package! {
+    package optionals;
+
+    public class optionals.Directory { * }
+}
+
+use optionals::Directory;
+
+pub fn main() -> duchess::GlobalResult<()> {
+    // An `Optional` converts to an `Option`, also converting its value.
+    let greeting: Option<String> = Directory::greeting(true)
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(greeting.as_deref(), Some("hello"));
+    let greeting: Option<String> = Directory::greeting(false)
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(greeting, None);
+
+    let extension: Option<i32> = Directory::extension("ferris")
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(extension, Some(42));
+
+    // Without `assert_not_null`, the outer `Option` is `None` only for a null reference.
+    let extension: Option<Option<i32>> = Directory::extension("duke").to_rust().execute()?;
+    assert_eq!(extension, Some(None));
+
+    // The methods of `Optional` are available as well.
+    let empty = Directory::greeting(false)
+        .assert_not_null()
+        .global()
+        .execute()?;
+    assert!(empty.is_empty().execute()?);
+    let fallback: String = empty
+        .or_else("nothing")
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(fallback, "nothing");
+    Ok(())
+}
@@ -0,0 +1,44 @@
+//@run
+use duchess::{java, prelude::*};
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let map = java::util::HashMap::<java::lang::String, java::lang::String>::new()
+        .global()
+        .execute()?;
+    map.put("key", "value").global().execute()?;
+    map.put("fallback", "fallback value").global().execute()?;
+
+    // The default is not used when the first op produces an object.
+    let value: String = map
+        .get("key")
+        .or_null(map.get("fallback"))
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(value, "value");
+
+    // ...and is used when it produces null.
+    let value: String = map
+        .get("missing")
+        .or_null(map.get("fallback"))
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(value, "fallback value");
+
+    // Chains can end with `null_op`, so the result is still nullable.
+    let value: Option<String> = map
+        .get("missing")
+        .or_null(map.get("also missing"))
+        .or_null(duchess::null_op())
+        .to_rust()
+        .execute()?;
+    assert_eq!(value, None);
+
+    let value: Option<String> = duchess::null_op::<java::lang::String>()
+        .or_null(map.get("key"))
+        .to_rust()
+        .execute()?;
+    assert_eq!(value.as_deref(), Some("value"));
+    Ok(())
+}
@@ -0,0 +1,51 @@
+//@run
+use std::io::{ErrorKind, Write};
+
+use duchess::io::JavaWriter;
+use duchess::{java, prelude::*};
+
+duchess::java_package! {
+    package streams;
+
+    public class streams.ClosedPipe extends java.io.OutputStream {
+        public streams.ClosedPipe();
+    }
+
+    public class streams.ReadOnly extends java.io.OutputStream {
+        public streams.ReadOnly(java.lang.String);
+    }
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    // Writes larger than the writer's buffer take several calls.
+    let data: Vec<u8> = (0..100_000).map(|i| (i % 251) as u8).collect();
+    let stream = java::io::ByteArrayOutputStream::new().global().execute()?;
+    let mut writer = JavaWriter::new(
+        (&stream)
+            .upcast::<java::io::OutputStream>()
+            .global()
+            .execute()?,
+    )?;
+    writer.write_all(&data).unwrap();
+    writer.flush().unwrap();
+    let contents: Vec<i8> = stream.to_byte_array().assert_not_null().to_rust().execute()?;
+    let contents: Vec<u8> = contents.into_iter().map(|b| b as u8).collect();
+    assert_eq!(contents, data);
+
+    // Java exceptions become `io::Error`s.
+    let pipe = streams::ClosedPipe::new()
+        .upcast::<java::io::OutputStream>()
+        .global()
+        .execute()?;
+    let error = JavaWriter::new(pipe)?.write(b"hello").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::BrokenPipe);
+
+    let read_only = streams::ReadOnly::new("/data/ledger")
+        .upcast::<java::io::OutputStream>()
+        .global()
+        .execute()?;
+    let error = JavaWriter::new(read_only)?.write(b"hello").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::PermissionDenied);
+    assert!(error.to_string().contains("/data/ledger"));
+    Ok(())
+}
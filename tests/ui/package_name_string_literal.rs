@@ -0,0 +1,29 @@
+//@run
+use duchess::prelude::*;
+
+// `impl` and `async` are Rust keywords, so the generated modules are
+// `keywords::r#impl::r#async`. The package can be named with a string
+// literal or with plain identifiers; both blocks declare the same package.
+duchess::java_package! {
+    package "keywords.impl.async";
+
+    public class keywords.impl.async.Task { * }
+
+    package keywords.impl.async;
+
+    public class keywords.impl.async.Step { * }
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let step = keywords::r#impl::r#async::Step::new("build")
+        .global()
+        .execute()?;
+    let name: String = keywords::r#impl::r#async::Task::named("fetch")
+        .then(&step)
+        .name()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(name, "fetch then build");
+    Ok(())
+}
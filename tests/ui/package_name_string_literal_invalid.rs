@@ -0,0 +1,5 @@
+duchess::java_package! {
+    package "keywords..async"; //~ ERROR: expected a dotted Java name
+}
+
+fn main() {}
@@ -0,0 +1,41 @@
+//@run
+#![deny(warnings)]
+
+use duchess::{prelude::*, Global};
+
+duchess::java_package! {
+    package clients.foo (prefix = "Foo");
+
+    public class Client { * }
+
+    package clients.bar (prefix = "Bar");
+
+    public class Client { * }
+
+    // An alias takes precedence over the prefix.
+    public class Registry as ClientRegistry { * }
+}
+
+// Both `Client`s can be flattened into one module.
+mod flat {
+    pub use super::clients::bar::*;
+    pub use super::clients::foo::*;
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let foo: String = flat::FooClient::new()
+        .service()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(foo, "foo");
+
+    // `Registry::primary` refers to `clients.bar.Client`, which is generated as `BarClient`.
+    let bar: Global<flat::BarClient> = flat::ClientRegistry::primary()
+        .assert_not_null()
+        .global()
+        .execute()?;
+    let bar: String = bar.service().assert_not_null().to_rust().execute()?;
+    assert_eq!(bar, "bar");
+    Ok(())
+}
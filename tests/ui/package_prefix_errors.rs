@@ -0,0 +1,17 @@
+mod not_a_string {
+    duchess::java_package! {
+        package clients.foo (prefix = Foo); //~ ERROR: expected a string after `prefix`
+
+        public class Client { * }
+    }
+}
+
+mod no_comma {
+    duchess::java_package! {
+        package clients.foo (prefix = "Foo" omit_deprecated); //~ ERROR: unexpected `omit_deprecated`, expected `,` between package options
+
+        public class Client { * }
+    }
+}
+
+fn main() {}
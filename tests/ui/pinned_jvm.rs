@@ -0,0 +1,65 @@
+//@run
+use std::sync::{Arc, Mutex};
+
+use duchess::java;
+use duchess::prelude::*;
+use duchess::{Error, PinnedJvm};
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let pinned = Arc::new(PinnedJvm::spawn()?);
+
+    // Work submitted from several threads runs on the one pinned thread, in FIFO order per caller.
+    let pinned_thread = pinned.run_with(|_jvm| Ok(std::thread::current().id()))?;
+    let log = Arc::new(Mutex::new(vec![]));
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            let pinned = pinned.clone();
+            let log = log.clone();
+            std::thread::spawn(move || {
+                for j in 0..10 {
+                    let log = log.clone();
+                    let id = pinned
+                        .run_with(move |_jvm| {
+                            log.lock().unwrap().push((i, j));
+                            Ok(std::thread::current().id())
+                        })
+                        .unwrap();
+                    assert_eq!(id, pinned_thread);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    let log = log.lock().unwrap();
+    assert_eq!(log.len(), 40);
+    for i in 0..4 {
+        let order: Vec<_> = log.iter().filter(|(t, _)| *t == i).map(|(_, j)| *j).collect();
+        assert_eq!(order, (0..10).collect::<Vec<_>>());
+    }
+
+    // Owned results can be passed back across threads.
+    let date = pinned.run(java::util::Date::new().global())?;
+    let s: String =
+        pinned.run_with(move |jvm| date.to_string().assert_not_null().to_rust().execute_with(jvm))?;
+    assert!(!s.is_empty());
+
+    // Re-entrant use from the pinned thread is rejected instead of deadlocking.
+    let inner = pinned.clone();
+    let nested = pinned.run_with(move |_jvm| Ok(inner.run_with(|_jvm| Ok(()))))?;
+    assert!(matches!(nested, Err(Error::NestedUsage)));
+
+    // Panics are reported as errors.
+    std::panic::set_hook(Box::new(|_| {}));
+    let panicked = pinned.run_with(|_jvm| -> duchess::Result<'_, ()> { panic!("boom") });
+    assert!(matches!(panicked, Err(Error::JvmInternal(_))));
+
+    let _ = std::panic::take_hook();
+
+    // The pinned thread keeps working after a panic.
+    assert_eq!(pinned.run_with(|_jvm| Ok(22))?, 22);
+
+    Arc::try_unwrap(pinned).ok().unwrap().shutdown();
+    Ok(())
+}
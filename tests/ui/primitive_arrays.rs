@@ -0,0 +1,39 @@
+//@run
+use duchess::prelude::*;
+
+duchess::java_package! {
+    package signatures;
+
+    public class signatures.Overloads {
+        count(int[]);
+        max("[J");
+    }
+}
+
+macro_rules! assert_round_trip {
+    ($ty:ty: $($value:expr),*) => {
+        let values: Vec<$ty> = vec![$($value),*];
+        let round_trip: Vec<$ty> = (&values).to_rust().execute()?;
+        assert_eq!(round_trip, values);
+    };
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    // Slices, `Vec`s and arrays of scalars are Java arrays of the corresponding primitive type.
+    let values = vec![1, 2, 3];
+    assert_eq!(signatures::Overloads::count(&values).execute()?, 3);
+    assert_eq!(signatures::Overloads::count(&values[1..]).execute()?, 2);
+    assert_eq!(signatures::Overloads::max(&[4_i64, 9, 2]).execute()?, 9);
+    assert_eq!(signatures::Overloads::count(&[0_i32; 0]).execute()?, 0);
+
+    // Each of them converts back into a `Vec` with `to_rust`.
+    assert_round_trip!(bool: true, false);
+    assert_round_trip!(i8: i8::MIN, 0, i8::MAX);
+    assert_round_trip!(u16: 0x61, 0xd800);
+    assert_round_trip!(i16: i16::MIN, i16::MAX);
+    assert_round_trip!(i32: i32::MIN, i32::MAX);
+    assert_round_trip!(i64: i64::MIN, i64::MAX);
+    assert_round_trip!(f32: 0.5, -1.0);
+    assert_round_trip!(f64: 0.25, f64::MAX);
+    Ok(())
+}
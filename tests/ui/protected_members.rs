@@ -0,0 +1,40 @@
+//@run
+use duchess::prelude::*;
+
+// Only the public members of `Widget` are generated.
+mod public {
+    duchess::java_package! {
+        package widgets;
+
+        public class widgets.Widget { * }
+    }
+    pub use widgets::*;
+}
+
+// With `(protected)`, the protected constructor, field, and methods are generated as well.
+mod protected {
+    duchess::java_package! {
+        package widgets;
+
+        public class widgets.Widget (protected) { * }
+    }
+    pub use widgets::*;
+}
+
+use protected::Widget;
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let widget = public::Widget::new().global().execute()?;
+    assert_eq!(widget.area().execute()?, 1);
+
+    let widget = Widget::new_from_i32(2).global().execute()?;
+    assert_eq!(widget.area().execute()?, 4);
+    widget.grow(1).execute()?;
+    assert_eq!(widget.get_size().execute()?, 3);
+    widget.set_size(5).execute()?;
+    assert_eq!(widget.area().execute()?, 25);
+
+    let description: String = Widget::describe(5).assert_not_null().to_rust().execute()?;
+    assert_eq!(description, "widget of size 5");
+    Ok(())
+}
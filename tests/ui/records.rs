@@ -0,0 +1,53 @@
+//@run
+use duchess::{java, prelude::*};
+
+duchess::java_package! {
+    package records;
+
+    public final class records.Point { * }
+
+    // The component accessors are selected like any other method.
+    public final class records.Tagged<T> { new; label; value; tags; }
+}
+
+fn assert_record<R: duchess::JavaRecord>() {}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    assert_record::<records::Point>();
+    assert_record::<records::Tagged<java::lang::Object>>();
+
+    let tags = vec!["red".to_string(), "round".to_string()];
+    let label = "ball".to_string();
+    duchess::Jvm::with(|jvm| {
+        let point = records::Point::new(3, 4).execute_with(jvm)?;
+        assert_eq!(point.x().execute_with(jvm)?, 3);
+        assert_eq!(point.y().execute_with(jvm)?, 4);
+        assert_eq!(point.sum().execute_with(jvm)?, 7);
+
+        // Records compare with `equals()` and format with `toString()`.
+        let same = records::Point::new(3, 4).execute_with(jvm)?;
+        let other = records::Point::new(4, 3).execute_with(jvm)?;
+        assert!(*point == *same);
+        assert!(*point != *other);
+        assert_eq!(format!("{point:?}"), "Point[x=3, y=4]");
+
+        let tags = tags.to_java::<java::util::List<java::lang::String>>();
+        let tagged =
+            records::Tagged::<records::Point>::new(&label, &point, tags).execute_with(jvm)?;
+        let tagged_label: String = tagged
+            .label()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(tagged_label, "ball");
+        assert_eq!(tagged.tags().size().execute_with(jvm)?, 2);
+        let value = tagged.value().assert_not_null().execute_with(jvm)?;
+        assert!(*value == *point);
+        Ok(())
+    })?;
+
+    // The compact constructor still validates the components.
+    let result = records::Point::new(-1, 0).global().execute();
+    assert!(matches!(result, Err(duchess::Error::Thrown(_))));
+    Ok(())
+}
@@ -0,0 +1,24 @@
+//@run
+use duchess::prelude::*;
+
+// Bindings generated for a JDK class, in nested `java::lang` modules of this crate.
+duchess::java_package! {
+    package java.lang;
+
+    public class Object { new; toString; hashCode; equals; }
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let object = java::lang::Object::new().global().execute()?;
+    let description: String = object.to_string().assert_not_null().to_rust().execute()?;
+    assert!(description.starts_with("java.lang.Object@"));
+
+    // The receiver can itself be an op, so calls chain within one call into the JVM.
+    let description: String = java::lang::Object::new()
+        .to_string()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert!(description.starts_with("java.lang.Object@"));
+    Ok(())
+}
@@ -0,0 +1,40 @@
+//@run
+use duchess::prelude::*;
+
+// A binding specialized to compare strings.
+pub mod specialized {
+    duchess::java_package! {
+        package java.lang;
+
+        public interface java.lang.Comparable<java.lang.String> { * }
+    }
+    pub use java::*;
+}
+
+// A generic binding, with the parameter spelled out (and checked).
+pub mod generic {
+    duchess::java_package! {
+        package java.lang;
+
+        public interface java.lang.Comparable<T extends java.lang.Object> { * }
+    }
+    pub use java::*;
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let ordering = duchess::Jvm::with(|jvm| {
+        let a = "a"
+            .upcast::<duchess::java::lang::Object>()
+            .try_downcast::<specialized::lang::Comparable>()
+            .execute_with(jvm)?
+            .ok()
+            .expect("strings are comparable");
+
+        // `compareTo` takes a `java.lang.String` here, not a generic `T`.
+        a.compare_to("b").execute_with(jvm)
+    })?;
+    assert!(ordering < 0);
+
+    let _: Option<duchess::Global<generic::lang::Comparable<duchess::java::lang::String>>> = None;
+    Ok(())
+}
@@ -0,0 +1,17 @@
+mod wrong_name {
+    duchess::java_package! {
+        package java.lang;
+
+        public interface java.lang.Comparable<X> { * } //~ ERROR: does not match
+    }
+}
+
+mod wrong_count {
+    duchess::java_package! {
+        package java.lang;
+
+        public interface java.lang.Comparable<T, U> { * } //~ ERROR: generic parameter(s)
+    }
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+//@run
+use duchess::prelude::*;
+
+// `Dog` extends `Animal`, which implements `Named`. Declaring the supertypes
+// lets the binding skip `Animal` and upcast straight to `Named`.
+duchess::java_package! {
+    package hierarchy;
+
+    public class hierarchy.Dog extends java.lang.Object implements hierarchy.Named { * }
+    public interface hierarchy.Named { * }
+}
+
+fn name(named: &impl AsJRef<hierarchy::Named>) -> duchess::GlobalResult<String> {
+    let Ok(named) = named.as_jref() else {
+        panic!("named is null")
+    };
+    named.name().assert_not_null().to_rust().execute()
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let dog = hierarchy::Dog::new("Rex").global().execute()?;
+    let bark: String = dog.bark().assert_not_null().to_rust().execute()?;
+    assert_eq!(bark, "Rex says woof");
+    assert_eq!(name(&dog)?, "Rex");
+    Ok(())
+}
@@ -0,0 +1,17 @@
+mod wrong_superclass {
+    duchess::java_package! {
+        package hierarchy;
+
+        public class hierarchy.Dog extends java.lang.Number { * } //~ ERROR: not found in the reflected superclasses
+    }
+}
+
+mod wrong_interface {
+    duchess::java_package! {
+        package hierarchy;
+
+        public class hierarchy.Dog implements java.lang.Runnable { * } //~ ERROR: not found in the reflected interfaces
+    }
+}
+
+fn main() {}
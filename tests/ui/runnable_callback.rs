@@ -0,0 +1,82 @@
+//@run
+use std::sync::{
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+    Arc, Mutex,
+};
+
+use duchess::{java::lang::Runnable, prelude::*, Jvm};
+
+struct DropFlag(Arc<AtomicBool>);
+
+impl Drop for DropFlag {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let dropped = Arc::new(AtomicBool::new(false));
+
+    Jvm::with(|jvm| {
+        let flag = DropFlag(dropped.clone());
+        let counter = calls.clone();
+        let runnable = duchess::callback::runnable(
+            move || {
+                let _flag = &flag;
+                counter.fetch_add(1, Ordering::SeqCst);
+            },
+            jvm,
+        )?;
+
+        runnable.run().execute_with(jvm)?;
+        runnable.run().execute_with(jvm)?;
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert!(!dropped.load(Ordering::SeqCst));
+
+        // Destroying drops the closure; destroying twice is fine.
+        duchess::callback::destroy(&runnable, jvm)?;
+        assert!(dropped.load(Ordering::SeqCst));
+        duchess::callback::destroy(&runnable, jvm)?;
+
+        // `run()` throws once the closure is gone.
+        let result = runnable.run().execute_with(jvm);
+        assert!(matches!(result, Err(duchess::Error::Thrown(_))));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        Ok(())
+    })?;
+
+    // The closure can neither run itself again nor destroy itself while it is running.
+    let this: Arc<Mutex<Option<Global<Runnable>>>> = Arc::default();
+    let errors: Arc<Mutex<Vec<String>>> = Arc::default();
+    let runnable = Jvm::with(|jvm| {
+        let slot = this.clone();
+        let errors = errors.clone();
+        let runnable = duchess::callback::runnable(
+            move || {
+                let this = slot.lock().unwrap();
+                let this = this.as_ref().unwrap();
+                let run = this.run().execute();
+                let destroy = Jvm::with(|jvm| duchess::callback::destroy(this, jvm));
+                let mut errors = errors.lock().unwrap();
+                errors.push(run.unwrap_err().to_string());
+                errors.push(destroy.unwrap_err().to_string());
+            },
+            jvm,
+        )?;
+        *this.lock().unwrap() = Some(jvm.global(&*runnable));
+        Ok(jvm.global(&*runnable))
+    })?;
+    runnable.run().execute()?;
+    {
+        let errors = errors.lock().unwrap();
+        assert!(errors[0].contains("already running"), "{}", errors[0]);
+        assert!(errors[1].contains("closure is running"), "{}", errors[1]);
+    }
+
+    // The closure is still there, and can be destroyed once it has returned.
+    runnable.run().execute()?;
+    assert_eq!(errors.lock().unwrap().len(), 4);
+    Jvm::with(|jvm| duchess::callback::destroy(&runnable, jvm))?;
+    Ok(())
+}
@@ -0,0 +1,47 @@
+//@run
+use duchess::prelude::*;
+
+duchess::java_package! {
+    package shapes;
+
+    // `Shape` permits `Circle`, `Square`, and `Triangle`, which is not declared here.
+    public abstract class shapes.Shape { * }
+    public final class shapes.Circle { * }
+    public final class shapes.Square { * }
+}
+
+use shapes::{Shape, ShapeSealed};
+
+fn describe<J>(shape: J) -> duchess::GlobalResult<String>
+where
+    J: JvmOp,
+    for<'jvm> J::Output<'jvm>: AsJRef<Shape>,
+{
+    shape
+        .seal::<Shape>()
+        .and_then(|sealed, jvm| {
+            Ok(match sealed {
+                ShapeSealed::Circle(circle) => {
+                    format!("circle of radius {}", circle.radius().execute_with(jvm)?)
+                }
+                ShapeSealed::Square(square) => {
+                    format!("square of side {}", square.side().execute_with(jvm)?)
+                }
+                ShapeSealed::Unknown(shape) => {
+                    format!("shape of area {}", shape.area().execute_with(jvm)?)
+                }
+            })
+        })
+        .execute()
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    assert_eq!(describe(Shape::circle(2.0))?, "circle of radius 2");
+    assert_eq!(describe(Shape::square(3.0))?, "square of side 3");
+    assert_eq!(describe(Shape::triangle(4.0, 5.0))?, "shape of area 10");
+
+    // A reference typed as a permitted subclass can be sealed as well.
+    let circle = shapes::Circle::new(1.0).global().execute()?;
+    assert_eq!(describe(&circle)?, "circle of radius 1");
+    Ok(())
+}
@@ -0,0 +1,36 @@
+//@run
+use duchess::{java, prelude::*};
+
+// Every method of `Overloads` is overloaded; the parameter types pick one of each.
+duchess::java_package! {
+    package signatures;
+
+    public class signatures.Overloads {
+        count(int[]);
+        join(java.lang.String[]);
+        sum(int[][]);
+        max("[J");
+    }
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let count = signatures::Overloads::count(&[1, 2, 3][..]).execute()?;
+    assert_eq!(count, 3);
+
+    let parts = vec!["a".to_string(), "b".to_string()];
+    let joined: String =
+        signatures::Overloads::join(parts.to_java::<java::Array<java::lang::String>>())
+            .assert_not_null()
+            .to_rust()
+            .execute()?;
+    assert_eq!(joined, "a+b");
+
+    let rows = vec![vec![1, 2], vec![3, 4, 5]];
+    let sum =
+        signatures::Overloads::sum(rows.to_java::<java::Array<java::Array<i32>>>()).execute()?;
+    assert_eq!(sum, 15);
+
+    let max = signatures::Overloads::max(&[4_i64, 9, 2][..]).execute()?;
+    assert_eq!(max, 9);
+    Ok(())
+}
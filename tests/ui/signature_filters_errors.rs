@@ -0,0 +1,29 @@
+mod no_match {
+    duchess::java_package! {
+        package signatures;
+
+        public class signatures.Overloads { count(double[]); } //~ ERROR: no method of `signatures.Overloads` matches `count(double[])`; the `count` methods are `([I)I`, `([J)I`
+    }
+}
+
+mod bad_descriptor {
+    duchess::java_package! {
+        package signatures;
+
+        public class signatures.Overloads { max("[Q"); } //~ ERROR: `[Q` is not a valid list of JVM parameter descriptors
+    }
+}
+
+mod not_selected {
+    duchess::java_package! {
+        package signatures;
+
+        public class signatures.Overloads { sum(int[]); }
+    }
+
+    fn call() {
+        signatures::Overloads::join("a"); //~ ERROR: no function or associated item named `join`
+    }
+}
+
+fn main() {}
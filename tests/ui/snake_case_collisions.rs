@@ -0,0 +1,11 @@
+//@compile-flags: --crate-type lib
+
+duchess::java_package! {
+    package naming;
+
+    public class naming.Legacy {
+        public naming.Legacy();
+        public int getValue();
+        public int get_value(); //~ ERROR: would both be named `get_value` in Rust
+    }
+}
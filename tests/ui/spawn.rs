@@ -0,0 +1,39 @@
+//@run
+use duchess::{java, prelude::*, Jvm};
+
+use java::lang::Thread;
+
+pub fn main() -> duchess::GlobalResult<()> {
+    // Duchess calls work from the spawned thread, which stays attached across them,
+    // so the Java thread is the same for each call.
+    let handle = duchess::spawn(|| -> duchess::GlobalResult<_> {
+        let first = Thread::current_thread().global().execute()?;
+        let length = Jvm::with(|jvm| {
+            let spawned = "spawned".execute_with(jvm)?;
+            spawned.length().execute_with(jvm)
+        })?;
+        assert_eq!(length, 7);
+        let second = Thread::current_thread().global().execute()?;
+        assert!(first.equals(&second).execute()?);
+        Ok(first)
+    });
+    let java_thread = handle.join().unwrap()??;
+
+    // Once the closure has returned, the thread is detached, ending its Java thread.
+    assert!(!java_thread.is_alive().execute()?);
+
+    // The same goes for a closure that panics.
+    std::panic::set_hook(Box::new(|_| {}));
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let handle = duchess::spawn(move || {
+        let thread = Thread::current_thread().global().execute();
+        sender.send(thread).unwrap();
+        panic!("spawned thread panicked");
+    });
+    assert!(handle.join().is_err());
+    let _ = std::panic::take_hook();
+    let java_thread = receiver.recv().unwrap()?;
+    assert!(!java_thread.is_alive().execute()?);
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+//@run
+use duchess::prelude::*;
+
+// `format` and `fahrenheit` each have a static and an instance overload.
+duchess::java_package! {
+    package statics;
+
+    public class statics.Celsius { * }
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let hundred = statics::Celsius::of(100).global().execute()?;
+
+    // Instance methods keep their names, also in their fully qualified form...
+    let text: String = hundred.format().assert_not_null().to_rust().execute()?;
+    assert_eq!(text, "100 C");
+    let text: String = statics::Celsius::format(&hundred)
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(text, "100 C");
+    assert_eq!(hundred.fahrenheit().execute()?, 212);
+
+    // ...while the static methods of the same name get a `_static` suffix.
+    let text: String = statics::Celsius::format_static(-40)
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(text, "-40 C");
+    assert_eq!(statics::Celsius::fahrenheit_static(-40).execute()?, -40);
+    Ok(())
+}
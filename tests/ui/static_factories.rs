@@ -0,0 +1,35 @@
+//@run
+use duchess::{prelude::*, Local};
+
+duchess::java_package! {
+    package beans;
+
+    public class beans.Customer { of; getName; getAge; }
+}
+
+use beans::Customer;
+
+pub fn main() -> duchess::GlobalResult<()> {
+    // `Customer.of` returns a `Customer`, so its result is a non-null `Local<Customer>`
+    // that can be chained into instance methods directly.
+    assert_eq!(Customer::of("Ferris", 7).get_age().execute()?, 7);
+    let name: String = Customer::of("Duke", 29)
+        .get_name()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(name, "Duke");
+
+    duchess::Jvm::with(|jvm| {
+        let customer: Local<Customer> = Customer::of("Corro", 3).execute_with(jvm)?;
+        assert_eq!(customer.get_age().execute_with(jvm)?, 3);
+        Ok(())
+    })?;
+
+    // So do boxing factories, like `Integer.valueOf`.
+    let boxed = duchess::java::lang::Integer::value_of(7)
+        .global()
+        .execute()?;
+    assert_eq!(boxed.int_value().execute()?, 7);
+    Ok(())
+}
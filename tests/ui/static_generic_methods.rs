@@ -0,0 +1,37 @@
+//@run
+use duchess::java;
+use duchess::prelude::*;
+
+duchess::java_package! {
+    package generics;
+
+    public class generics.Pair<A, B> { * }
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    // Static generic methods of a generic class are called on the class without type
+    // arguments, and those of the method are inferred.
+    let pair: duchess::Global<generics::Pair<java::lang::String, java::lang::Integer>> =
+        generics::Pair::of("left", java::lang::Integer::value_of(1))
+            .global()
+            .execute()?;
+    let first: String = pair.first().assert_not_null().to_rust().execute()?;
+    assert_eq!(first, "left");
+
+    // Here `A` and `B` follow from the type of `pair`.
+    let swapped: duchess::Global<generics::Pair<java::lang::Integer, java::lang::String>> =
+        generics::Pair::swap(&pair).global().execute()?;
+    let second: String = swapped.second().assert_not_null().to_rust().execute()?;
+    assert_eq!(second, "left");
+
+    let order = java::lang::String::get_case_insensitive_order()
+        .assert_not_null()
+        .global()
+        .execute()?;
+    let nulls_last: duchess::Global<java::util::Comparator<java::lang::String>> =
+        java::util::Comparator::nulls_last(&order)
+            .global()
+            .execute()?;
+    assert!(nulls_last.compare("a", "B").execute()? < 0);
+    Ok(())
+}
@@ -0,0 +1,26 @@
+//@run
+use duchess::{java, prelude::*};
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let object = java::lang::Object::new().global().execute()?;
+    let description: String = object.to_string().assert_not_null().to_rust().execute()?;
+    assert!(description.starts_with("java.lang.Object@"));
+
+    // `StringBuilder(int)` takes the initial capacity.
+    let builder = java::lang::StringBuilder::new_from_i32(64)
+        .global()
+        .execute()?;
+    assert_eq!(builder.capacity().execute()?, 64);
+    builder.append("Hello, ").append("world").global().execute()?;
+    assert_eq!(builder.length().execute()?, 12);
+    let text: String = builder.to_string().assert_not_null().to_rust().execute()?;
+    assert_eq!(text, "Hello, world");
+
+    let empty: String = java::lang::StringBuilder::new()
+        .to_string()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(empty, "");
+    Ok(())
+}
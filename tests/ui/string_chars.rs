@@ -0,0 +1,43 @@
+//@run
+use duchess::prelude::*;
+use duchess::{java, Error};
+
+fn main() -> duchess::GlobalResult<()> {
+    let data = String::from("crab 🦀");
+    let text = data
+        .to_java::<java::lang::String>()
+        .assert_not_null()
+        .global()
+        .execute()?;
+
+    // A Java `char` is a `u16`, i.e. a UTF-16 code unit.
+    let c: u16 = text.char_at(1).execute()?;
+    assert_eq!(c, 'r' as u16);
+    let units: Vec<u16> = text.to_char_array().assert_not_null().to_rust().execute()?;
+    assert_eq!(units, data.encode_utf16().collect::<Vec<_>>());
+
+    // Rust `char`s can be passed where they fit into a single UTF-16 code unit...
+    let replaced: Option<String> = text.replace('a', 'ä').to_rust().execute()?;
+    assert_eq!(replaced.as_deref(), Some("cräb 🦀"));
+
+    // ...and are an error otherwise.
+    let result = text.replace('a', '🦀').global().execute();
+    assert!(matches!(result, Err(Error::CharOutOfRange('🦀'))));
+    let result = text.replace('a', '\u{10000}').global().execute();
+    assert!(matches!(result, Err(Error::CharOutOfRange('\u{10000}'))));
+
+    // Java `char`s convert back to Rust `char`s, except for the halves of a surrogate pair.
+    let c: char = text.char_at(1).to_rust().execute()?;
+    assert_eq!(c, 'r');
+    let supplementary = String::from("\u{10000}")
+        .to_java::<java::lang::String>()
+        .assert_not_null()
+        .global()
+        .execute()?;
+    let result = supplementary.char_at(0).to_rust::<char>().execute();
+    assert!(matches!(result, Err(Error::SurrogateChar(0xd800))));
+    let result = supplementary.char_at(1).to_rust::<char>().execute();
+    assert!(matches!(result, Err(Error::SurrogateChar(0xdc00))));
+
+    Ok(())
+}
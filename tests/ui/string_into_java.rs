@@ -0,0 +1,26 @@
+//@ run
+
+use duchess::java;
+use duchess::prelude::*;
+
+fn main() -> duchess::GlobalResult<()> {
+    let map = java::util::HashMap::<java::lang::String, java::lang::String>::new()
+        .global()
+        .execute()?;
+
+    // Rust strings can be passed wherever a `java.lang.String` is expected.
+    let key: &str = "key";
+    let value: String = format!("value");
+    map.put(key, &value).global().execute()?;
+
+    let found: Option<String> = map.get(key).to_rust().execute()?;
+    assert_eq!(found.as_deref(), Some("value"));
+
+    // Non-ASCII text, including characters outside the BMP, survives the round trip.
+    let crab = String::from("crab 🦀");
+    map.put("crab", &crab).global().execute()?;
+    let found: Option<String> = map.get("crab").to_rust().execute()?;
+    assert_eq!(found.as_deref(), Some("crab 🦀"));
+
+    Ok(())
+}
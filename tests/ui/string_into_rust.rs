@@ -0,0 +1,41 @@
+//@ run
+
+use duchess::java;
+use duchess::prelude::*;
+use duchess::{Error, IntoRust, Jvm};
+
+fn main() -> duchess::GlobalResult<()> {
+    // Strings with characters outside the BMP are encoded with surrogate pairs in Modified UTF-8.
+    for data in ["", "Hello, Duchess!", "nul \0 byte", "crab 🦀 and ☃"] {
+        let round_trip: String = data.to_rust().execute()?;
+        assert_eq!(round_trip, data);
+    }
+
+    // Long strings convert without issue.
+    let long = "duchess🦀".repeat(1 << 16);
+    let round_trip: String = long.as_str().to_rust().execute()?;
+    assert_eq!(round_trip, long);
+
+    Jvm::with(|jvm| {
+        let map = java::util::HashMap::<java::lang::String, java::lang::String>::new()
+            .execute_with(jvm)?;
+        map.put("present", "value").execute_with(jvm)?;
+
+        // Methods returning `java.lang.String` may return null; that comes back as `None`...
+        let present: Option<String> = map.get("present").to_rust().execute_with(jvm)?;
+        assert_eq!(present.as_deref(), Some("value"));
+        let missing: Option<String> = map.get("missing").to_rust().execute_with(jvm)?;
+        assert_eq!(missing, None);
+
+        // ...or as an error, if the caller asserts it is not null.
+        let missing = map.get("missing").assert_not_null().execute_with(jvm);
+        assert!(matches!(missing, Err(Error::NullDeref)));
+
+        let value = map.get("present").execute_with(jvm)?.unwrap();
+        let value: String = value.into_rust(jvm)?;
+        assert_eq!(value, "value");
+        Ok(())
+    })?;
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+//@run
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use duchess::{java, prelude::*, Local};
+
+pub fn main() -> duchess::GlobalResult<()> {
+    // `Instant` -> `SystemTime`, including instants before the epoch, whose
+    // nanoseconds count forwards from the (earlier) second.
+    let time: SystemTime = java::time::Instant::of_epoch_second(1_700_000_000_i64, 123_456_789_i64)
+        .to_rust()
+        .execute()?;
+    assert_eq!(time, UNIX_EPOCH + Duration::new(1_700_000_000, 123_456_789));
+
+    let time: SystemTime = java::time::Instant::of_epoch_second(-2_i64, 250_000_000_i64)
+        .to_rust()
+        .execute()?;
+    assert_eq!(time, UNIX_EPOCH - Duration::new(1, 750_000_000));
+
+    // `Date` -> `SystemTime`
+    let date = java::util::Date::new().global().execute()?;
+    date.set_time(1_700_000_000_123_i64).execute()?;
+    let time: SystemTime = (&*date).to_rust().execute()?;
+    assert_eq!(time, UNIX_EPOCH + Duration::from_millis(1_700_000_000_123));
+
+    date.set_time(-1_i64).execute()?;
+    let time: SystemTime = (&*date).to_rust().execute()?;
+    assert_eq!(time, UNIX_EPOCH - Duration::from_millis(1));
+
+    // `SystemTime` -> `Instant`, wherever an `Instant` is expected.
+    duchess::Jvm::with(|jvm| {
+        let before_epoch = UNIX_EPOCH - Duration::new(1, 250_000_000);
+        let instant: Local<'_, java::time::Instant> = before_epoch.into_java(jvm)?;
+        assert_eq!(instant.get_epoch_second().execute_with(jvm)?, -2);
+        assert_eq!(instant.get_nano().execute_with(jvm)?, 750_000_000);
+
+        let now = SystemTime::now();
+        let instant: Local<'_, java::time::Instant> = now.into_java(jvm)?;
+        let round_trip: SystemTime = (&*instant).to_rust().execute_with(jvm)?;
+        assert_eq!(round_trip, now);
+
+        let epoch = java::time::Instant::get_epoch()
+            .assert_not_null()
+            .execute_with(jvm)?;
+        assert!(epoch.is_before(now).execute_with(jvm)?);
+        Ok(())
+    })
+}
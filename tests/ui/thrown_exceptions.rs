@@ -0,0 +1,51 @@
+//@run
+use duchess::{java, prelude::*};
+
+// `read` is declared to throw `files.LockedException`, which gets a struct even though it is
+// not listed here, as does its superclass `files.StoreException`.
+duchess::java_package! {
+    package files;
+
+    public class files.Store { read; }
+}
+
+fn read(name: &str) -> duchess::GlobalResult<String> {
+    files::Store::read(name)
+        .assert_not_null()
+        .to_rust()
+        .execute()
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    assert_eq!(read("notes")?, "contents of notes");
+
+    let error = read("missing").unwrap_err();
+    assert!(error.is::<java::io::FileNotFoundException>());
+    assert!(error.is::<java::io::IOException>());
+    assert!(!error.is::<files::StoreException>());
+
+    let error = read("locked").unwrap_err();
+    assert!(error.is::<files::LockedException>());
+    assert!(error.is::<files::StoreException>());
+    assert!(error.is::<java::io::IOException>());
+    assert!(!error.is::<java::io::FileNotFoundException>());
+
+    // Exceptions that are not declared can be checked the same way.
+    let error = read("").unwrap_err();
+    assert!(error.is::<java::lang::RuntimeException>());
+    assert!(!error.is::<java::io::IOException>());
+
+    // The generated classes can also be caught.
+    duchess::Jvm::with(|jvm| {
+        let locked = files::Store::read("locked")
+            .catch::<files::LockedException>()
+            .execute_with(jvm)?;
+        assert!(locked.is_err());
+        Ok(())
+    })?;
+
+    // Other errors are never exceptions.
+    let error: duchess::Error<duchess::Global<java::lang::Throwable>> = duchess::Error::NullDeref;
+    assert!(!error.is::<java::lang::Throwable>());
+    Ok(())
+}
@@ -3,9 +3,9 @@
 duchess::java_package! {
     package java.lang;
 
-    public class java.lang.Object { //~ ERROR: generic type parameter `Id { data: "bool" }` not among in-scope parameters: []
+    public class java.lang.Object {
         public java.lang.Object();
-        public native bool hashCode();
+        public native bool hashCode(); //~ ERROR: generic type parameter `Id { data: "bool" }` not among in-scope parameters: []
     }
 }
 
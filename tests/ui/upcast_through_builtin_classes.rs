@@ -0,0 +1,46 @@
+//@run
+use duchess::{java, prelude::*};
+
+// `AttributeList` only names `ArrayList` as its superclass, so the upcast
+// to `java.util.List` comes from following the hierarchy of the classes
+// that duchess declares itself in `duchess::java`. Likewise, `ElementKind`
+// can be upcast to `java.lang.Comparable`, which `java.lang.Enum` implements.
+mod our_java {
+    duchess::java_package! {
+        package javax.management;
+
+        public class javax.management.AttributeList extends java.util.ArrayList<java.lang.Object> {
+            public javax.management.AttributeList();
+        }
+
+        package javax.lang.model.element;
+
+        public final class javax.lang.model.element.ElementKind extends java.lang.Enum<javax.lang.model.element.ElementKind> {
+            public static javax.lang.model.element.ElementKind valueOf(java.lang.String);
+        }
+    }
+    pub use javax::*;
+}
+
+use our_java::lang::model::element::ElementKind;
+
+fn len(list: &impl AsJRef<java::util::List<java::lang::Object>>) -> duchess::GlobalResult<i32> {
+    let Ok(list) = list.as_jref() else {
+        panic!("list is null")
+    };
+    list.size().execute()
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let list = our_java::management::AttributeList::new()
+        .global()
+        .execute()?;
+    assert_eq!(len(&list)?, 0);
+
+    let kind = ElementKind::value_of("CLASS").global().execute()?;
+    let Ok(comparable) = AsJRef::<java::lang::Comparable<ElementKind>>::as_jref(&kind) else {
+        panic!("kind is null")
+    };
+    assert_eq!(comparable.compare_to(&kind).execute()?, 0);
+    Ok(())
+}
@@ -0,0 +1,53 @@
+//@run
+use duchess::prelude::*;
+
+// `use` lines let the member signatures refer to classes by their simple name.
+mod our_java {
+    duchess::java_package! {
+        package proto;
+
+        use java.lang.String;
+
+        public final class proto.Person extends java.lang.Record {
+            public proto.Person(String, String);
+            public String name();
+        }
+
+        package service;
+
+        use java.lang.String;
+        use proto.Person;
+
+        public class service.Greeter {
+            public service.Greeter();
+            public Person relocate(Person, String);
+        }
+    }
+    pub use proto::*;
+    pub use service::*;
+}
+
+// Imports also apply to the type arguments of reflected classes.
+mod specialized {
+    duchess::java_package! {
+        package java.lang;
+
+        use java.lang.String;
+
+        public interface java.lang.Comparable<String> { * }
+    }
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let person = our_java::Person::new("Ada", "London");
+    let moved = our_java::Greeter::new()
+        .relocate(person, "Paris")
+        .name()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(moved, "Ada");
+
+    let _: Option<duchess::Global<specialized::java::lang::Comparable>> = None;
+    Ok(())
+}
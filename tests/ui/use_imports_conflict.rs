@@ -0,0 +1,23 @@
+mod duplicate {
+    duchess::java_package! {
+        package java.util;
+
+        use java.lang.String;
+        use java.lang.String; //~ ERROR: imported more than once
+
+        public class java.util.ArrayList<E> { * }
+    }
+}
+
+mod ambiguous {
+    duchess::java_package! {
+        package java.util;
+
+        use java.util.List;
+        use java.awt.List; //~ ERROR: ambiguous import
+
+        public class java.util.ArrayList<E> { * }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,49 @@
+//@run
+use duchess::{java, prelude::*};
+
+duchess::java_package! {
+    package varargs;
+
+    public class varargs.Joiner { * }
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let joiner = varargs::Joiner::new(", ").global().execute()?;
+    // Varargs of objects take a tuple of arguments of any type, including none at all...
+    let name = "a".to_string();
+    let name = name.to_java::<java::lang::String>();
+    let answer = java::lang::Integer::value_of(42);
+    let joined: String = joiner
+        .join((name, answer, duchess::null_op::<java::lang::Object>()))
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(joined, "a, 42, null");
+    let joined: String = joiner.join(()).assert_not_null().to_rust().execute()?;
+    assert_eq!(joined, "");
+
+    // ...a Rust array of arguments of the same type...
+    let joined: String = varargs::Joiner::join_with("-", ["x", "y", "z"])
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(joined, "x-y-z");
+
+    // ...or, like in Java, an existing array.
+    let parts = vec!["b".to_string(), "c".to_string()];
+    let parts = parts
+        .to_java::<java::Array<java::lang::String>>()
+        .assert_not_null()
+        .global()
+        .execute()?;
+    let joined: String = varargs::Joiner::join_with("+", &parts)
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(joined, "b+c");
+
+    // Scalar varargs and plain array parameters take arrays.
+    assert_eq!(varargs::Joiner::sum(&[1, 2, 3][..]).execute()?, 6);
+    assert_eq!(varargs::Joiner::count(&parts).execute()?, 2);
+    Ok(())
+}
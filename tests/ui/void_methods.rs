@@ -0,0 +1,30 @@
+//@run
+use duchess::{java, prelude::*, Error};
+
+fn main() -> duchess::GlobalResult<()> {
+    let list = java::util::ArrayList::<java::lang::String>::new()
+        .global()
+        .execute()?;
+    list.add("a").execute()?;
+
+    // Void methods produce `()`...
+    let () = list.clear().execute()?;
+    assert_eq!(list.size().execute()?, 0);
+
+    // ...as do static ones, including those named after Rust keywords.
+    java::lang::Thread::sleep(1_i64).execute()?;
+    java::lang::Thread::r#yield().execute()?;
+
+    // Exceptions thrown by void methods are still errors.
+    let result = java::lang::Thread::sleep(-1_i64).execute();
+    let Err(Error::Thrown(exception)) = result else {
+        panic!("expected an `IllegalArgumentException`");
+    };
+    let message: String = exception
+        .to_string()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert!(message.contains("IllegalArgumentException"), "{message}");
+    Ok(())
+}
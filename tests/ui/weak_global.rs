@@ -0,0 +1,44 @@
+//@run
+use std::time::Duration;
+
+use duchess::{prelude::*, Jvm, WeakGlobal};
+
+duchess::java_package! {
+    package java.lang;
+
+    public class System { gc; }
+}
+
+type Object = duchess::java::lang::Object;
+
+fn is_alive(weak: &WeakGlobal<Object>) -> duchess::GlobalResult<bool> {
+    Jvm::with(|jvm| Ok(weak.upgrade(jvm).is_some()))
+}
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let object = Object::new().global().execute()?;
+    let weak = object.downgrade()?;
+
+    // The object is kept alive by the global reference...
+    java::lang::System::gc().execute()?;
+    assert!(is_alive(&weak)?);
+
+    // ...but not by the weak one. `System.gc()` is only a hint, so give it a few tries.
+    drop(object);
+    let mut tries = 0;
+    while is_alive(&weak)? {
+        tries += 1;
+        assert!(tries < 100, "object was not collected");
+        java::lang::System::gc().execute()?;
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    // Local references can be downgraded too, and upgraded within the same call.
+    Jvm::with(|jvm| {
+        let local = Object::new().execute_with(jvm)?;
+        let weak = local.downgrade();
+        let upgraded = weak.upgrade(jvm).expect("object is still referenced");
+        assert!(local.reference_eq(&upgraded).execute_with(jvm)?);
+        Ok(())
+    })
+}
@@ -0,0 +1,57 @@
+//@run
+use duchess::{java, prelude::*};
+
+duchess::java_package! {
+    package generics;
+
+    public class generics.Shelf { * }
+}
+
+use generics::Shelf;
+use java::lang::{Integer, Number, Object};
+
+pub fn main() -> duchess::GlobalResult<()> {
+    let shelf = Shelf::new().global().execute()?;
+
+    // `ArrayList<? extends Number>` is an `ArrayList<Wild<Number>>`, whose elements upcast to `Number`.
+    let numbers = shelf.numbers().assert_not_null().global().execute()?;
+    assert_eq!(numbers.size().execute()?, 2);
+    let second = numbers.get(1).upcast::<Number>().global().execute()?;
+    assert_eq!(second.int_value().execute()?, 2);
+
+    // `List<?>` is a `List<Wild<Object>>`.
+    let anything = shelf.anything().assert_not_null().global().execute()?;
+    let something: String = Object::to_string(anything.get(0))
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(something, "something");
+
+    // So is the `List<? super Integer>` of a field.
+    let sink = shelf.get_sink().assert_not_null().global().execute()?;
+    let first = sink.get(0).upcast::<Object>().global().execute()?;
+    assert_eq!(first.hash_code().execute()?, 42);
+
+    // Static methods, and `Class.getSuperclass()` (a `Class<? super T>`).
+    let number_class = Shelf::number_class().assert_not_null().global().execute()?;
+    let name: String = number_class
+        .get_name()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(name, "java.lang.Integer");
+
+    let integer = Integer::class_object().global().execute()?;
+    let superclass = integer
+        .get_superclass()
+        .assert_not_null()
+        .global()
+        .execute()?;
+    let name: String = superclass
+        .get_name()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(name, "java.lang.Number");
+    Ok(())
+}